@@ -15,3 +15,18 @@
 pub use arrow;
 pub use arrow_format;
 pub use parquet2 as parquet;
+use parquet2::compression::Compression;
+
+/// Whether the parquet pages compressed by the given codec can be decompressed by this build.
+/// Each codec is behind a `parquet-<codec>` feature of this crate, LZO is never supported.
+pub fn is_parquet_codec_enabled(compression: Compression) -> bool {
+    match compression {
+        Compression::Uncompressed => true,
+        Compression::Snappy => cfg!(feature = "parquet-snappy"),
+        Compression::Gzip => cfg!(feature = "parquet-gzip"),
+        Compression::Lz4 => cfg!(feature = "parquet-lz4"),
+        Compression::Zstd => cfg!(feature = "parquet-zstd"),
+        Compression::Brotli => cfg!(feature = "parquet-brotli"),
+        Compression::Lzo => false,
+    }
+}
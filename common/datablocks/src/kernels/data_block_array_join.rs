@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::DataBlock;
+
+impl DataBlock {
+    /// Expands each element of the list column `column` into its own row, the values of the other
+    /// columns are replicated. A row with an empty list is dropped, a row with a null list is kept
+    /// once with a null element.
+    pub fn array_join(raw: &DataBlock, column: &str) -> Result<DataBlock> {
+        let schema = raw.schema();
+        let join_index = schema.index_of(column)?;
+        let element_type = match schema.field(join_index).data_type() {
+            DataType::List(inner) => inner.data_type().clone(),
+            other => {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "arrayJoin requires a list column, but {} is {}",
+                    column, other
+                )));
+            }
+        };
+
+        let fields = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                if i == join_index {
+                    DataField::new(f.name(), element_type.clone(), true)
+                } else {
+                    f.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        let schema = DataSchemaRefExt::create(fields);
+
+        let array = raw.column(join_index).to_array()?;
+        let mut indices = Vec::with_capacity(raw.num_rows());
+        let mut elements = Vec::with_capacity(raw.num_rows());
+        for row in 0..raw.num_rows() {
+            match array.try_get(row)? {
+                DataValue::List(Some(values), _) => {
+                    indices.extend(std::iter::repeat(row).take(values.len()));
+                    elements.extend(values);
+                }
+                _ => {
+                    indices.push(row);
+                    elements.push(DataValue::from(&element_type));
+                }
+            }
+        }
+
+        if indices.is_empty() {
+            return Ok(DataBlock::empty_with_schema(schema));
+        }
+
+        let columns = raw
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                if i == join_index {
+                    let series = DataValue::try_into_data_array(&elements, &element_type)?;
+                    return Ok(DataColumn::Array(series));
+                }
+
+                match column {
+                    DataColumn::Array(array) => {
+                        let mut indices = indices.iter().copied();
+                        let series = unsafe { array.take_iter_unchecked(&mut indices) }?;
+                        Ok(DataColumn::Array(series))
+                    }
+                    DataColumn::Constant(v, _) => {
+                        Ok(DataColumn::Constant(v.clone(), indices.len()))
+                    }
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DataBlock::create(schema, columns))
+    }
+}
@@ -25,9 +25,9 @@ impl DataBlock {
         }
 
         let first_block = &blocks[0];
-        for block in blocks.iter() {
+        for (i, block) in blocks.iter().enumerate() {
             if block.schema().ne(first_block.schema()) {
-                return Result::Err(ErrorCode::DataStructMissMatch("Schema not matched"));
+                return Result::Err(schema_mismatch(first_block.schema(), block.schema(), i));
             }
         }
 
@@ -47,3 +47,32 @@ impl DataBlock {
         ))
     }
 }
+
+/// Names the first field of the block `index` which differs from the first block.
+fn schema_mismatch(expected: &DataSchemaRef, actual: &DataSchemaRef, index: usize) -> ErrorCode {
+    if expected.fields().len() != actual.fields().len() {
+        return ErrorCode::DataStructMissMatch(format!(
+            "Schema not matched, block {} has {} fields, but expected {}",
+            index,
+            actual.fields().len(),
+            expected.fields().len()
+        ));
+    }
+
+    let describe = |f: &DataField| {
+        let null = if f.is_nullable() { "NULL" } else { "NOT NULL" };
+        format!("{} {} {}", f.name(), f.data_type(), null)
+    };
+    for (i, (e, a)) in expected.fields().iter().zip(actual.fields()).enumerate() {
+        if e != a {
+            return ErrorCode::DataStructMissMatch(format!(
+                "Schema not matched, field {} of block {} is {}, but expected {}",
+                i,
+                index,
+                describe(a),
+                describe(e)
+            ));
+        }
+    }
+    ErrorCode::DataStructMissMatch("Schema not matched")
+}
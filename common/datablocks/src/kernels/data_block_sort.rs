@@ -38,6 +38,17 @@ impl DataBlock {
         sort_columns_descriptions: &[SortColumnDescription],
         limit: Option<usize>,
     ) -> Result<DataBlock> {
+        let indices = DataBlock::sort_indices(block, sort_columns_descriptions, limit)?;
+        DataBlock::block_take_by_indices(block, &[], indices.inner().values().as_slice())
+    }
+
+    /// Returns the permutation of the rows that sorts the block, truncated to `limit` rows.
+    /// Every key uses its own direction and null placement, later keys break the ties of earlier ones.
+    pub fn sort_indices(
+        block: &DataBlock,
+        sort_columns_descriptions: &[SortColumnDescription],
+        limit: Option<usize>,
+    ) -> Result<DFUInt32Array> {
         let order_columns = sort_columns_descriptions
             .iter()
             .map(|f| Ok(block.try_array_by_name(&f.column_name)?.get_array_ref()))
@@ -58,7 +69,7 @@ impl DataBlock {
             .collect::<Result<Vec<_>>>()?;
 
         let indices = arrow_sort::lexsort_to_indices(&order_arrays, limit)?;
-        DataBlock::block_take_by_indices(block, &[], indices.values())
+        Ok(DFUInt32Array::new(indices))
     }
 
     pub fn merge_sort_block(
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_data_block_array_join() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new(
+            "b",
+            DataType::List(Box::new(DataField::new("item", DataType::UInt16, true))),
+            true,
+        ),
+    ]);
+
+    let mut builder = get_list_builder(&DataType::UInt16, 3, 4);
+    builder.append_series(&Series::new(vec![1_u16, 2]));
+    builder.append_series(&Series::new(Vec::<u16>::new()));
+    builder.append_null();
+    builder.append_series(&Series::new(vec![3_u16]));
+
+    let raw = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1i64, 2, 3, 4]),
+        builder.finish().into_series(),
+    ]);
+
+    // The empty list of row 2 is dropped, the null list of row 3 is kept as a null element.
+    let joined = DataBlock::array_join(&raw, "b")?;
+    assert_eq!(4, joined.num_rows());
+    assert_eq!(
+        &DataField::new("b", DataType::UInt16, true),
+        joined.schema().field_with_name("b")?
+    );
+
+    let expected = vec![
+        "+---+------+",
+        "| a | b    |",
+        "+---+------+",
+        "| 1 | 1    |",
+        "| 1 | 2    |",
+        "| 3 | NULL |",
+        "| 4 | 3    |",
+        "+---+------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[joined]);
+
+    // Only list columns can be joined.
+    let err = DataBlock::array_join(&raw, "a").unwrap_err();
+    assert_eq!(
+        "Code: 7, displayText = arrayJoin requires a list column, but a is Int64.",
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -61,3 +61,76 @@ fn test_data_block_concat() -> Result<()> {
     common_datablocks::assert_blocks_eq(expected, &[results]);
     Ok(())
 }
+
+#[test]
+fn test_data_block_concat_nullable() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::String, true),
+    ]);
+
+    let blocks = vec![
+        DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![1i64, 2]),
+            Series::new(vec![Some("b1"), None]),
+        ]),
+        DataBlock::create_by_array(schema, vec![
+            Series::new(vec![3i64, 4]),
+            Series::new(vec![None, Some("b4")]),
+        ]),
+    ];
+
+    let results = DataBlock::concat_blocks(&blocks)?;
+    assert_eq!(blocks[0].schema(), results.schema());
+
+    let expected = vec![
+        "+---+------+",
+        "| a | b    |",
+        "+---+------+",
+        "| 1 | b1   |",
+        "| 2 | NULL |",
+        "| 3 | NULL |",
+        "| 4 | b4   |",
+        "+---+------+",
+    ];
+    common_datablocks::assert_blocks_eq(expected, &[results]);
+    Ok(())
+}
+
+#[test]
+fn test_data_block_concat_schema_mismatch() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1i64, 2]),
+        Series::new(vec!["b1", "b2"]),
+    ]);
+
+    let nullable_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::String, true),
+    ]);
+    let nullable_block = DataBlock::create_by_array(nullable_schema, vec![
+        Series::new(vec![3i64]),
+        Series::new(vec![Some("b3")]),
+    ]);
+
+    let err =
+        DataBlock::concat_blocks(&[block.clone(), block.clone(), nullable_block]).unwrap_err();
+    assert_eq!(
+        "Code: 17, displayText = Schema not matched, field 1 of block 2 is b String NULL, but expected b String NOT NULL.",
+        err.to_string()
+    );
+
+    let narrow_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+    let narrow_block = DataBlock::create_by_array(narrow_schema, vec![Series::new(vec![3i64])]);
+
+    let err = DataBlock::concat_blocks(&[block, narrow_block]).unwrap_err();
+    assert_eq!(
+        "Code: 17, displayText = Schema not matched, block 1 has 1 fields, but expected 2.",
+        err.to_string()
+    );
+    Ok(())
+}
@@ -72,6 +72,100 @@ fn test_data_block_sort() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_data_block_sort_multi_keys_with_nulls() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, true),
+        DataField::new("b", DataType::String, false),
+    ]);
+
+    let raw = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![Some(1i64), None, Some(2), Some(1), None]),
+        Series::new(vec!["x", "y", "z", "w", "v"]),
+    ]);
+
+    {
+        let options = vec![
+            SortColumnDescription {
+                column_name: "a".to_owned(),
+                asc: false,
+                nulls_first: true,
+            },
+            SortColumnDescription {
+                column_name: "b".to_owned(),
+                asc: true,
+                nulls_first: false,
+            },
+        ];
+        assert_eq!(
+            &[4, 1, 2, 3, 0],
+            DataBlock::sort_indices(&raw, &options, None)?
+                .inner()
+                .values()
+                .as_slice()
+        );
+
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+        let expected = vec![
+            "+------+---+",
+            "| a    | b |",
+            "+------+---+",
+            "| NULL | v |",
+            "| NULL | y |",
+            "| 2    | z |",
+            "| 1    | w |",
+            "| 1    | x |",
+            "+------+---+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    {
+        let options = vec![
+            SortColumnDescription {
+                column_name: "a".to_owned(),
+                asc: true,
+                nulls_first: false,
+            },
+            SortColumnDescription {
+                column_name: "b".to_owned(),
+                asc: false,
+                nulls_first: false,
+            },
+        ];
+        assert_eq!(
+            &[0, 3, 2, 1, 4],
+            DataBlock::sort_indices(&raw, &options, None)?
+                .inner()
+                .values()
+                .as_slice()
+        );
+        assert_eq!(
+            &[0, 3],
+            DataBlock::sort_indices(&raw, &options, Some(2))?
+                .inner()
+                .values()
+                .as_slice()
+        );
+
+        let results = DataBlock::sort_block(&raw, &options, None)?;
+        let expected = vec![
+            "+------+---+",
+            "| a    | b |",
+            "+------+---+",
+            "| 1    | x |",
+            "| 1    | w |",
+            "| 2    | z |",
+            "| NULL | y |",
+            "| NULL | v |",
+            "+------+---+",
+        ];
+        common_datablocks::assert_blocks_eq(expected, &[results]);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_data_block_merge_sort() -> Result<()> {
     let schema = DataSchemaRefExt::create(vec![
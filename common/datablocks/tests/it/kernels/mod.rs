@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod data_block_array_join;
 mod data_block_concat;
 mod data_block_filter;
 mod data_block_group_by;
@@ -189,6 +189,7 @@ where
                 let opt_rhs = rhs.get(0);
                 match opt_rhs {
                     None => Ok(DFUInt8Array::full_null(self.len()).into_series()),
+                    Some(rhs) if rhs.is_zero() => Err(ErrorCode::BadArguments("Division by zero")),
                     Some(rhs) => match self.data_type() {
                         DataType::UInt64 => {
                             let arr = self.array.as_any().downcast_ref::<UInt64Array>().unwrap();
@@ -234,6 +235,62 @@ where
     }
 }
 
+/// Applies an integer division like `op` on the rows where both sides are not null,
+/// errors if any of these rows divides by zero.
+fn checked_int_arithmetic<T, F>(
+    lhs: &DFPrimitiveArray<T>,
+    rhs: &DFPrimitiveArray<T>,
+    op: F,
+) -> Result<DFPrimitiveArray<T>>
+where
+    T: DFIntegerType + num::Zero,
+    F: Fn(T, T) -> T,
+{
+    let apply = |lhs: Option<&T>, rhs: Option<&T>| match (lhs, rhs) {
+        (Some(_), Some(rhs)) if rhs.is_zero() => Err(ErrorCode::BadArguments("Division by zero")),
+        (Some(lhs), Some(rhs)) => Ok(Some(op(*lhs, *rhs))),
+        _ => Ok(None),
+    };
+
+    match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => lhs
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(lhs, rhs)| apply(lhs, rhs))
+            .collect(),
+        // broadcast right path
+        (_, 1) => {
+            let rhs = rhs.get(0);
+            lhs.into_iter()
+                .map(|lhs| apply(lhs, rhs.as_ref()))
+                .collect()
+        }
+        (1, _) => {
+            let lhs = lhs.get(0);
+            rhs.into_iter()
+                .map(|rhs| apply(lhs.as_ref(), rhs))
+                .collect()
+        }
+        _ => unreachable!(),
+    }
+}
+
+impl<T> DFPrimitiveArray<T>
+where T: DFIntegerType + num::Zero + num::CheckedDiv + num::CheckedRem
+{
+    /// Integer division truncated toward zero, `MIN / -1` wraps around to `MIN`.
+    pub fn checked_int_div(&self, rhs: &Self) -> Result<Self> {
+        checked_int_arithmetic(self, rhs, |lhs, rhs| lhs.checked_div(&rhs).unwrap_or(lhs))
+    }
+
+    /// Remainder of the integer division truncated toward zero, it has the sign of `self`.
+    pub fn checked_rem(&self, rhs: &Self) -> Result<Self> {
+        checked_int_arithmetic(self, rhs, |lhs, rhs| {
+            lhs.checked_rem(&rhs).unwrap_or_else(T::zero)
+        })
+    }
+}
+
 impl<T> Neg for &DFPrimitiveArray<T>
 where
     T: DFPrimitiveType,
@@ -152,7 +152,7 @@ macro_rules! impl_cmp_common {
             if let Some(value) = $rhs.get(0) {
                 $self.comparison_scalar(value, Operator::$kop)
             } else {
-                Ok(DFBooleanArray::full(false, $self.len()))
+                Ok(DFBooleanArray::full_null($self.len()))
             }
         } else if $self.len() == 1 {
             $rhs.$neg_func($self)
@@ -291,7 +291,7 @@ macro_rules! impl_like_string {
             if let Some(value) = $rhs.get(0) {
                 $self.$scalar_op(value)
             } else {
-                Ok(DFBooleanArray::full(false, $self.len()))
+                Ok(DFBooleanArray::full_null($self.len()))
             }
         } else if $self.len() == 1 {
             if let Some(value) = $self.get(0) {
@@ -299,7 +299,7 @@ macro_rules! impl_like_string {
                 let left = DFStringArray::new_from_iter(it);
                 left.$op($rhs)
             } else {
-                Ok(DFBooleanArray::full(false, $rhs.len()))
+                Ok(DFBooleanArray::full_null($rhs.len()))
             }
         } else {
             $self.$op($rhs)
@@ -345,6 +345,8 @@ impl ArrayCompare<&DFNullArray> for DFNullArray {}
 
 impl ArrayCompare<&DFStructArray> for DFStructArray {}
 
+impl ArrayCompare<&DFDecimalArray> for DFDecimalArray {}
+
 macro_rules! impl_cmp_numeric_string_list {
     ($self:ident, $rhs:ident, $cmp_method:ident) => {{
         match ($self.null_count(), $rhs.null_count()) {
@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::array::*;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// DFDecimalArray wraps arrow's PrimitiveArray<i128>, each value is the unscaled integer
+/// of a fixed-point number, e.g. 123.45 is stored as 12345 with scale 2.
+#[derive(Debug, Clone)]
+pub struct DFDecimalArray {
+    pub(crate) array: PrimitiveArray<i128>,
+    data_type: DataType,
+}
+
+impl From<PrimitiveArray<i128>> for DFDecimalArray {
+    fn from(array: PrimitiveArray<i128>) -> Self {
+        Self::new(array)
+    }
+}
+
+impl DFDecimalArray {
+    pub fn new(array: PrimitiveArray<i128>) -> Self {
+        let data_type = array.data_type().into();
+        Self { array, data_type }
+    }
+
+    pub fn from_arrow_array(array: &dyn Array) -> Self {
+        Self::new(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap()
+                .clone(),
+        )
+    }
+
+    pub fn new_from_slice(precision: usize, scale: usize, v: &[i128]) -> Self {
+        let array = PrimitiveArray::<i128>::from_slice(v);
+        Self::new(array.to(ArrowDataType::Decimal(precision, scale)))
+    }
+
+    pub fn new_from_opt_slice(precision: usize, scale: usize, opt_v: &[Option<i128>]) -> Self {
+        let array = PrimitiveArray::<i128>::from(opt_v);
+        Self::new(array.to(ArrowDataType::Decimal(precision, scale)))
+    }
+
+    pub fn full(value: i128, precision: usize, scale: usize, length: usize) -> Self {
+        Self::new_from_slice(precision, scale, &vec![value; length])
+    }
+
+    pub fn full_null(precision: usize, scale: usize, length: usize) -> Self {
+        Self::new_from_opt_slice(precision, scale, &vec![None; length])
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn precision(&self) -> usize {
+        match self.data_type {
+            DataType::Decimal128(precision, _) => precision,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn scale(&self) -> usize {
+        match self.data_type {
+            DataType::Decimal128(_, scale) => scale,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn inner(&self) -> &PrimitiveArray<i128> {
+        &self.array
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<i128> {
+        match self.array.is_null(index) {
+            true => None,
+            false => Some(self.array.value(index)),
+        }
+    }
+
+    /// # Safety
+    /// Note this doesn't do any bound checking, for performance reason.
+    pub unsafe fn try_get(&self, index: usize) -> Result<DataValue> {
+        let v = match self.array.is_null(index) {
+            true => None,
+            false => Some(self.array.value_unchecked(index)),
+        };
+        Ok(DataValue::Decimal(v, self.precision(), self.scale()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        self.array.null_count()
+    }
+
+    #[inline]
+    pub fn is_null(&self, i: usize) -> bool {
+        self.array.is_null(i)
+    }
+
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.array.validity()
+    }
+
+    /// Take a view of top n elements
+    pub fn limit(&self, num_elements: usize) -> Self {
+        self.slice(0, num_elements)
+    }
+
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        let array = self.array.slice(offset, length);
+        Self::new(array)
+    }
+
+    pub fn collect_values(&self) -> Vec<Option<i128>> {
+        self.array.iter().map(|c| c.copied()).collect()
+    }
+
+    /// Unpack a array to the same physical type.
+    ///
+    /// # Safety
+    ///
+    /// This is unsafe as the data_type may be uncorrect and
+    /// is assumed to be correct in other unsafe code.
+    pub unsafe fn unpack(&self, array: &Series) -> Result<&Self> {
+        let array_trait = &**array;
+        if self.data_type() == array.data_type() {
+            let ca = &*(array_trait as *const dyn SeriesTrait as *const Self);
+            Ok(ca)
+        } else {
+            Err(ErrorCode::IllegalDataType(format!(
+                "cannot unpack array {:?} into matching type {:?}",
+                array,
+                self.data_type()
+            )))
+        }
+    }
+}
+
+/// Format the unscaled integer of a decimal as a fixed-point number, e.g. (-12345, 2) => "-123.45".
+pub fn format_decimal(value: i128, scale: usize) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
@@ -114,6 +114,21 @@ impl ListStringArrayBuilder {
 
         ListStringArrayBuilder { builder }
     }
+
+    pub fn append_slice(&mut self, opt_v: Option<&[&[u8]]>) {
+        match opt_v {
+            Some(items) => {
+                let values = self.builder.mut_values();
+                for item in items {
+                    values.push(Some(*item));
+                }
+                self.builder.try_push_valid().unwrap();
+            }
+            None => {
+                self.builder.push_null();
+            }
+        }
+    }
 }
 
 impl ListBuilderTrait for ListStringArrayBuilder {
@@ -21,6 +21,7 @@ mod trusted_len;
 mod upstream_traits;
 
 mod boolean;
+mod decimal;
 mod list;
 mod null;
 mod primitive;
@@ -31,6 +32,7 @@ pub use arithmetic::*;
 pub use boolean::*;
 pub use builder::*;
 pub use comparison::*;
+pub use decimal::*;
 pub use list::*;
 pub use null::*;
 pub use ops::*;
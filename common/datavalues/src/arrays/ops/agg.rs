@@ -356,3 +356,5 @@ impl ArrayAgg for DFListArray {}
 impl ArrayAgg for DFNullArray {}
 
 impl ArrayAgg for DFStructArray {}
+
+impl ArrayAgg for DFDecimalArray {}
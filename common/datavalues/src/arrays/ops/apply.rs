@@ -14,9 +14,19 @@
 use std::borrow::Cow;
 
 use common_arrow::arrow::compute::arity::unary;
+use common_exception::Result;
 
 use crate::prelude::*;
 
+/// How `try_apply_cast` handles a row whose closure returns an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryApplyMode {
+    /// The failed row becomes null.
+    NullOnError,
+    /// The first error is returned.
+    FailOnError,
+}
+
 macro_rules! apply {
     ($self:expr, $f:expr) => {{
         if $self.null_count() == 0 {
@@ -27,6 +37,20 @@ macro_rules! apply {
     }};
 }
 
+macro_rules! try_apply_cast {
+    ($self:expr, $iter:expr, $f:expr, $mode:expr) => {{
+        let mut builder = PrimitiveArrayBuilder::<S>::with_capacity($self.len());
+        for opt_v in $iter {
+            match opt_v.map($f).transpose() {
+                Ok(v) => builder.append_option(v),
+                Err(_) if $mode == TryApplyMode::NullOnError => builder.append_null(),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(builder.finish())
+    }};
+}
+
 macro_rules! apply_enumerate {
     ($self:expr, $f:expr) => {{
         if $self.null_count() == 0 {
@@ -52,6 +76,14 @@ pub trait ArrayApply<'a, A, B> {
         F: Fn(A) -> S + Copy,
         S: DFPrimitiveType;
 
+    /// Apply a fallible closure elementwise and cast to a Numeric DFPrimitiveArray.
+    ///
+    /// Null values remain null, a failed row becomes null or fails the whole array depending on the mode.
+    fn try_apply_cast<F, S>(&'a self, f: F, mode: TryApplyMode) -> Result<DFPrimitiveArray<S>>
+    where
+        F: Fn(A) -> Result<S> + Copy,
+        S: DFPrimitiveType;
+
     /// Apply a closure on optional values and cast to Numeric DFPrimitiveArray without null values.
     fn branch_apply_cast_numeric_no_null<F, S>(&'a self, f: F) -> DFPrimitiveArray<S>
     where
@@ -88,6 +120,14 @@ where T: DFPrimitiveType
         DFPrimitiveArray::<S>::new(array)
     }
 
+    fn try_apply_cast<F, S>(&self, f: F, mode: TryApplyMode) -> Result<DFPrimitiveArray<S>>
+    where
+        F: Fn(T) -> Result<S> + Copy,
+        S: DFPrimitiveType,
+    {
+        try_apply_cast!(self, self.into_iter().map(|v| v.copied()), f, mode)
+    }
+
     fn branch_apply_cast_numeric_no_null<F, S>(&self, f: F) -> DFPrimitiveArray<S>
     where
         F: Fn(Option<T>) -> S + Copy,
@@ -141,6 +181,14 @@ impl<'a> ArrayApply<'a, bool, bool> for DFBooleanArray {
         to_primitive::<S>(values, validity)
     }
 
+    fn try_apply_cast<F, S>(&self, f: F, mode: TryApplyMode) -> Result<DFPrimitiveArray<S>>
+    where
+        F: Fn(bool) -> Result<S> + Copy,
+        S: DFPrimitiveType,
+    {
+        try_apply_cast!(self, self.into_iter(), f, mode)
+    }
+
     fn branch_apply_cast_numeric_no_null<F, S>(&self, f: F) -> DFPrimitiveArray<S>
     where
         F: Fn(Option<bool>) -> S + Copy,
@@ -179,6 +227,14 @@ impl<'a> ArrayApply<'a, &'a [u8], Cow<'a, [u8]>> for DFStringArray {
         to_primitive::<S>(av, validity.cloned())
     }
 
+    fn try_apply_cast<F, S>(&'a self, f: F, mode: TryApplyMode) -> Result<DFPrimitiveArray<S>>
+    where
+        F: Fn(&'a [u8]) -> Result<S> + Copy,
+        S: DFPrimitiveType,
+    {
+        try_apply_cast!(self, self.into_iter(), f, mode)
+    }
+
     fn branch_apply_cast_numeric_no_null<F, S>(&'a self, f: F) -> DFPrimitiveArray<S>
     where
         F: Fn(Option<&'a [u8]>) -> S + Copy,
@@ -114,3 +114,12 @@ impl ArrayCast for DFNullArray {
 
 impl ArrayCast for DFListArray {}
 impl ArrayCast for DFStructArray {}
+
+impl ArrayCast for DFDecimalArray {
+    fn cast_with_type(&self, data_type: &DataType) -> Result<Series> {
+        if data_type == self.data_type() {
+            return Ok(self.clone().into_series());
+        }
+        cast_ca(&self.array, data_type)
+    }
+}
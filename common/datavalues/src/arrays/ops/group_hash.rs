@@ -111,3 +111,5 @@ impl GroupHash for DFStringArray {
 impl GroupHash for DFListArray {}
 impl GroupHash for DFNullArray {}
 impl GroupHash for DFStructArray {}
+
+impl GroupHash for DFDecimalArray {}
@@ -162,3 +162,5 @@ impl ArrayIf for DFNullArray {
 
 impl ArrayIf for DFListArray {}
 impl ArrayIf for DFStructArray {}
+
+impl ArrayIf for DFDecimalArray {}
@@ -212,3 +212,5 @@ impl ArrayScatter for DFStringArray {
 
 impl ArrayScatter for DFNullArray {}
 impl ArrayScatter for DFStructArray {}
+
+impl ArrayScatter for DFDecimalArray {}
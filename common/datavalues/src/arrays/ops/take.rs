@@ -250,6 +250,38 @@ impl ArrayTake for DFStringArray {
 impl ArrayTake for DFNullArray {}
 impl ArrayTake for DFStructArray {}
 
+impl ArrayTake for DFDecimalArray {
+    unsafe fn take_unchecked<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        let values: Vec<Option<i128>> = match indices {
+            TakeIdx::Array(array) => array
+                .iter()
+                .map(|idx| idx.and_then(|idx| self.get(*idx as usize)))
+                .collect(),
+            TakeIdx::Iter(iter) => iter.map(|idx| self.get(idx)).collect(),
+            TakeIdx::IterNulls(iter) => iter.map(|idx| idx.and_then(|idx| self.get(idx))).collect(),
+        };
+        Ok(Self::new_from_opt_slice(
+            self.precision(),
+            self.scale(),
+            &values,
+        ))
+    }
+
+    fn take<I, INulls>(&self, indices: TakeIdx<I, INulls>) -> Result<Self>
+    where
+        Self: std::marker::Sized,
+        I: Iterator<Item = usize>,
+        INulls: Iterator<Item = Option<usize>>,
+    {
+        unsafe { self.take_unchecked(indices) }
+    }
+}
+
 pub trait AsTakeIndex {
     fn as_take_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a>;
 
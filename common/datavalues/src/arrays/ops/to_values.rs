@@ -145,6 +145,15 @@ impl ToValues for DFNullArray {
     }
 }
 
+impl ToValues for DFDecimalArray {
+    fn to_values(&self) -> Result<Vec<DataValue>> {
+        let (precision, scale) = (self.precision(), self.scale());
+        Ok((0..self.len())
+            .map(|index| DataValue::Decimal(self.get(index), precision, scale))
+            .collect())
+    }
+}
+
 impl ToValues for DFStructArray {
     fn to_values(&self) -> Result<Vec<DataValue>> {
         let mut values = Vec::with_capacity(self.len());
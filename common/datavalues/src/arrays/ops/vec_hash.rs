@@ -99,4 +99,6 @@ impl VecHash for DFListArray {
 
 impl VecHash for DFStructArray {}
 
+impl VecHash for DFDecimalArray {}
+
 impl VecHash for DFNullArray {}
@@ -18,10 +18,20 @@ use common_arrow::arrow::buffer::MutableBuffer;
 
 use crate::prelude::*;
 
-/// tranform from DFStringArray to DFStringArray
+/// The spare capacity of `values` after the first `offset` bytes.
+///
 /// # Safety
-/// The caller must uphold the following invariants:
-/// * ensure the total len of transformed DFStringArray values <= estimate_bytes
+/// `offset` must not exceed the capacity of `values`.
+#[inline]
+unsafe fn spare_bytes(values: &mut MutableBuffer<u8>, offset: usize) -> &mut [u8] {
+    std::slice::from_raw_parts_mut(values.as_mut_ptr().add(offset), values.capacity() - offset)
+}
+
+/// tranform from DFStringArray to DFStringArray
+///
+/// `estimate_bytes` is the capacity reserved for the transformed values up front. If `f` is given
+/// a buffer smaller than the transformed value, it must return the number of bytes it needs
+/// instead, the buffer is then grown and `f` is called again with the same value until it fits.
 pub fn transform<F>(from: &DFStringArray, estimate_bytes: usize, mut f: F) -> DFStringArray
 where F: FnMut(&[u8], &mut [u8]) -> Option<usize> {
     let mut values: MutableBuffer<u8> = MutableBuffer::with_capacity(estimate_bytes);
@@ -33,12 +43,19 @@ where F: FnMut(&[u8], &mut [u8]) -> Option<usize> {
 
     unsafe {
         for x in from.into_no_null_iter() {
-            let bytes = std::slice::from_raw_parts_mut(
-                values.as_mut_ptr().add(offset),
-                values.capacity() - offset,
-            );
-            if let Some(len) = f(x, bytes) {
+            let mut res = f(x, spare_bytes(&mut values, offset));
+            // Every call is checked again, the value is only written once it fits.
+            while let Some(len) = res {
+                if len <= values.capacity() - offset {
+                    break;
+                }
+                values.reserve(len);
+                res = f(x, spare_bytes(&mut values, offset));
+            }
+
+            if let Some(len) = res {
                 offset += len;
+                values.set_len(offset);
                 offsets.push(i64::from_isize(offset as isize).unwrap());
                 validity.push(true);
             } else {
@@ -46,7 +63,6 @@ where F: FnMut(&[u8], &mut [u8]) -> Option<usize> {
                 validity.push(false);
             }
         }
-        values.set_len(offset);
         values.shrink_to_fit();
         let validity = combine_validities(from.array.validity(), Some(&validity.into()));
         DFStringArray::from_data_unchecked(offsets.into(), values.into(), validity)
@@ -54,9 +70,8 @@ where F: FnMut(&[u8], &mut [u8]) -> Option<usize> {
 }
 
 /// tranform from DFStringArray to DFStringArray
-/// # Safety
-/// The caller must uphold the following invariants:
-/// * ensure the len of transformed DFStringArray values <= estimate_bytes
+///
+/// Same as [`transform`], but `f` never turns a value into null.
 pub fn transform_with_no_null<F>(
     from: &DFStringArray,
     estimate_bytes: usize,
@@ -73,16 +88,17 @@ where
 
     unsafe {
         for x in from.into_no_null_iter() {
-            let bytes = std::slice::from_raw_parts_mut(
-                values.as_mut_ptr().add(offset),
-                values.capacity() - offset,
-            );
-            let len = f(x, bytes);
+            let mut len = f(x, spare_bytes(&mut values, offset));
+            // Every call is checked again, the value is only written once it fits.
+            while len > values.capacity() - offset {
+                values.reserve(len);
+                len = f(x, spare_bytes(&mut values, offset));
+            }
 
             offset += len;
+            values.set_len(offset);
             offsets.push(i64::from_isize(offset as isize).unwrap());
         }
-        values.set_len(offset);
         values.shrink_to_fit();
         DFStringArray::from_data_unchecked(
             offsets.into(),
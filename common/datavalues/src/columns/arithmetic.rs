@@ -80,6 +80,10 @@ impl Neg for &DataColumn {
     type Output = Result<DataColumn>;
 
     fn neg(self) -> Self::Output {
+        if self.data_type().is_null() {
+            return Ok(self.clone());
+        }
+
         let lhs = self.to_minimal_array()?;
         let lhs = Neg::neg(&lhs)?;
         let result: DataColumn = lhs.into();
@@ -94,7 +98,7 @@ impl IntDiv for &DataColumn {
         let lhs = self.to_minimal_array()?;
         let rhs = rhs.to_minimal_array()?;
         let result: DataColumn = lhs.int_div(&rhs)?.into();
-        Ok(result.resize_constant(result.len()))
+        Ok(result.resize_constant(self.len()))
     }
 }
 
@@ -18,10 +18,40 @@ use crate::columns::DataColumn;
 use crate::prelude::*;
 
 impl DataColumn {
+    /// Picks the row of `lhs` where `self` is true and the row of `rhs` otherwise,
+    /// a null condition is treated as false.
+    ///
+    /// A constant condition returns one of the branches as a whole.
     pub fn if_then_else(&self, lhs: &DataColumn, rhs: &DataColumn) -> Result<DataColumn> {
+        let dtype = aggregate_types(&[lhs.data_type(), rhs.data_type()])?;
+
+        let pick = |branch: &DataColumn| {
+            if branch.data_type() == dtype {
+                Ok(branch.clone())
+            } else {
+                branch.cast_with_type(&dtype)
+            }
+        };
+
+        if let DataColumn::Constant(v, _) = self {
+            return pick(if v.as_bool()? { lhs } else { rhs });
+        }
+
+        if self.data_type() == DataType::Null {
+            return pick(rhs);
+        }
+
         let cond = self.to_minimal_array()?;
+        let cond = cond.bool()?;
+        // The values of the null slots are undefined, mask them out so they pick `rhs`.
+        let cond = match cond.validity() {
+            Some(validity) if cond.null_count() > 0 => {
+                let values = cond.inner().values() & validity;
+                DFBooleanArray::from_arrow_data(values, None)
+            }
+            _ => cond.clone(),
+        };
 
-        let dtype = aggregate_types(&[lhs.data_type(), rhs.data_type()])?;
         let mut left = lhs.to_minimal_array()?;
         if left.data_type() != &dtype {
             left = left.cast_with_type(&dtype)?;
@@ -31,7 +61,7 @@ impl DataColumn {
             right = right.cast_with_type(&dtype)?;
         }
 
-        let result = left.if_then_else(&right, &cond)?;
+        let result = left.if_then_else(&right, &cond.into_series())?;
         let result: DataColumn = result.into();
         Ok(result.resize_constant(self.len()))
     }
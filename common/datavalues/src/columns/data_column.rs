@@ -118,6 +118,28 @@ impl DataColumn {
         }
     }
 
+    /// Converts all the columns into arrays. `to_array` allocates a new array for a constant on
+    /// every call, here the equal constants share the array of the first one instead.
+    pub fn to_arrays(columns: &[DataColumn]) -> Result<Vec<Series>> {
+        let mut arrays: Vec<Series> = Vec::with_capacity(columns.len());
+        for (i, column) in columns.iter().enumerate() {
+            let array = match column {
+                DataColumn::Array(array) => array.clone(),
+                DataColumn::Constant(value, size) => {
+                    let equal = columns[..i].iter().position(
+                        |c| matches!(c, DataColumn::Constant(v, s) if v == value && s == size),
+                    );
+                    match equal {
+                        Some(j) => arrays[j].clone(),
+                        None => value.to_series_with_size(*size)?,
+                    }
+                }
+            };
+            arrays.push(array);
+        }
+        Ok(arrays)
+    }
+
     #[inline]
     pub fn to_values(&self) -> Result<Vec<DataValue>> {
         match self {
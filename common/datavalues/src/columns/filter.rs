@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Filtering of DataColumn by a boolean mask.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+impl DataColumn {
+    /// Keeps the rows whose mask value is true, a null in the mask excludes the row like false.
+    /// A constant column stays a constant of the number of the kept rows.
+    pub fn filter(&self, mask: &DFBooleanArray) -> Result<DataColumn> {
+        if mask.len() != self.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The mask of filter must have the same length as the column, expected {}, but got {}",
+                self.len(),
+                mask.len()
+            )));
+        }
+
+        match self {
+            DataColumn::Constant(v, _) => {
+                let rows = mask.into_iter().filter(|v| *v == Some(true)).count();
+                Ok(DataColumn::Constant(v.clone(), rows))
+            }
+            DataColumn::Array(series) => {
+                let mut filtered = DataArrayFilter::filter_batch_array(vec![series.clone()], mask)?;
+                Ok(filtered.remove(0).into())
+            }
+        }
+    }
+}
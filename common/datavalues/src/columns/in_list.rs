@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// A list longer than this is probed with a `HashSet` instead of a linear scan.
+const IN_LIST_HASH_THRESHOLD: usize = 16;
+
+impl DataColumn {
+    /// Evaluates `self IN (list)`, or `self NOT IN (list)` if `negated`, to a boolean array of `self.len()` rows.
+    ///
+    /// The column and the list values are compared in the type they aggregate to.
+    /// Null follows the SQL semantics: a null input yields null,
+    /// and if no value matches but the list contains a null, the result is null instead of false(true for NOT IN).
+    pub fn eval_in_list(&self, list: &[DataValue], negated: bool) -> Result<DFBooleanArray> {
+        let mut types = vec![self.data_type()];
+        types.extend(list.iter().map(|v| v.data_type()));
+        let dtype = aggregate_types(&types)?;
+
+        let mut has_null = false;
+        let mut values = Vec::with_capacity(list.len());
+        for v in list {
+            let v = v
+                .to_series_with_size(1)?
+                .cast_with_type(&dtype)?
+                .try_get(0)?;
+            if v.is_null() {
+                has_null = true;
+            } else {
+                values.push(DataGroupValue::try_from(&v)?);
+            }
+        }
+
+        let set = match values.len() > IN_LIST_HASH_THRESHOLD {
+            true => Some(values.iter().cloned().collect::<HashSet<_>>()),
+            false => None,
+        };
+
+        let mut input = self.to_minimal_array()?;
+        if input.data_type() != &dtype {
+            input = input.cast_with_type(&dtype)?;
+        }
+
+        let mut result = Vec::with_capacity(input.len());
+        for i in 0..input.len() {
+            let v = input.try_get(i)?;
+            if v.is_null() {
+                result.push(None);
+                continue;
+            }
+
+            let v = DataGroupValue::try_from(&v)?;
+            let found = match &set {
+                Some(set) => set.contains(&v),
+                None => values.contains(&v),
+            };
+
+            result.push(match (found, has_null) {
+                (true, _) => Some(!negated),
+                (false, true) => None,
+                (false, false) => Some(negated),
+            });
+        }
+
+        match self {
+            DataColumn::Constant(_, size) => Ok(DFBooleanArray::new_from_opt_iter(
+                std::iter::repeat(result[0]).take(*size),
+            )),
+            DataColumn::Array(_) => Ok(DFBooleanArray::new_from_opt_slice(&result)),
+        }
+    }
+}
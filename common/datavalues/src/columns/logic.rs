@@ -51,7 +51,17 @@ impl DataColumn {
         match op {
             DataValueLogicOperator::And => apply_logic! {self, rhs, and_kleene},
             DataValueLogicOperator::Or => apply_logic! {self, rhs, or_kleene},
-            DataValueLogicOperator::Not => apply_logic! {self, not},
+            DataValueLogicOperator::Not => match self {
+                // Negate the scalar directly, instead of a round trip through a one row array.
+                DataColumn::Constant(DataValue::Boolean(v), rows) => Ok(DataColumn::Constant(
+                    DataValue::Boolean(v.map(|v| !v)),
+                    *rows,
+                )),
+                DataColumn::Constant(DataValue::Null, rows) => {
+                    Ok(DataColumn::Constant(DataValue::Boolean(None), *rows))
+                }
+                _ => apply_logic! {self, not},
+            },
         }
     }
 }
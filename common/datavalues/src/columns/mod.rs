@@ -17,8 +17,11 @@ mod common;
 mod comparison;
 mod conditional;
 mod data_column;
+mod filter;
+mod in_list;
 mod logic;
 mod nullable;
+mod take;
 
 pub use common::*;
 pub use comparison::*;
@@ -19,10 +19,27 @@ use crate::prelude::*;
 
 impl DataColumn {
     pub fn is_null(&self) -> Result<DataColumn> {
+        if let DataColumn::Constant(v, size) = self {
+            return Ok(DataColumn::Constant(
+                DataValue::Boolean(Some(v.is_null())),
+                *size,
+            ));
+        }
+
         if self.data_type() == DataType::Null {
             return Ok(DFBooleanArray::full(true, self.len()).into_series().into());
         }
 
+        // An array without nulls, e.g. of a non-nullable column, needs no bitmap.
+        if let DataColumn::Array(series) = self {
+            if series.null_count() == 0 {
+                return Ok(DataColumn::Constant(
+                    DataValue::Boolean(Some(false)),
+                    series.len(),
+                ));
+            }
+        }
+
         let input = self.to_minimal_array()?;
         let result = DFBooleanArray::new(compute::boolean::is_null(input.get_array_ref().as_ref()));
         let result: DataColumn = result.into_series().into();
@@ -30,10 +47,27 @@ impl DataColumn {
     }
 
     pub fn is_not_null(&self) -> Result<DataColumn> {
+        if let DataColumn::Constant(v, size) = self {
+            return Ok(DataColumn::Constant(
+                DataValue::Boolean(Some(!v.is_null())),
+                *size,
+            ));
+        }
+
         if self.data_type() == DataType::Null {
             return Ok(DFBooleanArray::full(false, self.len()).into_series().into());
         }
 
+        // An array without nulls, e.g. of a non-nullable column, needs no bitmap.
+        if let DataColumn::Array(series) = self {
+            if series.null_count() == 0 {
+                return Ok(DataColumn::Constant(
+                    DataValue::Boolean(Some(true)),
+                    series.len(),
+                ));
+            }
+        }
+
         let input = self.to_minimal_array()?;
         let result = DFBooleanArray::new(compute::boolean::is_not_null(
             input.get_array_ref().as_ref(),
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Gathering of DataColumn rows by index.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::compute::take;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+impl DataColumn {
+    /// Gathers the rows at the indices into a new column, in the order of the indices which may
+    /// repeat. A null index gives a null row. A constant column stays a constant of the number
+    /// of the indices if none of them is null, otherwise it is expanded to an array.
+    pub fn take(&self, indices: &DFUInt32Array) -> Result<DataColumn> {
+        let rows = self.len();
+        if let Some(index) = indices.iter().flatten().find(|v| **v as usize >= rows) {
+            return Err(ErrorCode::BadArguments(format!(
+                "The index {} of take is out of bounds of the column of {} rows",
+                index, rows
+            )));
+        }
+
+        match self {
+            DataColumn::Constant(v, _) if indices.null_count() == 0 => {
+                Ok(DataColumn::Constant(v.clone(), indices.len()))
+            }
+            _ => {
+                let series = self.to_array()?;
+                let array = take::take(series.get_array_ref().as_ref(), indices.inner())?;
+                let array: ArrayRef = Arc::from(array);
+                Ok(array.into_series().into())
+            }
+        }
+    }
+}
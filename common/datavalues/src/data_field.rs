@@ -82,11 +82,15 @@ impl DataField {
             DataType::Date16 => Some("Date16"),
             DataType::Date32 => Some("Date32"),
             DataType::DateTime32(_) => Some("DateTime32"),
+            DataType::DateTime64(_, _) => Some("DateTime64"),
             _ => None,
         };
 
         let custom_metadata = match self.data_type() {
             DataType::DateTime32(tz) => tz.clone(),
+            DataType::DateTime64(precision, tz) => {
+                Some(DataType::date_time64_metadata(*precision, tz))
+            }
             _ => None,
         };
 
@@ -118,6 +122,14 @@ impl From<&ArrowField> for DataField {
                     "Date16" => dt = DataType::Date16,
                     "Date32" => dt = DataType::Date32,
                     "DateTime32" => dt = DataType::DateTime32(metatada.cloned()),
+                    // A DateTime64 of an invalid precision is read as its Int64 ticks.
+                    "DateTime64" => {
+                        if let Some(t) =
+                            DataType::date_time64_from_metadata(metatada.map(|m| m.as_str()))
+                        {
+                            dt = t
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -52,6 +52,8 @@ pub enum DataValue {
     Float32(Option<f32>),
     Float64(Option<f64>),
     String(Option<Vec<u8>>),
+    /// Unscaled value with precision and scale.
+    Decimal(Option<i128>, usize, usize),
 
     // Container struct.
     List(Option<Vec<DataValue>>, DataType),
@@ -80,6 +82,7 @@ impl DataValue {
                 | DataValue::Float32(None)
                 | DataValue::Float64(None)
                 | DataValue::String(None)
+                | DataValue::Decimal(None, _, _)
                 | DataValue::Null
                 | DataValue::List(None, _)
         )
@@ -99,6 +102,7 @@ impl DataValue {
             DataValue::UInt64(_) => DataType::UInt64,
             DataValue::Float32(_) => DataType::Float32,
             DataValue::Float64(_) => DataType::Float64,
+            DataValue::Decimal(_, precision, scale) => DataType::Decimal128(*precision, *scale),
             DataValue::List(_, data_type) => {
                 DataType::List(Box::new(DataField::new("item", data_type.clone(), true)))
             }
@@ -177,6 +181,10 @@ impl DataValue {
                 None => Ok(DFStringArray::full_null(size).into_series()),
                 Some(v) => Ok(DFStringArray::full(v.deref(), size).into_series()),
             },
+            DataValue::Decimal(value, precision, scale) => match value {
+                None => Ok(DFDecimalArray::full_null(*precision, *scale, size).into_series()),
+                Some(v) => Ok(DFDecimalArray::full(*v, *precision, *scale, size).into_series()),
+            },
             DataValue::List(values, data_type) => match data_type {
                 DataType::Int8 => build_list_series! {i8, values, size, data_type },
                 DataType::Int16 => build_list_series! {i16, values, size, data_type },
@@ -319,6 +327,7 @@ impl DataValue {
             DataValue::UInt64(Some(v)) => Ok(*v as f64),
             DataValue::Float32(Some(v)) => Ok(*v as f64),
             DataValue::Float64(Some(v)) => Ok(*v),
+            DataValue::Decimal(Some(v), _, scale) => Ok(*v as f64 / 10f64.powi(*scale as i32)),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get f64 number",
                 other.data_type()
@@ -346,7 +355,11 @@ impl DataValue {
             DataType::Date16 => DataValue::UInt16(Some(0)),
             DataType::Date32 => DataValue::Int32(Some(0)),
             DataType::DateTime32(_) => DataValue::UInt32(Some(0)),
+            DataType::DateTime64(_, _) => DataValue::Int64(Some(0)),
             DataType::Interval(_) => DataValue::Int64(Some(0)),
+            DataType::Decimal128(precision, scale) => {
+                DataValue::Decimal(Some(0), *precision, *scale)
+            }
             DataType::List(f) => DataValue::List(Some(vec![]), f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
             DataType::String => DataValue::String(Some(vec![])),
@@ -365,6 +378,9 @@ impl DataValue {
             DataValue::Float32(Some(v)) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::Float64(Some(v)) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::String(Some(v)) => Ok(v.to_owned()),
+            DataValue::Decimal(Some(v), _, scale) => {
+                Ok(Vec::<u8>::from(format_decimal(*v, *scale)))
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{:?} to get string",
                 other.data_type()
@@ -459,10 +475,12 @@ impl From<&DataType> for DataValue {
             DataType::Date16 => DataValue::UInt16(None),
             DataType::Date32 => DataValue::Int32(None),
             DataType::DateTime32(_) => DataValue::UInt32(None),
+            DataType::DateTime64(_, _) => DataValue::Int64(None),
             DataType::List(f) => DataValue::List(None, f.data_type().clone()),
             DataType::Struct(_) => DataValue::Struct(vec![]),
             DataType::String => DataValue::String(None),
             DataType::Interval(_) => DataValue::Int64(None),
+            DataType::Decimal128(precision, scale) => DataValue::Decimal(None, *precision, *scale),
         }
     }
 }
@@ -501,6 +519,8 @@ impl fmt::Display for DataValue {
                     Ok(())
                 }
             },
+            DataValue::Decimal(None, ..) => write!(f, "NULL"),
+            DataValue::Decimal(Some(v), _, scale) => write!(f, "{}", format_decimal(*v, *scale)),
             DataValue::List(None, ..) => write!(f, "NULL"),
             DataValue::List(Some(v), ..) => {
                 write!(
@@ -537,6 +557,7 @@ impl fmt::Debug for DataValue {
             DataValue::Float64(v) => format_data_value_with_option!(f, v),
             DataValue::String(None) => write!(f, "{}", self),
             DataValue::String(Some(_)) => write!(f, "{}", self),
+            DataValue::Decimal(_, _, _) => write!(f, "{}", self),
             DataValue::List(_, _) => write!(f, "[{}]", self),
             DataValue::Struct(v) => write!(f, "{:?}", v),
         }
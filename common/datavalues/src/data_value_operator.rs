@@ -104,6 +104,13 @@ impl std::fmt::Display for DataValueLogicOperator {
     }
 }
 
+/// Where the nulls sort when comparing DataValues.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
 pub trait IntDiv<Rhs = Self> {
     type Output;
 
@@ -12,12 +12,81 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::prelude::*;
 
 impl DataValue {
+    /// Compares with another value, the nulls sort first, see `compare_with`.
+    pub fn compare(&self, other: &DataValue) -> Result<Ordering> {
+        self.compare_with(other, NullsOrder::First)
+    }
+
+    /// Compares with another value of a comparable type:
+    /// - A null of any type equals another null and sorts before or after any value as `nulls` says.
+    ///   A struct of only nulls is a null.
+    /// - Numbers compare by value whatever their types, integers and decimals exactly and the
+    ///   others as f64, a NaN is greater than any other number and equals a NaN.
+    /// - Booleans compare with booleans, false is less than true.
+    /// - Strings compare with strings by their bytes.
+    /// - Lists and structs compare their values in order, a shorter prefix is less.
+    ///
+    /// Any other pair, e.g. a string and a number, is an error.
+    pub fn compare_with(&self, other: &DataValue, nulls: NullsOrder) -> Result<Ordering> {
+        match (self.is_null(), other.is_null()) {
+            (true, true) => return Ok(Ordering::Equal),
+            (true, false) if nulls == NullsOrder::First => return Ok(Ordering::Less),
+            (true, false) => return Ok(Ordering::Greater),
+            (false, true) if nulls == NullsOrder::First => return Ok(Ordering::Greater),
+            (false, true) => return Ok(Ordering::Less),
+            (false, false) => {}
+        }
+
+        match (self, other) {
+            (DataValue::Boolean(Some(a)), DataValue::Boolean(Some(b))) => Ok(a.cmp(b)),
+            (DataValue::String(Some(a)), DataValue::String(Some(b))) => Ok(a.cmp(b)),
+            (DataValue::List(Some(a), _), DataValue::List(Some(b), _)) => {
+                compare_values(a, b, nulls)
+            }
+            (DataValue::Struct(a), DataValue::Struct(b)) => compare_values(a, b, nulls),
+            (a, b) if a.is_exact_number() && b.is_exact_number() => {
+                Ok(compare_decimals(a.as_scaled_i128()?, b.as_scaled_i128()?))
+            }
+            (a, b) if a.is_number() && b.is_number() => Ok(compare_f64(a.as_f64()?, b.as_f64()?)),
+            (a, b) => Err(ErrorCode::BadDataValueType(format!(
+                "Cannot compare {} of type {} with {} of type {}",
+                a,
+                a.data_type(),
+                b,
+                b.data_type()
+            ))),
+        }
+    }
+
+    fn is_number(&self) -> bool {
+        self.is_integer()
+            || matches!(
+                self,
+                DataValue::Float32(_) | DataValue::Float64(_) | DataValue::Decimal(_, _, _)
+            )
+    }
+
+    fn is_exact_number(&self) -> bool {
+        self.is_integer() || matches!(self, DataValue::Decimal(_, _, _))
+    }
+
+    /// The unscaled value and the scale of an integer or a decimal, an integer is of scale 0.
+    fn as_scaled_i128(&self) -> Result<(i128, usize)> {
+        match self {
+            DataValue::Decimal(Some(v), _, scale) => Ok((*v, *scale)),
+            _ if self.is_unsigned_integer() => Ok((self.as_u64()? as i128, 0)),
+            _ => Ok((self.as_i64()? as i128, 0)),
+        }
+    }
+
     pub fn custom_display(&self, single_quote: bool) -> String {
         let s = self.to_string();
         if single_quote {
@@ -102,6 +171,9 @@ impl DataValue {
             DataType::DateTime32(_) => {
                 try_build_array! {PrimitiveArrayBuilder, u32, UInt32, values}
             }
+            DataType::DateTime64(_, _) => {
+                try_build_array! {PrimitiveArrayBuilder, i64, Int64, values}
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{} for DataValue List",
                 other
@@ -109,3 +181,44 @@ impl DataValue {
         }
     }
 }
+
+fn compare_values(a: &[DataValue], b: &[DataValue], nulls: NullsOrder) -> Result<Ordering> {
+    for (a, b) in a.iter().zip(b.iter()) {
+        let ordering = a.compare_with(b, nulls)?;
+        if ordering != Ordering::Equal {
+            return Ok(ordering);
+        }
+    }
+    Ok(a.len().cmp(&b.len()))
+}
+
+/// Compares two unscaled values after rescaling them to the larger scale. A value which overflows
+/// i128 on rescaling is out of the range of the other one, so its sign decides.
+fn compare_decimals((a, a_scale): (i128, usize), (b, b_scale): (i128, usize)) -> Ordering {
+    match a_scale.cmp(&b_scale) {
+        Ordering::Equal => a.cmp(&b),
+        Ordering::Less => match rescale(a, b_scale - a_scale) {
+            Some(a) => a.cmp(&b),
+            None => a.cmp(&0),
+        },
+        Ordering::Greater => compare_decimals((b, b_scale), (a, a_scale)).reverse(),
+    }
+}
+
+fn rescale(v: i128, by: usize) -> Option<i128> {
+    if v == 0 {
+        return Some(0);
+    }
+    10i128
+        .checked_pow(by as u32)
+        .and_then(|factor| v.checked_mul(factor))
+}
+
+fn compare_f64(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
@@ -49,6 +49,7 @@ pub use crate::DataValueComparisonOperator::*;
 pub use crate::DataValueLogicOperator;
 pub use crate::DataValueLogicOperator::*;
 pub use crate::IntDiv;
+pub use crate::NullsOrder;
 
 pub type AlignedVec<T> = common_arrow::arrow::buffer::MutableBuffer<T>;
 pub type LargeBinaryArray = common_arrow::arrow::array::BinaryArray<i64>;
@@ -27,6 +27,28 @@ use common_exception::Result;
 use crate::prelude::*;
 use crate::DataValueArithmeticOperator;
 
+macro_rules! apply_checked_int_arithmetic {
+    ($lhs:expr, $rhs:expr, $method:ident) => {{
+        let result = match $lhs.data_type() {
+            DataType::UInt8 => $lhs.u8()?.$method($rhs.u8()?)?.into_series(),
+            DataType::UInt16 => $lhs.u16()?.$method($rhs.u16()?)?.into_series(),
+            DataType::UInt32 => $lhs.u32()?.$method($rhs.u32()?)?.into_series(),
+            DataType::UInt64 => $lhs.u64()?.$method($rhs.u64()?)?.into_series(),
+            DataType::Int8 => $lhs.i8()?.$method($rhs.i8()?)?.into_series(),
+            DataType::Int16 => $lhs.i16()?.$method($rhs.i16()?)?.into_series(),
+            DataType::Int32 => $lhs.i32()?.$method($rhs.i32()?)?.into_series(),
+            DataType::Int64 => $lhs.i64()?.$method($rhs.i64()?)?.into_series(),
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unsupported integer arithmetic for {:?}",
+                    other
+                )))
+            }
+        };
+        Result::Ok(result)
+    }};
+}
+
 impl Add for &Series {
     type Output = Result<Series>;
 
@@ -75,7 +97,12 @@ impl Rem for &Series {
         )?;
 
         let (lhs, rhs) = coerce_lhs_rhs_no_op(self, rhs)?;
-        let result = lhs.remainder(&rhs, &dtype)?;
+        let result =
+            if lhs.data_type().is_integer() && !(rhs.len() == 1 && dtype == DataType::UInt8) {
+                apply_checked_int_arithmetic!(lhs, rhs, checked_rem)?
+            } else {
+                lhs.remainder(&rhs, &dtype)?
+            };
 
         // then cast back to the lowest types
         if result.data_type() != &dtype {
@@ -105,6 +132,11 @@ impl IntDiv for &Series {
             rhs.data_type(),
         )?;
 
+        if self.data_type().is_integer() && rhs.data_type().is_integer() {
+            let (lhs, rhs) = coerce_lhs_rhs(&DataValueArithmeticOperator::IntDiv, self, rhs)?;
+            return apply_checked_int_arithmetic!(lhs, rhs, checked_int_div);
+        }
+
         let mut left = self.clone();
         if self.data_type() != &DataType::Float64 {
             left = self.cast_with_type(&DataType::Float64)?;
@@ -225,6 +257,7 @@ impl NumOpsDispatch for DFBooleanArray {}
 impl NumOpsDispatch for DFListArray {}
 impl NumOpsDispatch for DFNullArray {}
 impl NumOpsDispatch for DFStructArray {}
+impl NumOpsDispatch for DFDecimalArray {}
 
 fn coerce_lhs_rhs(
     op: &DataValueArithmeticOperator,
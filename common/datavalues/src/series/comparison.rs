@@ -45,6 +45,8 @@ macro_rules! impl_compare {
             DataType::Float64 => $self.f64().unwrap().$method($rhs.f64().unwrap()),
             DataType::Date16 => $self.u16().unwrap().$method($rhs.u16().unwrap()),
             DataType::Date32 => $self.i32().unwrap().$method($rhs.i32().unwrap()),
+            DataType::DateTime32(_) => $self.u32().unwrap().$method($rhs.u32().unwrap()),
+            DataType::DateTime64(_, _) => $self.i64().unwrap().$method($rhs.i64().unwrap()),
             _ => unimplemented!(),
         }
     }};
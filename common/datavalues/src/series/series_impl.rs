@@ -177,6 +177,14 @@ pub trait SeriesTrait: Send + Sync + fmt::Debug {
         )))
     }
 
+    /// Unpack to DFArray of data_type decimal
+    fn decimal(&self) -> Result<&DFDecimalArray> {
+        Err(ErrorCode::IllegalDataType(format!(
+            "{:?} != decimal",
+            self.data_type()
+        )))
+    }
+
     /// Unpack to DFArray of data_type struct
     fn tuple(&self) -> Result<&DFStructArray> {
         Err(ErrorCode::IllegalDataType(format!(
@@ -337,6 +345,7 @@ impl IntoSeries for ArrayRef {
 
             Float32 => DFFloat32Array::from_arrow_array(self.as_ref()).into_series(),
             Float64 => DFFloat64Array::from_arrow_array(self.as_ref()).into_series(),
+            Decimal128(_, _) => DFDecimalArray::from_arrow_array(self.as_ref()).into_series(),
 
             List(_) => DFListArray::from_arrow_array(self.as_ref()).into_series(),
             Struct(_) => DFStructArray::from_arrow_array(self.as_ref()).into_series(),
@@ -305,6 +305,18 @@ macro_rules! impl_dyn_array {
                 }
             }
 
+            /// Unpack to DFArray of data_type decimal
+            fn decimal(&self) -> Result<&DFDecimalArray> {
+                if matches!(self.0.data_type(), &DataType::Decimal128(_, _)) {
+                    unsafe { Ok(&*(self as *const dyn SeriesTrait as *const DFDecimalArray)) }
+                } else {
+                    Err(ErrorCode::IllegalDataType(format!(
+                        "cannot unpack Series of type {:?} into decimal",
+                        self.data_type(),
+                    )))
+                }
+            }
+
             /// Unpack to DFArray of data_type struct
             fn tuple(&self) -> Result<&DFStructArray> {
                 if matches!(self.0.data_type(), &DataType::Struct(_)) {
@@ -358,4 +370,5 @@ impl_dyn_array!(DFInt64Array);
 impl_dyn_array!(DFListArray);
 impl_dyn_array!(DFBooleanArray);
 impl_dyn_array!(DFStringArray);
+impl_dyn_array!(DFDecimalArray);
 impl_dyn_array!(DFStructArray);
@@ -22,6 +22,8 @@ use common_macros::MallocSizeOf;
 use crate::DataField;
 use crate::PhysicalDataType;
 
+pub const MAX_DATE_TIME64_PRECISION: u32 = 9;
+
 #[derive(
     serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, MallocSizeOf,
 )]
@@ -50,8 +52,17 @@ pub enum DataType {
     /// Option<String> indicates the timezone, if it's None, it's UTC
     DateTime32(Option<String>),
 
+    /// A 64-bit datetime representing the elapsed time since UNIX epoch (1970-01-01)
+    /// in ticks of 10^-precision seconds, e.g. milliseconds for DateTime64(3), it's physical type is Int64
+    /// Option<String> indicates the timezone, if it's None, it's UTC
+    DateTime64(u32, Option<String>),
+
     Interval(IntervalUnit),
 
+    /// A fixed-point number with precision and scale, e.g. Decimal128(5, 2) holds 123.45.
+    /// The value is stored as an unscaled 128-bit integer, it's physical type is Decimal128
+    Decimal128(usize, usize),
+
     List(Box<DataField>),
     Struct(Vec<DataField>),
     String,
@@ -134,7 +145,10 @@ impl DataType {
     pub fn is_date_or_date_time(&self) -> bool {
         matches!(
             self,
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+            DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
         )
     }
 
@@ -181,6 +195,40 @@ impl DataType {
         }
     }
 
+    /// The metadata of a DateTime64 field, the precision followed by the timezone if any, e.g. "3,Asia/Shanghai".
+    pub fn date_time64_metadata(precision: u32, tz: &Option<String>) -> String {
+        match tz {
+            Some(tz) => format!("{},{}", precision, tz),
+            None => format!("{}", precision),
+        }
+    }
+
+    /// The type from the metadata of a DateTime64 field, the precision is 3 if it is missing.
+    /// Returns None if the precision is invalid, i.e. not a number between 0 and 9.
+    pub fn date_time64_from_metadata(metadata: Option<&str>) -> Option<DataType> {
+        let metadata = metadata.unwrap_or("");
+        let (precision, tz) = match metadata.split_once(',') {
+            Some((precision, tz)) => (precision, Some(tz.to_string())),
+            None => (metadata, None),
+        };
+        let precision = match precision {
+            "" => 3,
+            precision => precision.parse().ok()?,
+        };
+        DataType::create_date_time64(precision, tz).ok()
+    }
+
+    /// A DateTime64 counts ticks of 10^-precision seconds, the precision is at most 9, i.e. nanoseconds.
+    pub fn create_date_time64(precision: u32, tz: Option<String>) -> Result<DataType> {
+        if precision > MAX_DATE_TIME64_PRECISION {
+            return Err(ErrorCode::BadArguments(format!(
+                "The precision of DateTime64 must be between 0 and {}, but got {}",
+                MAX_DATE_TIME64_PRECISION, precision
+            )));
+        }
+        Ok(DataType::DateTime64(precision, tz))
+    }
+
     pub fn to_arrow(&self) -> ArrowDataType {
         use DataType::*;
         match self {
@@ -200,6 +248,7 @@ impl DataType {
             Date32 => ArrowDataType::Int32,
             // we don't use DataType::Extension because extension types are not supported in parquet
             DateTime32(_) => ArrowDataType::UInt32,
+            DateTime64(_, _) => ArrowDataType::Int64,
             List(dt) => ArrowDataType::LargeList(Box::new(dt.to_arrow())),
             Struct(fs) => {
                 let arrows_fields = fs.iter().map(|f| f.to_arrow()).collect();
@@ -207,6 +256,7 @@ impl DataType {
             }
             String => ArrowDataType::LargeBinary,
             Interval(_) => ArrowDataType::Int64,
+            Decimal128(precision, scale) => ArrowDataType::Decimal(*precision, *scale),
         }
     }
 }
@@ -233,6 +283,7 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Boolean => DataType::Boolean,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
+            ArrowDataType::Decimal(precision, scale) => DataType::Decimal128(*precision, *scale),
             ArrowDataType::List(f) | ArrowDataType::LargeList(f) => {
                 let f: DataField = (f.as_ref()).into();
                 DataType::List(Box::new(f))
@@ -248,6 +299,10 @@ impl From<&ArrowDataType> for DataType {
                 "Date16" => DataType::Date16,
                 "Date32" => DataType::Date32,
                 "DateTime32" => DataType::DateTime32(extra.clone()),
+                // A DateTime64 of an invalid precision is read as its Int64 ticks.
+                "DateTime64" => {
+                    DataType::date_time64_from_metadata(extra.as_deref()).unwrap_or(DataType::Int64)
+                }
                 _ => unimplemented!("data_type: {}", dt),
             },
 
@@ -296,10 +351,20 @@ impl fmt::Debug for DataType {
                     write!(f, "DateTime32")
                 }
             }
+            Self::DateTime64(precision, tz) => {
+                if let Some(tz) = tz {
+                    write!(f, "DateTime64({}, {:?})", precision, tz)
+                } else {
+                    write!(f, "DateTime64({})", precision)
+                }
+            }
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
             Self::Struct(arg0) => f.debug_tuple("Struct").field(arg0).finish(),
             Self::String => write!(f, "String"),
             Self::Interval(unit) => write!(f, "Interval({})", unit),
+            Self::Decimal128(precision, scale) => {
+                write!(f, "Decimal128({}, {})", precision, scale)
+            }
         }
     }
 }
@@ -270,6 +270,11 @@ pub fn numerical_unary_arithmetic_coercion(
     op: &DataValueArithmeticOperator,
     val_type: &DataType,
 ) -> Result<DataType> {
+    // the unary arithmetic of null is null
+    if val_type.is_null() {
+        return Ok(DataType::Null);
+    }
+
     // error on any non-numeric type
     if !val_type.is_numeric() {
         return Result::Err(ErrorCode::BadDataValueType(format!(
@@ -298,6 +303,21 @@ pub fn numerical_unary_arithmetic_coercion(
     }
 }
 
+/// Coercion rule for bitwise operations: both sides must be integers, the result has the width
+/// of the wider side and is signed if either side is signed.
+pub fn bitwise_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    if !lhs_type.is_integer() || !rhs_type.is_integer() {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported bitwise operation between ({:?}) and ({:?})",
+            lhs_type, rhs_type
+        )));
+    }
+
+    let has_signed = lhs_type.is_signed_integer() || rhs_type.is_signed_integer();
+    let max_size = cmp::max(lhs_type.numeric_byte_size()?, rhs_type.numeric_byte_size()?);
+    construct_numeric_type(has_signed, false, max_size)
+}
+
 // coercion rules for compare operations. This is a superset of all numerical coercion rules.
 pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
     if lhs_type == rhs_type {
@@ -343,6 +363,17 @@ pub fn compare_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<Data
 
     // one of is datetime and other is number or string
     if lhs_type.is_date_or_date_time() || rhs_type.is_date_or_date_time() {
+        // one of is datetime64, keep the finer precision
+        match (lhs_type, rhs_type) {
+            (DataType::DateTime64(l, _), DataType::DateTime64(r, _)) => {
+                return Ok(DataType::DateTime64(*l.max(r), None));
+            }
+            (DataType::DateTime64(precision, _), _) | (_, DataType::DateTime64(precision, _)) => {
+                return Ok(DataType::DateTime64(*precision, None));
+            }
+            _ => {}
+        }
+
         // one of is datetime
         if matches!(lhs_type, DataType::DateTime32(_))
             || matches!(rhs_type, DataType::DateTime32(_))
@@ -22,6 +22,9 @@ use num::cast::AsPrimitive;
 pub trait DateConverter {
     fn to_date(&self, tz: &Tz) -> Date<Tz>;
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz>;
+    /// Converts a DateTime64 value, counted in ticks of 10^-precision seconds.
+    /// Returns None if the precision is greater than 9 or the time is out of the range of DateTime.
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> Option<DateTime<Tz>>;
 }
 
 impl<T> DateConverter for T
@@ -36,4 +39,13 @@ where T: AsPrimitive<i64>
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz> {
         tz.timestamp_millis(self.as_() * 1000)
     }
+
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> Option<DateTime<Tz>> {
+        let ticks_per_second = 10_i64.checked_pow(precision)?;
+        let nanos_per_tick = 10_i64.checked_pow(9_u32.checked_sub(precision)?)?;
+        let v: i64 = self.as_();
+        let nanos = v.rem_euclid(ticks_per_second) * nanos_per_tick;
+        tz.timestamp_opt(v.div_euclid(ticks_per_second), nanos as u32)
+            .single()
+    }
 }
@@ -82,3 +82,75 @@ where
         self.builder.finish().into_series()
     }
 }
+
+pub struct DateTime64Deserializer {
+    pub builder: PrimitiveArrayBuilder<i64>,
+    pub precision: u32,
+    pub tz: Tz,
+}
+
+impl TypeDeserializer for DateTime64Deserializer {
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: i64 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: i64 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        if reader.eq_ignore_ascii_case(b"null") {
+            self.builder.append_null();
+            return Ok(());
+        }
+
+        match lexical_core::parse::<i64>(reader) {
+            Ok(v) => {
+                self.builder.append_value(v);
+                Ok(())
+            }
+            Err(_) => {
+                let v = std::str::from_utf8(reader)
+                    .map_err_to_code(ErrorCode::BadBytes, || "Cannot convert value to utf8")?;
+                let res = self
+                    .tz
+                    .datetime_from_str(v, "%Y-%m-%d %H:%M:%S%.f")
+                    .map_err_to_code(ErrorCode::BadBytes, || {
+                        "Cannot parse value to DateTime64 type"
+                    })?;
+                // Digits finer than the precision are truncated.
+                let ticks = res
+                    .timestamp()
+                    .checked_mul(10_i64.pow(self.precision))
+                    .and_then(|t| {
+                        t.checked_add(
+                            (res.timestamp_subsec_nanos() / 10_u32.pow(9 - self.precision)) as i64,
+                        )
+                    })
+                    .ok_or_else(|| {
+                        ErrorCode::BadBytes(format!(
+                            "The time {} is out of the range of DateTime64({})",
+                            v, self.precision
+                        ))
+                    })?;
+                self.builder.append_value(ticks);
+                Ok(())
+            }
+        }
+    }
+
+    fn de_null(&mut self) {
+        self.builder.append_null()
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        self.builder.finish().into_series()
+    }
+}
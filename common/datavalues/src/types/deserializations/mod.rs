@@ -66,6 +66,15 @@ impl DataType {
                         tz: tz.parse::<Tz>().unwrap(),
                     }))
                 }
+                DataType::DateTime64(precision, tz) => {
+                    DataType::create_date_time64(precision, None)?;
+                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
+                    Ok(Box::new(DateTime64Deserializer {
+                        builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                        precision,
+                        tz: tz.parse::<Tz>().unwrap(),
+                    }))
+                }
                 DataType::String => Ok(Box::new(StringDeserializer {
                     builder: StringArrayBuilder::with_capacity(capacity),
                 })),
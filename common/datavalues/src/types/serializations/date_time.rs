@@ -17,6 +17,7 @@ use std::ops::AddAssign;
 
 use chrono::Duration;
 use chrono::NaiveDateTime;
+use chrono_tz::Tz;
 use common_exception::*;
 
 use crate::prelude::*;
@@ -64,3 +65,55 @@ impl<T: DFPrimitiveType> TypeSerializer for DateTimeSerializer<T> {
         Ok(result)
     }
 }
+
+pub struct DateTime64Serializer {
+    precision: u32,
+}
+
+impl DateTime64Serializer {
+    pub fn create(precision: u32) -> Self {
+        Self { precision }
+    }
+
+    /// Formats the value with exactly `precision` fractional digits, e.g. 2021-10-01 12:30:56.789 for DateTime64(3).
+    pub fn format(&self, v: i64) -> Result<String> {
+        let dt = v.to_date_time64(self.precision, &Tz::UTC).ok_or_else(|| {
+            ErrorCode::BadDataValueType(format!(
+                "The value {} is out of the range of DateTime64({})",
+                v, self.precision
+            ))
+        })?;
+        let mut s = dt.format("%Y-%m-%d %H:%M:%S").to_string();
+        if self.precision > 0 {
+            let fraction = v.rem_euclid(10_i64.pow(self.precision));
+            s.push_str(&format!(
+                ".{:0width$}",
+                fraction,
+                width = self.precision as usize
+            ));
+        }
+        Ok(s)
+    }
+}
+
+impl TypeSerializer for DateTime64Serializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        if value.is_null() {
+            return Ok("NULL".to_owned());
+        }
+        self.format(value.as_i64()?)
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array = array.i64()?;
+
+        array
+            .iter()
+            .map(|x| match x {
+                Some(v) => self.format(*v),
+                None => Ok("NULL".to_owned()),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::prelude::*;
+
+/// Formats a decimal with exactly `scale` fractional digits, e.g. 12.30 for Decimal128(5, 2).
+pub struct DecimalSerializer {
+    pub scale: usize,
+}
+
+impl TypeSerializer for DecimalSerializer {
+    fn serialize_value(&self, value: &DataValue) -> Result<String> {
+        match value {
+            DataValue::Decimal(Some(v), _, _) => Ok(format_decimal(*v, self.scale)),
+            v if v.is_null() => Ok("NULL".to_owned()),
+            v => Err(ErrorCode::BadDataValueType(format!(
+                "Cannot serialize {:?} as a decimal",
+                v
+            ))),
+        }
+    }
+
+    fn serialize_column(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array = array.decimal()?;
+
+        let result: Vec<String> = array
+            .collect_values()
+            .into_iter()
+            .map(|x| {
+                x.map(|v| format_decimal(v, self.scale))
+                    .unwrap_or_else(|| "NULL".to_owned())
+            })
+            .collect();
+        Ok(result)
+    }
+}
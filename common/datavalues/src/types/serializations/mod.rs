@@ -19,6 +19,7 @@ use crate::prelude::*;
 mod boolean;
 mod date;
 mod date_time;
+mod decimal;
 mod nulls;
 mod number;
 mod string;
@@ -27,6 +28,7 @@ mod r#struct;
 pub use boolean::*;
 pub use date::*;
 pub use date_time::*;
+pub use decimal::*;
 pub use nulls::*;
 pub use number::*;
 pub use r#struct::*;
@@ -55,6 +57,10 @@ impl DataType {
             DataType::Date16 => Box::new(DateSerializer::<u16>::default()),
             DataType::Date32 => Box::new(DateSerializer::<i32>::default()),
             DataType::DateTime32(_) => Box::new(DateTimeSerializer::<u32>::default()),
+            DataType::DateTime64(precision, _) => {
+                Box::new(DateTime64Serializer::create(*precision))
+            }
+            DataType::Decimal128(_, scale) => Box::new(DecimalSerializer { scale: *scale }),
             DataType::String => Box::new(StringSerializer {}),
             DataType::Struct(fields) => Box::new(StructSerializer {
                 fields: fields.to_vec(),
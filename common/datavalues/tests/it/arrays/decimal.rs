@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_decimal_array() -> Result<()> {
+    let data_array = DFDecimalArray::new_from_opt_slice(5, 2, &[Some(12345), None, Some(-5)]);
+
+    assert_eq!(3, data_array.len());
+    assert_eq!(1, data_array.null_count());
+    assert_eq!(&DataType::Decimal128(5, 2), data_array.data_type());
+    assert_eq!(
+        vec![Some(12345), None, Some(-5)],
+        data_array.collect_values()
+    );
+
+    let series = data_array.into_series();
+    assert_eq!(&DataType::Decimal128(5, 2), series.data_type());
+    assert_eq!(DataValue::Decimal(Some(12345), 5, 2), series.try_get(0)?);
+    assert_eq!(DataValue::Decimal(None, 5, 2), series.try_get(1)?);
+    assert_eq!(
+        vec![
+            DataValue::Decimal(Some(12345), 5, 2),
+            DataValue::Decimal(None, 5, 2),
+            DataValue::Decimal(Some(-5), 5, 2),
+        ],
+        series.to_values()?
+    );
+
+    // arrow round trip
+    let series = series.get_array_ref().into_series();
+    assert_eq!(&DataType::Decimal128(5, 2), series.data_type());
+    assert_eq!(
+        vec![Some(12345), None, Some(-5)],
+        series.decimal()?.collect_values()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_decimal_data_column() -> Result<()> {
+    let value = DataValue::Decimal(Some(12345), 5, 2);
+    assert_eq!(DataType::Decimal128(5, 2), value.data_type());
+
+    let column = DataColumn::Constant(value.clone(), 3);
+    assert_eq!(DataType::Decimal128(5, 2), column.data_type());
+
+    let array = column.to_array()?;
+    assert_eq!(3, array.len());
+    assert_eq!(&DataType::Decimal128(5, 2), array.data_type());
+    assert_eq!(vec![value.clone(); 3], array.to_values()?);
+
+    let column = DataColumn::Array(array);
+    assert_eq!(value, column.try_get(2)?);
+    assert_eq!(value, column.slice(1, 1).try_get(0)?);
+
+    let column = DataColumn::Constant(DataValue::Decimal(None, 5, 2), 2);
+    let array = column.to_array()?;
+    assert_eq!(2, array.null_count());
+    assert_eq!(DataValue::Decimal(None, 5, 2), array.try_get(0)?);
+    Ok(())
+}
+
+#[test]
+fn test_decimal_display() {
+    let tests = vec![
+        (DataValue::Decimal(Some(12345), 5, 2), "123.45"),
+        (DataValue::Decimal(Some(-12345), 5, 2), "-123.45"),
+        (DataValue::Decimal(Some(5), 5, 3), "0.005"),
+        (DataValue::Decimal(Some(-5), 5, 3), "-0.005"),
+        (DataValue::Decimal(Some(0), 5, 2), "0.00"),
+        (DataValue::Decimal(Some(42), 5, 0), "42"),
+        (DataValue::Decimal(None, 5, 2), "NULL"),
+    ];
+
+    for (value, expect) in tests {
+        assert_eq!(expect, format!("{}", value));
+        assert_eq!(expect, format!("{:?}", value));
+    }
+
+    assert_eq!(
+        "Decimal128(5, 2)",
+        format!("{}", DataType::Decimal128(5, 2))
+    );
+    assert_eq!(
+        b"-123.45".to_vec(),
+        DataValue::Decimal(Some(-12345), 5, 2).as_string().unwrap()
+    );
+}
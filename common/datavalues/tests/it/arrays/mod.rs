@@ -14,6 +14,7 @@
 
 mod arithmetic;
 mod boolean;
+mod decimal;
 mod ops;
 mod primitive;
 mod string;
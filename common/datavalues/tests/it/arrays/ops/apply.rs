@@ -16,6 +16,7 @@ use std::borrow::Cow;
 
 use common_arrow::arrow::array::Array;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 fn new_test_uint16_array(cap: usize, begin: i32, end: i32) -> DFPrimitiveArray<u16> {
@@ -225,3 +226,69 @@ fn test_string_array_apply() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_array_try_apply_cast() -> Result<()> {
+    // array=[null, "by", "cz", null, "13"]
+    let array = new_test_string_array(5, 0, 5);
+    let parse = |v: &[u8]| -> Result<u16> {
+        std::str::from_utf8(v)
+            .map_err(|e| ErrorCode::BadBytes(e.to_string()))?
+            .parse::<u16>()
+            .map_err(|e| ErrorCode::BadArguments(format!("Cannot parse value: {}", e)))
+    };
+
+    // The rows failed to parse become null.
+    let result = array.try_apply_cast(parse, TryApplyMode::NullOnError)?;
+    assert_eq!(
+        vec![None, None, None, None, Some(13)],
+        result.collect_values()
+    );
+
+    // The first failure is returned.
+    let result = array.try_apply_cast(parse, TryApplyMode::FailOnError);
+    let err = result.unwrap_err();
+    assert_eq!(ErrorCode::BadArguments("").code(), err.code());
+    assert_eq!(
+        "Code: 6, displayText = Cannot parse value: invalid digit found in string.",
+        err.to_string()
+    );
+
+    // No failure, the nulls remain null in both modes.
+    // array=[null, 1, 2, null, 4]
+    let array = new_test_uint16_array(5, 0, 5);
+    let hundredfold = |v: u16| -> Result<u8> {
+        u8::try_from(v * 100).map_err(|e| ErrorCode::Overflow(e.to_string()))
+    };
+    for mode in [TryApplyMode::NullOnError, TryApplyMode::FailOnError] {
+        let result = array.try_apply_cast(|v| hundredfold(v.min(2)), mode)?;
+        assert_eq!(
+            vec![None, Some(100), Some(200), None, Some(200)],
+            result.collect_values()
+        );
+    }
+
+    let result = array.try_apply_cast(hundredfold, TryApplyMode::NullOnError)?;
+    assert_eq!(
+        vec![None, Some(100), Some(200), None, None],
+        result.collect_values()
+    );
+
+    let result = array.try_apply_cast(hundredfold, TryApplyMode::FailOnError);
+    assert_eq!(ErrorCode::Overflow("").code(), result.unwrap_err().code());
+
+    // array=[null, true, false, null, true]
+    let array = new_test_boolean_array(5, 0, 5);
+    let result = array.try_apply_cast(
+        |v| match v {
+            true => Ok(1_u8),
+            false => Err(ErrorCode::BadArguments("false")),
+        },
+        TryApplyMode::NullOnError,
+    )?;
+    assert_eq!(
+        vec![None, Some(1), None, None, Some(1)],
+        result.collect_values()
+    );
+    Ok(())
+}
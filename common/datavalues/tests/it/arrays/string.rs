@@ -63,3 +63,64 @@ fn test_new_from_opt_iter() {
     assert_eq!(Some(None), iter.next());
     assert_eq!(None, iter.next());
 }
+
+#[test]
+fn test_transform_grows_buffer() {
+    let data_array = DFStringArray::new_from_opt_slice(&[Some("ab"), None, Some("cde")]);
+
+    // Repeats every value three times, starting from an empty buffer.
+    let repeated = transform_with_no_null(&data_array, 0, |v, buffer| {
+        let len = v.len() * 3;
+        if buffer.len() < len {
+            return len;
+        }
+        for chunk in buffer[..len].chunks_mut(v.len()) {
+            chunk.copy_from_slice(v);
+        }
+        len
+    });
+    let values: Vec<_> = repeated.into_iter().collect();
+    assert_eq!(
+        vec![
+            Some("ababab".as_bytes()),
+            None,
+            Some("cdecdecde".as_bytes())
+        ],
+        values
+    );
+
+    let repeated = transform(&data_array, 1, |v, buffer| {
+        let len = v.len() * 3;
+        if v == b"cde" {
+            return None;
+        }
+        if buffer.len() < len {
+            return Some(len);
+        }
+        for chunk in buffer[..len].chunks_mut(v.len()) {
+            chunk.copy_from_slice(v);
+        }
+        Some(len)
+    });
+    let values: Vec<_> = repeated.into_iter().collect();
+    assert_eq!(vec![Some("ababab".as_bytes()), None, None], values);
+
+    // The first answer is still too small, the value is asked again until it fits.
+    let long = "x".repeat(100);
+    let data_array = DFStringArray::new_from_slice(&[long.as_str()]);
+    let mut asked = false;
+    let repeated = transform_with_no_null(&data_array, 0, |v, buffer| {
+        let len = v.len() * 3;
+        if buffer.len() < len {
+            let need = if asked { len } else { v.len() };
+            asked = true;
+            return need;
+        }
+        for chunk in buffer[..len].chunks_mut(v.len()) {
+            chunk.copy_from_slice(v);
+        }
+        len
+    });
+    let values: Vec<_> = repeated.into_iter().collect();
+    assert_eq!(vec![Some(long.repeat(3).as_bytes())], values);
+}
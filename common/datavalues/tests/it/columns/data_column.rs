@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_data_column_to_arrays() -> Result<()> {
+    let columns = vec![
+        DataColumn::Constant(DataValue::String(Some(b"a".to_vec())), 4),
+        Series::new(vec!["b", "c", "d", "e"]).into(),
+        DataColumn::Constant(DataValue::String(Some(b"a".to_vec())), 4),
+    ];
+
+    // Without sharing, every conversion materializes a new array.
+    let a = columns[0].to_array()?;
+    let b = columns[2].to_array()?;
+    assert_ne!(a.get_data_ptr(), b.get_data_ptr());
+
+    let arrays = DataColumn::to_arrays(&columns)?;
+    assert_eq!(3, arrays.len());
+    assert_eq!(arrays[0].get_data_ptr(), arrays[2].get_data_ptr());
+    assert_ne!(arrays[0].get_data_ptr(), arrays[1].get_data_ptr());
+    // Arrays are never copied.
+    assert_eq!(
+        columns[1].to_array()?.get_data_ptr(),
+        arrays[1].get_data_ptr()
+    );
+    assert_eq!(
+        DataValue::String(Some(b"a".to_vec())),
+        arrays[2].try_get(3)?
+    );
+
+    // A different value or size is materialized on its own.
+    let columns = vec![
+        DataColumn::Constant(DataValue::Int64(Some(3)), 8),
+        DataColumn::Constant(DataValue::Int64(Some(4)), 8),
+        DataColumn::Constant(DataValue::Int64(Some(3)), 4),
+    ];
+    let arrays = DataColumn::to_arrays(&columns)?;
+    assert_ne!(arrays[0].get_data_ptr(), arrays[1].get_data_ptr());
+    assert_ne!(arrays[0].get_data_ptr(), arrays[2].get_data_ptr());
+    assert_eq!(4, arrays[2].len());
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_filter_array() -> Result<()> {
+    let column: DataColumn = Series::new(vec![1i32, 2, 3, 4, 5]).into();
+    let mask =
+        DFBooleanArray::new_from_opt_slice(&[Some(true), None, Some(false), Some(true), None]);
+
+    // The nulls of the mask are excluded like false.
+    let got = column.filter(&mask)?;
+    let expect: DataColumn = Series::new(vec![1i32, 4]).into();
+    assert_eq!(&expect, &got);
+    Ok(())
+}
+
+#[test]
+fn test_filter_constant() -> Result<()> {
+    let column = DataColumn::Constant(DataValue::Int32(Some(7)), 4);
+    let mask = DFBooleanArray::new_from_opt_slice(&[Some(true), Some(false), None, Some(true)]);
+
+    let got = column.filter(&mask)?;
+    match got {
+        DataColumn::Constant(DataValue::Int32(Some(7)), 2) => {}
+        got => panic!("expected a constant of 2 rows, but got {:?}", got),
+    }
+
+    // The mask must have a value for every row.
+    let mask = DFBooleanArray::new_from_slice(&[true]);
+    let err = column.filter(&mask).unwrap_err();
+    assert_eq!(
+        "Code: 6, displayText = The mask of filter must have the same length as the column, expected 4, but got 1.",
+        err.to_string()
+    );
+    Ok(())
+}
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_eval_in_list_numeric() -> Result<()> {
+    let column: DataColumn = Series::new(vec![Some(1i64), Some(2), Some(3), None]).into();
+    let list = vec![DataValue::Int64(Some(2)), DataValue::Int64(Some(3))];
+
+    let got = column.eval_in_list(&list, false)?;
+    assert_eq!(
+        vec![Some(false), Some(true), Some(true), None],
+        got.collect_values()
+    );
+
+    let got = column.eval_in_list(&list, true)?;
+    assert_eq!(
+        vec![Some(true), Some(false), Some(false), None],
+        got.collect_values()
+    );
+
+    // Int32 column against an Int64 list value out of the Int32 range.
+    let column: DataColumn = Series::new(vec![1i32, 7]).into();
+    let list = vec![DataValue::Int64(Some(7)), DataValue::Int64(Some(i64::MAX))];
+    let got = column.eval_in_list(&list, false)?;
+    assert_eq!(vec![Some(false), Some(true)], got.collect_values());
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_in_list_string() -> Result<()> {
+    let column: DataColumn = Series::new(vec!["a", "b", "c"]).into();
+    let list = vec![
+        DataValue::String(Some(b"a".to_vec())),
+        DataValue::String(Some(b"c".to_vec())),
+    ];
+
+    let got = column.eval_in_list(&list, false)?;
+    assert_eq!(
+        vec![Some(true), Some(false), Some(true)],
+        got.collect_values()
+    );
+
+    let got = column.eval_in_list(&list, true)?;
+    assert_eq!(
+        vec![Some(false), Some(true), Some(false)],
+        got.collect_values()
+    );
+
+    // A numeric column can not be compared with a string list.
+    let column: DataColumn = Series::new(vec![1i64]).into();
+    let got = column.eval_in_list(&list, false);
+    assert!(got.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_in_list_with_null() -> Result<()> {
+    let column: DataColumn = Series::new(vec![Some(1i64), Some(2), None]).into();
+    let list = vec![DataValue::Int64(Some(2)), DataValue::Null];
+
+    // No match with a null in list is null, not false.
+    let got = column.eval_in_list(&list, false)?;
+    assert_eq!(vec![None, Some(true), None], got.collect_values());
+
+    // NOT IN a list containing a null is never true.
+    let got = column.eval_in_list(&list, true)?;
+    assert_eq!(vec![None, Some(false), None], got.collect_values());
+
+    // Typed nulls in list.
+    let list = vec![DataValue::Int64(Some(2)), DataValue::Int64(None)];
+    let got = column.eval_in_list(&list, true)?;
+    assert_eq!(vec![None, Some(false), None], got.collect_values());
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_in_list_large_list() -> Result<()> {
+    let column: DataColumn = Series::new(vec![5u32, 200, 99]).into();
+    let list = (0..100u32)
+        .map(|v| DataValue::UInt32(Some(v)))
+        .collect::<Vec<_>>();
+
+    let got = column.eval_in_list(&list, false)?;
+    assert_eq!(
+        vec![Some(true), Some(false), Some(true)],
+        got.collect_values()
+    );
+
+    let got = column.eval_in_list(&list, true)?;
+    assert_eq!(
+        vec![Some(false), Some(true), Some(false)],
+        got.collect_values()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_in_list_constant() -> Result<()> {
+    let column = DataColumn::Constant(DataValue::Int64(Some(3)), 4);
+    let list = vec![DataValue::Int64(Some(3))];
+
+    let got = column.eval_in_list(&list, false)?;
+    assert_eq!(vec![Some(true); 4], got.collect_values());
+
+    let column = DataColumn::Constant(DataValue::Null, 2);
+    let got = column.eval_in_list(&list, false)?;
+    assert_eq!(vec![None; 2], got.collect_values());
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_logic_not_constant() -> Result<()> {
+    let tests = vec![
+        (
+            DataValue::Boolean(Some(true)),
+            DataValue::Boolean(Some(false)),
+        ),
+        (
+            DataValue::Boolean(Some(false)),
+            DataValue::Boolean(Some(true)),
+        ),
+        (DataValue::Boolean(None), DataValue::Boolean(None)),
+        (DataValue::Null, DataValue::Boolean(None)),
+    ];
+
+    for (value, expect) in tests {
+        let column = DataColumn::Constant(value.clone(), 3);
+        let got = column.logic(DataValueLogicOperator::Not, &[])?;
+        // The result stays a constant of the same rows.
+        match got {
+            DataColumn::Constant(got, 3) => assert_eq!(expect, got, "case: NOT {:?}", value),
+            got => panic!(
+                "case: NOT {:?}, expected a constant, but got {:?}",
+                value, got
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_logic_not_array() -> Result<()> {
+    let column: DataColumn = Series::new(vec![Some(true), Some(false), None]).into();
+    let got = column.logic(DataValueLogicOperator::Not, &[])?;
+    assert!(matches!(got, DataColumn::Array(_)));
+    assert_eq!(
+        vec![
+            DataValue::Boolean(Some(false)),
+            DataValue::Boolean(Some(true)),
+            DataValue::Boolean(None)
+        ],
+        got.to_values()?
+    );
+
+    Ok(())
+}
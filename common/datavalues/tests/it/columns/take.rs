@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_take_array() -> Result<()> {
+    let column: DataColumn = Series::new(vec![Some("a"), None, Some("c")]).into();
+
+    // Reorder, the null row stays null.
+    let indices = DFUInt32Array::new_from_slice(&[2, 1, 0]);
+    let got = column.take(&indices)?;
+    let expect: DataColumn = Series::new(vec![Some("c"), None, Some("a")]).into();
+    assert_eq!(&expect, &got);
+
+    // Repeated indices, a null index gives a null row.
+    let indices = DFUInt32Array::new_from_opt_slice(&[Some(0), Some(0), None, Some(2), Some(0)]);
+    let got = column.take(&indices)?;
+    let expect: DataColumn =
+        Series::new(vec![Some("a"), Some("a"), None, Some("c"), Some("a")]).into();
+    assert_eq!(&expect, &got);
+
+    let indices = DFUInt32Array::new_from_slice(&[0, 3]);
+    let err = column.take(&indices).unwrap_err();
+    assert_eq!(
+        "Code: 6, displayText = The index 3 of take is out of bounds of the column of 3 rows.",
+        err.to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_take_constant() -> Result<()> {
+    let column = DataColumn::Constant(DataValue::UInt64(Some(42)), 3);
+    let indices = DFUInt32Array::new_from_slice(&[2, 2, 1, 0, 1]);
+
+    let got = column.take(&indices)?;
+    match got {
+        DataColumn::Constant(DataValue::UInt64(Some(42)), 5) => {}
+        got => panic!("expected a constant of 5 rows, but got {:?}", got),
+    }
+
+    // A null index gives a null row, so the constant is expanded.
+    let indices = DFUInt32Array::new_from_opt_slice(&[Some(2), None, Some(0)]);
+    let got = column.take(&indices)?;
+    let expect: DataColumn = Series::new(vec![Some(42u64), None, Some(42u64)]).into();
+    assert_eq!(&expect, &got);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+#[test]
+fn test_data_value_compare() -> Result<()> {
+    let tests = vec![
+        (
+            DataValue::Int32(Some(1)),
+            DataValue::Int32(Some(2)),
+            Ordering::Less,
+        ),
+        (
+            DataValue::UInt64(Some(u64::MAX)),
+            DataValue::Int64(Some(-1)),
+            Ordering::Greater,
+        ),
+        (
+            DataValue::Int8(Some(3)),
+            DataValue::UInt16(Some(3)),
+            Ordering::Equal,
+        ),
+        (
+            DataValue::Float64(Some(1.5)),
+            DataValue::Int32(Some(1)),
+            Ordering::Greater,
+        ),
+        (
+            DataValue::Float32(Some(-0.5)),
+            DataValue::Float64(Some(0.0)),
+            Ordering::Less,
+        ),
+        (
+            DataValue::Float64(Some(f64::NAN)),
+            DataValue::Float64(Some(f64::INFINITY)),
+            Ordering::Greater,
+        ),
+        (
+            DataValue::Float64(Some(f64::NAN)),
+            DataValue::Float64(Some(f64::NAN)),
+            Ordering::Equal,
+        ),
+        (
+            DataValue::Decimal(Some(12345), 5, 2),
+            DataValue::Int32(Some(123)),
+            Ordering::Greater,
+        ),
+        // The decimals below are equal as f64.
+        (
+            DataValue::Decimal(Some(100_000_000_000_000_000_001), 30, 2),
+            DataValue::Decimal(Some(100_000_000_000_000_000_000), 30, 2),
+            Ordering::Greater,
+        ),
+        (
+            DataValue::Decimal(Some(1_234_567_890_123_456_789_012), 30, 2),
+            DataValue::Decimal(Some(12_345_678_901_234_567_890_121), 30, 3),
+            Ordering::Less,
+        ),
+        (
+            DataValue::Decimal(Some(922_337_203_685_477_580_701), 25, 2),
+            DataValue::Int64(Some(i64::MAX)),
+            Ordering::Greater,
+        ),
+        (
+            DataValue::Decimal(Some(1_000_000_000_000_000_000_000), 30, 3),
+            DataValue::Decimal(Some(1_000_000_000_000_000_000), 30, 0),
+            Ordering::Equal,
+        ),
+        // -10^37 overflows i128 at scale 38.
+        (
+            DataValue::Decimal(
+                Some(-10_000_000_000_000_000_000_000_000_000_000_000_000),
+                38,
+                0,
+            ),
+            DataValue::Decimal(Some(1), 38, 38),
+            Ordering::Less,
+        ),
+        (
+            DataValue::Boolean(Some(false)),
+            DataValue::Boolean(Some(true)),
+            Ordering::Less,
+        ),
+        (
+            DataValue::String(Some(b"ab".to_vec())),
+            DataValue::String(Some(b"b".to_vec())),
+            Ordering::Less,
+        ),
+        (
+            DataValue::List(
+                Some(vec![DataValue::Int32(Some(1)), DataValue::Int32(Some(2))]),
+                DataType::Int32,
+            ),
+            DataValue::List(Some(vec![DataValue::Int32(Some(1))]), DataType::Int32),
+            Ordering::Greater,
+        ),
+        (
+            DataValue::Struct(vec![
+                DataValue::Int32(Some(1)),
+                DataValue::String(Some(b"b".to_vec())),
+            ]),
+            DataValue::Struct(vec![
+                DataValue::Int32(Some(1)),
+                DataValue::String(Some(b"a".to_vec())),
+            ]),
+            Ordering::Greater,
+        ),
+    ];
+
+    for (a, b, expect) in tests {
+        assert_eq!(expect, a.compare(&b)?, "case: {:?} vs {:?}", a, b);
+        assert_eq!(expect.reverse(), b.compare(&a)?, "case: {:?} vs {:?}", b, a);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_data_value_compare_nulls() -> Result<()> {
+    let value = DataValue::Int32(Some(i32::MIN));
+    for null in vec![
+        DataValue::Null,
+        DataValue::Int32(None),
+        DataValue::String(None),
+    ] {
+        assert_eq!(Ordering::Less, null.compare(&value)?, "case: {:?}", null);
+        assert_eq!(Ordering::Greater, value.compare(&null)?, "case: {:?}", null);
+        assert_eq!(
+            Ordering::Greater,
+            null.compare_with(&value, NullsOrder::Last)?
+        );
+        assert_eq!(Ordering::Less, value.compare_with(&null, NullsOrder::Last)?);
+    }
+
+    // The nulls of any types are equal.
+    assert_eq!(
+        Ordering::Equal,
+        DataValue::Null.compare(&DataValue::UInt8(None))?
+    );
+    assert_eq!(
+        Ordering::Equal,
+        DataValue::String(None).compare_with(&DataValue::Boolean(None), NullsOrder::Last)?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_data_value_compare_incomparable() -> Result<()> {
+    let err = DataValue::String(Some(b"1".to_vec()))
+        .compare(&DataValue::Int32(Some(1)))
+        .unwrap_err();
+    assert_eq!(
+        "Code: 10, displayText = Cannot compare 1 of type String with 1 of type Int32.",
+        err.to_string()
+    );
+
+    let err = DataValue::Boolean(Some(true))
+        .compare(&DataValue::UInt8(Some(1)))
+        .unwrap_err();
+    assert_eq!(
+        "Code: 10, displayText = Cannot compare true of type Boolean with 1 of type UInt8.",
+        err.to_string()
+    );
+    Ok(())
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod arrays;
+mod columns;
 mod data_array_filter;
+mod data_value;
 mod series;
 mod types;
@@ -55,6 +55,30 @@ fn test_serializers() -> Result<()> {
                 "NULL".to_owned(),
             ],
         },
+        Test {
+            name: "datetime64 with milliseconds",
+            data_type: DataType::DateTime64(3, None),
+            value: DataValue::Int64(Some(1630320462123)),
+            column: Series::new(vec![Some(1630320462123i64), Some(-1i64), None]).into(),
+            val_str: "2021-08-30 10:47:42.123",
+            col_str: vec![
+                "2021-08-30 10:47:42.123".to_owned(),
+                "1969-12-31 23:59:59.999".to_owned(),
+                "NULL".to_owned(),
+            ],
+        },
+        Test {
+            name: "datetime64 with microseconds",
+            data_type: DataType::DateTime64(6, None),
+            value: DataValue::Int64(Some(1630320462000005)),
+            column: Series::new(vec![Some(1630320462000005i64), Some(0i64), None]).into(),
+            val_str: "2021-08-30 10:47:42.000005",
+            col_str: vec![
+                "2021-08-30 10:47:42.000005".to_owned(),
+                "1970-01-01 00:00:00.000000".to_owned(),
+                "NULL".to_owned(),
+            ],
+        },
         Test {
             name: "date32",
             data_type: DataType::Date32,
@@ -75,6 +99,16 @@ fn test_serializers() -> Result<()> {
             val_str: "hello",
             col_str: vec!["hello".to_owned(), "world".to_owned(), "NULL".to_owned()],
         },
+        Test {
+            name: "decimal",
+            data_type: DataType::Decimal128(5, 2),
+            value: DataValue::Decimal(Some(1230), 5, 2),
+            column: DFDecimalArray::new_from_opt_slice(5, 2, &[Some(-5), Some(100), None])
+                .into_series()
+                .into(),
+            val_str: "12.30",
+            col_str: vec!["-0.05".to_owned(), "1.00".to_owned(), "NULL".to_owned()],
+        },
     ];
 
     for test in tests {
@@ -105,5 +139,48 @@ fn test_serializers() -> Result<()> {
         assert_eq!(&result, expect);
     }
 
+    // The precision and the timezone of a DateTime64 survive the arrow schema.
+    {
+        let field = DataField::new(
+            "t",
+            DataType::DateTime64(3, Some("Asia/Shanghai".into())),
+            false,
+        );
+        let arrow_field = field.to_arrow();
+        assert_eq!(DataType::from(arrow_field.data_type()), DataType::Int64);
+        assert_eq!(DataField::from(&arrow_field), field);
+
+        let field = DataField::new("t", DataType::DateTime64(6, None), true);
+        assert_eq!(DataField::from(&field.to_arrow()), field);
+    }
+
+    // The precision of a DateTime64 is at most 9, a value out of its range is an error.
+    {
+        assert!(DataType::create_date_time64(9, None).is_ok());
+        let result = DataType::create_date_time64(10, None);
+        assert_eq!(
+            result.unwrap_err().message(),
+            "The precision of DateTime64 must be between 0 and 9, but got 10"
+        );
+
+        assert_eq!(
+            DataType::date_time64_from_metadata(Some("6,UTC")),
+            Some(DataType::DateTime64(6, Some("UTC".into())))
+        );
+        assert_eq!(
+            DataType::date_time64_from_metadata(None),
+            Some(DataType::DateTime64(3, None))
+        );
+        assert_eq!(DataType::date_time64_from_metadata(Some("100")), None);
+        assert_eq!(DataType::date_time64_from_metadata(Some("x")), None);
+
+        let serializer = DataType::DateTime64(0, None).create_serializer();
+        let result = serializer.serialize_value(&DataValue::Int64(Some(i64::MAX)));
+        assert_eq!(
+            result.unwrap_err().message(),
+            "The value 9223372036854775807 is out of the range of DateTime64(0)"
+        );
+    }
+
     Ok(())
 }
@@ -237,6 +237,7 @@ build_exceptions! {
     UnknownTableId(2406),
     TableVersionMissMatch(2407),
     UnknownDatabaseId(2408),
+    MetaChangesCompacted(2409),
 
     // KVSrv server error
 
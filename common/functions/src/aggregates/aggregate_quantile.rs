@@ -0,0 +1,319 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::traits::AsPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::with_match_primitive_type;
+
+/// The number of values kept by the approximate quantile, inputs with at most
+/// this many values get the exact result.
+const RESERVOIR_SIZE: usize = 8192;
+
+/// Keeps a uniform sample of at most `capacity` values out of the `count` values seen,
+/// the sample holds all the values while `count <= capacity`.
+#[derive(Serialize, Deserialize)]
+struct AggregateQuantileState {
+    pub values: Vec<f64>,
+    pub count: u64,
+    pub capacity: usize,
+    pub sorted: bool,
+}
+
+impl AggregateQuantileState {
+    fn new(capacity: usize) -> Self {
+        Self {
+            values: Vec::new(),
+            count: 0,
+            capacity,
+            sorted: true,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.sorted = false;
+        if self.values.len() < self.capacity {
+            self.values.push(value);
+            return;
+        }
+
+        // Reservoir sampling, the random index is derived from the count so that
+        // the same input always gives the same sample.
+        let index = random(self.count) % self.count;
+        if (index as usize) < self.capacity {
+            self.values[index as usize] = value;
+        }
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        self.sorted = false;
+        if self.values.len() + other.values.len() <= self.capacity
+            && self.count == self.values.len() as u64
+            && other.count == other.values.len() as u64
+        {
+            self.values.extend_from_slice(&other.values);
+            self.count += other.count;
+            return;
+        }
+
+        // Pick every value of the merged sample from either side in proportion to
+        // the number of values the side has seen.
+        let total = self.count + other.count;
+        let size = std::cmp::min(self.capacity as u64, total) as usize;
+        let mut values = Vec::with_capacity(size);
+        for i in 0..size as u64 {
+            let r = random(total + i);
+            let side = if r % total < self.count {
+                &self.values
+            } else {
+                &other.values
+            };
+            values.push(side[(random(r) % side.len() as u64) as usize]);
+        }
+        self.values = values;
+        self.count = total;
+    }
+
+    /// The q-th quantile, linearly interpolated between the closest sampled values.
+    fn quantile(&mut self, level: f64) -> Option<f64> {
+        if self.values.is_empty() {
+            return None;
+        }
+        if !self.sorted {
+            self.values
+                .sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            self.sorted = true;
+        }
+
+        let pos = level * (self.values.len() - 1) as f64;
+        let lower = pos.floor() as usize;
+        let upper = pos.ceil() as usize;
+        let lower_value = self.values[lower];
+        Some(lower_value + (self.values[upper] - lower_value) * (pos - lower as f64))
+    }
+}
+
+/// SplitMix64, a cheap and well mixed hash of the seed.
+#[inline(always)]
+fn random(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+#[derive(Clone)]
+pub struct AggregateQuantileFunction<T> {
+    display_name: String,
+    _arguments: Vec<DataField>,
+    level: f64,
+    capacity: usize,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateQuantileFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateQuantileState::new(self.capacity));
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateQuantileState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+
+        if array.null_count() == 0 {
+            for value in array.into_no_null_iter() {
+                state.add(value.as_());
+            }
+        } else {
+            array.iter().for_each(|value| {
+                if let Some(value) = value {
+                    state.add(value.as_());
+                }
+            });
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                let state = place.get::<AggregateQuantileState>();
+                state.add(value.as_());
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let writer = BufMut::writer(writer);
+        bincode::serialize_into(writer, state)?;
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        *state = bincode::deserialize_from(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateQuantileState>();
+        let rhs = rhs.get::<AggregateQuantileState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateQuantileState>();
+        Ok(DataValue::Float64(state.quantile(self.level)))
+    }
+}
+
+impl<T> fmt::Display for AggregateQuantileFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateQuantileFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(
+        display_name: &str,
+        level: f64,
+        capacity: usize,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+            level,
+            capacity,
+            t: PhantomData,
+        }))
+    }
+}
+
+fn try_create_quantile(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+    capacity: usize,
+) -> Result<AggregateFunctionRef> {
+    if params.len() > 1 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at most one parameter, but got {}",
+            display_name,
+            params.len()
+        )));
+    }
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let level = match params.first() {
+        Some(param) => param.as_f64()?,
+        None => 0.5,
+    };
+    if !(0.0..=1.0).contains(&level) {
+        return Err(ErrorCode::BadArguments(format!(
+            "The level of {} must be between 0 and 1, but got {}",
+            display_name, level
+        )));
+    }
+
+    let data_type = arguments[0].data_type();
+    with_match_primitive_type!(data_type, |$T| {
+        AggregateQuantileFunction::<$T>::try_create(display_name, level, capacity, arguments)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_quantile_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create_quantile(display_name, params, arguments, RESERVOIR_SIZE)
+}
+
+pub fn try_create_aggregate_quantile_exact_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<AggregateFunctionRef> {
+    try_create_quantile(display_name, params, arguments, usize::MAX)
+}
+
+pub fn aggregate_quantile_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_function))
+}
+
+pub fn aggregate_quantile_exact_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_quantile_exact_function))
+}
@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::hash::Hasher;
+use std::io::Read;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use twox_hash::XxHash64;
+
+use super::AggregateFunctionRef;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregator_common::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::with_match_primitive_type;
+
+/// The number of the leading bits of a hash choosing its register, the 2^12 registers give a
+/// standard error of about 1.6%.
+const PRECISION: u32 = 12;
+const REGISTERS: usize = 1 << PRECISION;
+
+/// The HyperLogLog sketch, every register keeps the max rank of the hashes it was chosen by.
+/// The sketch of a part of the rows is merged by taking the max of every register.
+pub struct AggregateUniqHLLState {
+    registers: [u8; REGISTERS],
+}
+
+impl AggregateUniqHLLState {
+    #[inline(always)]
+    fn add(&mut self, hash: u64) {
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // The rank is the position of the first 1 bit in the remaining bits of the hash.
+        let rank = ((hash << PRECISION).leading_zeros() + 1).min(64 - PRECISION + 1) as u8;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = REGISTERS as f64;
+        let (sum, zeros) = self.registers.iter().fold((0.0, 0), |(sum, zeros), r| {
+            (sum + 2f64.powi(-(*r as i32)), zeros + (*r == 0) as usize)
+        });
+
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / sum;
+        // Linear counting is more accurate while many registers are still empty.
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+/// Hashes the arguments of every row with xxHash64, a row with a NULL argument is None.
+/// The hash is specified by its algorithm and seed only, so the sketches built by the nodes
+/// of different builds or platforms can be merged.
+fn hash_rows(arrays: &[Series], input_rows: usize) -> Result<Vec<Option<u64>>> {
+    if arrays.iter().any(|array| array.data_type().is_null()) {
+        return Ok(vec![None; input_rows]);
+    }
+
+    let mut hashers = vec![XxHash64::with_seed(0); input_rows];
+    for array in arrays {
+        hash_column(array, &mut hashers)?;
+    }
+
+    Ok(hashers
+        .iter()
+        .enumerate()
+        .map(|(row, hasher)| {
+            if arrays.iter().any(|array| array.is_null(row)) {
+                return None;
+            }
+            Some(hasher.finish())
+        })
+        .collect())
+}
+
+/// Feeds the little-endian bytes of every value of the array to the hasher of its row.
+fn hash_column(array: &Series, hashers: &mut [XxHash64]) -> Result<()> {
+    let data_type: DataType = array.data_type().to_physical_type().into();
+    match data_type {
+        DataType::Boolean => {
+            for (hasher, value) in hashers.iter_mut().zip(array.bool()?.into_no_null_iter()) {
+                hasher.write_u8(value as u8);
+            }
+        }
+        DataType::String => {
+            for (hasher, value) in hashers.iter_mut().zip(array.string()?.into_no_null_iter()) {
+                // The length keeps the rows ('ab', 'c') and ('a', 'bc') apart.
+                hasher.write(&(value.len() as u64).to_le_bytes());
+                hasher.write(value);
+            }
+        }
+        _ => with_match_primitive_type!(&data_type, |$T| {
+            let array: &DFPrimitiveArray<$T> = array.static_cast();
+            for (hasher, value) in hashers.iter_mut().zip(array.into_no_null_iter()) {
+                hasher.write(&value.to_le_bytes());
+            }
+        },
+
+        {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "AggregateUniqHLLFunction does not support type '{:?}'",
+                array.data_type()
+            )));
+        }),
+    }
+    Ok(())
+}
+
+/// uniqHLL12 estimates the number of distinct rows with a HyperLogLog sketch of a fixed size.
+/// The exact `uniq` is kept next to it: it keeps every distinct row of a group, so its result is
+/// exact but its state grows with the number of distinct rows, while the 4 KB state of uniqHLL12
+/// costs a standard error of about 1.6%.
+#[derive(Clone)]
+pub struct AggregateUniqHLLFunction {
+    display_name: String,
+    _arguments: Vec<DataField>,
+}
+
+impl AggregateUniqHLLFunction {
+    pub fn try_create(
+        display_name: &str,
+        _params: Vec<DataValue>,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        assert_variadic_arguments(display_name, arguments.len(), (1, 32))?;
+        Ok(Arc::new(AggregateUniqHLLFunction {
+            display_name: display_name.to_string(),
+            _arguments: arguments,
+        }))
+    }
+
+    pub fn desc() -> AggregateFunctionDescription {
+        AggregateFunctionDescription::creator(Box::new(Self::try_create))
+    }
+}
+
+impl AggregateFunction for AggregateUniqHLLFunction {
+    fn name(&self) -> &str {
+        "AggregateUniqHLLFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateUniqHLLState {
+            registers: [0; REGISTERS],
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateUniqHLLState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateUniqHLLState>();
+        for hash in hash_rows(arrays, input_rows)?.into_iter().flatten() {
+            state.add(hash);
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        input_rows: usize,
+    ) -> Result<()> {
+        let hashes = hash_rows(arrays, input_rows)?;
+        for (hash, place) in hashes.into_iter().zip(places.iter()) {
+            if let Some(hash) = hash {
+                let place = place.next(offset);
+                let state = place.get::<AggregateUniqHLLState>();
+                state.add(hash);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateUniqHLLState>();
+        writer.extend_from_slice(&state.registers);
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateUniqHLLState>();
+        reader.read_exact(&mut state.registers)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateUniqHLLState>();
+        let rhs = rhs.get::<AggregateUniqHLLState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateUniqHLLState>();
+        Ok(DataValue::UInt64(Some(state.estimate())))
+    }
+}
+
+impl fmt::Display for AggregateUniqHLLFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -20,12 +20,15 @@ use crate::aggregates::aggregate_covariance::aggregate_covariance_sample_desc;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionFactory;
 use crate::aggregates::aggregate_min_max::aggregate_max_function_desc;
 use crate::aggregates::aggregate_min_max::aggregate_min_function_desc;
+use crate::aggregates::aggregate_quantile::aggregate_quantile_exact_function_desc;
+use crate::aggregates::aggregate_quantile::aggregate_quantile_function_desc;
 use crate::aggregates::aggregate_stddev_pop::aggregate_stddev_pop_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
 use crate::aggregates::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use crate::aggregates::AggregateCountFunction;
 use crate::aggregates::AggregateDistinctCombinator;
 use crate::aggregates::AggregateIfCombinator;
+use crate::aggregates::AggregateUniqHLLFunction;
 
 pub struct Aggregators;
 
@@ -44,8 +47,11 @@ impl Aggregators {
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("windowFunnel", aggregate_window_funnel_function_desc());
         factory.register("uniq", AggregateDistinctCombinator::uniq_desc());
+        factory.register("uniqHLL12", AggregateUniqHLLFunction::desc());
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("quantile", aggregate_quantile_function_desc());
+        factory.register("quantileExact", aggregate_quantile_exact_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
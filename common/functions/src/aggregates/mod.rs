@@ -25,8 +25,10 @@ mod aggregate_window_funnel;
 
 // mod aggregate_min_max;
 mod aggregate_covariance;
+mod aggregate_quantile;
 mod aggregate_stddev_pop;
 mod aggregate_sum;
+mod aggregate_uniq_hll;
 mod aggregator;
 mod aggregator_common;
 
@@ -46,7 +48,9 @@ pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
 pub use aggregate_min_max::AggregateMinMaxFunction;
+pub use aggregate_quantile::AggregateQuantileFunction;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
 pub use aggregate_sum::AggregateSumFunction;
+pub use aggregate_uniq_hll::AggregateUniqHLLFunction;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
@@ -48,6 +48,7 @@ impl ArithmeticFunction {
         factory.register("%", ArithmeticModuloFunction::desc());
         factory.register("modulo", ArithmeticModuloFunction::desc());
         factory.register("div", ArithmeticIntDivFunction::desc());
+        factory.register("intDiv", ArithmeticIntDivFunction::desc());
     }
 
     pub fn try_create_func(op: DataValueArithmeticOperator) -> Result<Box<dyn Function>> {
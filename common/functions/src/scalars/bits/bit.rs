@@ -0,0 +1,35 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::BitAndFunction;
+use crate::scalars::BitNotFunction;
+use crate::scalars::BitOrFunction;
+use crate::scalars::BitShiftLeftFunction;
+use crate::scalars::BitShiftRightFunction;
+use crate::scalars::BitXorFunction;
+
+#[derive(Clone)]
+pub struct BitFunction;
+
+impl BitFunction {
+    pub fn register(factory: &mut FunctionFactory) {
+        factory.register("bitAnd", BitAndFunction::desc());
+        factory.register("bitOr", BitOrFunction::desc());
+        factory.register("bitXor", BitXorFunction::desc());
+        factory.register("bitNot", BitNotFunction::desc());
+        factory.register("bitShiftLeft", BitShiftLeftFunction::desc());
+        factory.register("bitShiftRight", BitShiftRightFunction::desc());
+    }
+}
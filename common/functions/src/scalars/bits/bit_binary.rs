@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use num::traits::PrimInt;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+pub type BitAndFunction = BitBinaryFunction<BitAnd>;
+pub type BitOrFunction = BitBinaryFunction<BitOr>;
+pub type BitXorFunction = BitBinaryFunction<BitXor>;
+
+/// The operators are all commutative, so `constant op array` is evaluated as `array op constant`.
+pub trait BitBinaryOperator: Send + Sync + Clone + 'static {
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T;
+}
+
+#[derive(Clone)]
+pub struct BitAnd;
+
+impl BitBinaryOperator for BitAnd {
+    #[inline]
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T {
+        lhs & rhs
+    }
+}
+
+#[derive(Clone)]
+pub struct BitOr;
+
+impl BitBinaryOperator for BitOr {
+    #[inline]
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T {
+        lhs | rhs
+    }
+}
+
+#[derive(Clone)]
+pub struct BitXor;
+
+impl BitBinaryOperator for BitXor {
+    #[inline]
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T {
+        lhs ^ rhs
+    }
+}
+
+#[derive(Clone)]
+pub struct BitBinaryFunction<T> {
+    display_name: String,
+    _marker: PhantomData<T>,
+}
+
+impl<T: BitBinaryOperator> BitBinaryFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            _marker: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+macro_rules! impl_bit_binary {
+    ($op:ty, $lhs:expr, $rhs:expr, $type:ident, $data_type:expr, $input_rows:expr) => {{
+        match (&$lhs, &$rhs) {
+            (DataColumn::Array(lhs), DataColumn::Array(rhs)) => {
+                let array: DFPrimitiveArray<$type> =
+                    binary(lhs.$type()?, rhs.$type()?, |l, r| <$op>::apply(l, r));
+                Ok(array.into())
+            }
+            (DataColumn::Array(array), DataColumn::Constant(value, _))
+            | (DataColumn::Constant(value, _), DataColumn::Array(array)) => {
+                if value.is_null() {
+                    return Ok(DataColumn::Constant(
+                        DataValue::from($data_type),
+                        $input_rows,
+                    ));
+                }
+
+                let value: $type = DFTryFrom::try_from(value.clone())?;
+                let array = array.$type()?.apply(|v| <$op>::apply(v, value));
+                Ok(array.into())
+            }
+            (DataColumn::Constant(_, _), DataColumn::Constant(_, _)) => {
+                let lhs = $lhs.to_minimal_array()?;
+                let rhs = $rhs.to_minimal_array()?;
+                let array: DFPrimitiveArray<$type> =
+                    binary(lhs.$type()?, rhs.$type()?, |l, r| <$op>::apply(l, r));
+                let column: DataColumn = array.into();
+                Ok(column.resize_constant($input_rows))
+            }
+        }
+    }};
+}
+
+impl<T: BitBinaryOperator> Function for BitBinaryFunction<T> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if !arg.is_integer() && !arg.is_null() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected integer or null, but got {}",
+                    arg
+                )));
+            }
+        }
+
+        if args[0].is_null() || args[1].is_null() {
+            return Ok(DataType::Null);
+        }
+        bitwise_coercion(&args[0], &args[1])
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_type = self.return_type(&[
+            columns[0].data_type().clone(),
+            columns[1].data_type().clone(),
+        ])?;
+
+        if data_type.is_null() {
+            return Ok(DataColumn::Constant(DataValue::Null, input_rows));
+        }
+
+        let lhs = columns[0].column().cast_with_type(&data_type)?;
+        let rhs = columns[1].column().cast_with_type(&data_type)?;
+
+        match &data_type {
+            DataType::Int8 => impl_bit_binary!(T, lhs, rhs, i8, &data_type, input_rows),
+            DataType::Int16 => impl_bit_binary!(T, lhs, rhs, i16, &data_type, input_rows),
+            DataType::Int32 => impl_bit_binary!(T, lhs, rhs, i32, &data_type, input_rows),
+            DataType::Int64 => impl_bit_binary!(T, lhs, rhs, i64, &data_type, input_rows),
+            DataType::UInt8 => impl_bit_binary!(T, lhs, rhs, u8, &data_type, input_rows),
+            DataType::UInt16 => impl_bit_binary!(T, lhs, rhs, u16, &data_type, input_rows),
+            DataType::UInt32 => impl_bit_binary!(T, lhs, rhs, u32, &data_type, input_rows),
+            DataType::UInt64 => impl_bit_binary!(T, lhs, rhs, u64, &data_type, input_rows),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<T> fmt::Display for BitBinaryFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct BitNotFunction {
+    display_name: String,
+}
+
+impl BitNotFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitNotFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+macro_rules! impl_bit_not {
+    ($column:expr, $type:ident) => {{
+        let series = $column.to_minimal_array()?;
+        let column: DataColumn = series.$type()?.apply(|v| !v).into();
+        Ok(column.resize_constant($column.len()))
+    }};
+}
+
+impl Function for BitNotFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0].is_integer() || args[0].is_null() {
+            Ok(args[0].clone())
+        } else {
+            Err(ErrorCode::IllegalDataType(format!(
+                "Expected integer or null, but got {}",
+                args[0]
+            )))
+        }
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let column = columns[0].column();
+        match columns[0].data_type() {
+            DataType::Int8 => impl_bit_not!(column, i8),
+            DataType::Int16 => impl_bit_not!(column, i16),
+            DataType::Int32 => impl_bit_not!(column, i32),
+            DataType::Int64 => impl_bit_not!(column, i64),
+            DataType::UInt8 => impl_bit_not!(column, u8),
+            DataType::UInt16 => impl_bit_not!(column, u16),
+            DataType::UInt32 => impl_bit_not!(column, u32),
+            DataType::UInt64 => impl_bit_not!(column, u64),
+            _ => Ok(column.clone()),
+        }
+    }
+}
+
+impl fmt::Display for BitNotFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
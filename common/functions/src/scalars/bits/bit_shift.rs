@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use num::traits::PrimInt;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+pub type BitShiftLeftFunction = BitShiftFunction<BitShiftLeft>;
+pub type BitShiftRightFunction = BitShiftFunction<BitShiftRight>;
+
+/// Shift amounts that are negative or not less than the bit width of the value would be
+/// undefined behavior for the primitive shifts, they are treated as shifting all the bits out.
+pub trait BitShiftOperator: Send + Sync + Clone + 'static {
+    fn apply<T: PrimInt>(value: T, n: i64) -> T;
+}
+
+#[inline]
+fn in_range<T: PrimInt>(n: i64) -> bool {
+    n >= 0 && n < T::zero().count_zeros() as i64
+}
+
+#[derive(Clone)]
+pub struct BitShiftLeft;
+
+impl BitShiftOperator for BitShiftLeft {
+    /// Out of range shift amounts produce 0.
+    #[inline]
+    fn apply<T: PrimInt>(value: T, n: i64) -> T {
+        if in_range::<T>(n) {
+            value << n as usize
+        } else {
+            T::zero()
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BitShiftRight;
+
+impl BitShiftOperator for BitShiftRight {
+    /// Signed values are shifted arithmetically, so the sign bit is kept, e.g. -8 >> 1 = -4.
+    /// Out of range shift amounts produce -1 for negative values and 0 otherwise.
+    #[inline]
+    fn apply<T: PrimInt>(value: T, n: i64) -> T {
+        if in_range::<T>(n) {
+            value >> n as usize
+        } else if value < T::zero() {
+            !T::zero()
+        } else {
+            T::zero()
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BitShiftFunction<T> {
+    display_name: String,
+    _marker: PhantomData<T>,
+}
+
+impl<T: BitShiftOperator> BitShiftFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            _marker: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+macro_rules! impl_bit_shift {
+    ($op:ty, $value:expr, $n:expr, $type:ident, $data_type:expr, $input_rows:expr) => {{
+        match (&$value, &$n) {
+            (DataColumn::Array(value), DataColumn::Array(n)) => {
+                let array: DFPrimitiveArray<$type> =
+                    binary(value.$type()?, n.i64()?, |v, n| <$op>::apply(v, n));
+                Ok(array.into())
+            }
+            (DataColumn::Array(value), DataColumn::Constant(n, _)) => {
+                if n.is_null() {
+                    return Ok(DataColumn::Constant(
+                        DataValue::from($data_type),
+                        $input_rows,
+                    ));
+                }
+
+                let n = n.as_i64()?;
+                let array = value.$type()?.apply(|v| <$op>::apply(v, n));
+                Ok(array.into())
+            }
+            (DataColumn::Constant(value, _), DataColumn::Array(n)) => {
+                if value.is_null() {
+                    return Ok(DataColumn::Constant(
+                        DataValue::from($data_type),
+                        $input_rows,
+                    ));
+                }
+
+                let value: $type = DFTryFrom::try_from(value.clone())?;
+                let array: DFPrimitiveArray<$type> =
+                    n.i64()?.apply_cast_numeric(|n| <$op>::apply(value, n));
+                Ok(array.into())
+            }
+            (DataColumn::Constant(_, _), DataColumn::Constant(_, _)) => {
+                let value = $value.to_minimal_array()?;
+                let n = $n.to_minimal_array()?;
+                let array: DFPrimitiveArray<$type> =
+                    binary(value.$type()?, n.i64()?, |v, n| <$op>::apply(v, n));
+                let column: DataColumn = array.into();
+                Ok(column.resize_constant($input_rows))
+            }
+        }
+    }};
+}
+
+impl<T: BitShiftOperator> Function for BitShiftFunction<T> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if !arg.is_integer() && !arg.is_null() {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected integer or null, but got {}",
+                    arg
+                )));
+            }
+        }
+
+        if args[1].is_null() {
+            return Ok(DataType::Null);
+        }
+        Ok(args[0].clone())
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_type = self.return_type(&[
+            columns[0].data_type().clone(),
+            columns[1].data_type().clone(),
+        ])?;
+
+        if data_type.is_null() {
+            return Ok(DataColumn::Constant(DataValue::Null, input_rows));
+        }
+
+        let value = columns[0].column();
+        let n = columns[1].column().cast_with_type(&DataType::Int64)?;
+
+        match &data_type {
+            DataType::Int8 => impl_bit_shift!(T, value, n, i8, &data_type, input_rows),
+            DataType::Int16 => impl_bit_shift!(T, value, n, i16, &data_type, input_rows),
+            DataType::Int32 => impl_bit_shift!(T, value, n, i32, &data_type, input_rows),
+            DataType::Int64 => impl_bit_shift!(T, value, n, i64, &data_type, input_rows),
+            DataType::UInt8 => impl_bit_shift!(T, value, n, u8, &data_type, input_rows),
+            DataType::UInt16 => impl_bit_shift!(T, value, n, u16, &data_type, input_rows),
+            DataType::UInt32 => impl_bit_shift!(T, value, n, u32, &data_type, input_rows),
+            DataType::UInt64 => impl_bit_shift!(T, value, n, u64, &data_type, input_rows),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<T> fmt::Display for BitShiftFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
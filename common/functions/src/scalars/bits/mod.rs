@@ -0,0 +1,35 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bit;
+mod bit_binary;
+mod bit_not;
+mod bit_shift;
+
+pub use bit::BitFunction;
+pub use bit_binary::BitAnd;
+pub use bit_binary::BitAndFunction;
+pub use bit_binary::BitBinaryFunction;
+pub use bit_binary::BitBinaryOperator;
+pub use bit_binary::BitOr;
+pub use bit_binary::BitOrFunction;
+pub use bit_binary::BitXor;
+pub use bit_binary::BitXorFunction;
+pub use bit_not::BitNotFunction;
+pub use bit_shift::BitShiftFunction;
+pub use bit_shift::BitShiftLeft;
+pub use bit_shift::BitShiftLeftFunction;
+pub use bit_shift::BitShiftOperator;
+pub use bit_shift::BitShiftRight;
+pub use bit_shift::BitShiftRightFunction;
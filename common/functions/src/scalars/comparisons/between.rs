@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueComparisonOperator;
+use common_exception::Result;
+use itertools::izip;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct BetweenFunction {
+    display_name: String,
+}
+
+impl BetweenFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BetweenFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for BetweenFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        compare_coercion(&compare_coercion(&args[0], &args[1])?, &args[2])?;
+        Ok(DataType::Boolean)
+    }
+
+    /// `lo <= x AND x <= hi` with both bounds inclusive. Unlike the AND of the two comparisons,
+    /// the result is null if any of the arguments is null, even if x is out of the other bound.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if columns.iter().any(|c| c.data_type() == &DataType::Null) {
+            return Ok(DataColumn::Constant(DataValue::Boolean(None), input_rows));
+        }
+
+        let dtype = compare_coercion(
+            &compare_coercion(columns[0].data_type(), columns[1].data_type())?,
+            columns[2].data_type(),
+        )?;
+        let x = columns[0].column().cast_with_type(&dtype)?;
+        let lo = columns[1].column().cast_with_type(&dtype)?;
+        let hi = columns[2].column().cast_with_type(&dtype)?;
+
+        let ge = x.compare(DataValueComparisonOperator::GtEq, &lo)?;
+        let le = x.compare(DataValueComparisonOperator::LtEq, &hi)?;
+
+        // Both comparisons are constant only if all the arguments are constant.
+        let all_constant = matches!(
+            (&ge, &le),
+            (DataColumn::Constant(_, _), DataColumn::Constant(_, _))
+        );
+        let (ge, le) = if all_constant {
+            (ge.to_minimal_array()?, le.to_minimal_array()?)
+        } else {
+            (ge.to_array()?, le.to_array()?)
+        };
+
+        let mut r_array = BooleanArrayBuilder::with_capacity(ge.len());
+        for ge_le in izip!(ge.bool()?, le.bool()?) {
+            r_array.append_option(match ge_le {
+                (Some(ge), Some(le)) => Some(ge && le),
+                _ => None,
+            });
+        }
+        let result: DataColumn = r_array.finish().into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for BetweenFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
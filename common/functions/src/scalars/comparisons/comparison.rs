@@ -20,6 +20,7 @@ use common_datavalues::DataValueComparisonOperator;
 use common_exception::Result;
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::BetweenFunction;
 use crate::scalars::CastFunction;
 use crate::scalars::ComparisonEqFunction;
 use crate::scalars::ComparisonGtEqFunction;
@@ -30,6 +31,8 @@ use crate::scalars::ComparisonLtFunction;
 use crate::scalars::ComparisonNotEqFunction;
 use crate::scalars::ComparisonNotLikeFunction;
 use crate::scalars::Function;
+use crate::scalars::GreatestFunction;
+use crate::scalars::LeastFunction;
 
 #[derive(Clone)]
 pub struct ComparisonFunction {
@@ -47,6 +50,9 @@ impl ComparisonFunction {
         factory.register("<>", ComparisonNotEqFunction::desc());
         factory.register("like", ComparisonLikeFunction::desc());
         factory.register("not like", ComparisonNotLikeFunction::desc());
+        factory.register("greatest", GreatestFunction::desc());
+        factory.register("least", LeastFunction::desc());
+        factory.register("between", BetweenFunction::desc());
     }
 
     pub fn try_create_func(op: DataValueComparisonOperator) -> Result<Box<dyn Function>> {
@@ -59,15 +65,17 @@ impl Function for ComparisonFunction {
         "ComparisonFunction"
     }
 
-    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        compare_coercion(&args[0], &args[1])?;
         Ok(DataType::Boolean)
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
-    }
-
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        // Comparison with null is null.
+        if columns[0].data_type() == &DataType::Null || columns[1].data_type() == &DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::Boolean(None), input_rows));
+        }
+
         if columns[0].data_type() != columns[1].data_type() {
             let compare_coercion_type =
                 compare_coercion(columns[0].data_type(), columns[1].data_type())?;
@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueComparisonOperator;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+struct ExtremumFunction {
+    display_name: String,
+    // The operator a row must satisfy against every other row to be picked.
+    op: DataValueComparisonOperator,
+}
+
+impl ExtremumFunction {
+    pub fn try_create(
+        display_name: &str,
+        op: DataValueComparisonOperator,
+    ) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            op,
+        }))
+    }
+}
+
+impl Function for ExtremumFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        0
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 1024))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let mut dtype = args[0].clone();
+        for arg in &args[1..] {
+            dtype = compare_coercion(&dtype, arg)?;
+        }
+        Ok(dtype)
+    }
+
+    /// Null arguments are skipped, the result is null only if all the arguments are null.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let types = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let dtype = self.return_type(&types)?;
+        if dtype == DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::Null, input_rows));
+        }
+
+        // Constant arguments are only expanded if some argument is not constant.
+        let all_constant = columns
+            .iter()
+            .all(|c| matches!(c.column(), DataColumn::Constant(_, _)));
+        let mut args = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = column.column().cast_with_type(&dtype)?;
+            let array = if all_constant {
+                column.to_minimal_array()?
+            } else {
+                column.to_array()?
+            };
+            args.push(DataColumn::from(array));
+        }
+
+        let mut result = args[0].clone();
+        for arg in &args[1..] {
+            // Keep the current row if it wins over `arg` or `arg` is null. A null current row
+            // compares to null, which picks the row of `arg`.
+            let wins = result.compare(self.op.clone(), arg)?.to_array()?;
+            let arg_null = arg.is_null()?.to_array()?;
+            let keep = wins.bool()?.or_kleene(arg_null.bool()?)?;
+            result = DataColumn::from(keep.into_series()).if_then_else(&result, arg)?;
+        }
+        Ok(result.resize_constant(input_rows))
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for ExtremumFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub struct GreatestFunction {}
+
+impl GreatestFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        ExtremumFunction::try_create(display_name, DataValueComparisonOperator::GtEq)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+pub struct LeastFunction {}
+
+impl LeastFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        ExtremumFunction::try_create(display_name, DataValueComparisonOperator::LtEq)
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod between;
 mod comparison;
 mod comparison_eq;
 mod comparison_gt;
@@ -21,7 +22,9 @@ mod comparison_lt;
 mod comparison_lt_eq;
 mod comparison_not_eq;
 mod comparison_not_like;
+mod greatest_least;
 
+pub use between::BetweenFunction;
 pub use comparison::ComparisonFunction;
 pub use comparison_eq::ComparisonEqFunction;
 pub use comparison_gt::ComparisonGtFunction;
@@ -31,3 +34,5 @@ pub use comparison_lt::ComparisonLtFunction;
 pub use comparison_lt_eq::ComparisonLtEqFunction;
 pub use comparison_not_eq::ComparisonNotEqFunction;
 pub use comparison_not_like::ComparisonNotLikeFunction;
+pub use greatest_least::GreatestFunction;
+pub use greatest_least::LeastFunction;
@@ -14,6 +14,7 @@
 
 use crate::scalars::function_factory::FunctionFactory;
 use crate::scalars::IfFunction;
+use crate::scalars::MultiIfFunction;
 
 #[derive(Clone)]
 pub struct ConditionalFunction;
@@ -21,5 +22,6 @@ pub struct ConditionalFunction;
 impl ConditionalFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("if", IfFunction::desc());
+        factory.register("multiIf", MultiIfFunction::desc());
     }
 }
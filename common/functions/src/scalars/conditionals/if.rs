@@ -18,6 +18,7 @@ use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::DataColumnsWithField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::scalars::function_factory::FunctionDescription;
@@ -52,6 +53,12 @@ impl Function for IfFunction {
     }
 
     fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] != DataType::Boolean && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected boolean or null, but got {}",
+                args[0]
+            )));
+        }
         common_datavalues::aggregate_types(&args[1..])
     }
 
@@ -59,6 +66,7 @@ impl Function for IfFunction {
         Ok(false)
     }
 
+    /// A null condition is treated as false, so the row is taken from the else branch.
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
         columns[0]
             .column()
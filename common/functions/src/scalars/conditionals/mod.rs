@@ -14,6 +14,8 @@
 
 mod conditional;
 mod r#if;
+mod multi_if;
 
 pub use conditional::ConditionalFunction;
+pub use multi_if::MultiIfFunction;
 pub use r#if::IfFunction;
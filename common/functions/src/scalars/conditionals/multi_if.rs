@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct MultiIfFunction {
+    display_name: String,
+}
+
+impl MultiIfFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MultiIfFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for MultiIfFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((3, usize::MAX - 1))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args.len() % 2 == 0 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expects pairs of conditions and values followed by an else value, but got {} arguments",
+                self.display_name,
+                args.len()
+            )));
+        }
+
+        let mut value_types = Vec::with_capacity(args.len() / 2 + 1);
+        for pair in args.chunks(2) {
+            if pair.len() == 2 && pair[0] != DataType::Boolean && pair[0] != DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected boolean or null, but got {}",
+                    pair[0]
+                )));
+            }
+            value_types.push(pair[pair.len() - 1].clone());
+        }
+        aggregate_types(&value_types)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Each row takes the value of the first true condition, or the else value if there is none.
+    /// A null condition is treated as false.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_types: Vec<DataType> = columns.iter().map(|c| c.data_type().clone()).collect();
+        let data_type = self.return_type(&data_types)?;
+        if data_type == DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::Null, input_rows));
+        }
+
+        // Fold from the else value backwards, so the earlier conditions override the later ones.
+        let mut result = columns[columns.len() - 1]
+            .column()
+            .cast_with_type(&data_type)?;
+        for pair in columns[..columns.len() - 1].chunks(2).rev() {
+            result = pair[0]
+                .column()
+                .if_then_else(pair[1].column(), &result)?
+                .cast_with_type(&data_type)?;
+        }
+        Ok(result)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for MultiIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
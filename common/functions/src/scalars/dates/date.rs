@@ -15,10 +15,12 @@
 use common_datavalues::DataValueArithmeticOperator;
 use common_exception::Result;
 
+use super::format_date_time::FormatDateTimeFunction;
 use super::interval_function::MonthsArithmeticFunction;
 use super::interval_function::SecondsArithmeticFunction;
 use super::now::NowFunction;
 use super::number_function::ToMondayFunction;
+use super::parse_date_time::ParseDateTimeFunction;
 use super::RoundFunction;
 use super::ToDayOfMonthFunction;
 use super::ToDayOfWeekFunction;
@@ -26,12 +28,14 @@ use super::ToDayOfYearFunction;
 use super::ToHourFunction;
 use super::ToMinuteFunction;
 use super::ToMonthFunction;
+use super::ToQuarterFunction;
 use super::ToSecondFunction;
 use super::ToStartOfISOYearFunction;
 use super::ToStartOfMonthFunction;
 use super::ToStartOfQuarterFunction;
 use super::ToStartOfWeekFunction;
 use super::ToStartOfYearFunction;
+use super::ToWeekFunction;
 use super::ToYYYYMMDDFunction;
 use super::ToYYYYMMDDhhmmssFunction;
 use super::ToYYYYMMFunction;
@@ -116,14 +120,19 @@ impl DateFunction {
         factory.register("toStartOfQuarter", ToStartOfQuarterFunction::desc());
         factory.register("toStartOfWeek", ToStartOfWeekFunction::desc());
         factory.register("toStartOfMonth", ToStartOfMonthFunction::desc());
+        factory.register("toQuarter", ToQuarterFunction::desc());
         factory.register("toMonth", ToMonthFunction::desc());
         factory.register("toDayOfYear", ToDayOfYearFunction::desc());
         factory.register("toDayOfMonth", ToDayOfMonthFunction::desc());
         factory.register("toDayOfWeek", ToDayOfWeekFunction::desc());
+        factory.register("toWeek", ToWeekFunction::desc());
         factory.register("toHour", ToHourFunction::desc());
         factory.register("toMinute", ToMinuteFunction::desc());
         factory.register("toSecond", ToSecondFunction::desc());
         factory.register("toMonday", ToMondayFunction::desc());
+        factory.register("formatDateTime", FormatDateTimeFunction::desc());
+        factory.register("parseDateTime", ParseDateTimeFunction::desc(true));
+        factory.register("parseDateTimeOrNull", ParseDateTimeFunction::desc(false));
 
         // rounders
         factory.register("toStartOfSecond", Self::round_function_creator(1));
@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::format::Item;
+use common_datavalues::chrono::format::StrftimeItems;
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Utc;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::number_function::date16_to_date_time;
+use super::number_function::date32_to_date_time;
+use super::number_function::date_time32_to_date_time;
+use super::number_function::date_time64_to_date_time;
+use crate::scalars::eval_unary;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct FormatDateTimeFunction {
+    display_name: String,
+}
+
+impl FormatDateTimeFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatDateTimeFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for FormatDateTimeFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_date_or_date_time() && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected date16/date32/datetime32/datetime64 or null, but got {}",
+                args[0]
+            )));
+        }
+        if args[1] != DataType::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string, but got {}",
+                args[1]
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    /// The format is a strftime-style pattern of chrono, it must be a constant and is parsed once.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let format = match columns[1].column() {
+            DataColumn::Constant(DataValue::String(Some(format)), _) => {
+                String::from_utf8(format.clone()).map_err(|e| {
+                    ErrorCode::BadArguments(format!("Invalid format of {}: {}", self.name(), e))
+                })?
+            }
+            DataColumn::Constant(DataValue::String(None), _) => {
+                return Ok(DataColumn::Constant(DataValue::String(None), input_rows));
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The format of {} must be a constant string",
+                    self.name()
+                )));
+            }
+        };
+        let items = parse_format(&format)?;
+
+        let column = columns[0].column();
+        match columns[0].data_type() {
+            DataType::Date16 => eval_unary(column, input_rows, DataType::String, |series| {
+                format_series(series, &items, date16_to_date_time)
+            }),
+            DataType::Date32 => eval_unary(column, input_rows, DataType::String, |series| {
+                format_series(series, &items, date32_to_date_time)
+            }),
+            DataType::DateTime32(_) => eval_unary(column, input_rows, DataType::String, |series| {
+                format_series(series, &items, date_time32_to_date_time)
+            }),
+            DataType::DateTime64(precision, _) => {
+                let precision = *precision;
+                eval_unary(column, input_rows, DataType::String, |series| {
+                    // A value out of the range of DateTime is null.
+                    let array = series.i64()?;
+                    let mut builder = StringArrayBuilder::with_capacity(array.len());
+                    for v in array {
+                        builder.append_option(
+                            v.and_then(|v| date_time64_to_date_time(*v, precision))
+                                .map(|dt| dt.format_with_items(items.iter()).to_string()),
+                        );
+                    }
+                    Ok(builder.finish().into_series())
+                })
+            }
+            _ => Ok(DataColumn::Constant(DataValue::String(None), input_rows)),
+        }
+    }
+}
+
+pub(super) fn parse_format(format: &str) -> Result<Vec<Item>> {
+    let items: Vec<Item> = StrftimeItems::new(format).collect();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(ErrorCode::BadArguments(format!(
+            "Invalid format specifier in '{}'",
+            format
+        )));
+    }
+    Ok(items)
+}
+
+fn format_series<T, F>(series: &Series, items: &[Item], to_date_time: F) -> Result<Series>
+where
+    T: DFPrimitiveType,
+    F: Fn(T) -> DateTime<Utc>,
+{
+    let array: &DFPrimitiveArray<T> = series.static_cast();
+    let mut builder = StringArrayBuilder::with_capacity(array.len());
+    for v in array {
+        builder
+            .append_option(v.map(|v| to_date_time(*v).format_with_items(items.iter()).to_string()));
+    }
+    Ok(builder.finish().into_series())
+}
+
+impl fmt::Display for FormatDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
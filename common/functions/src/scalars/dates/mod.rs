@@ -13,18 +13,22 @@
 // limitations under the License.
 
 mod date;
+mod format_date_time;
 mod interval_function;
 mod now;
 mod number_function;
+mod parse_date_time;
 mod round_function;
 mod simple_date;
 mod week_date;
 
 pub use date::DateFunction;
+pub use format_date_time::FormatDateTimeFunction;
 pub use interval_function::IntervalArithmeticFunction;
 pub use interval_function::IntervalFunctionFactory;
 pub use interval_function::MonthsArithmeticFunction;
 pub use interval_function::SecondsArithmeticFunction;
+pub use now::NowFunction;
 pub use number_function::ToDayOfMonthFunction;
 pub use number_function::ToDayOfWeekFunction;
 pub use number_function::ToDayOfYearFunction;
@@ -32,14 +36,17 @@ pub use number_function::ToHourFunction;
 pub use number_function::ToMinuteFunction;
 pub use number_function::ToMondayFunction;
 pub use number_function::ToMonthFunction;
+pub use number_function::ToQuarterFunction;
 pub use number_function::ToSecondFunction;
 pub use number_function::ToStartOfISOYearFunction;
 pub use number_function::ToStartOfMonthFunction;
 pub use number_function::ToStartOfQuarterFunction;
 pub use number_function::ToStartOfYearFunction;
+pub use number_function::ToWeekFunction;
 pub use number_function::ToYYYYMMDDFunction;
 pub use number_function::ToYYYYMMDDhhmmssFunction;
 pub use number_function::ToYYYYMMFunction;
+pub use parse_date_time::ParseDateTimeFunction;
 pub use round_function::RoundFunction;
 pub use simple_date::TodayFunction;
 pub use simple_date::TomorrowFunction;
@@ -46,6 +46,10 @@ impl Function for NowFunction {
         self.display_name.as_str()
     }
 
+    fn num_arguments(&self) -> usize {
+        0
+    }
+
     fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
         Ok(DataType::DateTime32(None))
     }
@@ -54,9 +58,10 @@ impl Function for NowFunction {
         Ok(false)
     }
 
+    /// The time is taken once, so all the rows of a block have the same value.
     fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let utc: DateTime<Utc> = Utc::now();
-        let value = DataValue::UInt32(Some((utc.timestamp_millis() / 1000) as u32));
+        let value = DataValue::UInt32(Some(utc.timestamp() as u32));
         Ok(DataColumn::Constant(value, input_rows))
     }
 }
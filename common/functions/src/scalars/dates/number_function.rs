@@ -21,9 +21,12 @@ use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Timelike;
 use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::scalars::eval_unary;
+use crate::scalars::eval_unary_numeric;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::CastFunction;
@@ -43,7 +46,6 @@ pub trait NumberResultFunction<R> {
 
     fn return_type() -> Result<DataType>;
     fn to_number(_value: DateTime<Utc>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>) -> DataValue;
     // Used to check the monotonicity of the function.
     // For example, ToDayOfYear is monotonous only when the time range is the same year.
     // So we can use ToStartOfYearFunction to check whether the time range is in the same year.
@@ -67,10 +69,6 @@ impl NumberResultFunction<u32> for ToYYYYMM {
     fn to_number(value: DateTime<Utc>) -> u32 {
         value.year() as u32 * 100 + value.month()
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt32(Some(Self::to_number(value)))
-    }
 }
 
 #[derive(Clone)]
@@ -85,10 +83,6 @@ impl NumberResultFunction<u32> for ToYYYYMMDD {
     fn to_number(value: DateTime<Utc>) -> u32 {
         value.year() as u32 * 10000 + value.month() * 100 + value.day()
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt32(Some(Self::to_number(value)))
-    }
 }
 
 #[derive(Clone)]
@@ -109,10 +103,6 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
             + value.minute() as u64 * 100
             + value.second() as u64
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt64(Some(Self::to_number(value)))
-    }
 }
 
 #[derive(Clone)]
@@ -128,10 +118,6 @@ impl NumberResultFunction<u16> for ToStartOfYear {
         let end: DateTime<Utc> = Utc.ymd(value.year(), 1, 1).and_hms(0, 0, 0);
         get_day(end) as u16
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt16(Some(Self::to_number(value) as u16))
-    }
 }
 
 #[derive(Clone)]
@@ -152,10 +138,6 @@ impl NumberResultFunction<u16> for ToStartOfISOYear {
         let end: DateTime<Utc> = Utc.timestamp_millis(result);
         get_day(end) as u16
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt16(Some(Self::to_number(value) as u16))
-    }
 }
 
 #[derive(Clone)]
@@ -172,10 +154,6 @@ impl NumberResultFunction<u16> for ToStartOfQuarter {
         let date = Utc.ymd(value.year(), new_month, 1).and_hms(0, 0, 0);
         get_day(date) as u16
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt16(Some(Self::to_number(value) as u16))
-    }
 }
 
 #[derive(Clone)]
@@ -191,10 +169,6 @@ impl NumberResultFunction<u16> for ToStartOfMonth {
         let date = Utc.ymd(value.year(), value.month(), 1).and_hms(0, 0, 0);
         get_day(date) as u16
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt16(Some(Self::to_number(value) as u16))
-    }
 }
 
 #[derive(Clone)]
@@ -210,10 +184,6 @@ impl NumberResultFunction<u8> for ToMonth {
         value.month() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt8(Some(Self::to_number(value)))
-    }
-
     // ToMonth is NOT a monotonic function in general, unless the time range is within the same year.
     // For example, date(2020-12-01) < date(2021-5-5), while ToMonth(2020-12-01) > ToMonth(2021-5-5).
     fn factor_function() -> Result<Box<dyn Function>> {
@@ -221,6 +191,25 @@ impl NumberResultFunction<u8> for ToMonth {
     }
 }
 
+#[derive(Clone)]
+pub struct ToQuarter;
+
+impl NumberResultFunction<u8> for ToQuarter {
+    const IS_DETERMINISTIC: bool = true;
+
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    fn to_number(value: DateTime<Utc>) -> u8 {
+        (value.month0() / 3 + 1) as u8
+    }
+
+    // ToQuarter is NOT a monotonic function in general, unless the time range is within the same year.
+    fn factor_function() -> Result<Box<dyn Function>> {
+        ToStartOfYearFunction::try_create("toStartOfYear")
+    }
+}
+
 #[derive(Clone)]
 pub struct ToDayOfYear;
 
@@ -234,10 +223,6 @@ impl NumberResultFunction<u16> for ToDayOfYear {
         value.ordinal() as u16
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt16(Some(Self::to_number(value)))
-    }
-
     // ToDayOfYear is NOT a monotonic function in general, unless the time range is within the same year.
     // For example, date(2020-12-01) < date(2021-5-5), while ToDayOfYear(2020-12-01) > ToDayOfYear(2021-5-5).
     fn factor_function() -> Result<Box<dyn Function>> {
@@ -258,10 +243,6 @@ impl NumberResultFunction<u8> for ToDayOfMonth {
         value.day() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt8(Some(Self::to_number(value)))
-    }
-
     // ToDayOfMonth is not a monotonic function in general, unless the time range is within the same month.
     // For example, date(2021-11-20) < date(2021-12-01), while ToDayOfMonth(2021-11-20) > ToDayOfMonth(2021-12-01).
     fn factor_function() -> Result<Box<dyn Function>> {
@@ -282,16 +263,34 @@ impl NumberResultFunction<u8> for ToDayOfWeek {
         value.weekday().number_from_monday() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt8(Some(Self::to_number(value)))
-    }
-
     // ToDayOfWeek is NOT a monotonic function in general, unless the time range is within the same week.
     fn factor_function() -> Result<Box<dyn Function>> {
         ToMondayFunction::try_create("toMonday")
     }
 }
 
+#[derive(Clone)]
+pub struct ToWeek;
+
+impl NumberResultFunction<u8> for ToWeek {
+    const IS_DETERMINISTIC: bool = true;
+
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    /// The ISO 8601 week number (1-53), the weeks start on Monday and the first week
+    /// of a year is the one containing its first Thursday.
+    fn to_number(value: DateTime<Utc>) -> u8 {
+        value.iso_week().week() as u8
+    }
+
+    // ToWeek is NOT a monotonic function in general, unless the time range is within the same ISO year.
+    // For example, date(2020-12-31) < date(2021-01-04), while ToWeek(2020-12-31) > ToWeek(2021-01-04).
+    fn factor_function() -> Result<Box<dyn Function>> {
+        ToStartOfISOYearFunction::try_create("toStartOfISOYear")
+    }
+}
+
 #[derive(Clone)]
 pub struct ToHour;
 
@@ -305,10 +304,6 @@ impl NumberResultFunction<u8> for ToHour {
         value.hour() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt8(Some(Self::to_number(value)))
-    }
-
     // ToHour is NOT a monotonic function in general, unless the time range is within the same day.
     fn factor_function() -> Result<Box<dyn Function>> {
         CastFunction::create("toDate".to_string(), DataType::Date16)
@@ -328,10 +323,6 @@ impl NumberResultFunction<u8> for ToMinute {
         value.minute() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt8(Some(Self::to_number(value)))
-    }
-
     // ToMinute is NOT a monotonic function in general, unless the time range is within the same hour.
     fn factor_function() -> Result<Box<dyn Function>> {
         RoundFunction::try_create("toStartOfHour", 60 * 60)
@@ -351,10 +342,6 @@ impl NumberResultFunction<u8> for ToSecond {
         value.second() as u8
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt8(Some(Self::to_number(value)))
-    }
-
     // ToSecond is NOT a monotonic function in general, unless the time range is within the same minute.
     fn factor_function() -> Result<Box<dyn Function>> {
         RoundFunction::try_create("toStartOfMinute", 60)
@@ -374,10 +361,6 @@ impl NumberResultFunction<u16> for ToMonday {
         let weekday = value.weekday();
         (get_day(value) - weekday.num_days_from_monday()) as u16
     }
-
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
-        DataValue::UInt16(Some(Self::to_number(value)))
-    }
 }
 
 impl<T, R> NumberFunction<T, R>
@@ -423,70 +406,27 @@ where
         T::return_type()
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
-    }
-
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let data_type = columns[0].data_type();
-        let number_array: DataColumn = match data_type {
-            DataType::Date16 => {
-                if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
-                    let constant_result = T::to_constant_value(date_time);
-                    Ok(DataColumn::Constant(constant_result, input_rows))
-                } else {
-                    let result: DFPrimitiveArray<R> = columns[0].column()
-                        .to_array()?
-                        .u16()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time)
-                        }
-                        );
-                    Ok(result.into())
-                }
-            }
-            DataType::Date32 => {
-                if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()? * 24 * 3600, 0_u32);
-                    let constant_result = T::to_constant_value(date_time);
-                    Ok(DataColumn::Constant(constant_result, input_rows))
-                } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .i32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time)
-                        }
-                        );
-                    Ok(result.into())
-                }
-            }
-            DataType::DateTime32(_) => {
-                if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64, 0_u32);
-                    let constant_result = T::to_constant_value(date_time);
-                    Ok(DataColumn::Constant(constant_result, input_rows))
-                } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .u32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
-                            T::to_number(date_time)
-                        }
-                        );
-                    Ok(result.into())
-                }
+        let column = columns[0].column();
+        match columns[0].data_type() {
+            DataType::Date16 => eval_unary_numeric(column, input_rows, |v: u16| {
+                T::to_number(date16_to_date_time(v))
+            }),
+            DataType::Date32 => eval_unary_numeric(column, input_rows, |v: i32| {
+                T::to_number(date32_to_date_time(v))
+            }),
+            DataType::DateTime32(_) => eval_unary_numeric(column, input_rows, |v: u32| {
+                T::to_number(date_time32_to_date_time(v))
+            }),
+            DataType::DateTime64(precision, _) => {
+                eval_date_time64(column, input_rows, *precision, T::to_number)
             }
+            DataType::Null => Ok(DataColumn::Constant(DataValue::Null, input_rows)),
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
-        }?;
-        Ok(number_array)
+        }
     }
 
     fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
@@ -519,6 +459,50 @@ impl<T, R> fmt::Display for NumberFunction<T, R> {
     }
 }
 
+/// Date16 values are the number of days since 1970-01-01.
+pub(crate) fn date16_to_date_time(v: u16) -> DateTime<Utc> {
+    Utc.timestamp(v as i64 * 24 * 3600, 0_u32)
+}
+
+/// Date32 values are the number of days since 1970-01-01, negative before it.
+pub(crate) fn date32_to_date_time(v: i32) -> DateTime<Utc> {
+    Utc.timestamp(v as i64 * 24 * 3600, 0_u32)
+}
+
+/// DateTime32 values are the number of seconds since 1970-01-01 00:00:00.
+pub(crate) fn date_time32_to_date_time(v: u32) -> DateTime<Utc> {
+    Utc.timestamp(v as i64, 0_u32)
+}
+
+/// DateTime64 values are the number of 10^-precision seconds since 1970-01-01 00:00:00,
+/// e.g. milliseconds for DateTime64(3). Returns None if the value is out of the range of DateTime.
+pub(crate) fn date_time64_to_date_time(v: i64, precision: u32) -> Option<DateTime<Utc>> {
+    v.to_date_time64(precision, &Tz::UTC)
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Like `eval_unary_numeric` for a DateTime64 column, a value out of the range of DateTime is null.
+pub(crate) fn eval_date_time64<R, F>(
+    column: &DataColumn,
+    input_rows: usize,
+    precision: u32,
+    f: F,
+) -> Result<DataColumn>
+where
+    R: DFPrimitiveType,
+    DFPrimitiveArray<R>: IntoSeries,
+    F: Fn(DateTime<Utc>) -> R,
+{
+    eval_unary(column, input_rows, R::data_type(), |series| {
+        let array = series.i64()?;
+        let it = array.into_iter().map(|v| {
+            v.and_then(|v| date_time64_to_date_time(*v, precision))
+                .map(&f)
+        });
+        Ok(DFPrimitiveArray::<R>::from_iter(it).into_series())
+    })
+}
+
 fn get_day(date: DateTime<Utc>) -> u32 {
     let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
     let duration = date.signed_duration_since(start);
@@ -534,10 +518,12 @@ pub type ToStartOfYearFunction = NumberFunction<ToStartOfYear, u16>;
 pub type ToStartOfQuarterFunction = NumberFunction<ToStartOfQuarter, u16>;
 pub type ToStartOfMonthFunction = NumberFunction<ToStartOfMonth, u16>;
 
+pub type ToQuarterFunction = NumberFunction<ToQuarter, u8>;
 pub type ToMonthFunction = NumberFunction<ToMonth, u8>;
 pub type ToDayOfYearFunction = NumberFunction<ToDayOfYear, u16>;
 pub type ToDayOfMonthFunction = NumberFunction<ToDayOfMonth, u8>;
 pub type ToDayOfWeekFunction = NumberFunction<ToDayOfWeek, u8>;
+pub type ToWeekFunction = NumberFunction<ToWeek, u8>;
 
 pub type ToHourFunction = NumberFunction<ToHour, u8>;
 pub type ToMinuteFunction = NumberFunction<ToMinute, u8>;
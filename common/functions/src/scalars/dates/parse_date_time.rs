@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::chrono::format::parse;
+use common_datavalues::chrono::format::Item;
+use common_datavalues::chrono::format::Parsed;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::format_date_time::parse_format;
+use crate::scalars::eval_unary;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+const DEFAULT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+// The default format also takes the seconds with a fraction and a trailing timezone offset,
+// e.g. `2021-06-15 10:20:30.5+08:00`.
+const DEFAULT_PARSE_FORMATS: [&str; 2] = ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f%z"];
+
+#[derive(Clone)]
+pub struct ParseDateTimeFunction {
+    display_name: String,
+    /// Fail on an unparseable row instead of returning null for it.
+    strict: bool,
+}
+
+impl ParseDateTimeFunction {
+    pub fn try_create(display_name: &str, strict: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseDateTimeFunction {
+            display_name: display_name.to_string(),
+            strict,
+        }))
+    }
+
+    pub fn desc(strict: bool) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name| {
+            Self::try_create(display_name, strict)
+        }))
+        .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for ParseDateTimeFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] != DataType::String && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or null, but got {}",
+                args[0]
+            )));
+        }
+        if args.len() > 1 && args[1] != DataType::String {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string, but got {}",
+                args[1]
+            )));
+        }
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Parses the strings with the optional strftime-style format of chrono, which must be a
+    /// constant and defaults to `%Y-%m-%d %H:%M:%S`. The time is in UTC unless the format parses
+    /// a timezone offset, the fraction of the seconds is truncated.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let (format, formats) = match columns.get(1).map(|c| c.column()) {
+            None => (
+                DEFAULT_FORMAT.to_string(),
+                DEFAULT_PARSE_FORMATS
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect::<Vec<_>>(),
+            ),
+            Some(DataColumn::Constant(DataValue::String(Some(format)), _)) => {
+                let format = String::from_utf8(format.clone()).map_err(|e| {
+                    ErrorCode::BadArguments(format!("Invalid format of {}: {}", self.name(), e))
+                })?;
+                (format.clone(), vec![format])
+            }
+            Some(_) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The format of {} must be a constant string",
+                    self.name()
+                )));
+            }
+        };
+        let items = formats
+            .iter()
+            .map(|f| parse_format(f))
+            .collect::<Result<Vec<_>>>()?;
+
+        let return_type = DataType::DateTime32(None);
+        eval_unary(columns[0].column(), input_rows, return_type, |series| {
+            let array = series.string()?;
+            let mut builder = PrimitiveArrayBuilder::<u32>::with_capacity(array.len());
+            for value in array {
+                match value {
+                    None => builder.append_null(),
+                    Some(v) => match items.iter().find_map(|items| parse_date_time(v, items)) {
+                        Some(timestamp) => builder.append_value(timestamp),
+                        None if self.strict => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Cannot parse '{}' as DateTime32 with format '{}'",
+                                String::from_utf8_lossy(v),
+                                format
+                            )));
+                        }
+                        None => builder.append_null(),
+                    },
+                }
+            }
+            Ok(builder.finish().into_series())
+        })
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+/// Returns the seconds since 1970-01-01 00:00:00 UTC, or None if the value doesn't match the format
+/// or is out of the range of DateTime32.
+fn parse_date_time(value: &[u8], items: &[Item]) -> Option<u32> {
+    let value = std::str::from_utf8(value).ok()?;
+    let mut parsed = Parsed::new();
+    parse(&mut parsed, value, items.iter()).ok()?;
+    let offset = parsed.offset.unwrap_or(0);
+    let date_time = parsed.to_naive_datetime_with_offset(offset).ok()?;
+    u32::try_from(date_time.timestamp() - offset as i64).ok()
+}
+
+impl fmt::Display for ParseDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -45,11 +45,7 @@ impl NoArgDateFunction for Today {
     const IS_DETERMINISTIC: bool = false;
 
     fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
-        let epoch = NaiveDate::from_ymd(1970, 1, 1);
-
-        let duration = utc.naive_utc().sub(epoch);
-        duration.num_days() as u16
+        today()
     }
 }
 
@@ -60,11 +56,7 @@ impl NoArgDateFunction for Yesterday {
     const IS_DETERMINISTIC: bool = false;
 
     fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
-        let epoch = NaiveDate::from_ymd(1970, 1, 1);
-
-        let duration = utc.naive_utc().sub(epoch);
-        duration.num_days() as u16 - 1
+        today() - 1
     }
 }
 
@@ -75,14 +67,19 @@ impl NoArgDateFunction for Tomorrow {
     const IS_DETERMINISTIC: bool = false;
 
     fn execute() -> u16 {
-        let utc: Date<Utc> = Utc::now().date();
-        let epoch = NaiveDate::from_ymd(1970, 1, 1);
-
-        let duration = utc.naive_utc().sub(epoch);
-        duration.num_days() as u16 + 1
+        today() + 1
     }
 }
 
+/// The day number of the current UTC date since 1970-01-01.
+fn today() -> u16 {
+    let utc: Date<Utc> = Utc::now().date();
+    let epoch = NaiveDate::from_ymd(1970, 1, 1);
+
+    let duration = utc.naive_utc().sub(epoch);
+    duration.num_days() as u16
+}
+
 impl<T> SimpleFunction<T>
 where T: NoArgDateFunction + Clone + Sync + Send + 'static
 {
@@ -111,6 +108,10 @@ where T: NoArgDateFunction + Clone + Sync + Send + 'static
         self.display_name.as_str()
     }
 
+    fn num_arguments(&self) -> usize {
+        0
+    }
+
     fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
         Ok(DataType::Date16)
     }
@@ -119,6 +120,7 @@ where T: NoArgDateFunction + Clone + Sync + Send + 'static
         Ok(false)
     }
 
+    /// The date is taken once, so all the rows of a block have the same value.
     fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let value = T::execute();
         Ok(DataColumn::Constant(
@@ -25,6 +25,11 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::number_function::date16_to_date_time;
+use super::number_function::date32_to_date_time;
+use super::number_function::date_time32_to_date_time;
+use super::number_function::eval_date_time64;
+use crate::scalars::eval_unary_numeric;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
@@ -42,7 +47,6 @@ pub trait WeekResultFunction<R> {
 
     fn return_type() -> Result<DataType>;
     fn to_number(_value: DateTime<Utc>, mode: Option<u64>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>, mode: Option<u64>) -> DataValue;
     fn factor_function() -> Result<Box<dyn Function>> {
         Err(ErrorCode::UnknownException(
             "Always monotonous, has no factor function",
@@ -53,13 +57,14 @@ pub trait WeekResultFunction<R> {
 #[derive(Clone)]
 pub struct ToStartOfWeek;
 
-impl WeekResultFunction<u32> for ToStartOfWeek {
+impl WeekResultFunction<u16> for ToStartOfWeek {
     const IS_DETERMINISTIC: bool = true;
 
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>, mode: Option<u64>) -> u32 {
+    /// The week starts on Sunday by default, an odd mode starts it on Monday.
+    fn to_number(value: DateTime<Utc>, mode: Option<u64>) -> u16 {
         let week_mode = mode.unwrap_or(0);
         let mut weekday = value.weekday().number_from_sunday();
         if week_mode & 1 == 1 {
@@ -68,11 +73,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
         weekday -= 1;
         let duration = Duration::days(weekday as i64);
         let result = value.sub(duration);
-        get_day(result)
-    }
-
-    fn to_constant_value(value: DateTime<Utc>, mode: Option<u64>) -> DataValue {
-        DataValue::UInt16(Some(Self::to_number(value, mode) as u16))
+        get_day(result) as u16
     }
 }
 
@@ -119,15 +120,10 @@ where
         Some((1, 2))
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
-    }
-
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let data_type = columns[0].data_type();
         let mut mode: Option<u64> = None;
         if columns.len() == 2 && !columns[1].column().is_empty() {
-            let week_mode = columns[1].column().to_values()?[0].clone().as_u64()?;
+            let week_mode = columns[1].column().try_get(0)?.as_u64()?;
             if !(0..=9).contains(&week_mode) {
                 return Err(ErrorCode::BadArguments(format!(
                     "The parameter:{} range is abnormal, it should be between 0-9",
@@ -136,64 +132,27 @@ where
             }
             mode = Some(week_mode);
         }
-        let number_array: DataColumn = match data_type {
-            DataType::Date16 => {
-                if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
-                    let constant_result = T::to_constant_value(date_time, mode);
-                    Ok(DataColumn::Constant(constant_result, input_rows))
-                } else {
-                    let result: DFPrimitiveArray<R> = columns[0].column()
-                        .to_array()?
-                        .u16()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time, mode)
-                        }
-                        );
-                    Ok(result.into())
-                }
-            },
-            DataType::Date32 => {
-                if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?  * 24 * 3600, 0_u32);
-                    let constant_result = T::to_constant_value(date_time, mode);
-                    Ok(DataColumn::Constant(constant_result, input_rows))
-                } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .i32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
-                            T::to_number(date_time, mode)
-                        }
-                        );
-                    Ok(result.into())
-                }
-            },
-            DataType::DateTime32(_) => {
-                if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_i64()?, 0_u32);
-                    let constant_result = T::to_constant_value(date_time, mode);
-                    Ok(DataColumn::Constant(constant_result, input_rows))
-                } else {
-                    let result = columns[0].column()
-                        .to_array()?
-                        .u32()?
-                        .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
-                            T::to_number(date_time, mode)
-                        }
-                        );
-                    Ok(result.into())
-                }
-            },
+
+        let column = columns[0].column();
+        match columns[0].data_type() {
+            DataType::Date16 => eval_unary_numeric(column, input_rows, |v: u16| {
+                T::to_number(date16_to_date_time(v), mode)
+            }),
+            DataType::Date32 => eval_unary_numeric(column, input_rows, |v: i32| {
+                T::to_number(date32_to_date_time(v), mode)
+            }),
+            DataType::DateTime32(_) => eval_unary_numeric(column, input_rows, |v: u32| {
+                T::to_number(date_time32_to_date_time(v), mode)
+            }),
+            DataType::DateTime64(precision, _) => {
+                eval_date_time64(column, input_rows, *precision, |v| T::to_number(v, mode))
+            }
+            DataType::Null => Ok(DataColumn::Constant(DataValue::Null, input_rows)),
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
-        }?;
-        Ok(number_array)
+        }
     }
 
     fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
@@ -231,4 +190,4 @@ fn get_day(date: DateTime<Utc>) -> u32 {
     duration.num_days() as u32
 }
 
-pub type ToStartOfWeekFunction = WeekFunction<ToStartOfWeek, u32>;
+pub type ToStartOfWeekFunction = WeekFunction<ToStartOfWeek, u16>;
@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use std::fmt;
+use std::sync::Arc;
 
+use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::compute::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
@@ -24,6 +28,7 @@ use common_datavalues::chrono::Utc;
 use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::ArrayApply;
 use common_datavalues::prelude::DFInt32Array;
+use common_datavalues::prelude::DFInt64Array;
 use common_datavalues::prelude::DFStringArray;
 use common_datavalues::prelude::DFUInt16Array;
 use common_datavalues::prelude::DFUInt32Array;
@@ -32,6 +37,7 @@ use common_datavalues::series::IntoSeries;
 use common_datavalues::series::Series;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
+use common_datavalues::DateTime64Serializer;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -43,15 +49,68 @@ pub struct CastFunction {
     _display_name: String,
     /// The data type to cast to
     cast_type: DataType,
+    /// Fail on a value which can't be converted instead of returning null for it
+    strict: bool,
 }
 
 impl CastFunction {
+    /// Creates a safe cast, a value which overflows the target type or can't be parsed is null.
     pub fn create(display_name: String, cast_type: DataType) -> Result<Box<dyn Function>> {
         Ok(Box::new(Self {
             _display_name: display_name,
             cast_type,
+            strict: false,
         }))
     }
+
+    /// Creates a strict cast, a value which overflows the target type or can't be parsed is an
+    /// error.
+    pub fn create_strict(display_name: String, cast_type: DataType) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            _display_name: display_name,
+            cast_type,
+            strict: true,
+        }))
+    }
+
+    /// Returns true if a numeric value of `from` may be out of the range of `to`, a safe cast
+    /// returns null for such a value.
+    pub fn is_narrowing_numeric_cast(from: &DataType, to: &DataType) -> bool {
+        if !from.is_numeric() || !to.is_numeric() || from == to {
+            return false;
+        }
+        let (from_size, to_size) = match (from.numeric_byte_size(), to.numeric_byte_size()) {
+            (Ok(from_size), Ok(to_size)) => (from_size, to_size),
+            _ => return false,
+        };
+
+        match (from.is_floating(), to.is_floating()) {
+            (true, false) => true,
+            (true, true) => to_size < from_size,
+            (false, true) => false,
+            (false, false) => {
+                to_size < from_size
+                    || (from.is_signed_numeric() && !to.is_signed_numeric())
+                    || (!from.is_signed_numeric() && to.is_signed_numeric() && to_size == from_size)
+            }
+        }
+    }
+
+    fn check_strict(&self, from_type: &DataType, from: &Series, to: &Series) -> Result<()> {
+        if to.null_count() <= from.null_count() {
+            return Ok(());
+        }
+
+        match (0..from.len()).find(|row| to.is_null(*row) && !from.is_null(*row)) {
+            Some(row) => Err(ErrorCode::BadDataValueType(format!(
+                "Cannot cast value {} of type {} to {}",
+                from.try_get(row)?,
+                from_type,
+                self.cast_type
+            ))),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Function for CastFunction {
@@ -63,15 +122,21 @@ impl Function for CastFunction {
         Ok(self.cast_type.clone())
     }
 
-    // TODO
+    // The type cast from is unknown here, a safe cast to a numeric type may narrow it.
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
+        Ok(!self.strict && self.cast_type.is_numeric())
     }
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         if columns[0].data_type() == &self.cast_type {
             return Ok(columns[0].column().clone());
         }
+        // The scales below are powers of ten by the precision, which must be valid first.
+        for data_type in [columns[0].data_type(), &self.cast_type] {
+            if let DataType::DateTime64(precision, tz) = data_type {
+                DataType::create_date_time64(*precision, tz.clone())?;
+            }
+        }
         let series = columns[0].column().clone().to_minimal_array()?;
         const DATE_FMT: &str = "%Y-%m-%d";
         const TIME_FMT: &str = "%Y-%m-%d %H:%M:%S";
@@ -91,7 +156,8 @@ impl Function for CastFunction {
                let arr = series.u16()?;
                match &self.cast_type {
                 Date32 => Ok(arr.apply_cast_numeric(|v| v as i32).into_series()),
-                DateTime32(_) => Ok(arr.apply_cast_numeric(|v|  Utc.timestamp(v as i64 * 24 * 3600, 0_u32).timestamp() as u32 ).into_series() ),
+                DateTime32(_) => Ok(DFUInt32Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| days_to_seconds(*x as i64)))).into_series()),
+                DateTime64(precision, _) => Ok(DFInt64Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| seconds_to_ticks(*x as i64 * 24 * 3600, *precision)))).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64 * 24 * 3600, 0_u32), DATE_FMT))) ).into_series()),
                 _ => error_fn(),
                }
@@ -103,7 +169,8 @@ impl Function for CastFunction {
                let arr = series.i32()?;
                match &self.cast_type {
                 Date32 => Ok(arr.apply_cast_numeric(|v| v as i32).into_series()),
-                DateTime32(_) => Ok(arr.apply_cast_numeric(|v|  Utc.timestamp(v as i64 * 24 * 3600, 0_u32).timestamp()  as u32).into_series() ),
+                DateTime32(_) => Ok(DFUInt32Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| days_to_seconds(*x as i64)))).into_series()),
+                DateTime64(precision, _) => Ok(DFInt64Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| seconds_to_ticks(*x as i64 * 24 * 3600, *precision)))).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64 * 24 * 3600, 0_u32), DATE_FMT))) ).into_series()),
                 _ => error_fn(),
                }
@@ -116,11 +183,40 @@ impl Function for CastFunction {
                match &self.cast_type {
                 Date16 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as u16).into_series()),
                 Date32 => Ok(arr.apply_cast_numeric(|v| (v as i64 / 24/ 3600) as i32).into_series()),
+                DateTime64(precision, _) => Ok(DFInt64Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| seconds_to_ticks(*x as i64, *precision)))).into_series()),
                 String => Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.map(|x| datetime_to_string( Utc.timestamp(*x as i64, 0_u32), TIME_FMT))) ).into_series()),
                 _ => error_fn(),
                }
             }),
 
+            (DataType::DateTime64(from_precision, _), _) => {
+                with_match_primitive_type!(&self.cast_type, |$T| {
+                    series.cast_with_type(&self.cast_type)
+                }, {
+                   let arr = series.i64()?;
+                   let ticks_per_second = 10_i64.pow(*from_precision);
+                   let ticks_per_day = ticks_per_second * 24 * 3600;
+                   match &self.cast_type {
+                    Date16 => Ok(DFUInt16Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| u16::try_from(x.div_euclid(ticks_per_day)).ok()))).into_series()),
+                    Date32 => Ok(DFInt32Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| i32::try_from(x.div_euclid(ticks_per_day)).ok()))).into_series()),
+                    DateTime32(_) => Ok(DFUInt32Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| u32::try_from(x.div_euclid(ticks_per_second)).ok()))).into_series()),
+                    DateTime64(precision, _) if precision >= from_precision => {
+                        let scale = 10_i64.pow(*precision - *from_precision);
+                        Ok(DFInt64Array::from_iter(arr.into_iter().map(|v| v.and_then(|x| x.checked_mul(scale)))).into_series())
+                    }
+                    DateTime64(precision, _) => {
+                        let scale = 10_i64.pow(*from_precision - *precision);
+                        Ok(arr.apply_cast_numeric(|v: i64| v.div_euclid(scale)).into_series())
+                    }
+                    String => {
+                        let serializer = DateTime64Serializer::create(*from_precision);
+                        Ok(DFStringArray::from_iter(arr.into_iter().map(|v| v.and_then(|x| serializer.format(*x).ok()))).into_series())
+                    }
+                    _ => error_fn(),
+                   }
+                })
+            }
+
             // others to Date/DateTime
             (_, DataType::Date16) => with_match_primitive_type!(columns[0].data_type(), |$T| {
                 series.cast_with_type(&self.cast_type)
@@ -159,7 +255,7 @@ impl Function for CastFunction {
                    match columns[0].data_type() {
                     String => {
                         let it = series.string()?.into_iter().map(|v| {
-                            v.and_then(string_to_datetime).map(|t| t.timestamp() as u32)
+                            v.and_then(string_to_datetime).and_then(|t| u32::try_from(t.timestamp()).ok())
                         });
                         Ok(DFUInt32Array::from_iter(it).into_series())
                     },
@@ -168,9 +264,50 @@ impl Function for CastFunction {
                 })
             }
 
+            (_, DataType::DateTime64(precision, _)) => {
+                with_match_primitive_type!(columns[0].data_type(), |$T| {
+                    series.cast_with_type(&self.cast_type)
+                }, {
+                   match columns[0].data_type() {
+                    String => {
+                        let it = series.string()?.into_iter().map(|v| {
+                            v.and_then(string_to_datetime).and_then(|t| {
+                                let nanos = t.timestamp_subsec_nanos() / 10_u32.pow(9 - *precision);
+                                t.timestamp()
+                                    .checked_mul(10_i64.pow(*precision))?
+                                    .checked_add(nanos as i64)
+                            })
+                        });
+                        Ok(DFInt64Array::from_iter(it).into_series())
+                    },
+                    _ => error_fn(),
+                   }
+                })
+            }
+
+            // others to String
+            (DataType::Boolean, DataType::String) => {
+                let it = series.bool()?.into_iter().map(|v| v.map(bool_to_string));
+                Ok(DFStringArray::from_iter(it).into_series())
+            }
+            (DataType::Float32, DataType::String) => {
+                let it = series.f32()?.into_iter().map(|v| v.map(|x| x.to_string()));
+                Ok(DFStringArray::from_iter(it).into_series())
+            }
+            (DataType::Float64, DataType::String) => {
+                let it = series.f64()?.into_iter().map(|v| v.map(|x| x.to_string()));
+                Ok(DFStringArray::from_iter(it).into_series())
+            }
+
+            (from, to) if from.is_numeric() && to.is_numeric() => checked_numeric_cast(&series, to),
+
             _ => series.cast_with_type(&self.cast_type),
         }?;
 
+        if self.strict {
+            self.check_strict(columns[0].data_type(), &series, &array)?;
+        }
+
         let column: DataColumn = array.into();
         Ok(column.resize_constant(input_rows))
     }
@@ -186,17 +323,61 @@ impl fmt::Display for CastFunction {
     }
 }
 
+/// Casts between the numeric types, a value out of the range of the target type is null instead of
+/// being wrapped around.
+fn checked_numeric_cast(series: &Series, data_type: &DataType) -> Result<Series> {
+    let array = cast::cast(
+        series.get_array_ref().as_ref(),
+        &data_type.to_arrow(),
+        CastOptions {
+            wrapped: false,
+            partial: false,
+        },
+    )?;
+    let array: ArrayRef = Arc::from(array);
+    Ok(array.into_series())
+}
+
+/// Converts days since the epoch to seconds since the epoch, a date out of the DateTime32 range
+/// is None instead of being wrapped around.
+#[inline]
+fn days_to_seconds(days: i64) -> Option<u32> {
+    u32::try_from(days * 24 * 3600).ok()
+}
+
+/// Converts seconds since the epoch to ticks of a DateTime64 with the given precision, a time out
+/// of the DateTime64 range is None instead of being wrapped around.
+#[inline]
+fn seconds_to_ticks(seconds: i64, precision: u32) -> Option<i64> {
+    seconds.checked_mul(10_i64.pow(precision))
+}
+
+#[inline]
+fn bool_to_string(v: bool) -> &'static str {
+    if v {
+        "true"
+    } else {
+        "false"
+    }
+}
+
 #[inline]
 fn datetime_to_string(date: DateTime<Utc>, fmt: &str) -> String {
     date.format(fmt).to_string()
 }
 
-// currently use UTC by default
-// TODO support timezone
+/// Parses `%Y-%m-%d %H:%M:%S` with optional fractional seconds, e.g. 2021-10-01 12:30:56.789, in UTC
+/// unless it ends with a timezone offset, e.g. 2021-10-01 20:30:56+08:00.
 #[inline]
 fn string_to_datetime(date_str: impl AsRef<[u8]>) -> Option<NaiveDateTime> {
-    let s = std::str::from_utf8(date_str.as_ref()).ok();
-    s.and_then(|c| NaiveDateTime::parse_from_str(c, "%Y-%m-%d %H:%M:%S").ok())
+    let s = std::str::from_utf8(date_str.as_ref()).ok()?;
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f")
+        .ok()
+        .or_else(|| {
+            DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%z")
+                .ok()
+                .map(|t| t.naive_utc())
+        })
 }
 
 #[inline]
@@ -23,45 +23,54 @@ use crate::scalars::CastFunction;
 pub struct ToCastFunction;
 
 impl ToCastFunction {
-    fn cast_function_creator(to_type: DataType) -> FunctionDescription {
+    fn cast_function_creator(to_type: DataType, strict: bool) -> FunctionDescription {
         let mut features = FunctionFeatures::default().deterministic().monotonicity();
         if to_type == DataType::Boolean {
             features = features.bool_function();
         }
 
         let function_creator: FactoryCreator = Box::new(move |display_name| {
-            CastFunction::create(display_name.to_string(), to_type.clone())
+            if strict {
+                CastFunction::create_strict(display_name.to_string(), to_type.clone())
+            } else {
+                CastFunction::create(display_name.to_string(), to_type.clone())
+            }
         });
 
         FunctionDescription::creator(function_creator).features(features)
     }
 
     pub fn register(factory: &mut FunctionFactory) {
-        factory.register("toNull", Self::cast_function_creator(DataType::Null));
-        factory.register("toBoolean", Self::cast_function_creator(DataType::Boolean));
-        factory.register("toUInt8", Self::cast_function_creator(DataType::UInt8));
-        factory.register("toUInt16", Self::cast_function_creator(DataType::UInt16));
-        factory.register("toUInt32", Self::cast_function_creator(DataType::UInt32));
-        factory.register("toUInt64", Self::cast_function_creator(DataType::UInt64));
-        factory.register("toInt8", Self::cast_function_creator(DataType::Int8));
-        factory.register("toInt16", Self::cast_function_creator(DataType::Int16));
-        factory.register("toInt32", Self::cast_function_creator(DataType::Int32));
-        factory.register("toInt64", Self::cast_function_creator(DataType::Int64));
-        factory.register("toFloat32", Self::cast_function_creator(DataType::Float32));
-        factory.register("toFloat64", Self::cast_function_creator(DataType::Float64));
-        factory.register("toDate16", Self::cast_function_creator(DataType::Date16));
-        factory.register("toDate32", Self::cast_function_creator(DataType::Date32));
-        factory.register("toString", Self::cast_function_creator(DataType::String));
+        let cast_types = [
+            ("toNull", DataType::Null),
+            ("toBoolean", DataType::Boolean),
+            ("toUInt8", DataType::UInt8),
+            ("toUInt16", DataType::UInt16),
+            ("toUInt32", DataType::UInt32),
+            ("toUInt64", DataType::UInt64),
+            ("toInt8", DataType::Int8),
+            ("toInt16", DataType::Int16),
+            ("toInt32", DataType::Int32),
+            ("toInt64", DataType::Int64),
+            ("toFloat32", DataType::Float32),
+            ("toFloat64", DataType::Float64),
+            ("toDate16", DataType::Date16),
+            ("toDate32", DataType::Date32),
+            ("toString", DataType::String),
+            // aliases
+            ("toDate", DataType::Date16),
+            ("toDateTime", DataType::DateTime32(None)),
+            ("toDateTime32", DataType::DateTime32(None)),
+            ("toDateTime64", DataType::DateTime64(3, None)),
+        ];
 
-        // aliases
-        factory.register("toDate", Self::cast_function_creator(DataType::Date16));
-        factory.register(
-            "toDateTime",
-            Self::cast_function_creator(DataType::DateTime32(None)),
-        );
-        factory.register(
-            "toDateTime32",
-            Self::cast_function_creator(DataType::DateTime32(None)),
-        );
+        for (name, to_type) in cast_types {
+            factory.register(name, Self::cast_function_creator(to_type.clone(), false));
+            // e.g., toUInt8Strict(300) is an error rather than null.
+            factory.register(
+                &format!("{}Strict", name),
+                Self::cast_function_creator(to_type, true),
+            );
+        }
     }
 }
@@ -76,6 +76,13 @@ pub trait Function: fmt::Display + Sync + Send + DynClone {
     fn passthrough_null(&self) -> bool {
         true
     }
+
+    /// Whether the function expands each row into a row per element of its list argument,
+    /// like arrayJoin. Such a function is not evaluated by `eval`, the expression executor
+    /// expands all the columns of the block instead.
+    fn expands_rows(&self) -> bool {
+        false
+    }
 }
 
 dyn_clone::clone_trait_object!(Function);
@@ -20,6 +20,7 @@ use common_exception::Result;
 use lazy_static::lazy_static;
 
 use crate::scalars::ArithmeticFunction;
+use crate::scalars::BitFunction;
 use crate::scalars::ComparisonFunction;
 use crate::scalars::ConditionalFunction;
 use crate::scalars::DateFunction;
@@ -111,6 +112,7 @@ lazy_static! {
     static ref FUNCTION_FACTORY: Arc<FunctionFactory> = {
         let mut function_factory = FunctionFactory::create();
         ArithmeticFunction::register(&mut function_factory);
+        BitFunction::register(&mut function_factory);
         ComparisonFunction::register(&mut function_factory);
         LogicFunction::register(&mut function_factory);
         NullableFunction::register(&mut function_factory);
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Evaluates a unary function over a column of primitive type `T`, value by value.
+///
+/// A constant column is evaluated only once and stays constant. Nulls are never passed to `f`,
+/// they remain nulls in the result so that a function needs no null handling of its own.
+pub fn eval_unary_numeric<T, R, F>(
+    column: &DataColumn,
+    input_rows: usize,
+    f: F,
+) -> Result<DataColumn>
+where
+    T: DFPrimitiveType,
+    R: DFPrimitiveType,
+    DFPrimitiveArray<R>: IntoSeries,
+    F: Fn(T) -> R + Copy,
+{
+    eval_unary(column, input_rows, R::data_type(), |series| {
+        let physical_type = PhysicalDataType::from(series.data_type().clone());
+        if physical_type != PhysicalDataType::from(T::data_type()) {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected {}, but got {}",
+                T::data_type(),
+                series.data_type()
+            )));
+        }
+
+        let array: &DFPrimitiveArray<T> = series.static_cast();
+        Ok(array.apply_cast_numeric(f).into_series())
+    })
+}
+
+/// Evaluates a unary function over a string column, value by value.
+///
+/// The constant and null handling is the same as `eval_unary_numeric`.
+pub fn eval_unary_string<R, F>(column: &DataColumn, input_rows: usize, f: F) -> Result<DataColumn>
+where
+    R: DFPrimitiveType,
+    DFPrimitiveArray<R>: IntoSeries,
+    F: Fn(&[u8]) -> R + Copy,
+{
+    eval_unary(column, input_rows, R::data_type(), |series| {
+        Ok(series.string()?.apply_cast_numeric(f).into_series())
+    })
+}
+
+/// Evaluates a unary function over the array of `column`, `f` must keep the nulls of its input.
+///
+/// A column of type null is not passed to `f`, the result is a constant null of `return_type`.
+pub fn eval_unary<F>(
+    column: &DataColumn,
+    input_rows: usize,
+    return_type: DataType,
+    f: F,
+) -> Result<DataColumn>
+where
+    F: Fn(&Series) -> Result<Series>,
+{
+    if column.data_type() == DataType::Null {
+        return Ok(DataColumn::Constant(
+            DataValue::from(&return_type),
+            input_rows,
+        ));
+    }
+
+    match column {
+        DataColumn::Array(series) => Ok(f(series)?.into()),
+        DataColumn::Constant(_, _) => {
+            let series = f(&column.to_minimal_array()?)?;
+            Ok(DataColumn::Constant(series.try_get(0)?, input_rows))
+        }
+    }
+}
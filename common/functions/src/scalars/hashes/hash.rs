@@ -14,6 +14,8 @@
 
 use crate::scalars::function_factory::FunctionFactory;
 use crate::scalars::Blake3HashFunction;
+use crate::scalars::IntHash32Function;
+use crate::scalars::IntHash64Function;
 use crate::scalars::Md5HashFunction;
 use crate::scalars::Sha1HashFunction;
 use crate::scalars::Sha2HashFunction;
@@ -35,5 +37,7 @@ impl HashesFunction {
         factory.register("blake3", Blake3HashFunction::desc());
         factory.register("xxhash32", XxHash32Function::desc());
         factory.register("xxhash64", XxHash64Function::desc());
+        factory.register("intHash32", IntHash32Function::desc());
+        factory.register("intHash64", IntHash64Function::desc());
     }
 }
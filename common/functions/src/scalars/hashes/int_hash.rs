@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::eval_unary_numeric;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+pub trait IntHash<R>: Send + Sync + Clone + 'static {
+    /// Hashes the integer, the signed integers are sign-extended to 64 bits first.
+    fn hash(value: u64) -> R;
+}
+
+/// The intHash32 of ClickHouse, a 64-bit to 32-bit mix of Thomas Wang.
+#[derive(Clone)]
+pub struct IntHash32;
+
+impl IntHash<u32> for IntHash32 {
+    fn hash(value: u64) -> u32 {
+        let mut key = (!value).wrapping_add(value << 18);
+        key ^= key.rotate_right(31);
+        key = key.wrapping_mul(21);
+        key ^= key.rotate_right(11);
+        key = key.wrapping_add(key << 6);
+        key ^= key.rotate_right(22);
+        key as u32
+    }
+}
+
+/// The intHash64 of ClickHouse, the finalizer of MurmurHash3.
+#[derive(Clone)]
+pub struct IntHash64;
+
+impl IntHash<u64> for IntHash64 {
+    fn hash(value: u64) -> u64 {
+        let mut key = value;
+        key ^= key >> 33;
+        key = key.wrapping_mul(0xff51afd7ed558ccd);
+        key ^= key >> 33;
+        key = key.wrapping_mul(0xc4ceb9fe1a85ec53);
+        key ^= key >> 33;
+        key
+    }
+}
+
+#[derive(Clone)]
+pub struct IntHashFunction<T, R> {
+    display_name: String,
+    t: PhantomData<T>,
+    r: PhantomData<R>,
+}
+
+impl<T, R> IntHashFunction<T, R>
+where
+    T: IntHash<R>,
+    R: DFPrimitiveType + Clone,
+    DFPrimitiveArray<R>: IntoSeries,
+{
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(IntHashFunction::<T, R> {
+            display_name: display_name.to_string(),
+            t: PhantomData,
+            r: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl<T, R> Function for IntHashFunction<T, R>
+where
+    T: IntHash<R>,
+    R: DFPrimitiveType + Clone,
+    DFPrimitiveArray<R>: IntoSeries,
+{
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_integer() && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected integer or null, but got {}",
+                args[0]
+            )));
+        }
+        Ok(R::data_type())
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let column = columns[0].column();
+        match columns[0].data_type() {
+            DataType::Int8 => eval_unary_numeric(column, input_rows, |v: i8| T::hash(v as u64)),
+            DataType::Int16 => eval_unary_numeric(column, input_rows, |v: i16| T::hash(v as u64)),
+            DataType::Int32 => eval_unary_numeric(column, input_rows, |v: i32| T::hash(v as u64)),
+            DataType::Int64 => eval_unary_numeric(column, input_rows, |v: i64| T::hash(v as u64)),
+            DataType::UInt8 => eval_unary_numeric(column, input_rows, |v: u8| T::hash(v as u64)),
+            DataType::UInt16 => eval_unary_numeric(column, input_rows, |v: u16| T::hash(v as u64)),
+            DataType::UInt32 => eval_unary_numeric(column, input_rows, |v: u32| T::hash(v as u64)),
+            DataType::UInt64 => eval_unary_numeric(column, input_rows, |v: u64| T::hash(v)),
+            DataType::Null => Ok(DataColumn::Constant(
+                DataValue::from(&R::data_type()),
+                input_rows,
+            )),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected integer or null, but got {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl<T, R> fmt::Display for IntHashFunction<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub type IntHash32Function = IntHashFunction<IntHash32, u32>;
+pub type IntHash64Function = IntHashFunction<IntHash64, u64>;
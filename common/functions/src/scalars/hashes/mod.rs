@@ -14,6 +14,7 @@
 
 mod blake3hash;
 mod hash;
+mod int_hash;
 mod md5hash;
 mod sha1hash;
 mod sha2hash;
@@ -22,6 +23,8 @@ mod xxhash;
 
 pub use blake3hash::Blake3HashFunction;
 pub use hash::HashesFunction;
+pub use int_hash::IntHash32Function;
+pub use int_hash::IntHash64Function;
 pub use md5hash::Md5HashFunction;
 pub use sha1hash::Sha1HashFunction;
 pub use sha2hash::Sha2HashFunction;
@@ -17,11 +17,11 @@ use std::fmt;
 
 use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::*;
-use common_datavalues::DataSchema;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::scalars::eval_unary;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
@@ -76,15 +76,16 @@ impl Function for SipHashFunction {
         }
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
-    }
-
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let series = columns[0].column().to_minimal_array()?;
-        let hasher = DFHasher::SipHasher(DefaultHasher::new());
-        let res: DataColumn = series.vec_hash(hasher)?.into();
-        Ok(res.resize_constant(input_rows))
+        eval_unary(
+            columns[0].column(),
+            input_rows,
+            DataType::UInt64,
+            |series| {
+                let hasher = DFHasher::SipHasher(DefaultHasher::new());
+                Ok(series.vec_hash(hasher)?.into_series())
+            },
+        )
     }
 }
 
@@ -12,15 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
 use std::hash::Hasher;
 
-use common_datavalues::DataType;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use twox_hash::XxHash32;
 use twox_hash::XxHash64;
 
+use crate::scalars::eval_unary_numeric;
+use crate::scalars::eval_unary_string;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::strings::NumberResultFunction;
 use crate::scalars::strings::String2NumberFunction;
+use crate::scalars::Function;
 
 #[derive(Clone)]
 pub struct DfXxHash32 {}
@@ -40,23 +47,101 @@ impl NumberResultFunction<u32> for DfXxHash32 {
     }
 }
 
+/// Integers are hashed by the xxHash64 of their little-endian bytes.
+pub trait XxHash64Input: DFPrimitiveType {
+    fn xxhash64(self) -> u64;
+}
+
+macro_rules! impl_xxhash64_input {
+    ($($t:ty),*) => {
+        $(
+            impl XxHash64Input for $t {
+                #[inline]
+                fn xxhash64(self) -> u64 {
+                    xxhash64(&self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_xxhash64_input!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+#[inline]
+fn xxhash64(value: &[u8]) -> u64 {
+    let mut hasher = XxHash64::default();
+    hasher.write(value);
+    hasher.finish()
+}
+
 #[derive(Clone)]
-pub struct DfXxHash64 {}
+pub struct XxHash64Function {
+    display_name: String,
+}
 
-impl NumberResultFunction<u64> for DfXxHash64 {
-    const IS_DETERMINISTIC: bool = true;
-    const MAYBE_MONOTONIC: bool = false;
+impl XxHash64Function {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(XxHash64Function {
+            display_name: display_name.to_string(),
+        }))
+    }
 
-    fn return_type() -> Result<DataType> {
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn eval_integer<T: XxHash64Input>(
+        column: &DataColumn,
+        input_rows: usize,
+    ) -> Result<DataColumn> {
+        eval_unary_numeric(column, input_rows, |v: T| v.xxhash64())
+    }
+}
+
+impl Function for XxHash64Function {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_integer() && args[0] != DataType::String && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected integer or string or null, but got {}",
+                args[0]
+            )));
+        }
         Ok(DataType::UInt64)
     }
 
-    fn to_number(value: &[u8]) -> u64 {
-        let mut hasher = XxHash64::default();
-        hasher.write(value);
-        hasher.finish()
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let column = columns[0].column();
+        match columns[0].data_type() {
+            DataType::Int8 => Self::eval_integer::<i8>(column, input_rows),
+            DataType::Int16 => Self::eval_integer::<i16>(column, input_rows),
+            DataType::Int32 => Self::eval_integer::<i32>(column, input_rows),
+            DataType::Int64 => Self::eval_integer::<i64>(column, input_rows),
+            DataType::UInt8 => Self::eval_integer::<u8>(column, input_rows),
+            DataType::UInt16 => Self::eval_integer::<u16>(column, input_rows),
+            DataType::UInt32 => Self::eval_integer::<u32>(column, input_rows),
+            DataType::UInt64 => Self::eval_integer::<u64>(column, input_rows),
+            DataType::String | DataType::Null => eval_unary_string(column, input_rows, xxhash64),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "Expected integer or string or null, but got {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for XxHash64Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
     }
 }
 
 pub type XxHash32Function = String2NumberFunction<DfXxHash32, u32>;
-pub type XxHash64Function = String2NumberFunction<DfXxHash64, u64>;
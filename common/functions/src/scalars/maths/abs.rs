@@ -42,7 +42,7 @@ impl AbsFunction {
 }
 
 macro_rules! impl_abs_function {
-    ($column:expr, $type:ident, $cast_type:expr) => {{
+    ($column:expr, $type:ident, $op:expr) => {{
         let mut series = $column.column().to_minimal_array()?;
 
         // coerce String to Float
@@ -51,10 +51,7 @@ macro_rules! impl_abs_function {
         }
 
         let primitive_array = series.$type()?;
-        let column: DataColumn = primitive_array
-            .apply_cast_numeric(|v| v.abs())
-            .cast_with_type(&$cast_type)?
-            .into();
+        let column: DataColumn = primitive_array.apply_cast_numeric($op).into();
         Ok(column.resize_constant($column.column().len()))
     }};
 }
@@ -92,13 +89,15 @@ impl Function for AbsFunction {
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
         match columns[0].data_type() {
-            DataType::Int8 => impl_abs_function!(columns[0], i8, DataType::UInt8),
-            DataType::Int16 => impl_abs_function!(columns[0], i16, DataType::UInt16),
-            DataType::Int32 => impl_abs_function!(columns[0], i32, DataType::UInt32),
-            DataType::Int64 => impl_abs_function!(columns[0], i64, DataType::UInt16),
-            DataType::Float32 => impl_abs_function!(columns[0], f32, DataType::Float32),
-            DataType::Float64 => impl_abs_function!(columns[0], f64, DataType::Float64),
-            DataType::String => impl_abs_function!(columns[0], f64, DataType::Float64),
+            // The signed integers map to the unsigned integers of the same width,
+            // so the abs of the minimum value (e.g. -128 for Int8) never overflows.
+            DataType::Int8 => impl_abs_function!(columns[0], i8, |v: i8| v.unsigned_abs()),
+            DataType::Int16 => impl_abs_function!(columns[0], i16, |v: i16| v.unsigned_abs()),
+            DataType::Int32 => impl_abs_function!(columns[0], i32, |v: i32| v.unsigned_abs()),
+            DataType::Int64 => impl_abs_function!(columns[0], i64, |v: i64| v.unsigned_abs()),
+            DataType::Float32 => impl_abs_function!(columns[0], f32, |v: f32| v.abs()),
+            DataType::Float64 => impl_abs_function!(columns[0], f64, |v: f64| v.abs()),
+            DataType::String => impl_abs_function!(columns[0], f64, |v: f64| v.abs()),
             DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
                 Ok(columns[0].column().clone())
             }
@@ -92,7 +92,7 @@ impl Function for CeilFunction {
             .f64()?
             .apply_cast_numeric(|v| v.ceil());
         let column: DataColumn = result.into();
-        Ok(column)
+        Ok(column.resize_constant(columns[0].column().len()))
     }
 
     fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
@@ -19,6 +19,7 @@ use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::*;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::scalars::function_factory::FunctionDescription;
@@ -57,15 +58,34 @@ impl Function for RoundingFunction {
         Ok(true)
     }
 
-    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_numeric() && args[0] != DataType::String && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected numeric types, but got {}",
+                args[0]
+            )));
+        }
+        if args.len() > 1 && !args[1].is_integer() && args[1] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected integer types for decimal places, but got {}",
+                args[1]
+            )));
+        }
         Ok(DataType::Float64)
     }
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        // Scaling by the decimal places may overflow for large x, such x has no digits
+        // left to round at that scale, so it is returned unchanged.
         let round = |x: f64, d: i64| match d.cmp(&0) {
             Ordering::Greater => {
                 let z = 10_f64.powi(if d > 30 { 30 } else { d as i32 });
-                (self.rounding_func)(x * z) / z
+                let y = x * z;
+                if y.is_finite() {
+                    (self.rounding_func)(y) / z
+                } else {
+                    x
+                }
             }
             Ordering::Less => {
                 let z = 10_f64.powi(if d < -30 { 30 } else { -d as i32 });
@@ -91,17 +111,7 @@ impl Function for RoundingFunction {
                     } else {
                         let x_arr = x_series.f64()?;
                         let v: i64 = DFTryFrom::try_from(d.clone())?;
-                        match v.cmp(&0) {
-                            Ordering::Greater => {
-                                let z = 10_f64.powi(if v > 30 { 30 } else { v as i32 });
-                                x_arr.apply(|x| (self.rounding_func)(x * z) / z)
-                            }
-                            Ordering::Less => {
-                                let z = 10_f64.powi(if v < -30 { 30 } else { -v as i32 });
-                                x_arr.apply(|x| (self.rounding_func)(x / z) * z)
-                            }
-                            Ordering::Equal => x_arr.apply(|x| (self.rounding_func)(x)),
-                        }
+                        x_arr.apply(|x| round(x, v))
                     }
                 }
                 (DataColumn::Constant(x, _), DataColumn::Array(d_series)) => {
@@ -130,6 +140,7 @@ impl fmt::Display for RoundingFunction {
     }
 }
 
+/// Rounds half away from zero, so `round(2.5)` is 3 and `round(-2.5)` is -3.
 pub struct RoundNumberFunction {}
 
 impl RoundNumberFunction {
@@ -143,6 +154,7 @@ impl RoundNumberFunction {
     }
 }
 
+/// Rounds toward zero, so `truncate(-2.7)` is -2.
 pub struct TruncNumberFunction {}
 
 impl TruncNumberFunction {
@@ -101,7 +101,7 @@ impl Function for SignFunction {
                 }
             });
         let column: DataColumn = result.into();
-        Ok(column)
+        Ok(column.resize_constant(columns[0].column().len()))
     }
 
     fn get_monotonicity(&self, args: &[Monotonicity]) -> Result<Monotonicity> {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod arithmetics;
+mod bits;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -23,6 +24,7 @@ mod function_column;
 mod function_factory;
 mod function_literal;
 mod function_monotonic;
+mod function_unary;
 mod hashes;
 mod logics;
 mod maths;
@@ -33,6 +35,7 @@ mod tuples;
 mod udfs;
 
 pub use arithmetics::*;
+pub use bits::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
@@ -43,6 +46,9 @@ pub use function_column::ColumnFunction;
 pub use function_factory::FunctionFactory;
 pub use function_literal::LiteralFunction;
 pub use function_monotonic::Monotonicity;
+pub use function_unary::eval_unary;
+pub use function_unary::eval_unary_numeric;
+pub use function_unary::eval_unary_string;
 pub use hashes::*;
 pub use logics::*;
 pub use maths::*;
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct CoalesceFunction {
+    display_name: String,
+}
+
+impl CoalesceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(CoalesceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for CoalesceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX - 1))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        aggregate_types(args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Takes the first non-null value of each row, the remaining arguments are not evaluated
+    /// once no null is left.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_types: Vec<DataType> = columns.iter().map(|c| c.data_type().clone()).collect();
+        let data_type = aggregate_types(&data_types)?;
+        if data_type == DataType::Null {
+            return Ok(DataColumn::Constant(DataValue::Null, input_rows));
+        }
+
+        let mut result = columns[0].column().cast_with_type(&data_type)?;
+        for column in columns[1..].iter() {
+            if !has_null(&result) {
+                break;
+            }
+
+            result = result
+                .is_null()?
+                .if_then_else(column.column(), &result)?
+                .cast_with_type(&data_type)?;
+        }
+        Ok(result)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+fn has_null(column: &DataColumn) -> bool {
+    match column {
+        DataColumn::Array(array) => array.null_count() > 0,
+        DataColumn::Constant(v, _) => v.is_null(),
+    }
+}
+
+impl fmt::Display for CoalesceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
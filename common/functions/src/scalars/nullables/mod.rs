@@ -12,10 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod assume_not_null;
+mod coalesce;
+mod if_null;
 mod is_not_null;
 mod is_null;
+mod null_if;
 mod nullable;
+mod to_nullable;
 
+pub use assume_not_null::AssumeNotNullFunction;
+pub use coalesce::CoalesceFunction;
+pub use if_null::IfNullFunction;
 pub use is_not_null::IsNotNullFunction;
 pub use is_null::IsNullFunction;
+pub use null_if::NullIfFunction;
 pub use nullable::NullableFunction;
+pub use to_nullable::ToNullableFunction;
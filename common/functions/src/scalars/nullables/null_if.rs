@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_datavalues::DataValueComparisonOperator;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct NullIfFunction {
+    display_name: String,
+}
+
+impl NullIfFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(NullIfFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for NullIfFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        Ok(args[0].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Returns null where the two arguments are equal, otherwise the first argument.
+    /// A null on either side never compares equal, so the first argument is kept.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let lhs_type = columns[0].data_type();
+        let rhs_type = columns[1].data_type();
+        if lhs_type.is_null() || rhs_type.is_null() {
+            return Ok(columns[0].column().clone());
+        }
+
+        let eq = if lhs_type != rhs_type {
+            let coercion_type = compare_coercion(lhs_type, rhs_type)?;
+            let lhs = columns[0].column().cast_with_type(&coercion_type)?;
+            let rhs = columns[1].column().cast_with_type(&coercion_type)?;
+            lhs.compare(DataValueComparisonOperator::Eq, &rhs)?
+        } else {
+            columns[0]
+                .column()
+                .compare(DataValueComparisonOperator::Eq, columns[1].column())?
+        };
+
+        let null = DataColumn::Constant(DataValue::from(lhs_type), input_rows);
+        eq.if_then_else(&null, columns[0].column())?
+            .cast_with_type(lhs_type)
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for NullIfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -13,8 +13,13 @@
 // limitations under the License.
 
 use crate::scalars::function_factory::FunctionFactory;
+use crate::scalars::AssumeNotNullFunction;
+use crate::scalars::CoalesceFunction;
+use crate::scalars::IfNullFunction;
 use crate::scalars::IsNotNullFunction;
 use crate::scalars::IsNullFunction;
+use crate::scalars::NullIfFunction;
+use crate::scalars::ToNullableFunction;
 
 #[derive(Clone)]
 pub struct NullableFunction;
@@ -23,5 +28,10 @@ impl NullableFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("isnull", IsNullFunction::desc());
         factory.register("isnotnull", IsNotNullFunction::desc());
+        factory.register("coalesce", CoalesceFunction::desc());
+        factory.register("ifNull", IfNullFunction::desc());
+        factory.register("nullIf", NullIfFunction::desc());
+        factory.register("toNullable", ToNullableFunction::desc());
+        factory.register("assumeNotNull", AssumeNotNullFunction::desc());
     }
 }
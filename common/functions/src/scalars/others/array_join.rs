@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::DataColumn;
+use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+// arrayJoin(list) expands each row into a row per element of the list, the other columns are
+// replicated. A row with an empty list is dropped, a row with a null list is kept with a null.
+// eg: SELECT arrayJoin(splitByChar(',', 'a,b,c'));
+#[derive(Clone)]
+pub struct ArrayJoinFunction {
+    display_name: String,
+}
+
+impl ArrayJoinFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayJoinFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        // Not deterministic, so that it is never folded into the constant of its first row.
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default())
+    }
+}
+
+impl Function for ArrayJoinFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(inner) => Ok(inner.data_type().clone()),
+            other => Err(ErrorCode::IllegalDataType(format!(
+                "{} requires a list argument, but got {}",
+                self.display_name, other
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, _columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        Err(ErrorCode::LogicalError(format!(
+            "{} expands the rows of the block, it can't be evaluated as a column",
+            self.display_name
+        )))
+    }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+
+    fn expands_rows(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for ArrayJoinFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
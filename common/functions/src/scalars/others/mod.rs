@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod array_join;
 mod ignore;
 mod inet_aton;
 mod inet_ntoa;
 mod other;
 mod running_difference_function;
 
+pub use array_join::ArrayJoinFunction;
 pub use ignore::IgnoreFunction;
 pub use inet_aton::InetAtonFunction;
 pub use inet_ntoa::InetNtoaFunction;
@@ -15,6 +15,7 @@
 use super::inet_aton::InetAtonFunction;
 use super::inet_ntoa::InetNtoaFunction;
 use super::running_difference_function::RunningDifferenceFunction;
+use super::ArrayJoinFunction;
 use super::IgnoreFunction;
 use crate::scalars::function_factory::FunctionFactory;
 
@@ -25,6 +26,7 @@ impl OtherFunction {
     pub fn register(factory: &mut FunctionFactory) {
         factory.register("runningDifference", RunningDifferenceFunction::desc());
         factory.register("ignore", IgnoreFunction::desc());
+        factory.register("arrayJoin", ArrayJoinFunction::desc());
         factory.register("inet_ntoa", InetNtoaFunction::desc());
         factory.register("IPv4NumToString", InetNtoaFunction::desc());
         factory.register("inet_aton", InetAtonFunction::desc());
@@ -21,17 +21,28 @@ use super::String2NumberFunction;
 #[derive(Clone)]
 pub struct Ascii {}
 
-impl NumberResultFunction<u8> for Ascii {
+impl NumberResultFunction<u32> for Ascii {
     const IS_DETERMINISTIC: bool = true;
     const MAYBE_MONOTONIC: bool = false;
 
     fn return_type() -> Result<DataType> {
-        Ok(DataType::UInt8)
+        Ok(DataType::UInt32)
     }
 
-    fn to_number(value: &[u8]) -> u8 {
-        value[0]
+    /// The code point of the first character, 0 for an empty string.
+    /// A string which doesn't start with a valid utf8 character gives its first byte.
+    fn to_number(value: &[u8]) -> u32 {
+        // A character takes at most 4 bytes.
+        let prefix = &value[..value.len().min(4)];
+        let valid = match std::str::from_utf8(prefix) {
+            Ok(s) => s,
+            Err(e) => std::str::from_utf8(&prefix[..e.valid_up_to()]).unwrap_or_default(),
+        };
+        match valid.chars().next() {
+            Some(c) => c as u32,
+            None => value.first().map_or(0, |b| *b as u32),
+        }
     }
 }
 
-pub type AsciiFunction = String2NumberFunction<Ascii, u8>;
+pub type AsciiFunction = String2NumberFunction<Ascii, u32>;
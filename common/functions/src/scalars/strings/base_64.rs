@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use common_datavalues::prelude::DFStringArray;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 use super::string2string::String2StringFunction;
 use super::string2string::StringOperator;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
 
 #[derive(Clone, Default)]
 pub struct Encode {}
@@ -23,6 +30,10 @@ pub struct Encode {}
 impl StringOperator for Encode {
     #[inline]
     fn apply_with_no_null<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> usize {
+        let len = (s.len() + 2) / 3 * 4;
+        if buffer.len() < len {
+            return len;
+        }
         base64::encode_config_slice(s, base64::STANDARD, buffer)
     }
 
@@ -37,6 +48,10 @@ pub struct Decode {}
 impl StringOperator for Decode {
     #[inline]
     fn apply<'a>(&'a mut self, s: &'a [u8], buffer: &mut [u8]) -> Option<usize> {
+        let len = (s.len() + 3) / 4 * 3;
+        if buffer.len() < len {
+            return Some(len);
+        }
         match base64::decode_config_slice(s, base64::STANDARD, buffer) {
             Ok(len) => Some(len),
             Err(_) => None,
@@ -48,9 +63,90 @@ impl StringOperator for Decode {
     }
 
     fn estimate_bytes(&self, array: &DFStringArray) -> usize {
-        array.inner().values().len() * 4 / 3 + array.len() * 4
+        array.inner().values().len() / 4 * 3 + array.len() * 3
     }
 }
 
 pub type Base64EncodeFunction = String2StringFunction<Encode>;
-pub type Base64DecodeFunction = String2StringFunction<Decode>;
+
+#[derive(Clone)]
+pub struct Base64DecodeFunction {
+    display_name: String,
+    /// Fail on a row which is not valid base64 instead of returning null for it.
+    strict: bool,
+}
+
+impl Base64DecodeFunction {
+    pub fn try_create(display_name: &str, strict: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Base64DecodeFunction {
+            display_name: display_name.to_string(),
+            strict,
+        }))
+    }
+
+    pub fn desc(strict: bool) -> FunctionDescription {
+        FunctionDescription::creator(Box::new(move |display_name| {
+            Self::try_create(display_name, strict)
+        }))
+        .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for Base64DecodeFunction {
+    fn name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_numeric() && args[0] != DataType::String && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or null, but got {}",
+                args[0]
+            )));
+        }
+
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let mut op = Decode::default();
+
+        let array = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_minimal_array()?;
+        let array = array.string()?;
+
+        let estimate_bytes = op.estimate_bytes(array);
+        let decoded = transform(array, estimate_bytes, |val, buffer| op.apply(val, buffer));
+
+        if self.strict && decoded.null_count() > array.null_count() {
+            let invalid =
+                (0..array.len()).find(|row| decoded.is_null(*row) && !array.is_null(*row));
+            if let Some(row) = invalid {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Invalid base64 string '{}' at row {}",
+                    String::from_utf8_lossy(array.inner().value(row)),
+                    row
+                )));
+            }
+        }
+
+        let column: DataColumn = decoded.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for Base64DecodeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.display_name)
+    }
+}
@@ -38,132 +38,34 @@ impl ConcatWsFunction {
         FunctionDescription::creator(Box::new(Self::try_create))
             .features(FunctionFeatures::default().deterministic())
     }
+}
+
+/// A string argument of concat_ws, a constant is read without being expanded to the input rows.
+enum StringArg<'a> {
+    Constant(Option<&'a [u8]>),
+    Array(&'a DFStringArray),
+}
 
-    fn concat_column_with_seperator(
-        seperator: &DataColumn,
-        acc: DataColumn,
-        columns: &DataColumnsWithField,
-    ) -> Result<DataColumn> {
-        if columns.is_empty() {
-            return Ok(acc);
+impl<'a> StringArg<'a> {
+    fn try_create(column: &'a DataColumn) -> Result<Self> {
+        match column {
+            DataColumn::Constant(DataValue::String(v), _) => Ok(StringArg::Constant(v.as_deref())),
+            DataColumn::Array(series) => Ok(StringArg::Array(series.string()?)),
+            _ => Err(ErrorCode::LogicalError(format!(
+                "Expected a string column, but got {}",
+                column.data_type()
+            ))),
         }
-        let current = columns[0].column();
-        // if this column is DataType::Null, just skip it
-        if current.data_type().is_null() {
-            return Self::concat_column_with_seperator(seperator, acc, &columns[1..]);
+    }
+
+    #[inline]
+    fn get(&self, row: usize) -> Option<&'a [u8]> {
+        match self {
+            StringArg::Constant(v) => *v,
+            StringArg::Array(array) if array.is_null(row) => None,
+            // Safety: the row is less than the input rows, which is the length of the array.
+            StringArg::Array(array) => Some(unsafe { array.value_unchecked(row) }),
         }
-        // whether is the last column to decide decide add separator or not
-        let is_last = columns.len() == 1;
-        // add this column value with acc
-        let current = current.cast_with_type(&DataType::String)?;
-        let acc = acc.cast_with_type(&DataType::String)?;
-        let sep = seperator.cast_with_type(&DataType::String)?;
-        let column = match sep {
-            DataColumn::Constant(DataValue::String(Some(sep)), len) => match (acc, current) {
-                (DataColumn::Array(lhs), DataColumn::Array(rhs)) => {
-                    let l_array = lhs.string()?;
-                    let r_array = rhs.string()?;
-                    let mut builder = StringArrayBuilder::with_capacity(l_array.len());
-                    let iter = l_array.into_iter().zip(r_array.into_iter());
-                    for (l, r) in iter {
-                        match (l, r) {
-                            (Some(l), Some(r)) => {
-                                if is_last {
-                                    builder.append_value([l, r].concat());
-                                } else {
-                                    builder.append_value([l, r, sep.as_slice()].concat());
-                                }
-                            }
-                            (Some(l), None) => {
-                                // current row is NULL, do not add separator
-                                builder.append_value(l);
-                            }
-                            // acc column could never be null
-                            (None, _) => {
-                                return Err(ErrorCode::UnexpectedError("CONCAT_WS internal error"));
-                            }
-                        }
-                    }
-                    Ok(DataColumn::Array(builder.finish().into_series()))
-                }
-                (DataColumn::Array(lhs), DataColumn::Constant(rhs, _)) => match rhs {
-                    DataValue::String(Some(r_value)) => {
-                        let l_array = lhs.string()?;
-                        let mut builder = StringArrayBuilder::with_capacity(len);
-                        let iter = l_array.into_iter();
-                        for val in iter {
-                            if let Some(val) = val {
-                                if is_last {
-                                    builder.append_value([val, r_value.as_slice()].concat());
-                                } else {
-                                    builder.append_value(
-                                        [val, r_value.as_slice(), sep.as_slice()].concat(),
-                                    );
-                                }
-                            } else {
-                                // this never happens, acc column could never be null
-                                return Err(ErrorCode::UnexpectedError("CONCAT_WS internal error"));
-                            }
-                        }
-                        let array = builder.finish();
-                        Ok(DataColumn::Array(array.into_series()))
-                    }
-                    _ => Ok(DataColumn::Array(lhs)),
-                },
-                (DataColumn::Constant(lhs, _), DataColumn::Array(rhs)) => match lhs {
-                    DataValue::String(Some(l_value)) => {
-                        let r_array = rhs.string()?;
-                        let mut builder = StringArrayBuilder::with_capacity(len);
-                        let iter = r_array.into_iter();
-                        for val in iter {
-                            if let Some(val) = val {
-                                if is_last {
-                                    builder.append_value([l_value.as_slice(), val].concat());
-                                } else {
-                                    builder.append_value(
-                                        [l_value.as_slice(), val, sep.as_slice()].concat(),
-                                    );
-                                }
-                            } else {
-                                // r_value is NULL, do not add separator
-                                builder.append_value(l_value.as_slice());
-                            }
-                        }
-                        let array = builder.finish();
-                        Ok(DataColumn::Array(array.into_series()))
-                    }
-                    // acc column could never be null
-                    _ => Err(ErrorCode::UnexpectedError("CONCAT_WS internal error")),
-                },
-                (DataColumn::Constant(lhs, _), DataColumn::Constant(rhs, _)) => match &lhs {
-                    DataValue::String(Some(l_val)) => match rhs {
-                        DataValue::String(Some(r_val)) => {
-                            let value = if is_last {
-                                DataValue::String(Some(
-                                    [l_val.as_slice(), r_val.as_slice()].concat(),
-                                ))
-                            } else {
-                                DataValue::String(Some(
-                                    [l_val.as_slice(), r_val.as_slice(), sep.as_slice()].concat(),
-                                ))
-                            };
-                            let column = DataColumn::Constant(value, len);
-                            Ok(column)
-                        }
-                        // current column is NULL, do not add separator
-                        _ => Ok(DataColumn::Constant(lhs.clone(), len)),
-                    },
-                    // acc column could never be NULL
-                    _ => Err(ErrorCode::UnexpectedError("CONCAT_WS internal error")),
-                },
-            },
-            // seprator must be DataColumn::Constant(DataValue::String(Some(x)))
-            _ => Err(ErrorCode::UnexpectedError(
-                "CONCAT_WS separator must be constant",
-            )),
-        }?;
-        let result = Self::concat_column_with_seperator(seperator, column, &columns[1..])?;
-        Ok(result)
     }
 }
 
@@ -183,19 +85,53 @@ impl Function for ConcatWsFunction {
         Some((2, 1024))
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
-    }
-
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let seperator = &columns[0];
-        if seperator.column().data_type().is_null() {
+        if columns[0].column().data_type().is_null() {
             return Ok(DataColumn::Constant(DataValue::Null, input_rows));
         }
-        // simplify seperator with only one column
-        let acc = DataColumn::Constant(DataValue::String(Some(Vec::new())), input_rows);
-        let result = Self::concat_column_with_seperator(seperator.column(), acc, &columns[1..])?;
-        Ok(result)
+
+        // The arguments of DataType::Null are always NULL, so they are skipped.
+        let columns = columns
+            .iter()
+            .filter(|c| !c.column().data_type().is_null())
+            .map(|c| c.column().cast_with_type(&DataType::String))
+            .collect::<Result<Vec<_>>>()?;
+        let args = columns
+            .iter()
+            .map(StringArg::try_create)
+            .collect::<Result<Vec<_>>>()?;
+
+        let (sep, args) = (&args[0], &args[1..]);
+        let concat = |row: usize, buf: &mut Vec<u8>| -> Option<()> {
+            let sep = sep.get(row)?;
+            buf.clear();
+            // The separator only goes between the non-null values, a NULL is skipped with its separator.
+            for (i, value) in args.iter().filter_map(|arg| arg.get(row)).enumerate() {
+                if i > 0 {
+                    buf.extend_from_slice(sep);
+                }
+                buf.extend_from_slice(value);
+            }
+            Some(())
+        };
+
+        let mut buf = Vec::new();
+        if columns
+            .iter()
+            .all(|c| matches!(c, DataColumn::Constant(_, _)))
+        {
+            let value = concat(0, &mut buf).map(|_| buf);
+            return Ok(DataColumn::Constant(DataValue::String(value), input_rows));
+        }
+
+        let mut builder = StringArrayBuilder::with_capacity(input_rows);
+        for row in 0..input_rows {
+            match concat(row, &mut buf) {
+                Some(_) => builder.append_value(&buf),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into())
     }
 
     fn passthrough_null(&self) -> bool {
@@ -92,10 +92,11 @@ impl Function for EltFunction {
                 }
             }
             DataColumn::Array(n_series) => {
-                let series = columns[1..]
+                let columns = columns[1..]
                     .iter()
-                    .map(|c| c.column().cast_with_type(&DataType::String)?.to_array())
-                    .collect::<Result<Vec<Series>>>()?;
+                    .map(|c| c.column().cast_with_type(&DataType::String))
+                    .collect::<Result<Vec<DataColumn>>>()?;
+                let series = DataColumn::to_arrays(&columns)?;
 
                 let columns = series
                     .iter()
@@ -65,7 +65,7 @@ impl Function for HexFunction {
         Ok(true)
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         match columns[0].data_type() {
             DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
                 let mut string_array = StringArrayBuilder::with_capacity(columns[0].column().len());
@@ -117,7 +117,7 @@ impl Function for HexFunction {
                         len
                     })
                     .into();
-                Ok(column)
+                Ok(column.resize_constant(input_rows))
             }
         }
     }
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use bstr::Finder;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use itertools::izip;
@@ -54,10 +55,10 @@ impl<const T: u8> Function for LocatingFunction<T> {
     }
 
     fn variadic_arguments(&self) -> Option<(usize, usize)> {
-        if T == FUNC_LOCATE {
-            Some((2, 3))
-        } else {
+        if T == FUNC_INSTR {
             Some((2, 2))
+        } else {
+            Some((2, 3))
         }
     }
 
@@ -82,7 +83,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
             )
         };
 
-        let p_column = if T == FUNC_LOCATE && columns.len() == 3 {
+        let p_column = if T != FUNC_INSTR && columns.len() == 3 {
             columns[2].column().cast_with_type(&DataType::UInt64)?
         } else {
             DataColumn::Constant(DataValue::UInt64(Some(1)), input_rows)
@@ -95,7 +96,10 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 DataColumn::Constant(DataValue::UInt64(p), _),
             ) => {
                 if let (Some(ss), Some(s), Some(p)) = (ss, s, p) {
-                    DataColumn::Constant(DataValue::UInt64(Some(find_at(&s, &ss, &p))), input_rows)
+                    DataColumn::Constant(
+                        DataValue::UInt64(Some(find_at(&s, &Finder::new(&ss), &p))),
+                        input_rows,
+                    )
                 } else {
                     DataColumn::Constant(DataValue::Null, input_rows)
                 }
@@ -106,9 +110,10 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 DataColumn::Constant(DataValue::UInt64(p), _),
             ) => {
                 if let (Some(ss), Some(p)) = (ss, p) {
+                    let finder = Finder::new(&ss);
                     let s_array = s_series.string()?;
                     DFUInt64Array::new_from_iter_validity(
-                        s_array.into_no_null_iter().map(|s| find_at(s, &ss, &p)),
+                        s_array.into_no_null_iter().map(|s| find_at(s, &finder, &p)),
                         s_array.inner().validity().cloned(),
                     )
                     .into()
@@ -124,7 +129,9 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 if let (Some(s), Some(p)) = (s, p) {
                     let ss_array = ss_series.string()?;
                     DFUInt64Array::new_from_iter_validity(
-                        ss_array.into_no_null_iter().map(|ss| find_at(&s, ss, &p)),
+                        ss_array
+                            .into_no_null_iter()
+                            .map(|ss| find_at(&s, &Finder::new(ss), &p)),
                         ss_array.inner().validity().cloned(),
                     )
                     .into()
@@ -144,7 +151,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                         ss_array
                             .into_no_null_iter()
                             .zip(s_array.into_no_null_iter())
-                            .map(|(ss, s)| find_at(s, ss, &p)),
+                            .map(|(ss, s)| find_at(s, &Finder::new(ss), &p)),
                         combine_validities(ss_array.inner().validity(), s_array.inner().validity()),
                     )
                     .into()
@@ -158,9 +165,10 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 DataColumn::Array(p_series),
             ) => {
                 if let (Some(ss), Some(s)) = (ss, s) {
+                    let finder = Finder::new(&ss);
                     let p_array = p_series.u64()?;
                     DFUInt64Array::new_from_iter_validity(
-                        p_array.into_no_null_iter().map(|p| find_at(&s, &ss, p)),
+                        p_array.into_no_null_iter().map(|p| find_at(&s, &finder, p)),
                         p_array.inner().validity().cloned(),
                     )
                     .into()
@@ -174,13 +182,14 @@ impl<const T: u8> Function for LocatingFunction<T> {
                 DataColumn::Array(p_series),
             ) => {
                 if let Some(ss) = ss {
+                    let finder = Finder::new(&ss);
                     let s_array = s_series.string()?;
                     let p_array = p_series.u64()?;
                     DFUInt64Array::new_from_iter_validity(
                         s_array
                             .into_no_null_iter()
                             .zip(p_array.into_no_null_iter())
-                            .map(|(s, p)| find_at(s, &ss, p)),
+                            .map(|(s, p)| find_at(s, &finder, p)),
                         combine_validities(s_array.inner().validity(), p_array.inner().validity()),
                     )
                     .into()
@@ -200,7 +209,7 @@ impl<const T: u8> Function for LocatingFunction<T> {
                         ss_array
                             .into_no_null_iter()
                             .zip(p_array.into_no_null_iter())
-                            .map(|(ss, p)| find_at(&s, ss, p)),
+                            .map(|(ss, p)| find_at(&s, &Finder::new(ss), p)),
                         combine_validities(ss_array.inner().validity(), p_array.inner().validity()),
                     )
                     .into()
@@ -223,13 +232,10 @@ impl<const T: u8> Function for LocatingFunction<T> {
                         s_array.into_no_null_iter(),
                         p_array.into_no_null_iter(),
                     )
-                    .map(|(ss, s, p)| find_at(s, ss, p)),
+                    .map(|(ss, s, p)| find_at(s, &Finder::new(ss), p)),
                     combine_validities(
-                        combine_validities(
-                            ss_array.inner().validity(),
-                            ss_array.inner().validity(),
-                        )
-                        .as_ref(),
+                        combine_validities(ss_array.inner().validity(), s_array.inner().validity())
+                            .as_ref(),
                         p_array.inner().validity(),
                     ),
                 )
@@ -247,20 +253,42 @@ impl<const T: u8> fmt::Display for LocatingFunction<T> {
     }
 }
 
+/// A constant needle is searched with the same `finder` on every row.
+/// An empty needle matches at the start position.
 #[inline]
-fn find_at(str: &[u8], substr: &[u8], pos: &u64) -> u64 {
+fn find_at(str: &[u8], finder: &Finder, pos: &u64) -> u64 {
     let pos = (*pos) as usize;
     if pos == 0 {
         return 0_u64;
     }
-    let p = pos - 1;
-    if p + substr.len() <= str.len() {
-        str[p..]
-            .windows(substr.len())
-            .position(|w| w == substr)
-            .map(|i| i + 1 + p)
-            .unwrap_or(0) as u64
-    } else {
-        0_u64
-    }
+
+    // Both the start position and the result are counted in characters.
+    let p = match char_boundaries(str).nth(pos - 1) {
+        Some(p) => p,
+        None => return 0_u64,
+    };
+    finder
+        .find(&str[p..])
+        .map(|i| char_count(&str[..p + i]) + 1)
+        .unwrap_or(0) as u64
+}
+
+/// The byte offsets where the characters start, followed by the length of the string.
+#[inline]
+fn char_boundaries(str: &[u8]) -> impl Iterator<Item = usize> + '_ {
+    str.iter()
+        .enumerate()
+        .filter(|(_, b)| !is_utf8_continuation(**b))
+        .map(|(i, _)| i)
+        .chain(std::iter::once(str.len()))
+}
+
+#[inline]
+fn char_count(str: &[u8]) -> usize {
+    str.iter().filter(|b| !is_utf8_continuation(**b)).count()
+}
+
+#[inline]
+fn is_utf8_continuation(b: u8) -> bool {
+    (b & 0xC0) == 0x80
 }
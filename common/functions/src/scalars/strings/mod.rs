@@ -16,6 +16,7 @@ mod ascii;
 mod base_64;
 mod bin;
 mod bit_length;
+mod char_function;
 mod char_length;
 mod concat;
 mod concat_ws;
@@ -35,17 +36,23 @@ mod octet_length;
 mod ord;
 mod pad;
 mod quote;
+mod regexp_match;
+mod regexp_replace;
 mod repeat;
 mod replace;
 mod reverse;
 mod soundex;
 mod space;
+mod split;
+mod split_by_char;
 mod strcmp;
 mod string;
 mod string2number;
 mod string2string;
+mod string_predicate;
 mod substring;
 mod substring_index;
+mod to_fixed_string;
 mod trim;
 mod unhex;
 mod upper;
@@ -55,6 +62,7 @@ pub use base_64::Base64DecodeFunction;
 pub use base_64::Base64EncodeFunction;
 pub use bin::BinFunction;
 pub use bit_length::BitLengthFunction;
+pub use char_function::CharFunction;
 pub use char_length::CharLengthFunction;
 pub use concat::ConcatFunction;
 pub use concat_ws::ConcatWsFunction;
@@ -77,19 +85,27 @@ pub use ord::OrdFunction;
 pub use pad::LeftPadFunction;
 pub use pad::RightPadFunction;
 pub use quote::QuoteFunction;
+pub use regexp_match::RegexpMatchFunction;
+pub use regexp_replace::RegexpReplaceFunction;
 pub use repeat::RepeatFunction;
 pub use replace::ReplaceFunction;
 pub use reverse::ReverseFunction;
 pub use soundex::SoundexFunction;
 pub use space::SpaceFunction;
+pub use split::SplitFunction;
+pub use split_by_char::SplitByCharFunction;
 pub use strcmp::StrcmpFunction;
 pub use string::StringFunction;
 pub use string2number::NumberResultFunction;
 pub use string2number::String2NumberFunction;
 pub use string2string::String2StringFunction;
 pub use string2string::StringOperator;
+pub use string_predicate::ContainsFunction;
+pub use string_predicate::EndsWithFunction;
+pub use string_predicate::StartsWithFunction;
 pub use substring::SubstringFunction;
 pub use substring_index::SubstringIndexFunction;
+pub use to_fixed_string::ToFixedStringFunction;
 pub use trim::LTrimFunction;
 pub use trim::RTrimFunction;
 pub use trim::TrimFunction;
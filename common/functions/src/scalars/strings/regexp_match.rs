@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+use regex::bytes::Regex;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct RegexpMatchFunction {
+    display_name: String,
+    cache: RegexCache,
+}
+
+impl RegexpMatchFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpMatchFunction {
+            display_name: display_name.to_string(),
+            cache: RegexCache::default(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpMatchFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] != DataType::String && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or null, but got {}",
+                args[0]
+            )));
+        }
+        if args[1] != DataType::String && args[1] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or null, but got {}",
+                args[1]
+            )));
+        }
+        Ok(DataType::Boolean)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let s_column = columns[0].column().cast_with_type(&DataType::String)?;
+        let p_column = columns[1].column().cast_with_type(&DataType::String)?;
+
+        let r_column: DataColumn = match (s_column, p_column) {
+            // #00
+            (
+                DataColumn::Constant(DataValue::String(s), _),
+                DataColumn::Constant(DataValue::String(p), _),
+            ) => {
+                if let (Some(s), Some(p)) = (s, p) {
+                    let re = self.cache.get(&p)?;
+                    DataColumn::Constant(DataValue::Boolean(Some(re.is_match(&s))), input_rows)
+                } else {
+                    DataColumn::Constant(DataValue::Null, input_rows)
+                }
+            }
+            // #10
+            (DataColumn::Array(s_series), DataColumn::Constant(DataValue::String(p), _)) => {
+                if let Some(p) = p {
+                    let re = self.cache.get(&p)?;
+                    let mut r_array = BooleanArrayBuilder::with_capacity(input_rows);
+                    for os in s_series.string()? {
+                        r_array.append_option(os.map(|s| re.is_match(s)));
+                    }
+                    r_array.finish().into()
+                } else {
+                    DataColumn::Constant(DataValue::Null, input_rows)
+                }
+            }
+            // #01
+            (DataColumn::Constant(DataValue::String(s), _), DataColumn::Array(p_series)) => {
+                if let Some(s) = s {
+                    let mut map = HashMap::new();
+                    let mut r_array = BooleanArrayBuilder::with_capacity(input_rows);
+                    for op in p_series.string()? {
+                        match op {
+                            Some(p) => {
+                                let re = row_regex(&mut map, p)?;
+                                r_array.append_value(re.is_match(&s));
+                            }
+                            None => r_array.append_null(),
+                        }
+                    }
+                    r_array.finish().into()
+                } else {
+                    DataColumn::Constant(DataValue::Null, input_rows)
+                }
+            }
+            // #11
+            (DataColumn::Array(s_series), DataColumn::Array(p_series)) => {
+                let mut map = HashMap::new();
+                let mut r_array = BooleanArrayBuilder::with_capacity(input_rows);
+                for s_p in izip!(s_series.string()?, p_series.string()?) {
+                    match s_p {
+                        (Some(s), Some(p)) => {
+                            let re = row_regex(&mut map, p)?;
+                            r_array.append_value(re.is_match(s));
+                        }
+                        _ => r_array.append_null(),
+                    }
+                }
+                r_array.finish().into()
+            }
+            _ => DataColumn::Constant(DataValue::Null, input_rows),
+        };
+        Ok(r_column)
+    }
+}
+
+impl fmt::Display for RegexpMatchFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// The regex compiled from the last constant pattern, shared by the clones of a function.
+#[derive(Clone, Default)]
+pub(crate) struct RegexCache {
+    inner: Arc<Mutex<Option<(Vec<u8>, Regex)>>>,
+}
+
+impl RegexCache {
+    /// Compile a constant pattern once and reuse it for the following evals.
+    pub(crate) fn get(&self, pattern: &[u8]) -> Result<Regex> {
+        let mut cached = self.inner.lock().unwrap();
+        if let Some((p, re)) = cached.as_ref() {
+            if p.as_slice() == pattern {
+                return Ok(re.clone());
+            }
+        }
+        let re = build_regex(pattern)?;
+        *cached = Some((pattern.to_vec(), re.clone()));
+        Ok(re)
+    }
+}
+
+#[inline]
+pub(crate) fn build_regex(pattern: &[u8]) -> Result<Regex> {
+    let pattern = std::str::from_utf8(pattern).map_err(|e| {
+        ErrorCode::BadArguments(format!("Unable to build regex from pattern: {}", e))
+    })?;
+    Regex::new(pattern)
+        .map_err(|e| ErrorCode::BadArguments(format!("Unable to build regex from pattern: {}", e)))
+}
+
+/// Compile each distinct per-row pattern once within a single eval.
+#[inline]
+pub(crate) fn row_regex<'a>(
+    map: &'a mut HashMap<Vec<u8>, Regex>,
+    pattern: &[u8],
+) -> Result<&'a Regex> {
+    if !map.contains_key(pattern) {
+        let re = build_regex(pattern)?;
+        map.insert(pattern.to_vec(), re);
+    }
+    Ok(&map[pattern])
+}
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+
+use super::regexp_match::row_regex;
+use super::regexp_match::RegexCache;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// Replaces all the non-overlapping matches of `pattern` in `str` with `replacement`.
+/// `$1` or `${name}` in the replacement refers to a capture group, a literal `$` is written as `$$`.
+#[derive(Clone)]
+pub struct RegexpReplaceFunction {
+    display_name: String,
+    cache: RegexCache,
+}
+
+impl RegexpReplaceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RegexpReplaceFunction {
+            display_name: display_name.to_string(),
+            cache: RegexCache::default(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for RegexpReplaceFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if *arg != DataType::String && *arg != DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::String)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let s_column = columns[0].column().cast_with_type(&DataType::String)?;
+        let p_column = columns[1].column().cast_with_type(&DataType::String)?;
+        let r_column = columns[2].column().cast_with_type(&DataType::String)?;
+
+        if [&s_column, &p_column, &r_column]
+            .iter()
+            .any(|c| matches!(c, DataColumn::Constant(v, _) if v.is_null()))
+        {
+            return Ok(DataColumn::Constant(DataValue::Null, input_rows));
+        }
+
+        let column: DataColumn = match (&s_column, &p_column, &r_column) {
+            // #000
+            (
+                DataColumn::Constant(DataValue::String(Some(s)), _),
+                DataColumn::Constant(DataValue::String(Some(p)), _),
+                DataColumn::Constant(DataValue::String(Some(r)), _),
+            ) => {
+                let re = self.cache.get(p)?;
+                let v = re.replace_all(s, r.as_slice()).into_owned();
+                DataColumn::Constant(DataValue::String(Some(v)), input_rows)
+            }
+            // #?0?
+            (_, DataColumn::Constant(DataValue::String(Some(p)), _), _) => {
+                let re = self.cache.get(p)?;
+                let s_series = s_column.to_array()?;
+                let r_series = r_column.to_array()?;
+                let mut r_array = StringArrayBuilder::with_capacity(input_rows);
+                for s_r in izip!(s_series.string()?, r_series.string()?) {
+                    r_array.append_option(match s_r {
+                        (Some(s), Some(r)) => Some(re.replace_all(s, r)),
+                        _ => None,
+                    });
+                }
+                r_array.finish().into()
+            }
+            // #?1?
+            _ => {
+                let mut map = HashMap::new();
+                let s_series = s_column.to_array()?;
+                let p_series = p_column.to_array()?;
+                let r_series = r_column.to_array()?;
+                let mut r_array = StringArrayBuilder::with_capacity(input_rows);
+                for s_p_r in izip!(s_series.string()?, p_series.string()?, r_series.string()?) {
+                    match s_p_r {
+                        (Some(s), Some(p), Some(r)) => {
+                            let re = row_regex(&mut map, p)?;
+                            r_array.append_value(re.replace_all(s, r));
+                        }
+                        _ => r_array.append_null(),
+                    }
+                }
+                r_array.finish().into()
+            }
+        };
+        Ok(column)
+    }
+}
+
+impl fmt::Display for RegexpReplaceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
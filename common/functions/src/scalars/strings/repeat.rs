@@ -22,7 +22,9 @@ use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
 
-const MAX_REPEAT_TIMES: u64 = 1000000;
+const MAX_REPEAT_TIMES: i64 = 1000000;
+/// The limit of the total bytes repeated into one column, to avoid running out of memory.
+const MAX_REPEAT_BYTES: usize = 128 * 1024 * 1024;
 
 #[derive(Clone)]
 pub struct RepeatFunction {
@@ -59,12 +61,9 @@ impl Function for RepeatFunction {
             )));
         }
 
-        if !args[1].is_unsigned_integer()
-            && args[1] != DataType::String
-            && args[1] != DataType::Null
-        {
+        if !args[1].is_integer() && args[1] != DataType::String && args[1] != DataType::Null {
             return Err(ErrorCode::IllegalDataType(format!(
-                "Expected parameter 2 is unsigned integer or string or null, but got {}",
+                "Expected parameter 2 is integer or string or null, but got {}",
                 args[1]
             )));
         }
@@ -76,16 +75,18 @@ impl Function for RepeatFunction {
         Ok(true)
     }
 
+    /// A non-positive number of times gives the empty string.
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let mut repeater = Repeater::default();
         match (
             columns[0].column().cast_with_type(&DataType::String)?,
-            columns[1].column().cast_with_type(&DataType::UInt64)?,
+            times_column(columns[1].column())?,
         ) {
             (
                 DataColumn::Constant(DataValue::String(input_string), _),
-                DataColumn::Constant(DataValue::UInt64(times), _),
+                DataColumn::Constant(DataValue::Int64(times), _),
             ) => Ok(DataColumn::Constant(
-                DataValue::String(repeat(input_string, times)?),
+                DataValue::String(repeater.repeat(input_string, times)?),
                 input_rows,
             )),
             (
@@ -93,25 +94,23 @@ impl Function for RepeatFunction {
                 DataColumn::Array(times),
             ) => {
                 let mut string_builder = StringArrayBuilder::with_capacity(input_rows);
-                for times in times.u64()? {
-                    string_builder.append_option(repeat(input_string.as_ref(), times.copied())?);
+                for times in times.i64()? {
+                    string_builder
+                        .append_option(repeater.repeat(input_string.as_ref(), times.copied())?);
                 }
                 Ok(string_builder.finish().into())
             }
-            (
-                DataColumn::Array(input_string),
-                DataColumn::Constant(DataValue::UInt64(times), _),
-            ) => {
+            (DataColumn::Array(input_string), DataColumn::Constant(DataValue::Int64(times), _)) => {
                 let mut string_builder = StringArrayBuilder::with_capacity(input_rows);
                 for input_string in input_string.string()? {
-                    string_builder.append_option(repeat(input_string, times)?);
+                    string_builder.append_option(repeater.repeat(input_string, times)?);
                 }
                 Ok(string_builder.finish().into())
             }
             (DataColumn::Array(input_string), DataColumn::Array(times)) => {
                 let mut string_builder = StringArrayBuilder::with_capacity(input_rows);
-                for (input_string, times) in input_string.string()?.into_iter().zip(times.u64()?) {
-                    string_builder.append_option(repeat(input_string, times.copied())?);
+                for (input_string, times) in input_string.string()?.into_iter().zip(times.i64()?) {
+                    string_builder.append_option(repeater.repeat(input_string, times.copied())?);
                 }
                 Ok(string_builder.finish().into())
             }
@@ -126,17 +125,54 @@ impl fmt::Display for RepeatFunction {
     }
 }
 
-#[inline]
-fn repeat(string: Option<impl AsRef<[u8]>>, times: Option<u64>) -> Result<Option<Vec<u8>>> {
-    if let (Some(string), Some(times)) = (string, times) {
+/// Casts the times to Int64, the unsigned values beyond its range saturate
+/// instead of turning into null, they are rejected as too many times anyway.
+fn times_column(column: &DataColumn) -> Result<DataColumn> {
+    if !column.data_type().is_unsigned_integer() {
+        return column.cast_with_type(&DataType::Int64);
+    }
+
+    let times = column
+        .cast_with_type(&DataType::UInt64)?
+        .to_minimal_array()?
+        .u64()?
+        .apply_cast_numeric(|v| v.min(i64::MAX as u64) as i64);
+    let times: DataColumn = times.into();
+    Ok(times.resize_constant(column.len()))
+}
+
+#[derive(Default)]
+struct Repeater {
+    total_bytes: usize,
+}
+
+impl Repeater {
+    #[inline]
+    fn repeat(
+        &mut self,
+        string: Option<impl AsRef<[u8]>>,
+        times: Option<i64>,
+    ) -> Result<Option<Vec<u8>>> {
+        let (string, times) = match (string, times) {
+            (Some(string), Some(times)) => (string, times),
+            _ => return Ok(None),
+        };
         if times > MAX_REPEAT_TIMES {
             return Err(ErrorCode::BadArguments(format!(
                 "Too many times to repeat: ({}), maximum is: {}",
                 times, MAX_REPEAT_TIMES
             )));
         }
-        Ok(Some(string.as_ref().repeat(times as usize)))
-    } else {
-        Ok(None)
+
+        let times = times.max(0) as usize;
+        let string = string.as_ref();
+        self.total_bytes += string.len() * times;
+        if self.total_bytes > MAX_REPEAT_BYTES {
+            return Err(ErrorCode::BadArguments(format!(
+                "The result of repeat is too large, maximum is: {} bytes",
+                MAX_REPEAT_BYTES
+            )));
+        }
+        Ok(Some(string.repeat(times)))
     }
 }
@@ -36,25 +36,21 @@ pub struct Replace {
 }
 
 impl ReplaceOperator for Replace {
+    /// Replaces all the non-overlapping occurrences of `from` with `to`.
+    /// An empty `from` matches nothing, so the input is returned unchanged.
     #[inline]
     fn apply<'a>(&'a mut self, str: &'a [u8], from: &'a [u8], to: &'a [u8]) -> &'a [u8] {
         if from.is_empty() || from == to {
             return str;
         }
         self.buf.clear();
-        let mut skip = 0;
-        for (p, w) in str.windows(from.len()).enumerate() {
-            if w == from {
-                self.buf.extend_from_slice(to);
-                skip = from.len();
-            } else if p + w.len() == str.len() {
-                self.buf.extend_from_slice(w);
-            } else if skip > 1 {
-                skip -= 1;
-            } else {
-                self.buf.extend_from_slice(&w[0..1]);
-            }
+        let mut rest = str;
+        while let Some(p) = rest.windows(from.len()).position(|w| w == from) {
+            self.buf.extend_from_slice(&rest[..p]);
+            self.buf.extend_from_slice(to);
+            rest = &rest[p + from.len()..];
         }
+        self.buf.extend_from_slice(rest);
         &self.buf
     }
 }
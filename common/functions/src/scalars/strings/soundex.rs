@@ -23,6 +23,7 @@ pub struct Soundex {
 }
 
 impl Soundex {
+    /// The code of a consonant, a vowel or 'h' / 'w' has none.
     #[inline(always)]
     fn number_map(i: char) -> Option<u8> {
         match i.to_ascii_lowercase() {
@@ -32,16 +33,14 @@ impl Soundex {
             'l' => Some(b'4'),
             'm' | 'n' => Some(b'5'),
             'r' => Some(b'6'),
-            _ => Some(b'0'),
+            _ => None,
         }
     }
 
+    /// Two letters of the same code separated by 'h' or 'w' are coded once, unlike by a vowel.
     #[inline(always)]
-    fn is_drop(c: char) -> bool {
-        matches!(
-            c.to_ascii_lowercase(),
-            'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'h' | 'w'
-        )
+    fn is_transparent(c: char) -> bool {
+        matches!(c.to_ascii_lowercase(), 'h' | 'w')
     }
 
     // https://github.com/mysql/mysql-server/blob/3290a66c89eb1625a7058e0ef732432b6952b435/sql/item_strfunc.cc#L1919
@@ -60,41 +59,50 @@ impl StringOperator for Soundex {
         self.buf.clear();
 
         for ch in String::from_utf8_lossy(data).chars() {
-            let score = Self::number_map(ch);
-            if last.is_none() {
+            if count == 0 {
                 if !Self::is_uni_alphabetic(ch) {
                     continue;
                 }
 
-                last = score;
+                last = Self::number_map(ch);
                 self.buf.push(ch.to_ascii_uppercase());
-            } else {
-                if !ch.is_ascii_alphabetic()
-                    || Self::is_drop(ch)
-                    || score.is_none()
-                    || score == last
-                {
-                    continue;
-                }
+                count += 1;
+                continue;
+            }
 
-                last = score;
-                self.buf.push(score.unwrap() as char);
+            if !ch.is_ascii_alphabetic() || Self::is_transparent(ch) {
+                continue;
             }
 
-            count += 1;
+            let score = Self::number_map(ch);
+            if let Some(code) = score {
+                if score != last {
+                    self.buf.push(code as char);
+                    count += 1;
+                    if count == 4 {
+                        break;
+                    }
+                }
+            }
+            last = score;
         }
 
         // add '0'
-        if !self.buf.is_empty() && count < 4 {
+        if count > 0 && count < 4 {
             self.buf.extend(vec!['0'; 4 - count])
         }
         let bytes = self.buf.as_bytes();
-        buffer.put_slice(bytes);
+        // The caller retries with a larger buffer if the estimated bytes are not enough.
+        if bytes.len() <= buffer.len() {
+            buffer.put_slice(bytes);
+        }
         bytes.len()
     }
 
+    // The code is the first letter of a value and three digits, the letter is at most all of the
+    // value, so a value never grows by more than three bytes, e.g. 'éa' is 'é000'.
     fn estimate_bytes(&self, array: &common_datavalues::prelude::DFStringArray) -> usize {
-        usize::max(array.inner().values().len(), 4 * array.len())
+        array.inner().values().len() + 3 * array.len()
     }
 }
 
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use bstr::ByteSlice;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::split_by_char::build_parts;
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct SplitFunction {
+    display_name: String,
+}
+
+impl SplitFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SplitFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn check_delimiter(&self, delimiter: &[u8]) -> Result<()> {
+        if delimiter.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The delimiter of {} must not be empty",
+                self.display_name
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Function for SplitFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if *arg != DataType::String && *arg != DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            DataType::String,
+            true,
+        ))))
+    }
+
+    /// The empty parts between consecutive delimiters are kept,
+    /// so an empty string is split into a single empty part.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let s_column = columns[0].column().cast_with_type(&DataType::String)?;
+        let d_column = columns[1].column().cast_with_type(&DataType::String)?;
+
+        let column = match &d_column {
+            DataColumn::Constant(DataValue::String(None), _) => {
+                return Ok(DataColumn::Constant(
+                    DataValue::List(None, DataType::String),
+                    input_rows,
+                ));
+            }
+            DataColumn::Constant(DataValue::String(Some(d)), _) => {
+                self.check_delimiter(d)?;
+                let s_array = s_column.to_minimal_array()?;
+                let s_array = s_array.string()?;
+                let rows = s_array.into_iter().map(|s| Ok(s.map(|s| s.split_str(d))));
+                build_parts(rows, s_array)?
+            }
+            _ => {
+                let s_array = s_column.to_array()?;
+                let s_array = s_array.string()?;
+                let d_array = d_column.to_array()?;
+                let rows = s_array
+                    .into_iter()
+                    .zip(d_array.string()?)
+                    .map(|s_d| -> Result<_> {
+                        match s_d {
+                            (Some(s), Some(d)) => {
+                                self.check_delimiter(d)?;
+                                Ok(Some(s.split_str(d)))
+                            }
+                            _ => Ok(None),
+                        }
+                    });
+                build_parts(rows, s_array)?
+            }
+        };
+
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for SplitFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
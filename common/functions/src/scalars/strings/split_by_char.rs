@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct SplitByCharFunction {
+    display_name: String,
+}
+
+impl SplitByCharFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(SplitByCharFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl Function for SplitByCharFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if *arg != DataType::String && *arg != DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            DataType::String,
+            true,
+        ))))
+    }
+
+    /// The delimiter must be a constant of exactly one byte, the empty parts between
+    /// consecutive delimiters are kept.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let delimiter = match columns[0].column().cast_with_type(&DataType::String)? {
+            DataColumn::Constant(DataValue::String(None), _) => {
+                return Ok(DataColumn::Constant(
+                    DataValue::List(None, DataType::String),
+                    input_rows,
+                ));
+            }
+            DataColumn::Constant(DataValue::String(Some(d)), _) if d.len() == 1 => d[0],
+            DataColumn::Constant(DataValue::String(Some(d)), _) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The delimiter of {} must be exactly one byte, but got '{}'",
+                    self.display_name,
+                    String::from_utf8_lossy(&d)
+                )));
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The delimiter of {} must be a constant",
+                    self.display_name
+                )));
+            }
+        };
+
+        let s_column = columns[1].column().cast_with_type(&DataType::String)?;
+        let s_array = s_column.to_minimal_array()?;
+        let s_array = s_array.string()?;
+
+        let rows = s_array
+            .into_iter()
+            .map(|s| Ok(s.map(|s| s.split(move |b| *b == delimiter))));
+        let column = build_parts(rows, s_array)?;
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+/// Builds a list of the parts of each row split from `s_array`, a `None` row is a null list.
+pub(crate) fn build_parts<'a, P>(
+    rows: impl Iterator<Item = Result<Option<P>>>,
+    s_array: &DFStringArray,
+) -> Result<DataColumn>
+where
+    P: Iterator<Item = &'a [u8]>,
+{
+    let mut builder =
+        ListStringArrayBuilder::with_capacity(s_array.inner().values().len(), s_array.len());
+    let mut parts = vec![];
+    for row in rows {
+        match row? {
+            Some(row_parts) => {
+                parts.clear();
+                parts.extend(row_parts);
+                builder.append_slice(Some(&parts));
+            }
+            None => builder.append_slice(None),
+        }
+    }
+    Ok(builder.finish().into_series().into())
+}
+
+impl fmt::Display for SplitByCharFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -18,10 +18,13 @@ use crate::scalars::Base64DecodeFunction;
 use crate::scalars::Base64EncodeFunction;
 use crate::scalars::BinFunction;
 use crate::scalars::BitLengthFunction;
+use crate::scalars::CharFunction;
 use crate::scalars::CharLengthFunction;
 use crate::scalars::ConcatFunction;
 use crate::scalars::ConcatWsFunction;
+use crate::scalars::ContainsFunction;
 use crate::scalars::EltFunction;
+use crate::scalars::EndsWithFunction;
 use crate::scalars::ExportSetFunction;
 use crate::scalars::FieldFunction;
 use crate::scalars::FindInSetFunction;
@@ -41,6 +44,8 @@ use crate::scalars::OrdFunction;
 use crate::scalars::PositionFunction;
 use crate::scalars::QuoteFunction;
 use crate::scalars::RTrimFunction;
+use crate::scalars::RegexpMatchFunction;
+use crate::scalars::RegexpReplaceFunction;
 use crate::scalars::RepeatFunction;
 use crate::scalars::ReplaceFunction;
 use crate::scalars::ReverseFunction;
@@ -48,9 +53,13 @@ use crate::scalars::RightFunction;
 use crate::scalars::RightPadFunction;
 use crate::scalars::SoundexFunction;
 use crate::scalars::SpaceFunction;
+use crate::scalars::SplitByCharFunction;
+use crate::scalars::SplitFunction;
+use crate::scalars::StartsWithFunction;
 use crate::scalars::StrcmpFunction;
 use crate::scalars::SubstringFunction;
 use crate::scalars::SubstringIndexFunction;
+use crate::scalars::ToFixedStringFunction;
 use crate::scalars::TrimFunction;
 use crate::scalars::UnhexFunction;
 use crate::scalars::UpperFunction;
@@ -73,8 +82,12 @@ impl StringFunction {
         factory.register("unhex", UnhexFunction::desc());
         factory.register("quote", QuoteFunction::desc());
         factory.register("ascii", AsciiFunction::desc());
+        factory.register("char", CharFunction::desc());
         factory.register("to_base64", Base64EncodeFunction::desc());
-        factory.register("from_base64", Base64DecodeFunction::desc());
+        factory.register("base64Encode", Base64EncodeFunction::desc());
+        factory.register("from_base64", Base64DecodeFunction::desc(false));
+        factory.register("base64Decode", Base64DecodeFunction::desc(true));
+        factory.register("tryBase64Decode", Base64DecodeFunction::desc(false));
         factory.register("locate", LocateFunction::desc());
         factory.register("position", PositionFunction::desc());
         factory.register("instr", InstrFunction::desc());
@@ -86,13 +99,20 @@ impl StringFunction {
         factory.register("replace", ReplaceFunction::desc());
         factory.register("reverse", ReverseFunction::desc());
         factory.register("strcmp", StrcmpFunction::desc());
+        factory.register("regexp_match", RegexpMatchFunction::desc());
+        factory.register("regexpMatch", RegexpMatchFunction::desc());
+        factory.register("regexp_replace", RegexpReplaceFunction::desc());
+        factory.register("regexpReplace", RegexpReplaceFunction::desc());
         factory.register("left", LeftFunction::desc());
         factory.register("right", RightFunction::desc());
         factory.register("concat_ws", ConcatWsFunction::desc());
         factory.register("elt", EltFunction::desc());
         factory.register("space", SpaceFunction::desc());
+        factory.register("split", SplitFunction::desc());
+        factory.register("splitByChar", SplitByCharFunction::desc());
         factory.register("lpad", LeftPadFunction::desc());
         factory.register("rpad", RightPadFunction::desc());
+        factory.register("toFixedString", ToFixedStringFunction::desc());
         factory.register("char_length", CharLengthFunction::desc());
         factory.register("character_length", CharLengthFunction::desc());
         factory.register("ord", OrdFunction::desc());
@@ -106,5 +126,8 @@ impl StringFunction {
         factory.register("lcase", LowerFunction::desc());
         factory.register("upper", UpperFunction::desc());
         factory.register("ucase", UpperFunction::desc());
+        factory.register("startsWith", StartsWithFunction::desc());
+        factory.register("endsWith", EndsWithFunction::desc());
+        factory.register("contains", ContainsFunction::desc());
     }
 }
@@ -18,11 +18,13 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::scalars::eval_unary_string;
 use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
 
-/// we can change this into the style of String2StringFunction in the future
+/// Maps the bytes of one string value into a number of type `R`.
+/// Eg: length, char_length, etc.
 pub trait NumberResultFunction<R> {
     const IS_DETERMINISTIC: bool;
     const MAYBE_MONOTONIC: bool;
@@ -67,8 +69,7 @@ where
     }
 }
 
-/// A common function template that transform string column into string column
-/// Eg: trim, lower, upper, etc.
+/// A common function template that transform string column into number column
 impl<T, R> Function for String2NumberFunction<T, R>
 where
     T: NumberResultFunction<R> + Clone + Sync + Send,
@@ -93,20 +94,9 @@ where
         T::return_type()
     }
 
-    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
-        Ok(false)
-    }
-
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let column = columns[0]
-            .column()
-            .cast_with_type(&DataType::String)?
-            .to_minimal_array()?
-            .string()?
-            .apply_cast_numeric(T::to_number);
-
-        let column: DataColumn = column.into();
-        Ok(column.resize_constant(input_rows))
+        let column = columns[0].column().cast_with_type(&DataType::String)?;
+        eval_unary_string(&column, input_rows, T::to_number)
     }
 }
 
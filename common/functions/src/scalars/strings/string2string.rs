@@ -23,6 +23,10 @@ use crate::scalars::function_factory::FunctionDescription;
 use crate::scalars::function_factory::FunctionFeatures;
 use crate::scalars::Function;
 
+/// `apply` and `apply_with_no_null` write the transformed value into `buffer` and return its
+/// length. If `buffer` is smaller than the transformed value, they must return the length they need
+/// without writing past the end of `buffer`, and are then called again with a large enough buffer,
+/// so `estimate_bytes` only needs to be a good guess.
 pub trait StringOperator: Send + Sync + Clone + Default + 'static {
     fn apply<'a>(&'a mut self, _: &'a [u8], _: &mut [u8]) -> Option<usize> {
         None
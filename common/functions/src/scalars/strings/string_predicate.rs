@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use itertools::izip;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+pub type StartsWithFunction = StringPredicateFunction<StartsWith>;
+pub type EndsWithFunction = StringPredicateFunction<EndsWith>;
+pub type ContainsFunction = StringPredicateFunction<Contains>;
+
+pub trait StringPredicateOperator: Send + Sync + Clone + 'static {
+    fn apply(str: &[u8], pattern: &[u8]) -> bool;
+}
+
+#[derive(Clone)]
+pub struct StartsWith;
+
+impl StringPredicateOperator for StartsWith {
+    #[inline]
+    fn apply(str: &[u8], prefix: &[u8]) -> bool {
+        str.starts_with(prefix)
+    }
+}
+
+#[derive(Clone)]
+pub struct EndsWith;
+
+impl StringPredicateOperator for EndsWith {
+    #[inline]
+    fn apply(str: &[u8], suffix: &[u8]) -> bool {
+        str.ends_with(suffix)
+    }
+}
+
+#[derive(Clone)]
+pub struct Contains;
+
+impl StringPredicateOperator for Contains {
+    /// An empty needle is contained in every string.
+    #[inline]
+    fn apply(str: &[u8], needle: &[u8]) -> bool {
+        needle.is_empty() || str.windows(needle.len()).any(|w| w == needle)
+    }
+}
+
+#[derive(Clone)]
+pub struct StringPredicateFunction<T> {
+    display_name: String,
+    _marker: PhantomData<T>,
+}
+
+impl<T: StringPredicateOperator> StringPredicateFunction<T> {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(Self {
+            display_name: display_name.to_string(),
+            _marker: PhantomData,
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+}
+
+impl<T: StringPredicateOperator> Function for StringPredicateFunction<T> {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            if *arg != DataType::String && *arg != DataType::Null {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Expected string or null, but got {}",
+                    arg
+                )));
+            }
+        }
+        Ok(DataType::Boolean)
+    }
+
+    /// The strings are compared byte by byte, so a multibyte character only matches itself.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let s_column = columns[0].column().cast_with_type(&DataType::String)?;
+        let p_column = columns[1].column().cast_with_type(&DataType::String)?;
+
+        let r_column: DataColumn = match (s_column, p_column) {
+            // #00
+            (
+                DataColumn::Constant(DataValue::String(s), _),
+                DataColumn::Constant(DataValue::String(p), _),
+            ) => {
+                let r = match (s, p) {
+                    (Some(s), Some(p)) => Some(T::apply(&s, &p)),
+                    _ => None,
+                };
+                DataColumn::Constant(DataValue::Boolean(r), input_rows)
+            }
+            // #10
+            (DataColumn::Array(s_series), DataColumn::Constant(DataValue::String(p), _)) => {
+                if let Some(p) = p {
+                    let mut r_array = BooleanArrayBuilder::with_capacity(input_rows);
+                    for os in s_series.string()? {
+                        r_array.append_option(os.map(|s| T::apply(s, &p)));
+                    }
+                    r_array.finish().into()
+                } else {
+                    DataColumn::Constant(DataValue::Boolean(None), input_rows)
+                }
+            }
+            // #01
+            (DataColumn::Constant(DataValue::String(s), _), DataColumn::Array(p_series)) => {
+                if let Some(s) = s {
+                    let mut r_array = BooleanArrayBuilder::with_capacity(input_rows);
+                    for op in p_series.string()? {
+                        r_array.append_option(op.map(|p| T::apply(&s, p)));
+                    }
+                    r_array.finish().into()
+                } else {
+                    DataColumn::Constant(DataValue::Boolean(None), input_rows)
+                }
+            }
+            // #11
+            (DataColumn::Array(s_series), DataColumn::Array(p_series)) => {
+                let mut r_array = BooleanArrayBuilder::with_capacity(input_rows);
+                for s_p in izip!(s_series.string()?, p_series.string()?) {
+                    r_array.append_option(match s_p {
+                        (Some(s), Some(p)) => Some(T::apply(s, p)),
+                        _ => None,
+                    });
+                }
+                r_array.finish().into()
+            }
+            _ => DataColumn::Constant(DataValue::Boolean(None), input_rows),
+        };
+        Ok(r_column)
+    }
+}
+
+impl<T> fmt::Display for StringPredicateFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::function_factory::FunctionDescription;
+use crate::scalars::function_factory::FunctionFeatures;
+use crate::scalars::Function;
+
+/// The maximum length of a fixed string, the same as the FixedString of ClickHouse.
+const MAX_FIXED_STRING_LENGTH: u64 = 0xFFFFFF;
+
+#[derive(Clone)]
+pub struct ToFixedStringFunction {
+    display_name: String,
+}
+
+impl ToFixedStringFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ToFixedStringFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic())
+    }
+
+    fn length(&self, column: &DataColumn) -> Result<usize> {
+        let value = match column {
+            DataColumn::Constant(value, _) => value,
+            DataColumn::Array(_) => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The length of {} must be a constant",
+                    self.display_name
+                )));
+            }
+        };
+
+        match value.as_u64() {
+            Ok(n) if n > 0 && n <= MAX_FIXED_STRING_LENGTH => Ok(n as usize),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The length of {} must be between 1 and {}, but got {}",
+                self.display_name, MAX_FIXED_STRING_LENGTH, value
+            ))),
+        }
+    }
+}
+
+impl Function for ToFixedStringFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !args[0].is_numeric() && args[0] != DataType::String && args[0] != DataType::Null {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected string or null, but got {}",
+                args[0]
+            )));
+        }
+        if !args[1].is_unsigned_integer() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Expected unsigned integer, but got {}",
+                args[1]
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Every value is exactly `n` bytes: a shorter value is padded with null bytes, a longer one
+    /// is truncated. The truncation never splits a UTF-8 character, the bytes of the character
+    /// crossing the `n`-th byte are padded too.
+    /// An integer is right aligned and padded with '0' after its sign instead, so that the fixed
+    /// strings of non-negative integers sort like the integers, e.g. when building keys. A longer
+    /// integer is an error, as a truncated integer would be another value.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let n = self.length(columns[1].column())?;
+        let is_integer = columns[0].data_type().is_integer();
+
+        let array = columns[0]
+            .column()
+            .cast_with_type(&DataType::String)?
+            .to_minimal_array()?;
+        let array = array.string()?;
+
+        if is_integer {
+            if let Some(v) = array.into_iter().flatten().find(|v| v.len() > n) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The integer {} is longer than the length {} of {}",
+                    String::from_utf8_lossy(v),
+                    n,
+                    self.display_name
+                )));
+            }
+        }
+
+        let column: DataColumn = transform_with_no_null(array, array.len() * n, |val, buffer| {
+            if buffer.len() < n {
+                return n;
+            }
+            if is_integer {
+                let sign = usize::from(val.first() == Some(&b'-'));
+                let start = n - (val.len() - sign);
+                buffer[..sign].copy_from_slice(&val[..sign]);
+                buffer[sign..start].fill(b'0');
+                buffer[start..n].copy_from_slice(&val[sign..]);
+            } else {
+                let len = truncated_len(val, n);
+                buffer[..len].copy_from_slice(&val[..len]);
+                buffer[len..n].fill(0);
+            }
+            n
+        })
+        .into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ToFixedStringFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// The length of the longest prefix of `val` within `n` bytes which doesn't end inside a
+/// UTF-8 character. A value which isn't valid UTF-8 is truncated at `n` bytes.
+#[inline]
+fn truncated_len(val: &[u8], n: usize) -> usize {
+    if val.len() <= n {
+        return val.len();
+    }
+    match std::str::from_utf8(val) {
+        Ok(s) => (0..=n).rev().find(|i| s.is_char_boundary(*i)).unwrap_or(0),
+        Err(_) => n,
+    }
+}
@@ -64,30 +64,28 @@ impl Function for UnhexFunction {
         Ok(true)
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
-        const BUFFER_SIZE: usize = 32;
-
+    /// Odd-length or non-hex strings are decoded to null.
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let array = columns[0]
             .column()
             .cast_with_type(&DataType::String)?
             .to_minimal_array()?;
         let c_array = array.string()?;
 
-        let column: DataColumn = transform(c_array, c_array.inner().values().len(), |x, buffer| {
-            if x.len() <= BUFFER_SIZE * 2 {
-                let size = x.len() / 2;
+        let estimate_bytes = c_array.inner().values().len() / 2;
+        let column: DataColumn = transform(c_array, estimate_bytes, |x, buffer| {
+            let size = x.len() / 2;
+            if buffer.len() < size {
+                return Some(size);
+            }
 
-                let buffer = &mut buffer[0..size];
-                match hex::decode_to_slice(x, buffer) {
-                    Ok(()) => Some(size),
-                    Err(_) => None,
-                }
-            } else {
-                None
+            match hex::decode_to_slice(x, &mut buffer[0..size]) {
+                Ok(()) => Some(size),
+                Err(_) => None,
             }
         })
         .into();
-        Ok(column)
+        Ok(column.resize_constant(input_rows))
     }
 }
 
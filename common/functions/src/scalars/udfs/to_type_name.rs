@@ -16,6 +16,7 @@ use std::fmt;
 
 use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::DataColumnsWithField;
+use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
 use common_datavalues::DataValue;
@@ -57,7 +58,8 @@ impl Function for ToTypeNameFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
-        let type_name = format!("{}", columns[0].data_type());
+        let field = columns[0].field();
+        let type_name = type_name(field.data_type(), field.is_nullable());
         Ok(DataColumn::Constant(
             DataValue::String(Some(type_name.into_bytes())),
             input_rows,
@@ -67,6 +69,36 @@ impl Function for ToTypeNameFunction {
     fn num_arguments(&self) -> usize {
         1
     }
+
+    fn passthrough_null(&self) -> bool {
+        false
+    }
+}
+
+/// Renders the type name with the nullable and nested field information, e.g. `Nullable(UInt32)`
+/// or `List(Nullable(String))`.
+fn type_name(data_type: &DataType, nullable: bool) -> String {
+    let name = match data_type {
+        DataType::List(inner) => format!("List({})", field_type_name(inner)),
+        DataType::Struct(fields) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|f| format!("{} {}", f.name(), field_type_name(f)))
+                .collect();
+            format!("Struct({})", fields.join(", "))
+        }
+        _ => format!("{}", data_type),
+    };
+
+    if nullable && *data_type != DataType::Null {
+        format!("Nullable({})", name)
+    } else {
+        name
+    }
+}
+
+fn field_type_name(field: &DataField) -> String {
+    type_name(field.data_type(), field.is_nullable())
 }
 
 impl fmt::Display for ToTypeNameFunction {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use bumpalo::Bump;
+use bytes::BytesMut;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::aggregates::*;
@@ -208,6 +209,40 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::Float64(Some(-1.25000)),
             error: "",
         },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "quantileExact-passed",
+            eval_nums: 2,
+            params: vec![DataValue::Float64(Some(0.25))],
+            args: vec![args[0].clone()],
+            display: "quantileExact",
+            func_name: "quantileExact",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.75)),
+            error: "",
+        },
+        Test {
+            name: "quantile-notpassed",
+            eval_nums: 1,
+            params: vec![DataValue::Float64(Some(2.0))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error:
+                "Code: 6, displayText = The level of quantile must be between 0 and 1, but got 2.",
+        },
         Test {
             name: "windowFunnel-passed",
             eval_nums: 2,
@@ -694,6 +729,28 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
             expect: DataValue::Float64(Some(f64::INFINITY)),
             error: "",
         },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "uniqHLL12-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "uniqHLL12",
+            func_name: "uniqHLL12",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(0)),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -776,3 +833,142 @@ fn test_covariance_with_comparable_data_sets() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_quantile_with_known_data_set() -> Result<()> {
+    let arena = Bump::new();
+
+    // Each half of 0..20000 is accumulated into its own state, the states are then merged.
+    let arrays: Vec<Series> = vec![
+        Series::new((0..10000u32).map(|v| v * 2).collect::<Vec<_>>()),
+        Series::new((0..10000u32).map(|v| v * 2 + 1).collect::<Vec<_>>()),
+    ];
+    let args = vec![DataField::new("a", DataType::UInt32, false)];
+
+    let factory = AggregateFunctionFactory::instance();
+
+    let run_test = |func_name: &'static str, level: f64| -> Result<f64> {
+        let func = factory.get(
+            func_name,
+            vec![DataValue::Float64(Some(level))],
+            args.clone(),
+        )?;
+        let addr1 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr1.into());
+        func.accumulate(addr1.into(), &arrays[0..1], 10000)?;
+
+        let addr2 = arena.alloc_layout(func.state_layout());
+        func.init_state(addr2.into());
+        func.accumulate(addr2.into(), &arrays[1..2], 10000)?;
+
+        func.merge(addr1.into(), addr2.into())?;
+        match func.merge_result(addr1.into())? {
+            DataValue::Float64(Some(val)) => Ok(val),
+            _ => {
+                panic!();
+            }
+        }
+    };
+
+    for (level, expect) in [(0.0, 0.0), (0.5, 9999.5), (0.9, 17999.1), (1.0, 19999.0)] {
+        let exact = run_test("quantileExact", level)?;
+        assert!(approx_eq!(f64, expect, exact, epsilon = 0.000001));
+
+        // The sample keeps 8192 of the 20000 values, allow an error of 2% of the range.
+        let approx = run_test("quantile", level)?;
+        assert!((approx - expect).abs() < 400.0, "{} {}", level, approx);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_uniq_hll12_with_known_data_set() -> Result<()> {
+    let arena = Bump::new();
+    let factory = AggregateFunctionFactory::instance();
+
+    // Each half of the rows is accumulated into its own state, the second state is sent through
+    // its serialized form before being merged, as the partial aggregation of another node.
+    let run_test = |name: &str, args: Vec<DataField>, arrays: Vec<Series>| -> Result<(u64, u64)> {
+        let rows = arrays[0].len();
+        let halves = [
+            arrays
+                .iter()
+                .map(|a| a.slice(0, rows / 2))
+                .collect::<Vec<_>>(),
+            arrays
+                .iter()
+                .map(|a| a.slice(rows / 2, rows - rows / 2))
+                .collect::<Vec<_>>(),
+        ];
+
+        let mut results = vec![];
+        for func_name in ["uniq", "uniqHLL12"] {
+            let func = factory.get(func_name, vec![], args.clone())?;
+            let addr1 = arena.alloc_layout(func.state_layout());
+            func.init_state(addr1.into());
+            func.accumulate(addr1.into(), &halves[0], rows / 2)?;
+
+            let addr2 = arena.alloc_layout(func.state_layout());
+            func.init_state(addr2.into());
+            func.accumulate(addr2.into(), &halves[1], rows - rows / 2)?;
+            let mut bytes = BytesMut::new();
+            func.serialize(addr2.into(), &mut bytes)?;
+
+            let addr3 = arena.alloc_layout(func.state_layout());
+            func.init_state(addr3.into());
+            func.deserialize(addr3.into(), &mut bytes.as_ref())?;
+
+            func.merge(addr1.into(), addr3.into())?;
+            match func.merge_result(addr1.into())? {
+                DataValue::UInt64(Some(val)) => results.push(val),
+                v => panic!("{}: {:?}", name, v),
+            }
+        }
+        Ok((results[0], results[1]))
+    };
+
+    let strings = (0..5000u32)
+        .map(|v| format!("value-{}", v % 2000))
+        .collect::<Vec<_>>();
+    let tests = vec![
+        (
+            "integers",
+            vec![DataField::new("a", DataType::UInt32, false)],
+            vec![Series::new(
+                (0..8000u32).map(|v| v % 3000).collect::<Vec<_>>(),
+            )],
+        ),
+        (
+            "strings with nulls",
+            vec![DataField::new("a", DataType::String, true)],
+            vec![Series::new(
+                strings
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| if i % 7 == 0 { None } else { Some(s.as_str()) })
+                    .collect::<Vec<_>>(),
+            )],
+        ),
+        (
+            "two columns",
+            vec![
+                DataField::new("a", DataType::Int64, false),
+                DataField::new("b", DataType::Boolean, false),
+            ],
+            vec![
+                Series::new((0..6000i64).map(|v| v % 1500).collect::<Vec<_>>()),
+                Series::new((0..6000i64).map(|v| v % 4 == 0).collect::<Vec<_>>()),
+            ],
+        ),
+    ];
+
+    for (name, args, arrays) in tests {
+        let (exact, approx) = run_test(name, args, arrays)?;
+        // Allow three times the standard error of 1.6%.
+        let error = (approx as f64 - exact as f64).abs() / exact as f64;
+        assert!(error < 0.05, "{}: exact {}, approx {}", name, exact, approx);
+    }
+
+    Ok(())
+}
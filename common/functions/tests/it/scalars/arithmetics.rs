@@ -14,6 +14,7 @@
 
 use common_datavalues::chrono;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::*;
 use pretty_assertions::assert_eq;
@@ -168,6 +169,98 @@ fn test_arithmetic_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_arithmetic_negate_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        column: DataColumnWithField,
+        expect_type: DataType,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "negate-int64-array",
+            column: DataColumnWithField::new(
+                Series::new(vec![4i64, -3, 0]).into(),
+                DataField::new("a", DataType::Int64, false),
+            ),
+            expect_type: DataType::Int64,
+            expect: Series::new(vec![-4i64, 3, 0]).into(),
+        },
+        Test {
+            name: "negate-float64-array",
+            column: DataColumnWithField::new(
+                Series::new(vec![1.5f64, -2.0]).into(),
+                DataField::new("a", DataType::Float64, false),
+            ),
+            expect_type: DataType::Float64,
+            expect: Series::new(vec![-1.5f64, 2.0]).into(),
+        },
+        Test {
+            name: "negate-nullable-int32-array",
+            column: DataColumnWithField::new(
+                Series::new(vec![Some(1i32), None, Some(-3)]).into(),
+                DataField::new("a", DataType::Int32, true),
+            ),
+            expect_type: DataType::Int32,
+            expect: Series::new(vec![Some(-1i32), None, Some(3)]).into(),
+        },
+        Test {
+            name: "negate-uint8-array-widened",
+            column: DataColumnWithField::new(
+                Series::new(vec![255u8, 1]).into(),
+                DataField::new("a", DataType::UInt8, false),
+            ),
+            expect_type: DataType::Int16,
+            expect: Series::new(vec![-255i16, -1]).into(),
+        },
+        Test {
+            name: "negate-int64-constant",
+            column: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::Int64(Some(7)), 3),
+                DataField::new("a", DataType::Int64, false),
+            ),
+            expect_type: DataType::Int64,
+            expect: DataColumn::Constant(DataValue::Int64(Some(-7)), 3),
+        },
+        Test {
+            name: "negate-float64-constant",
+            column: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::Float64(Some(-0.5)), 2),
+                DataField::new("a", DataType::Float64, false),
+            ),
+            expect_type: DataType::Float64,
+            expect: DataColumn::Constant(DataValue::Float64(Some(0.5)), 2),
+        },
+        Test {
+            name: "negate-null-constant",
+            column: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::Null, 3),
+                DataField::new("a", DataType::Null, true),
+            ),
+            expect_type: DataType::Null,
+            expect: DataColumn::Constant(DataValue::Null, 3),
+        },
+    ];
+
+    for t in tests {
+        let func = ArithmeticMinusFunction::try_create_func("")?;
+        let rows = t.column.column().len();
+
+        let actual_type = func.return_type(&[t.column.data_type().clone()])?;
+        assert_eq!(t.expect_type, actual_type, "{}", t.name);
+
+        let v = func.eval(&[t.column], rows)?;
+        assert_eq!(t.expect_type, v.data_type(), "{}", t.name);
+        assert_eq!(&t.expect, &v, "{}", t.name);
+        if let DataColumn::Constant(_, _) = t.expect {
+            assert!(matches!(v, DataColumn::Constant(_, _)), "{}", t.name);
+        }
+    }
+    Ok(())
+}
+
 #[test]
 fn test_arithmetic_date_interval() -> Result<()> {
     let to_seconds = |y: i32, m: u32, d: u32, h: u32, min: u32, s: u32| -> u32 {
@@ -455,3 +548,158 @@ fn test_arithmetic_date_interval() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_arithmetic_int_div_and_modulo() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        lhs: DataColumnWithField,
+        rhs: DataColumnWithField,
+        expect: Result<DataColumn>,
+    }
+
+    let column = |column: DataColumn, data_type: DataType| {
+        DataColumnWithField::new(column, DataField::new("a", data_type, true))
+    };
+
+    let tests = vec![
+        Test {
+            name: "int_div truncates toward zero",
+            func: ArithmeticIntDivFunction::try_create_func("")?,
+            lhs: column(
+                Series::new(vec![7i64, -7, 7, -7, i64::MIN]).into(),
+                DataType::Int64,
+            ),
+            rhs: column(
+                Series::new(vec![2i64, 2, -2, -2, -1]).into(),
+                DataType::Int64,
+            ),
+            expect: Ok(Series::new(vec![3i64, -3, -3, 3, i64::MIN]).into()),
+        },
+        Test {
+            name: "int_div of mixed types",
+            func: ArithmeticIntDivFunction::try_create_func("")?,
+            lhs: column(Series::new(vec![10u32, 9]).into(), DataType::UInt32),
+            rhs: column(Series::new(vec![-3i8, 3]).into(), DataType::Int8),
+            expect: Ok(Series::new(vec![-3i32, 3]).into()),
+        },
+        Test {
+            name: "int_div with nulls",
+            func: ArithmeticIntDivFunction::try_create_func("")?,
+            lhs: column(
+                Series::new(vec![Some(4i64), None, Some(9)]).into(),
+                DataType::Int64,
+            ),
+            rhs: column(
+                Series::new(vec![Some(2i64), Some(0), None]).into(),
+                DataType::Int64,
+            ),
+            expect: Ok(Series::new(vec![Some(2i64), None, None]).into()),
+        },
+        Test {
+            name: "int_div of constants",
+            func: ArithmeticIntDivFunction::try_create_func("")?,
+            lhs: column(
+                DataColumn::Constant(DataValue::Int64(Some(-7)), 3),
+                DataType::Int64,
+            ),
+            rhs: column(
+                DataColumn::Constant(DataValue::Int64(Some(2)), 3),
+                DataType::Int64,
+            ),
+            expect: Ok(DataColumn::Constant(DataValue::Int64(Some(-3)), 3)),
+        },
+        Test {
+            name: "int_div by zero",
+            func: ArithmeticIntDivFunction::try_create_func("")?,
+            lhs: column(Series::new(vec![1i64, 2]).into(), DataType::Int64),
+            rhs: column(Series::new(vec![1i64, 0]).into(), DataType::Int64),
+            expect: Err(ErrorCode::BadArguments("Division by zero")),
+        },
+        Test {
+            name: "modulo has the sign of the dividend",
+            func: ArithmeticModuloFunction::try_create_func("")?,
+            lhs: column(
+                Series::new(vec![7i64, -7, 7, -7, i64::MIN]).into(),
+                DataType::Int64,
+            ),
+            rhs: column(
+                Series::new(vec![2i64, 2, -2, -2, -1]).into(),
+                DataType::Int64,
+            ),
+            expect: Ok(Series::new(vec![1i64, -1, 1, -1, 0]).into()),
+        },
+        Test {
+            name: "modulo of mixed types",
+            func: ArithmeticModuloFunction::try_create_func("")?,
+            lhs: column(Series::new(vec![-199i32]).into(), DataType::Int32),
+            rhs: column(Series::new(vec![200u8]).into(), DataType::UInt8),
+            expect: Ok(Series::new(vec![-199i16]).into()),
+        },
+        Test {
+            name: "modulo of constants",
+            func: ArithmeticModuloFunction::try_create_func("")?,
+            lhs: column(
+                DataColumn::Constant(DataValue::Int64(Some(-7)), 3),
+                DataType::Int64,
+            ),
+            rhs: column(
+                DataColumn::Constant(DataValue::Int64(Some(2)), 3),
+                DataType::Int64,
+            ),
+            expect: Ok(DataColumn::Constant(DataValue::Int64(Some(-1)), 3)),
+        },
+        Test {
+            name: "modulo by zero",
+            func: ArithmeticModuloFunction::try_create_func("")?,
+            lhs: column(Series::new(vec![1i64, 2]).into(), DataType::Int64),
+            rhs: column(Series::new(vec![0i64, 1]).into(), DataType::Int64),
+            expect: Err(ErrorCode::BadArguments("Division by zero")),
+        },
+        Test {
+            name: "modulo by constant zero",
+            func: ArithmeticModuloFunction::try_create_func("")?,
+            lhs: column(Series::new(vec![1u64, 2]).into(), DataType::UInt64),
+            rhs: column(
+                DataColumn::Constant(DataValue::UInt8(Some(0)), 2),
+                DataType::UInt8,
+            ),
+            expect: Err(ErrorCode::BadArguments("Division by zero")),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.lhs.column().len();
+        let types = vec![t.lhs.data_type().clone(), t.rhs.data_type().clone()];
+        let got = t.func.eval(&[t.lhs, t.rhs], rows);
+        match t.expect {
+            Ok(expect) => {
+                let got = got?;
+                assert_eq!(
+                    got.data_type(),
+                    t.func.return_type(&types)?,
+                    "case: {}",
+                    t.name
+                );
+                assert_eq!(
+                    matches!(got, DataColumn::Constant(..)),
+                    matches!(expect, DataColumn::Constant(..)),
+                    "case: {}",
+                    t.name
+                );
+                assert_eq!(&got, &expect, "case: {}", t.name);
+            }
+            Err(expect) => {
+                assert_eq!(
+                    got.unwrap_err().to_string(),
+                    expect.to_string(),
+                    "case: {}",
+                    t.name
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
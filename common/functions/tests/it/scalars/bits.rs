@@ -0,0 +1,338 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_bit_binary_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        expect_type: DataType,
+        expect: DataColumn,
+    }
+    let tests = vec![
+        Test {
+            name: "bitAnd(Int32, Int32)",
+            func: BitAndFunction::try_create("bitAnd")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([Some(12_i32), Some(-1), None]).into(),
+                    DataField::new("a", DataType::Int32, true),
+                ),
+                DataColumnWithField::new(
+                    Series::new([10_i32, 7, 3]).into(),
+                    DataField::new("b", DataType::Int32, false),
+                ),
+            ],
+            expect_type: DataType::Int32,
+            expect: Series::new([Some(8_i32), Some(7), None]).into(),
+        },
+        Test {
+            name: "bitOr(UInt8, Int16)",
+            func: BitOrFunction::try_create("bitOr")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([1_u8, 255]).into(),
+                    DataField::new("a", DataType::UInt8, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([256_i16, -256]).into(),
+                    DataField::new("b", DataType::Int16, false),
+                ),
+            ],
+            expect_type: DataType::Int16,
+            expect: Series::new([257_i16, -1]).into(),
+        },
+        Test {
+            name: "bitXor(UInt64, UInt8 constant)",
+            func: BitXorFunction::try_create("bitXor")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([0_u64, 5, u64::MAX]).into(),
+                    DataField::new("a", DataType::UInt64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt8(Some(3)), 3),
+                    DataField::new("b", DataType::UInt8, false),
+                ),
+            ],
+            expect_type: DataType::UInt64,
+            expect: Series::new([3_u64, 6, u64::MAX - 3]).into(),
+        },
+        Test {
+            name: "bitAnd(Int8 constant, Int64)",
+            func: BitAndFunction::try_create("bitAnd")?,
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int8(Some(-2)), 3),
+                    DataField::new("a", DataType::Int8, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([1_i64, 2, 3]).into(),
+                    DataField::new("b", DataType::Int64, false),
+                ),
+            ],
+            expect_type: DataType::Int64,
+            expect: Series::new([0_i64, 2, 2]).into(),
+        },
+        Test {
+            name: "bitOr(constant, constant)",
+            func: BitOrFunction::try_create("bitOr")?,
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt16(Some(1)), 3),
+                    DataField::new("a", DataType::UInt16, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt32(Some(6)), 3),
+                    DataField::new("b", DataType::UInt32, false),
+                ),
+            ],
+            expect_type: DataType::UInt32,
+            expect: DataColumn::Constant(DataValue::UInt32(Some(7)), 3),
+        },
+        Test {
+            name: "bitAnd(Int32, null constant)",
+            func: BitAndFunction::try_create("bitAnd")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([1_i32, 2, 3]).into(),
+                    DataField::new("a", DataType::Int32, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int32(None), 3),
+                    DataField::new("b", DataType::Int32, true),
+                ),
+            ],
+            expect_type: DataType::Int32,
+            expect: DataColumn::Constant(DataValue::Int32(None), 3),
+        },
+    ];
+
+    for t in tests {
+        let args = t
+            .args
+            .iter()
+            .map(|a| a.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            t.expect_type,
+            t.func.return_type(&args)?,
+            "case: {}",
+            t.name
+        );
+
+        let got = t.func.eval(&t.args, 3)?;
+        assert_eq!(t.expect, got, "case: {}", t.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bit_not_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        arg: DataColumnWithField,
+        expect: DataColumn,
+    }
+    let tests = vec![
+        Test {
+            name: "bitNot(UInt8)",
+            arg: DataColumnWithField::new(
+                Series::new([Some(0_u8), Some(1), Some(255), None]).into(),
+                DataField::new("a", DataType::UInt8, true),
+            ),
+            expect: Series::new([Some(255_u8), Some(254), Some(0), None]).into(),
+        },
+        Test {
+            name: "bitNot(UInt32)",
+            arg: DataColumnWithField::new(
+                Series::new([0_u32, 0xF0F0_F0F0, 1, 2]).into(),
+                DataField::new("a", DataType::UInt32, false),
+            ),
+            expect: Series::new([u32::MAX, 0x0F0F_0F0F, u32::MAX - 1, u32::MAX - 2]).into(),
+        },
+        Test {
+            name: "bitNot(Int32)",
+            arg: DataColumnWithField::new(
+                Series::new([0_i32, -1, 5, i32::MIN]).into(),
+                DataField::new("a", DataType::Int32, false),
+            ),
+            expect: Series::new([-1_i32, 0, -6, i32::MAX]).into(),
+        },
+        Test {
+            name: "bitNot(UInt16 constant)",
+            arg: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::UInt16(Some(1)), 4),
+                DataField::new("a", DataType::UInt16, false),
+            ),
+            expect: DataColumn::Constant(DataValue::UInt16(Some(u16::MAX - 1)), 4),
+        },
+    ];
+
+    let func = BitNotFunction::try_create("bitNot")?;
+    for t in tests {
+        let data_type = func.return_type(&[t.arg.data_type().clone()])?;
+        assert_eq!(t.arg.data_type(), &data_type, "case: {}", t.name);
+
+        let got = func.eval(&[t.arg], 4)?;
+        assert_eq!(t.expect, got, "case: {}", t.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bit_function_illegal_type() -> Result<()> {
+    let func = BitAndFunction::try_create("bitAnd")?;
+    let got = func.return_type(&[DataType::Int32, DataType::Float64]);
+    assert_eq!(
+        got.unwrap_err().to_string(),
+        ErrorCode::IllegalDataType("Expected integer or null, but got Float64").to_string()
+    );
+
+    let func = BitNotFunction::try_create("bitNot")?;
+    let got = func.return_type(&[DataType::String]);
+    assert_eq!(
+        got.unwrap_err().to_string(),
+        ErrorCode::IllegalDataType("Expected integer or null, but got String").to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bit_shift_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        expect: DataColumn,
+    }
+    let tests = vec![
+        Test {
+            name: "bitShiftLeft(UInt8, UInt8)",
+            func: BitShiftLeftFunction::try_create("bitShiftLeft")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([Some(1_u8), Some(3), Some(255), Some(1), None]).into(),
+                    DataField::new("a", DataType::UInt8, true),
+                ),
+                DataColumnWithField::new(
+                    Series::new([0_u8, 2, 4, 8, 1]).into(),
+                    DataField::new("n", DataType::UInt8, false),
+                ),
+            ],
+            // shift by the bit width gives 0
+            expect: Series::new([Some(1_u8), Some(12), Some(240), Some(0), None]).into(),
+        },
+        Test {
+            name: "bitShiftLeft(Int32, constant)",
+            func: BitShiftLeftFunction::try_create("bitShiftLeft")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([1_i32, -1, i32::MAX]).into(),
+                    DataField::new("a", DataType::Int32, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt64(Some(31)), 3),
+                    DataField::new("n", DataType::UInt64, false),
+                ),
+            ],
+            expect: Series::new([i32::MIN, i32::MIN, i32::MIN]).into(),
+        },
+        Test {
+            name: "bitShiftRight(Int16, Int16)",
+            func: BitShiftRightFunction::try_create("bitShiftRight")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([-8_i16, -8, -1, 100, -100, -100, 100]).into(),
+                    DataField::new("a", DataType::Int16, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([0_i16, 1, 15, 2, 16, -1, 16]).into(),
+                    DataField::new("n", DataType::Int16, false),
+                ),
+            ],
+            // arithmetic shift, out of range shift gives -1 for negative values and 0 otherwise
+            expect: Series::new([-8_i16, -4, -1, 25, -1, -1, 0]).into(),
+        },
+        Test {
+            name: "bitShiftRight(UInt64, constant)",
+            func: BitShiftRightFunction::try_create("bitShiftRight")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([u64::MAX, 1024, 7]).into(),
+                    DataField::new("a", DataType::UInt64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int8(Some(63)), 3),
+                    DataField::new("n", DataType::Int8, false),
+                ),
+            ],
+            expect: Series::new([1_u64, 0, 0]).into(),
+        },
+        Test {
+            name: "bitShiftRight(constant, Int32)",
+            func: BitShiftRightFunction::try_create("bitShiftRight")?,
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(Some(-1024)), 3),
+                    DataField::new("a", DataType::Int64, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([Some(0_i32), Some(3), None]).into(),
+                    DataField::new("n", DataType::Int32, true),
+                ),
+            ],
+            expect: Series::new([Some(-1024_i64), Some(-128), None]).into(),
+        },
+        Test {
+            name: "bitShiftLeft(constant, constant)",
+            func: BitShiftLeftFunction::try_create("bitShiftLeft")?,
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt16(Some(3)), 3),
+                    DataField::new("a", DataType::UInt16, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt8(Some(4)), 3),
+                    DataField::new("n", DataType::UInt8, false),
+                ),
+            ],
+            expect: DataColumn::Constant(DataValue::UInt16(Some(48)), 3),
+        },
+    ];
+
+    for t in tests {
+        let args = t
+            .args
+            .iter()
+            .map(|a| a.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            t.args[0].data_type(),
+            &t.func.return_type(&args)?,
+            "case: {}",
+            t.name
+        );
+
+        let got = t.func.eval(&t.args, 3)?;
+        assert_eq!(t.expect, got, "case: {}", t.name);
+    }
+    Ok(())
+}
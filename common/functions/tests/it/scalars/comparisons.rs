@@ -188,3 +188,461 @@ fn test_comparison_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_comparison_function_coercion() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "uint8 lt int64",
+            func: ComparisonLtFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec![1u8, 2, 255]).into(),
+                    DataField::new("a", DataType::UInt8, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(vec![2i64, 2, -1]).into(),
+                    DataField::new("b", DataType::Int64, false),
+                ),
+            ],
+            expect: Series::new(vec![true, false, false]).into(),
+        },
+        Test {
+            name: "int32 eq float64",
+            func: ComparisonEqFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec![1i32, 2]).into(),
+                    DataField::new("a", DataType::Int32, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(vec![1.0f64, 2.5]).into(),
+                    DataField::new("b", DataType::Float64, false),
+                ),
+            ],
+            expect: Series::new(vec![true, false]).into(),
+        },
+        Test {
+            name: "string gt-eq string",
+            func: ComparisonGtEqFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec!["a", "b"]).into(),
+                    DataField::new("a", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(vec!["a", "c"]).into(),
+                    DataField::new("b", DataType::String, false),
+                ),
+            ],
+            expect: Series::new(vec![true, false]).into(),
+        },
+        Test {
+            name: "date16 lt-eq date32",
+            func: ComparisonLtEqFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec![18893u16, 18894]).into(),
+                    DataField::new("a", DataType::Date16, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(vec![18893i32, 18893]).into(),
+                    DataField::new("b", DataType::Date32, false),
+                ),
+            ],
+            expect: Series::new(vec![true, false]).into(),
+        },
+        Test {
+            name: "datetime32 gt datetime32",
+            func: ComparisonGtFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec![10u32, 20]).into(),
+                    DataField::new("a", DataType::DateTime32(None), false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(vec![15u32, 15]).into(),
+                    DataField::new("b", DataType::DateTime32(None), false),
+                ),
+            ],
+            expect: Series::new(vec![false, true]).into(),
+        },
+        Test {
+            name: "constant not-eq array",
+            func: ComparisonNotEqFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(Some(2)), 3),
+                    DataField::new("a", DataType::Int64, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new(vec![1u8, 2, 3]).into(),
+                    DataField::new("b", DataType::UInt8, false),
+                ),
+            ],
+            expect: Series::new(vec![true, false, true]).into(),
+        },
+        Test {
+            name: "constant eq constant",
+            func: ComparisonEqFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(Some(1)), 3),
+                    DataField::new("a", DataType::Int64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt8(Some(1)), 3),
+                    DataField::new("b", DataType::UInt8, false),
+                ),
+            ],
+            expect: DataColumn::Constant(DataValue::Boolean(Some(true)), 3),
+        },
+        Test {
+            name: "array with nulls eq array",
+            func: ComparisonEqFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec![Some(1i64), None, Some(3)]).into(),
+                    DataField::new("a", DataType::Int64, true),
+                ),
+                DataColumnWithField::new(
+                    Series::new(vec![1i64, 1, 2]).into(),
+                    DataField::new("b", DataType::Int64, false),
+                ),
+            ],
+            expect: Series::new(vec![Some(true), None, Some(false)]).into(),
+        },
+        Test {
+            name: "array lt null constant",
+            func: ComparisonLtFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec![1i64, 2]).into(),
+                    DataField::new("a", DataType::Int64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(None), 2),
+                    DataField::new("b", DataType::Int64, true),
+                ),
+            ],
+            expect: Series::new(vec![None::<bool>, None]).into(),
+        },
+        Test {
+            name: "array gt null",
+            func: ComparisonGtFunction::try_create_func("")?,
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new(vec![1i64, 2]).into(),
+                    DataField::new("a", DataType::Int64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Null, 2),
+                    DataField::new("b", DataType::Null, true),
+                ),
+            ],
+            expect: DataColumn::Constant(DataValue::Boolean(None), 2),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.args[0].column().len();
+        let types: Vec<DataType> = t.args.iter().map(|c| c.data_type().clone()).collect();
+        assert_eq!(t.func.return_type(&types)?, DataType::Boolean);
+
+        let fields: Vec<DataField> = t.args.iter().map(|c| c.field().clone()).collect();
+        let nullable = fields.iter().any(|f| f.is_nullable());
+        let schema = DataSchemaRefExt::create(fields);
+        assert_eq!(t.func.nullable(&schema)?, nullable, "case: {}", t.name);
+
+        let got = t.func.eval(&t.args, rows)?;
+        assert_eq!(
+            matches!(got, DataColumn::Constant(..)),
+            matches!(t.expect, DataColumn::Constant(..)),
+            "case: {}",
+            t.name
+        );
+        assert_eq!(&got, &t.expect, "case: {}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_comparison_function_incompatible_types() -> Result<()> {
+    let func = ComparisonEqFunction::try_create_func("")?;
+    let got = func.return_type(&[DataType::Boolean, DataType::String]);
+    assert_eq!(
+        got.unwrap_err().to_string(),
+        "Code: 7, displayText = Can not compare Boolean with String."
+    );
+    Ok(())
+}
+
+#[test]
+fn test_greatest_least_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        args: Vec<DataColumnWithField>,
+        expect_type: DataType,
+        expect: DataColumn,
+    }
+
+    let arg = |name: &str, column: DataColumn| {
+        let dtype = column.data_type();
+        DataColumnWithField::new(column, DataField::new(name, dtype, true))
+    };
+
+    let tests = vec![
+        Test {
+            name: "greatest-integers-promoted",
+            func: GreatestFunction::try_create("greatest")?,
+            args: vec![
+                arg("a", Series::new(vec![1i8, 5, -3]).into()),
+                arg("b", Series::new(vec![2i64, 4, -7]).into()),
+            ],
+            expect_type: DataType::Int64,
+            expect: Series::new(vec![2i64, 5, -3]).into(),
+        },
+        Test {
+            name: "least-unsigned-widths-promoted",
+            func: LeastFunction::try_create("least")?,
+            args: vec![
+                arg("a", Series::new(vec![Some(200u8), None, Some(7)]).into()),
+                arg("b", Series::new(vec![Some(70000u32), Some(3), None]).into()),
+                arg("c", Series::new(vec![Some(300u32), None, None]).into()),
+            ],
+            expect_type: DataType::UInt32,
+            expect: Series::new(vec![Some(200u32), Some(3), Some(7)]).into(),
+        },
+        Test {
+            name: "least-integer-and-float",
+            func: LeastFunction::try_create("least")?,
+            args: vec![
+                arg("a", Series::new(vec![1i32, 5]).into()),
+                arg("b", Series::new(vec![1.5f64, 2.5]).into()),
+            ],
+            expect_type: DataType::Float64,
+            expect: Series::new(vec![1.0f64, 2.5]).into(),
+        },
+        Test {
+            name: "greatest-skips-nulls",
+            func: GreatestFunction::try_create("greatest")?,
+            args: vec![
+                arg("a", Series::new(vec![Some(1i32), None, None]).into()),
+                arg("b", Series::new(vec![None, Some(2i32), None]).into()),
+                arg("c", Series::new(vec![Some(0i32), Some(3), None]).into()),
+            ],
+            expect_type: DataType::Int32,
+            expect: Series::new(vec![Some(1i32), Some(3), None]).into(),
+        },
+        Test {
+            name: "least-skips-nulls",
+            func: LeastFunction::try_create("least")?,
+            args: vec![
+                arg("a", Series::new(vec![Some(1i32), None, None]).into()),
+                arg("b", Series::new(vec![None, Some(2i32), None]).into()),
+                arg("c", Series::new(vec![Some(0i32), Some(3), None]).into()),
+            ],
+            expect_type: DataType::Int32,
+            expect: Series::new(vec![Some(0i32), Some(2), None]).into(),
+        },
+        Test {
+            name: "least-strings",
+            func: LeastFunction::try_create("least")?,
+            args: vec![
+                arg("a", Series::new(vec!["b", "abc"]).into()),
+                arg("b", Series::new(vec!["a", "abd"]).into()),
+            ],
+            expect_type: DataType::String,
+            expect: Series::new(vec!["a", "abc"]).into(),
+        },
+        Test {
+            name: "greatest-null-argument",
+            func: GreatestFunction::try_create("greatest")?,
+            args: vec![
+                arg("a", DataColumn::Constant(DataValue::Null, 2)),
+                arg("b", Series::new(vec![1i32, 2]).into()),
+            ],
+            expect_type: DataType::Int32,
+            expect: Series::new(vec![1i32, 2]).into(),
+        },
+        Test {
+            name: "greatest-constants",
+            func: GreatestFunction::try_create("greatest")?,
+            args: vec![
+                arg("a", DataColumn::Constant(DataValue::Int32(Some(3)), 2)),
+                arg("b", DataColumn::Constant(DataValue::Int32(Some(7)), 2)),
+            ],
+            expect_type: DataType::Int32,
+            expect: DataColumn::Constant(DataValue::Int32(Some(7)), 2),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.args[0].column().len();
+        let types: Vec<DataType> = t.args.iter().map(|c| c.data_type().clone()).collect();
+        assert_eq!(
+            t.func.return_type(&types)?,
+            t.expect_type,
+            "case: {}",
+            t.name
+        );
+
+        let got = t.func.eval(&t.args, rows)?;
+        assert_eq!(
+            matches!(got, DataColumn::Constant(..)),
+            matches!(t.expect, DataColumn::Constant(..)),
+            "case: {}",
+            t.name
+        );
+        assert_eq!(&got, &t.expect, "case: {}", t.name);
+    }
+
+    let func = GreatestFunction::try_create("greatest")?;
+    let got = func.return_type(&[DataType::Int32, DataType::Boolean]);
+    assert_eq!(
+        got.unwrap_err().to_string(),
+        "Code: 7, displayText = Can not compare Int32 with Boolean."
+    );
+    Ok(())
+}
+
+#[test]
+fn test_between_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        args: Vec<DataColumnWithField>,
+        expect: DataColumn,
+    }
+
+    let arg = |name: &str, column: DataColumn| {
+        let dtype = column.data_type();
+        DataColumnWithField::new(column, DataField::new(name, dtype, true))
+    };
+
+    let tests = vec![
+        Test {
+            name: "between-constant-bounds",
+            args: vec![
+                arg("x", Series::new(vec![0i32, 1, 2, 3, 4, 5]).into()),
+                arg("lo", DataColumn::Constant(DataValue::Int32(Some(1)), 6)),
+                arg("hi", DataColumn::Constant(DataValue::Int32(Some(4)), 6)),
+            ],
+            expect: Series::new(vec![false, true, true, true, true, false]).into(),
+        },
+        Test {
+            name: "between-array-bounds",
+            args: vec![
+                arg("x", Series::new(vec![5i64, 5, 5, 5]).into()),
+                arg("lo", Series::new(vec![1i64, 5, 6, 3]).into()),
+                arg("hi", Series::new(vec![9i64, 5, 9, 4]).into()),
+            ],
+            expect: Series::new(vec![true, true, false, false]).into(),
+        },
+        Test {
+            name: "between-constant-value",
+            args: vec![
+                arg("x", DataColumn::Constant(DataValue::Int32(Some(3)), 3)),
+                arg("lo", Series::new(vec![1i32, 3, 4]).into()),
+                arg("hi", DataColumn::Constant(DataValue::Int32(Some(3)), 3)),
+            ],
+            expect: Series::new(vec![true, true, false]).into(),
+        },
+        Test {
+            name: "between-types-promoted",
+            args: vec![
+                arg("x", Series::new(vec![200u8, 1, 10]).into()),
+                arg("lo", DataColumn::Constant(DataValue::Int8(Some(-1)), 3)),
+                arg("hi", DataColumn::Constant(DataValue::Float64(Some(9.5)), 3)),
+            ],
+            expect: Series::new(vec![false, true, false]).into(),
+        },
+        Test {
+            name: "between-strings",
+            args: vec![
+                arg("x", Series::new(vec!["a", "b", "bc", "c"]).into()),
+                arg(
+                    "lo",
+                    DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 4),
+                ),
+                arg(
+                    "hi",
+                    DataColumn::Constant(DataValue::String(Some(b"bc".to_vec())), 4),
+                ),
+            ],
+            expect: Series::new(vec![false, true, true, false]).into(),
+        },
+        Test {
+            name: "between-nulls",
+            args: vec![
+                arg(
+                    "x",
+                    Series::new(vec![None, Some(5i32), Some(5), Some(5)]).into(),
+                ),
+                arg(
+                    "lo",
+                    Series::new(vec![Some(1i32), None, Some(1), Some(1)]).into(),
+                ),
+                arg(
+                    "hi",
+                    Series::new(vec![Some(9i32), Some(3), None, Some(9)]).into(),
+                ),
+            ],
+            expect: Series::new(vec![None, None, None, Some(true)]).into(),
+        },
+        Test {
+            name: "between-null-argument",
+            args: vec![
+                arg("x", Series::new(vec![1i32, 2]).into()),
+                arg("lo", DataColumn::Constant(DataValue::Null, 2)),
+                arg("hi", DataColumn::Constant(DataValue::Int32(Some(3)), 2)),
+            ],
+            expect: DataColumn::Constant(DataValue::Boolean(None), 2),
+        },
+        Test {
+            name: "between-constants",
+            args: vec![
+                arg("x", DataColumn::Constant(DataValue::Int32(Some(3)), 2)),
+                arg("lo", DataColumn::Constant(DataValue::Int32(Some(3)), 2)),
+                arg("hi", DataColumn::Constant(DataValue::Int32(Some(7)), 2)),
+            ],
+            expect: DataColumn::Constant(DataValue::Boolean(Some(true)), 2),
+        },
+    ];
+
+    let func = BetweenFunction::try_create("between")?;
+    for t in tests {
+        let rows = t.args[0].column().len();
+        let types: Vec<DataType> = t.args.iter().map(|c| c.data_type().clone()).collect();
+        assert_eq!(
+            func.return_type(&types)?,
+            DataType::Boolean,
+            "case: {}",
+            t.name
+        );
+
+        let got = func.eval(&t.args, rows)?;
+        assert_eq!(
+            matches!(got, DataColumn::Constant(..)),
+            matches!(t.expect, DataColumn::Constant(..)),
+            "case: {}",
+            t.name
+        );
+        assert_eq!(&got, &t.expect, "case: {}", t.name);
+    }
+
+    let got = func.return_type(&[DataType::Int32, DataType::Int32, DataType::Boolean]);
+    assert_eq!(
+        got.unwrap_err().to_string(),
+        "Code: 7, displayText = Can not compare Int32 with Boolean."
+    );
+    Ok(())
+}
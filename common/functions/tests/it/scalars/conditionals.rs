@@ -87,3 +87,185 @@ fn test_if_function() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_if_function_nulls() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "mixed null branches",
+            columns: vec![
+                Series::new(vec![true, false, true, false]).into(),
+                Series::new(vec![Some(1i64), None, None, Some(4)]).into(),
+                Series::new(vec![None, Some(20i64), Some(30), None]).into(),
+            ],
+            expect: Series::new(vec![Some(1i64), Some(20), None, None]).into(),
+        },
+        Test {
+            name: "null condition picks else",
+            columns: vec![
+                Series::new(vec![Some(true), None, Some(false), None]).into(),
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                Series::new(vec![10i64, 20, 30, 40]).into(),
+            ],
+            expect: Series::new(vec![1i64, 20, 30, 40]).into(),
+        },
+        Test {
+            name: "constant true condition",
+            columns: vec![
+                DataColumn::Constant(DataValue::Boolean(Some(true)), 4),
+                Series::new(vec![Some(1i64), None, Some(3), None]).into(),
+                Series::new(vec![10i64, 20, 30, 40]).into(),
+            ],
+            expect: Series::new(vec![Some(1i64), None, Some(3), None]).into(),
+        },
+        Test {
+            name: "constant null condition",
+            columns: vec![
+                DataColumn::Constant(DataValue::Boolean(None), 4),
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                Series::new(vec![Some(10i64), None, Some(30), None]).into(),
+            ],
+            expect: Series::new(vec![Some(10i64), None, Some(30), None]).into(),
+        },
+        Test {
+            name: "null condition",
+            columns: vec![
+                DataColumn::Constant(DataValue::Null, 4),
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(7)), 4),
+            ],
+            expect: DataColumn::Constant(DataValue::Int64(Some(7)), 4),
+        },
+    ];
+
+    let func = IfFunction::try_create_func("if")?;
+    for t in tests {
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let args: Vec<DataType> = t.columns.iter().map(|c| c.data_type()).collect();
+        let expect_type = func.return_type(&args)?;
+
+        let v = func.eval(&columns, 4)?;
+        assert_eq!(expect_type, v.data_type(), "case: {}", t.name);
+        assert_eq!(&v, &t.expect, "case: {}", t.name);
+    }
+
+    // The condition must be a boolean.
+    let err = func
+        .return_type(&[DataType::Int32, DataType::Int32, DataType::Int32])
+        .unwrap_err();
+    assert_eq!(
+        "Code: 7, displayText = Expected boolean or null, but got Int32.",
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_multi_if_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "two branches and else",
+            columns: vec![
+                Series::new(vec![true, false, false, false]).into(),
+                Series::new(vec![1i32, 2, 3, 4]).into(),
+                Series::new(vec![false, true, false, true]).into(),
+                Series::new(vec![10i64, 20, 30, 40]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(0)), 4),
+            ],
+            expect: Series::new(vec![1i64, 20, 0, 40]).into(),
+        },
+        Test {
+            name: "first true condition wins",
+            columns: vec![
+                Series::new(vec![true, true, false, false]).into(),
+                Series::new(vec!["a", "b", "c", "d"]).into(),
+                Series::new(vec![true, false, true, false]).into(),
+                Series::new(vec!["e", "f", "g", "h"]).into(),
+                Series::new(vec!["i", "j", "k", "l"]).into(),
+            ],
+            expect: Series::new(vec!["a", "b", "g", "l"]).into(),
+        },
+        Test {
+            name: "null conditions are false",
+            columns: vec![
+                Series::new(vec![None, Some(true), None, Some(false)]).into(),
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                Series::new(vec![Some(true), None, None, Some(true)]).into(),
+                Series::new(vec![10i64, 20, 30, 40]).into(),
+                Series::new(vec![Some(100i64), Some(200), None, Some(400)]).into(),
+            ],
+            expect: Series::new(vec![Some(10i64), Some(2), None, Some(40)]).into(),
+        },
+        Test {
+            name: "constant conditions",
+            columns: vec![
+                DataColumn::Constant(DataValue::Boolean(None), 4),
+                Series::new(vec![1i64, 2, 3, 4]).into(),
+                DataColumn::Constant(DataValue::Boolean(Some(true)), 4),
+                DataColumn::Constant(DataValue::Int64(Some(7)), 4),
+                Series::new(vec![10i64, 20, 30, 40]).into(),
+            ],
+            expect: DataColumn::Constant(DataValue::Int64(Some(7)), 4),
+        },
+    ];
+
+    let func = MultiIfFunction::try_create("multiIf")?;
+    for t in tests {
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let args: Vec<DataType> = t.columns.iter().map(|c| c.data_type()).collect();
+        let expect_type = func.return_type(&args)?;
+
+        let v = func.eval(&columns, 4)?;
+        assert_eq!(expect_type, v.data_type(), "case: {}", t.name);
+        assert_eq!(&v, &t.expect, "case: {}", t.name);
+    }
+
+    // The else value is required.
+    let err = func
+        .return_type(&[DataType::Boolean, DataType::Int32])
+        .unwrap_err();
+    assert_eq!(
+        "Code: 28, displayText = multiIf expects pairs of conditions and values followed by an else value, but got 2 arguments.",
+        err.to_string()
+    );
+
+    // The conditions must be booleans.
+    let err = func
+        .return_type(&[
+            DataType::Boolean,
+            DataType::Int32,
+            DataType::Int32,
+            DataType::Int32,
+            DataType::Int32,
+        ])
+        .unwrap_err();
+    assert_eq!(
+        "Code: 7, displayText = Expected boolean or null, but got Int32.",
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_functions::scalars::*;
@@ -66,6 +67,113 @@ fn test_to_start_of_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_fixed_granularity_functions() -> Result<()> {
+    // 2021-08-30 10:47:42 UTC, a Monday.
+    let ts = 1630320462u32;
+    let factory = FunctionFactory::instance();
+
+    let tests = vec![
+        Test {
+            name: "test-toStartOfMinute",
+            display: "toStartOfMinute",
+            nullable: false,
+            columns: vec![Series::new(vec![ts]).into()],
+            func: factory.get("toStartOfMinute"),
+            // 2021-08-30 10:47:00
+            expect: Series::new(vec![1630320420u32]),
+            error: "",
+        },
+        Test {
+            name: "test-toStartOfFifteenMinutes",
+            display: "toStartOfFifteenMinutes",
+            nullable: false,
+            columns: vec![Series::new(vec![ts]).into()],
+            func: factory.get("toStartOfFifteenMinutes"),
+            // 2021-08-30 10:45:00
+            expect: Series::new(vec![1630320300u32]),
+            error: "",
+        },
+        Test {
+            name: "test-toStartOfHour",
+            display: "toStartOfHour",
+            nullable: false,
+            columns: vec![Series::new(vec![ts]).into()],
+            func: factory.get("toStartOfHour"),
+            // 2021-08-30 10:00:00
+            expect: Series::new(vec![1630317600u32]),
+            error: "",
+        },
+        Test {
+            name: "test-toStartOfDay",
+            display: "toStartOfDay",
+            nullable: false,
+            columns: vec![Series::new(vec![ts]).into()],
+            func: factory.get("toStartOfDay"),
+            // 2021-08-30 00:00:00
+            expect: Series::new(vec![1630281600u32]),
+            error: "",
+        },
+        Test {
+            name: "test-toMonday",
+            display: "toMonday()",
+            nullable: false,
+            // 2021-09-01 00:00:00, a Wednesday.
+            columns: vec![Series::new(vec![ts, 1630454400u32]).into()],
+            func: factory.get("toMonday"),
+            // 2021-08-30
+            expect: Series::new(vec![18869u16, 18869u16]),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        do_test(t)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_now_and_today_functions() -> Result<()> {
+    let seconds = || Utc::now().timestamp() as u32;
+
+    let now = NowFunction::try_create("now")?;
+    assert_eq!(0, now.num_arguments());
+    assert_eq!(DataType::DateTime32(None), now.return_type(&[])?);
+
+    let before = seconds();
+    let v = now.eval(&[], 3)?;
+    let after = seconds();
+    match v {
+        DataColumn::Constant(DataValue::UInt32(Some(now)), 3) => {
+            assert!(before <= now && now <= after);
+        }
+        other => panic!(
+            "now() should be a constant DateTime32 of 3 rows, got {:?}",
+            other
+        ),
+    }
+
+    let today = TodayFunction::try_create("today")?;
+    assert_eq!(0, today.num_arguments());
+    assert_eq!(DataType::Date16, today.return_type(&[])?);
+
+    let before = (seconds() / (24 * 3600)) as u16;
+    let v = today.eval(&[], 3)?;
+    let after = (seconds() / (24 * 3600)) as u16;
+    match v {
+        DataColumn::Constant(DataValue::UInt16(Some(today)), 3) => {
+            assert!(before <= today && today <= after);
+        }
+        other => panic!(
+            "today() should be a constant Date16 of 3 rows, got {:?}",
+            other
+        ),
+    }
+
+    Ok(())
+}
+
 fn do_test(t: Test) -> Result<()> {
     let dummy = DataField::new("dummy", DataType::DateTime32(None), false);
     let rows = t.columns[0].len();
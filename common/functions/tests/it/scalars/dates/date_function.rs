@@ -91,6 +91,27 @@ fn test_toyyyymm_function() -> Result<()> {
             expect: Series::new(vec![197001u32]).into(),
             error: "",
         },
+        Test {
+            name: "test_toyyyymm_datetime64",
+            display: "d()",
+            arg_names: vec!["d"],
+            func: ToYYYYMMFunction::try_create("d")?,
+            // 2021-09-05 09:23:17.123 and 1969-12-31 23:59:59.999
+            columns: vec![Series::new(vec![1630833797123i64, -1]).into()],
+            nullable: false,
+            expect: Series::new(vec![202109u32, 196912]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toyyyymm_datetime64_out_of_range",
+            display: "d()",
+            arg_names: vec!["d"],
+            func: ToYYYYMMFunction::try_create("d")?,
+            columns: vec![Series::new(vec![1630833797123i64, i64::MAX]).into()],
+            nullable: false,
+            expect: Series::new(vec![Some(202109u32), None]).into(),
+            error: "",
+        },
         Test {
             name: "test_toyyyymmdd_date16",
             display: "c()",
@@ -480,6 +501,110 @@ fn test_todatofweek_function() -> Result<()> {
     do_test(tests)
 }
 
+#[test]
+fn test_toquarter_function() -> Result<()> {
+    let tests = vec![
+        Test {
+            name: "test_toquarter_date16",
+            display: "c()",
+            arg_names: vec!["c"],
+            func: ToQuarterFunction::try_create("c")?,
+            // 2021-03-31, 2021-04-01, 2019-12-30
+            columns: vec![Series::new(vec![18717u16, 18718, 18260]).into()],
+            nullable: false,
+            expect: Series::new(vec![1u8, 2, 4]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toquarter_date32",
+            display: "b()",
+            arg_names: vec!["b"],
+            func: ToQuarterFunction::try_create("b")?,
+            columns: vec![Series::new(vec![0i32]).into()],
+            nullable: false,
+            expect: Series::new(vec![1u8]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toquarter_datetime",
+            display: "a()",
+            arg_names: vec!["a"],
+            func: ToQuarterFunction::try_create("a")?,
+            // 2021-10-02 11:15:24
+            columns: vec![Series::new(vec![1633173324u32]).into()],
+            nullable: false,
+            expect: Series::new(vec![4u8]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toquarter_datetime_constant",
+            display: "a()",
+            arg_names: vec!["a"],
+            func: ToQuarterFunction::try_create("a")?,
+            columns: vec![DataColumn::Constant(
+                DataValue::UInt32(Some(1633173324u32)),
+                1,
+            )],
+            nullable: false,
+            expect: Series::new(vec![4u8]).into(),
+            error: "",
+        },
+    ];
+
+    do_test(tests)
+}
+
+#[test]
+fn test_toweek_function() -> Result<()> {
+    let tests = vec![
+        Test {
+            name: "test_toweek_date16_year_boundary",
+            display: "c()",
+            arg_names: vec!["c"],
+            func: ToWeekFunction::try_create("c")?,
+            // 2019-12-29, 2019-12-30, 2020-12-31, 2021-01-01, 2021-01-04
+            columns: vec![Series::new(vec![18259u16, 18260, 18627, 18628, 18631]).into()],
+            nullable: false,
+            expect: Series::new(vec![52u8, 1, 53, 53, 1]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toweek_date32",
+            display: "b()",
+            arg_names: vec!["b"],
+            func: ToWeekFunction::try_create("b")?,
+            // 1970-01-01 is a Thursday, so it is in the first week.
+            columns: vec![Series::new(vec![0i32]).into()],
+            nullable: false,
+            expect: Series::new(vec![1u8]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toweek_datetime",
+            display: "a()",
+            arg_names: vec!["a"],
+            func: ToWeekFunction::try_create("a")?,
+            // 2021-10-02 11:15:24
+            columns: vec![Series::new(vec![1633173324u32]).into()],
+            nullable: false,
+            expect: Series::new(vec![39u8]).into(),
+            error: "",
+        },
+        Test {
+            name: "test_toweek_date16_constant",
+            display: "c()",
+            arg_names: vec!["c"],
+            func: ToWeekFunction::try_create("c")?,
+            columns: vec![DataColumn::Constant(DataValue::UInt16(Some(18628u16)), 1)],
+            nullable: false,
+            expect: Series::new(vec![53u8]).into(),
+            error: "",
+        },
+    ];
+
+    do_test(tests)
+}
+
 #[test]
 fn test_todayofmonth_function() -> Result<()> {
     let tests = vec![
@@ -759,6 +884,17 @@ fn test_tosecond_function() -> Result<()> {
             expect: Series::new(vec![42u8]).into(),
             error: "",
         },
+        Test {
+            name: "test_tosecond_datetime64",
+            display: "d()",
+            arg_names: vec!["d"],
+            func: ToSecondFunction::try_create("d")?,
+            // 2021-10-18 10:05:42.001, 2021-10-18 10:05:42.999 and 1969-12-31 23:59:59.999
+            columns: vec![Series::new(vec![1634551542001i64, 1634551542999, -1]).into()],
+            nullable: false,
+            expect: Series::new(vec![42u8, 42, 59]).into(),
+            error: "",
+        },
     ];
 
     do_test(tests)
@@ -847,11 +983,297 @@ fn test_tomonday_function() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_tostartofweek_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        arg: DataColumnWithField,
+        mode: Option<u64>,
+        expect: DataColumn,
+    }
+
+    // 2021-09-23 is a Thursday, 2021-09-20 is a Monday.
+    let thursday = DataField::new("c", DataType::Date16, false);
+    let tests = vec![
+        Test {
+            name: "mid-week date16 starts on sunday",
+            arg: DataColumnWithField::new(Series::new(vec![18893u16]).into(), thursday.clone()),
+            mode: None,
+            expect: Series::new(vec![18889u16]).into(),
+        },
+        Test {
+            name: "mid-week date16 starts on monday",
+            arg: DataColumnWithField::new(Series::new(vec![18893u16]).into(), thursday.clone()),
+            mode: Some(1),
+            expect: Series::new(vec![18890u16]).into(),
+        },
+        Test {
+            name: "exact monday date32 starts on sunday",
+            arg: DataColumnWithField::new(
+                Series::new(vec![18890i32]).into(),
+                DataField::new("b", DataType::Date32, false),
+            ),
+            mode: Some(0),
+            expect: Series::new(vec![18889u16]).into(),
+        },
+        Test {
+            name: "exact monday date32 starts on monday",
+            arg: DataColumnWithField::new(
+                Series::new(vec![18890i32]).into(),
+                DataField::new("b", DataType::Date32, false),
+            ),
+            mode: Some(1),
+            expect: Series::new(vec![18890u16]).into(),
+        },
+        Test {
+            name: "mid-week datetime starts on monday",
+            arg: DataColumnWithField::new(
+                Series::new(vec![1632397739u32]).into(),
+                DataField::new("a", DataType::DateTime32(None), false),
+            ),
+            mode: Some(3),
+            expect: Series::new(vec![18890u16]).into(),
+        },
+        Test {
+            name: "date16 with nulls",
+            arg: DataColumnWithField::new(
+                Series::new(vec![Some(18893u16), None]).into(),
+                DataField::new("c", DataType::Date16, true),
+            ),
+            mode: Some(1),
+            expect: Series::new(vec![Some(18890u16), None]).into(),
+        },
+    ];
+
+    let func = ToStartOfWeekFunction::try_create("toStartOfWeek")?;
+    for t in tests {
+        let rows = t.arg.column().len();
+        let mut columns = vec![t.arg];
+        if let Some(mode) = t.mode {
+            columns.push(DataColumnWithField::new(
+                DataColumn::Constant(DataValue::UInt64(Some(mode)), rows),
+                DataField::new("mode", DataType::UInt64, false),
+            ));
+        }
+
+        let v = func.eval(&columns, rows)?;
+        assert_eq!(&v, &t.expect, "case: {}", t.name);
+        assert_eq!(DataType::UInt16, v.data_type(), "case: {}", t.name);
+    }
+
+    // The mode is out of range.
+    let columns = vec![
+        DataColumnWithField::new(Series::new(vec![18893u16]).into(), thursday),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::UInt64(Some(10)), 1),
+            DataField::new("mode", DataType::UInt64, false),
+        ),
+    ];
+    let err = func.eval(&columns, 1).unwrap_err();
+    assert_eq!(
+        "Code: 6, displayText = The parameter:10 range is abnormal, it should be between 0-9.",
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_formatdatetime_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        arg: DataColumnWithField,
+        format: &'static str,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "date16 to date",
+            arg: DataColumnWithField::new(
+                Series::new(vec![Some(18893u16), None, Some(0u16)]).into(),
+                DataField::new("c", DataType::Date16, true),
+            ),
+            format: "%Y-%m-%d",
+            expect: Series::new(vec![Some("2021-09-23"), None, Some("1970-01-01")]).into(),
+        },
+        Test {
+            name: "datetime32 to time",
+            arg: DataColumnWithField::new(
+                Series::new(vec![1632397739u32, 0u32]).into(),
+                DataField::new("a", DataType::DateTime32(None), false),
+            ),
+            format: "%H:%M:%S",
+            expect: Series::new(vec!["11:48:59", "00:00:00"]).into(),
+        },
+        Test {
+            name: "datetime64 to time with milliseconds",
+            arg: DataColumnWithField::new(
+                Series::new(vec![1632397739123i64, 5]).into(),
+                DataField::new("d", DataType::DateTime64(3, None), false),
+            ),
+            format: "%H:%M:%S%.3f",
+            expect: Series::new(vec!["11:48:59.123", "00:00:00.005"]).into(),
+        },
+        Test {
+            name: "constant date32",
+            arg: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::Int32(Some(18893)), 2),
+                DataField::new("b", DataType::Date32, false),
+            ),
+            format: "%d/%m/%Y",
+            expect: DataColumn::Constant(DataValue::String(Some(b"23/09/2021".to_vec())), 2),
+        },
+    ];
+
+    let func = FormatDateTimeFunction::try_create("formatDateTime")?;
+    for t in tests {
+        let rows = t.arg.column().len();
+        let columns = vec![
+            t.arg,
+            DataColumnWithField::new(
+                DataColumn::Constant(DataValue::String(Some(t.format.as_bytes().to_vec())), rows),
+                DataField::new("format", DataType::String, false),
+            ),
+        ];
+
+        let v = func.eval(&columns, rows)?;
+        assert_eq!(&v, &t.expect, "case: {}", t.name);
+        assert_eq!(DataType::String, v.data_type(), "case: {}", t.name);
+    }
+
+    // The format specifier is invalid.
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec![18893u16]).into(),
+            DataField::new("c", DataType::Date16, false),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"%Y-%Q".to_vec())), 1),
+            DataField::new("format", DataType::String, false),
+        ),
+    ];
+    let err = func.eval(&columns, 1).unwrap_err();
+    assert_eq!(
+        "Code: 6, displayText = Invalid format specifier in '%Y-%Q'.",
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_parsedatetime_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        strict: bool,
+        arg: Series,
+        format: Option<&'static str>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "default format",
+            strict: true,
+            arg: Series::new(vec![Some("2021-06-15 10:20:30"), None]),
+            format: None,
+            expect: Series::new(vec![Some(1623752430u32), None]).into(),
+        },
+        Test {
+            name: "bad row to null",
+            strict: false,
+            arg: Series::new(vec!["2021-06-15 10:20:30", "2021-06-15", "abc"]),
+            format: None,
+            expect: Series::new(vec![Some(1623752430u32), None, None]).into(),
+        },
+        Test {
+            name: "custom format",
+            strict: true,
+            arg: Series::new(vec!["2021/09/23 11.48.59", "1970/01/01 00.00.00"]),
+            format: Some("%Y/%m/%d %H.%M.%S"),
+            expect: Series::new(vec![1632397739u32, 0u32]).into(),
+        },
+        Test {
+            name: "fractional seconds are truncated",
+            strict: true,
+            arg: Series::new(vec![
+                "2021-06-15 10:20:30.999",
+                "2021-06-15 10:20:30.000001",
+            ]),
+            format: None,
+            expect: Series::new(vec![1623752430u32, 1623752430]).into(),
+        },
+        Test {
+            name: "timezone offset",
+            strict: true,
+            arg: Series::new(vec![
+                "2021-06-15 18:20:30+08:00",
+                "2021-06-15 09:20:30.5 -0100",
+                "2021-06-15 10:20:30+00:00",
+            ]),
+            format: None,
+            expect: Series::new(vec![1623752430u32, 1623752430, 1623752430]).into(),
+        },
+        Test {
+            name: "custom format with timezone offset",
+            strict: true,
+            arg: Series::new(vec!["15/06/2021 12:20 +0200"]),
+            format: Some("%d/%m/%Y %H:%M %z"),
+            expect: Series::new(vec![1623752400u32]).into(),
+        },
+        Test {
+            name: "out of range to null",
+            strict: false,
+            arg: Series::new(vec![
+                "1969-12-31 23:59:59",
+                "2106-02-07 06:28:16",
+                "1970-01-01 08:00:00+08:00",
+            ]),
+            format: None,
+            expect: Series::new(vec![None, None, Some(0u32)]).into(),
+        },
+    ];
+
+    for t in tests {
+        let func = ParseDateTimeFunction::try_create("parseDateTime", t.strict)?;
+        let rows = t.arg.len();
+        let mut columns = vec![DataColumnWithField::new(
+            t.arg.into(),
+            DataField::new("a", DataType::String, true),
+        )];
+        if let Some(format) = t.format {
+            columns.push(DataColumnWithField::new(
+                DataColumn::Constant(DataValue::String(Some(format.as_bytes().to_vec())), rows),
+                DataField::new("format", DataType::String, false),
+            ));
+        }
+
+        let v = func.eval(&columns, rows)?;
+        assert_eq!(&v, &t.expect, "case: {}", t.name);
+    }
+
+    // A bad row is an error in the strict mode.
+    let func = ParseDateTimeFunction::try_create("parseDateTime", true)?;
+    let columns = vec![DataColumnWithField::new(
+        Series::new(vec!["2021-06-15 10:20:30", "abc"]).into(),
+        DataField::new("a", DataType::String, false),
+    )];
+    let err = func.eval(&columns, 2).unwrap_err();
+    assert_eq!(
+        "Code: 6, displayText = Cannot parse 'abc' as DateTime32 with format '%Y-%m-%d %H:%M:%S'.",
+        err.to_string()
+    );
+
+    Ok(())
+}
+
 fn do_test(tests: Vec<Test>) -> Result<()> {
     let schema = DataSchemaRefExt::create(vec![
         DataField::new("a", DataType::DateTime32(None), false),
         DataField::new("b", DataType::Date32, false),
         DataField::new("c", DataType::Date16, false),
+        DataField::new("d", DataType::DateTime64(3, None), false),
     ]);
 
     for t in tests {
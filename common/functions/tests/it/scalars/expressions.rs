@@ -35,7 +35,7 @@ fn test_cast_function() -> Result<()> {
         Test {
             name: "cast-int64-to-int8-passed",
             display: "CAST",
-            nullable: false,
+            nullable: true,
             columns: vec![Series::new(vec![4i64, 3, 2, 4]).into()],
             column_types: vec![DataType::Int64],
             func: CastFunction::create("toint8".to_string(), DataType::Int8),
@@ -45,7 +45,7 @@ fn test_cast_function() -> Result<()> {
         Test {
             name: "cast-string-to-int8-passed",
             display: "CAST",
-            nullable: false,
+            nullable: true,
             columns: vec![Series::new(vec!["4", "3", "2", "4"]).into()],
             column_types: vec![DataType::String],
             func: CastFunction::create("toint8".to_string(), DataType::Int8),
@@ -55,7 +55,7 @@ fn test_cast_function() -> Result<()> {
         Test {
             name: "cast-string-to-int16-passed",
             display: "CAST",
-            nullable: false,
+            nullable: true,
             columns: vec![Series::new(vec!["4", "3", "2", "4"]).into()],
             column_types: vec![DataType::String],
             func: CastFunction::create("toint16".to_string(), DataType::Int16),
@@ -65,7 +65,7 @@ fn test_cast_function() -> Result<()> {
         Test {
             name: "cast-string-to-int32-passed",
             display: "CAST",
-            nullable: false,
+            nullable: true,
             columns: vec![Series::new(vec!["4", "3", "2", "4"]).into()],
             column_types: vec![DataType::String],
             func: CastFunction::create("toint32".to_string(), DataType::Int32),
@@ -75,7 +75,7 @@ fn test_cast_function() -> Result<()> {
         Test {
             name: "cast-string-to-int64-passed",
             display: "CAST",
-            nullable: false,
+            nullable: true,
             columns: vec![Series::new(vec!["4", "3", "2", "4"]).into()],
             column_types: vec![DataType::String],
             func: CastFunction::create("toint64".to_string(), DataType::Int64),
@@ -112,6 +112,22 @@ fn test_cast_function() -> Result<()> {
             expect: Series::new(vec![1614906061u32, 1635070210]),
             error: "",
         },
+        Test {
+            name: "cast-string-to-datetime32-fraction-offset-passed",
+            display: "CAST",
+            nullable: false,
+            columns: vec![Series::new(vec![
+                "2021-03-05 01:01:01.999",
+                "2021-03-05 09:01:01+08:00",
+                "2021-03-05",
+                "1969-12-31 23:59:59",
+            ])
+            .into()],
+            column_types: vec![DataType::String],
+            func: CastFunction::create("cast".to_string(), DataType::DateTime32(None)),
+            expect: Series::new(vec![Some(1614906061u32), Some(1614906061), None, None]),
+            error: "",
+        },
         Test {
             name: "cast-date32-to-string-passed",
             display: "CAST",
@@ -167,3 +183,208 @@ fn test_cast_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_cast_function_modes() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        column: DataColumnWithField,
+        cast_type: DataType,
+        safe_expect: DataColumn,
+        strict_error: Option<&'static str>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "narrowing-overflow",
+            column: DataColumnWithField::new(
+                Series::new(vec![1i64, 300, 255]).into(),
+                DataField::new("a", DataType::Int64, false),
+            ),
+            cast_type: DataType::UInt8,
+            safe_expect: Series::new(vec![Some(1u8), None, Some(255u8)]).into(),
+            strict_error: Some(
+                "Code: 10, displayText = Cannot cast value 300 of type Int64 to UInt8.",
+            ),
+        },
+        Test {
+            name: "string-to-int-parse-failure",
+            column: DataColumnWithField::new(
+                Series::new(vec![Some("1"), Some("abc"), None]).into(),
+                DataField::new("a", DataType::String, true),
+            ),
+            cast_type: DataType::Int32,
+            safe_expect: Series::new(vec![Some(1i32), None, None]).into(),
+            strict_error: Some(
+                "Code: 10, displayText = Cannot cast value abc of type String to Int32.",
+            ),
+        },
+        Test {
+            name: "date-to-string",
+            column: DataColumnWithField::new(
+                Series::new(vec![18893u16, 0]).into(),
+                DataField::new("a", DataType::Date16, false),
+            ),
+            cast_type: DataType::String,
+            safe_expect: Series::new(vec!["2021-09-23", "1970-01-01"]).into(),
+            strict_error: None,
+        },
+        Test {
+            name: "date16-to-datetime32-overflow",
+            column: DataColumnWithField::new(
+                Series::new(vec![0u16, 18893, 65535]).into(),
+                DataField::new("a", DataType::Date16, false),
+            ),
+            cast_type: DataType::DateTime32(None),
+            safe_expect: Series::new(vec![Some(0u32), Some(1632355200), None]).into(),
+            strict_error: Some(
+                "Code: 10, displayText = Cannot cast value 65535 of type Date16 to DateTime32.",
+            ),
+        },
+        Test {
+            name: "date32-before-epoch-to-datetime32",
+            column: DataColumnWithField::new(
+                Series::new(vec![-1i32, 18893]).into(),
+                DataField::new("a", DataType::Date32, false),
+            ),
+            cast_type: DataType::DateTime32(None),
+            safe_expect: Series::new(vec![None, Some(1632355200u32)]).into(),
+            strict_error: Some(
+                "Code: 10, displayText = Cannot cast value -1 of type Date32 to DateTime32.",
+            ),
+        },
+        Test {
+            name: "string-to-datetime32-parse-failure",
+            column: DataColumnWithField::new(
+                Series::new(vec![Some("2021-09-23 10:20:30.5+08:00"), Some("2021-09-23 25:00:00"), None]).into(),
+                DataField::new("a", DataType::String, true),
+            ),
+            cast_type: DataType::DateTime32(None),
+            safe_expect: Series::new(vec![Some(1632363630u32), None, None]).into(),
+            strict_error: Some(
+                "Code: 10, displayText = Cannot cast value 2021-09-23 25:00:00 of type String to DateTime32.",
+            ),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.column.column().len();
+        let columns = vec![t.column];
+
+        let safe = CastFunction::create("cast".to_string(), t.cast_type.clone())?;
+        let v = safe.eval(&columns, rows)?;
+        assert_eq!(&v, &t.safe_expect, "case: {}", t.name);
+
+        let strict = CastFunction::create_strict("cast".to_string(), t.cast_type.clone())?;
+        match (strict.eval(&columns, rows), t.strict_error) {
+            (Ok(v), None) => assert_eq!(&v, &t.safe_expect, "case: {}", t.name),
+            (Err(e), Some(error)) => assert_eq!(error, e.to_string(), "case: {}", t.name),
+            (res, error) => panic!("case: {}, expect {:?}, but got {:?}", t.name, error, res),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_cast_function_narrowing() -> Result<()> {
+    let tests = vec![
+        (DataType::Int64, DataType::Int8, true),
+        (DataType::Int8, DataType::Int64, false),
+        (DataType::Int8, DataType::UInt64, true),
+        (DataType::UInt32, DataType::Int32, true),
+        (DataType::UInt32, DataType::Int64, false),
+        (DataType::Float64, DataType::Int64, true),
+        (DataType::Float64, DataType::Float32, true),
+        (DataType::Int64, DataType::Float64, false),
+        (DataType::Int32, DataType::Int32, false),
+        (DataType::String, DataType::Int8, false),
+    ];
+
+    for (from, to, expect) in tests {
+        let actual = CastFunction::is_narrowing_numeric_cast(&from, &to);
+        assert_eq!(expect, actual, "cast from {} to {}", from, to);
+    }
+
+    // A safe cast to a numeric type may return null, a strict one fails instead.
+    let safe = CastFunction::create("toInt8".to_string(), DataType::Int8)?;
+    assert!(safe.nullable(&DataSchema::empty())?);
+    let strict = CastFunction::create_strict("toInt8Strict".to_string(), DataType::Int8)?;
+    assert!(!strict.nullable(&DataSchema::empty())?);
+    Ok(())
+}
+
+#[test]
+fn test_to_string_function() -> Result<()> {
+    let tests = vec![
+        (
+            "int8",
+            DataType::Int8,
+            Series::new(vec![Some(-3i8), None]),
+            vec![Some("-3"), None],
+        ),
+        (
+            "uint64",
+            DataType::UInt64,
+            Series::new(vec![Some(u64::MAX), Some(0)]),
+            vec![Some("18446744073709551615"), Some("0")],
+        ),
+        (
+            "float32",
+            DataType::Float32,
+            Series::new(vec![Some(1e20f32), Some(0.5), None]),
+            vec![Some("100000000000000000000"), Some("0.5"), None],
+        ),
+        (
+            "float64",
+            DataType::Float64,
+            Series::new(vec![1e-7f64, 2.0, -1.25]),
+            vec![Some("0.0000001"), Some("2"), Some("-1.25")],
+        ),
+        (
+            "boolean",
+            DataType::Boolean,
+            Series::new(vec![Some(true), Some(false), None]),
+            vec![Some("true"), Some("false"), None],
+        ),
+        (
+            "date16",
+            DataType::Date16,
+            Series::new(vec![Some(18893u16), None]),
+            vec![Some("2021-09-23"), None],
+        ),
+        (
+            "date32",
+            DataType::Date32,
+            Series::new(vec![-1i32, 18893]),
+            vec![Some("1969-12-31"), Some("2021-09-23")],
+        ),
+        (
+            "datetime32",
+            DataType::DateTime32(None),
+            Series::new(vec![1632397739u32]),
+            vec![Some("2021-09-23 11:48:59")],
+        ),
+        (
+            "string",
+            DataType::String,
+            Series::new(vec![Some("a"), None]),
+            vec![Some("a"), None],
+        ),
+    ];
+
+    let func = CastFunction::create("toString".to_string(), DataType::String)?;
+    for (name, data_type, series, expect) in tests {
+        let rows = series.len();
+        let columns = vec![DataColumnWithField::new(
+            series.into(),
+            DataField::new("a", data_type, true),
+        )];
+
+        let v = func.eval(&columns, rows)?;
+        let expect: DataColumn = Series::new(expect).into();
+        assert_eq!(&v, &expect, "case: {}", name);
+    }
+
+    Ok(())
+}
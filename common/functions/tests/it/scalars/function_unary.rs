@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_eval_unary_numeric() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        column: DataColumn,
+        expect: DataColumn,
+        expect_constant: bool,
+    }
+
+    let tests = vec![
+        Test {
+            name: "array without nulls",
+            column: Series::new(vec![1u32, 2, 3]).into(),
+            expect: Series::new(vec![2u64, 4, 6]).into(),
+            expect_constant: false,
+        },
+        Test {
+            name: "array with nulls",
+            column: Series::new(vec![Some(1u32), None, Some(3)]).into(),
+            expect: Series::new(vec![Some(2u64), None, Some(6)]).into(),
+            expect_constant: false,
+        },
+        Test {
+            name: "constant",
+            column: DataColumn::Constant(DataValue::UInt32(Some(2)), 3),
+            expect: DataColumn::Constant(DataValue::UInt64(Some(4)), 3),
+            expect_constant: true,
+        },
+        Test {
+            name: "constant null",
+            column: DataColumn::Constant(DataValue::UInt32(None), 3),
+            expect: DataColumn::Constant(DataValue::UInt64(None), 3),
+            expect_constant: true,
+        },
+        Test {
+            name: "null",
+            column: DataColumn::Constant(DataValue::Null, 3),
+            expect: DataColumn::Constant(DataValue::UInt64(None), 3),
+            expect_constant: true,
+        },
+    ];
+
+    for t in tests {
+        let got = eval_unary_numeric(&t.column, 3, |v: u32| v as u64 * 2)?;
+        assert_eq!(&got, &t.expect, "case: {}", t.name);
+        assert_eq!(
+            t.expect_constant,
+            matches!(got, DataColumn::Constant(_, _)),
+            "case: {}",
+            t.name
+        );
+    }
+
+    // The type of the column must match the type of the closure.
+    let column: DataColumn = Series::new(vec![1u8, 2, 3]).into();
+    let got = eval_unary_numeric(&column, 3, |v: u32| v as u64);
+    assert_eq!(
+        "Code: 7, displayText = Expected UInt32, but got UInt8.",
+        got.unwrap_err().to_string()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_eval_unary_string() -> Result<()> {
+    let column: DataColumn = Series::new(vec![Some("a"), None, Some("abc")]).into();
+    let got = eval_unary_string(&column, 3, |v| v.len() as u64)?;
+    let expect: DataColumn = Series::new(vec![Some(1u64), None, Some(3)]).into();
+    assert_eq!(&got, &expect);
+
+    let column = DataColumn::Constant(DataValue::String(None), 3);
+    let got = eval_unary_string(&column, 3, |v| v.len() as u64)?;
+    assert_eq!(&got, &DataColumn::Constant(DataValue::UInt64(None), 3));
+
+    Ok(())
+}
+
+#[test]
+fn test_unary_functions_keep_nulls() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        arg: DataColumnWithField,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "toYYYYMM",
+            func: ToYYYYMMFunction::try_create("toYYYYMM")?,
+            arg: DataColumnWithField::new(
+                Series::new(vec![Some(0u16), None]).into(),
+                DataField::new("a", DataType::Date16, true),
+            ),
+            expect: Series::new(vec![Some(197001u32), None]).into(),
+        },
+        Test {
+            name: "toYYYYMM constant null",
+            func: ToYYYYMMFunction::try_create("toYYYYMM")?,
+            arg: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::UInt16(None), 2),
+                DataField::new("a", DataType::Date16, true),
+            ),
+            expect: DataColumn::Constant(DataValue::UInt32(None), 2),
+        },
+        Test {
+            name: "siphash",
+            func: SipHashFunction::try_create("siphash")?,
+            arg: DataColumnWithField::new(
+                Series::new(vec![Some(1i8), None]).into(),
+                DataField::new("a", DataType::Int8, true),
+            ),
+            expect: Series::new(vec![Some(4952851536318644461u64), None]).into(),
+        },
+        Test {
+            name: "xxhash64",
+            func: XxHash64Function::try_create("xxhash64")?,
+            arg: DataColumnWithField::new(
+                Series::new(vec![None, Some("testing")]).into(),
+                DataField::new("a", DataType::String, true),
+            ),
+            expect: Series::new(vec![None, Some(5654940910216186247u64)]).into(),
+        },
+    ];
+
+    for t in tests {
+        let schema = DataSchemaRefExt::create(vec![t.arg.field().clone()]);
+        assert!(t.func.nullable(&schema)?, "case: {}", t.name);
+
+        let got = t.func.eval(&[t.arg], 2)?;
+        assert_eq!(&got, &t.expect, "case: {}", t.name);
+    }
+
+    Ok(())
+}
@@ -14,8 +14,12 @@
 
 use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::Blake3HashFunction;
+use common_functions::scalars::Function;
+use common_functions::scalars::IntHash32Function;
+use common_functions::scalars::IntHash64Function;
 use common_functions::scalars::Md5HashFunction;
 use common_functions::scalars::Sha1HashFunction;
 use common_functions::scalars::Sha2HashFunction;
@@ -600,6 +604,59 @@ fn test_xxhash64_function() -> Result<()> {
                 None,
             ]))),
         },
+        Test {
+            name: "constant string",
+            arg: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::String(Some(b"1234567890".to_vec())), 1),
+                DataField::new("arg1", DataType::String, true),
+            ),
+            expect: Ok(DataColumn::Constant(
+                DataValue::UInt64(Some(12237639266330420150u64)),
+                1,
+            )),
+        },
+        Test {
+            name: "int32 input",
+            arg: DataColumnWithField::new(
+                Series::new([Some(1i32), Some(-1), None]).into(),
+                DataField::new("arg1", DataType::Int32, true),
+            ),
+            expect: Ok(DataColumn::Array(Series::new(vec![
+                Some(17595444997414146897u64),
+                Some(9185342943168159635),
+                None,
+            ]))),
+        },
+        Test {
+            name: "int8 input",
+            arg: DataColumnWithField::new(
+                Series::new([-1i8]).into(),
+                DataField::new("arg1", DataType::Int8, true),
+            ),
+            expect: Ok(DataColumn::Array(Series::new(vec![
+                10764519495013463364u64,
+            ]))),
+        },
+        Test {
+            name: "uint64 input",
+            arg: DataColumnWithField::new(
+                Series::new([1u64]).into(),
+                DataField::new("arg1", DataType::UInt64, true),
+            ),
+            expect: Ok(DataColumn::Array(Series::new(vec![
+                11468921228449061269u64,
+            ]))),
+        },
+        Test {
+            name: "float input",
+            arg: DataColumnWithField::new(
+                Series::new([1.5f64]).into(),
+                DataField::new("arg1", DataType::Float64, true),
+            ),
+            expect: Err(ErrorCode::IllegalDataType(
+                "Expected integer or string or null, but got Float64",
+            )),
+        },
     ];
 
     let func = XxHash64Function::try_create("xxhash64")?;
@@ -617,3 +674,72 @@ fn test_xxhash64_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_int_hash_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        arg: DataColumnWithField,
+        expect: DataColumn,
+    }
+    let tests = vec![
+        Test {
+            name: "int_hash32 of unsigned",
+            func: IntHash32Function::try_create("intHash32")?,
+            arg: DataColumnWithField::new(
+                Series::new([Some(0u64), Some(1), Some(42), None]).into(),
+                DataField::new("arg1", DataType::UInt64, true),
+            ),
+            expect: Series::new([Some(0u32), Some(3788511810), Some(3458605522), None]).into(),
+        },
+        Test {
+            name: "int_hash32 of signed",
+            func: IntHash32Function::try_create("intHash32")?,
+            arg: DataColumnWithField::new(
+                Series::new([-1i8, 1]).into(),
+                DataField::new("arg1", DataType::Int8, false),
+            ),
+            expect: Series::new([2504521962u32, 3788511810]).into(),
+        },
+        Test {
+            name: "int_hash64 of unsigned",
+            func: IntHash64Function::try_create("intHash64")?,
+            arg: DataColumnWithField::new(
+                Series::new([Some(0u8), Some(1), Some(255), None]).into(),
+                DataField::new("arg1", DataType::UInt8, true),
+            ),
+            expect: Series::new([
+                Some(0u64),
+                Some(12994781566227106604),
+                Some(1297215527019907880),
+                None,
+            ])
+            .into(),
+        },
+        Test {
+            name: "int_hash64 of constant",
+            func: IntHash64Function::try_create("intHash64")?,
+            arg: DataColumnWithField::new(
+                DataColumn::Constant(DataValue::Int32(Some(-1)), 4),
+                DataField::new("arg1", DataType::Int32, false),
+            ),
+            expect: DataColumn::Constant(DataValue::UInt64(Some(7256831767414464289)), 4),
+        },
+    ];
+
+    for t in tests {
+        let rows = t.arg.column().len();
+        t.func.return_type(&[t.arg.data_type().clone()])?;
+        let got = t.func.eval(&[t.arg], rows)?;
+        assert_eq!(&got, &t.expect, "case: {}", t.name);
+    }
+
+    let func = IntHash64Function::try_create("intHash64")?;
+    let err = func.return_type(&[DataType::String]).unwrap_err();
+    assert_eq!(
+        "Code: 7, displayText = Expected integer or null, but got String.",
+        err.to_string()
+    );
+    Ok(())
+}
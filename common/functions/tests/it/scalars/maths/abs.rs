@@ -44,6 +44,52 @@ fn test_abs_function() -> Result<()> {
             ),
             expect: Ok(Series::new(vec![10086_u32]).into()),
         },
+        Test {
+            name: "abs(-128)",
+            func: AbsFunction::try_create("abs(-128)")?,
+            arg: DataColumnWithField::new(
+                Series::new([i8::MIN]).into(),
+                DataField::new("arg1", DataType::Int8, false),
+            ),
+            // The result is unsigned, so the minimum value does not overflow.
+            expect: Ok(Series::new(vec![128_u8]).into()),
+        },
+        Test {
+            name: "abs(i64::MIN)",
+            func: AbsFunction::try_create("abs(i64::MIN)")?,
+            arg: DataColumnWithField::new(
+                Series::new([i64::MIN]).into(),
+                DataField::new("arg1", DataType::Int64, false),
+            ),
+            expect: Ok(Series::new(vec![i64::MIN.unsigned_abs()]).into()),
+        },
+        Test {
+            name: "abs(0)",
+            func: AbsFunction::try_create("abs(0)")?,
+            arg: DataColumnWithField::new(
+                Series::new([0_i64, 1, -1]).into(),
+                DataField::new("arg1", DataType::Int64, false),
+            ),
+            expect: Ok(Series::new(vec![0_u64, 1, 1]).into()),
+        },
+        Test {
+            name: "abs(null)",
+            func: AbsFunction::try_create("abs(null)")?,
+            arg: DataColumnWithField::new(
+                Series::new([Some(-3_i16), None]).into(),
+                DataField::new("arg1", DataType::Int16, true),
+            ),
+            expect: Ok(Series::new(vec![Some(3_u16), None]).into()),
+        },
+        Test {
+            name: "abs(-1.5)",
+            func: AbsFunction::try_create("abs(-1.5)")?,
+            arg: DataColumnWithField::new(
+                Series::new([-1.5_f32]).into(),
+                DataField::new("arg1", DataType::Float32, false),
+            ),
+            expect: Ok(Series::new(vec![1.5_f32]).into()),
+        },
         Test {
             name: "abs('-2.0')",
             func: AbsFunction::try_create("abs('-2.0')")?,
@@ -82,3 +128,17 @@ fn test_abs_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_abs_function_constant() -> Result<()> {
+    let func = AbsFunction::try_create("abs")?;
+    let arg = DataColumnWithField::new(
+        DataColumn::Constant(DataValue::Int16(Some(-7)), 3),
+        DataField::new("arg1", DataType::Int16, false),
+    );
+
+    let result = func.eval(&[arg], 3)?;
+    assert!(matches!(result, DataColumn::Constant(_, 3)));
+    assert_eq!(&result, &Series::new(vec![7_u16, 7, 7]).into());
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::*;
 
@@ -379,6 +380,96 @@ fn test_round_number_function() -> Result<()> {
             expect: Series::new([Some(12346.0), None]).into(),
             error: "",
         },
+        Test {
+            name: "halfway rounds away from zero",
+            display: "round",
+            args: vec![DataColumnWithField::new(
+                Series::new([2.5, -2.5, 0.5, 1.5]).into(),
+                DataField::new("x", DataType::Float64, false),
+            )],
+            input_rows: 4,
+            expect: Series::new([3.0, -3.0, 1.0, 2.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "halfway with decimal places",
+            display: "round",
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([0.125, -0.125]).into(),
+                    DataField::new("x", DataType::Float64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(Some(2)), 1),
+                    DataField::new("d", DataType::Int64, false),
+                ),
+            ],
+            input_rows: 2,
+            expect: Series::new([0.13, -0.13]).into(),
+            error: "",
+        },
+        Test {
+            name: "negative x",
+            display: "round",
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([-12345.6789, -12345.6789]).into(),
+                    DataField::new("x", DataType::Float64, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([2, -2]).into(),
+                    DataField::new("d", DataType::Int64, false),
+                ),
+            ],
+            input_rows: 2,
+            expect: Series::new([-12345.68, -12300.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "integer x with negative second arg",
+            display: "round",
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([1250i64, -1250, 1234]).into(),
+                    DataField::new("x", DataType::Int64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(Some(-2)), 1),
+                    DataField::new("d", DataType::Int64, false),
+                ),
+            ],
+            input_rows: 3,
+            expect: Series::new([1300.0, -1300.0, 1200.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "large x with many decimal places",
+            display: "round",
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([1e300, -1e300, 0.5]).into(),
+                    DataField::new("x", DataType::Float64, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::Int64(Some(30)), 1),
+                    DataField::new("d", DataType::Int64, false),
+                ),
+            ],
+            input_rows: 3,
+            expect: Series::new([1e300, -1e300, 0.5]).into(),
+            error: "",
+        },
+        Test {
+            name: "integer x without second arg",
+            display: "round",
+            args: vec![DataColumnWithField::new(
+                Series::new([7i32, -7]).into(),
+                DataField::new("x", DataType::Int32, false),
+            )],
+            input_rows: 2,
+            expect: Series::new([7.0, -7.0]).into(),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -516,6 +607,23 @@ fn test_trunc_number_function() -> Result<()> {
             expect: Series::new([None, None, None, Some(22.0), Some(30.0), Some(44.4)]).into(),
             error: "",
         },
+        Test {
+            name: "negative x rounds toward zero",
+            display: "trunc",
+            args: vec![
+                DataColumnWithField::new(
+                    Series::new([-11.19, -11.19, -2.7]).into(),
+                    DataField::new("x", DataType::Float64, false),
+                ),
+                DataColumnWithField::new(
+                    Series::new([1, -1, 0]).into(),
+                    DataField::new("d", DataType::Int64, false),
+                ),
+            ],
+            input_rows: 3,
+            expect: Series::new([-11.1, -10.0, -2.0]).into(),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -531,3 +639,31 @@ fn test_trunc_number_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_rounding_function_return_type() -> Result<()> {
+    let round = RoundNumberFunction::try_create("round")?;
+    let trunc = TruncNumberFunction::try_create("truncate")?;
+
+    assert_eq!(round.return_type(&[DataType::Int32])?, DataType::Float64);
+    assert_eq!(
+        round.return_type(&[DataType::Float32, DataType::Int8])?,
+        DataType::Float64
+    );
+    assert_eq!(
+        trunc.return_type(&[DataType::Float64, DataType::Null])?,
+        DataType::Float64
+    );
+
+    let expect = ErrorCode::IllegalDataType("Expected numeric types, but got Boolean");
+    let got = round.return_type(&[DataType::Boolean]).unwrap_err();
+    assert_eq!(got.to_string(), expect.to_string());
+
+    let expect =
+        ErrorCode::IllegalDataType("Expected integer types for decimal places, but got Float64");
+    let got = trunc
+        .return_type(&[DataType::Float64, DataType::Float64])
+        .unwrap_err();
+    assert_eq!(got.to_string(), expect.to_string());
+    Ok(())
+}
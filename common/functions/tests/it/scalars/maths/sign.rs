@@ -96,6 +96,20 @@ fn test_sign_function() -> Result<()> {
             expect: Series::new([1_i8]).into(),
             error: "",
         },
+        Test {
+            name: "signed minimum",
+            display: "SIGN",
+            columns: Series::new([i8::MIN, i8::MAX]).into(),
+            expect: Series::new([-1_i8, 1]).into(),
+            error: "",
+        },
+        Test {
+            name: "i64 minimum",
+            display: "SIGN",
+            columns: Series::new([i64::MIN, 0, i64::MAX]).into(),
+            expect: Series::new([-1_i8, 0, 1]).into(),
+            error: "",
+        },
         Test {
             name: "u8",
             display: "SIGN",
@@ -162,3 +176,17 @@ fn test_sign_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_sign_function_constant() -> Result<()> {
+    let func = SignFunction::try_create("sign")?;
+    let columns = vec![DataColumnWithField::new(
+        DataColumn::Constant(DataValue::Int32(Some(-5)), 4),
+        DataField::new("dummpy", DataType::Int32, false),
+    )];
+
+    let result = func.eval(&columns, 4)?;
+    assert!(matches!(result, DataColumn::Constant(_, 4)));
+    assert_eq!(&result, &Series::new(vec![-1_i8, -1, -1, -1]).into());
+    Ok(())
+}
@@ -13,11 +13,13 @@
 // limitations under the License.
 
 mod arithmetics;
+mod bits;
 mod comparisons;
 mod conditionals;
 mod dates;
 mod expressions;
 mod function_column;
+mod function_unary;
 mod hashes;
 mod logics;
 mod maths;
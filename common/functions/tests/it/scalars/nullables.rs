@@ -91,3 +91,455 @@ fn test_nullable_function() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_coalesce_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "coalesce-all-null",
+            columns: vec![
+                DataColumn::Constant(DataValue::Null, 3),
+                DataColumn::Constant(DataValue::Null, 3),
+            ],
+            expect: DataColumn::Constant(DataValue::Null, 3),
+        },
+        Test {
+            name: "coalesce-all-null-rows",
+            columns: vec![
+                Series::new(vec![Some(1i32), None, None]).into(),
+                Series::new(vec![Some(2i32), None, None]).into(),
+            ],
+            expect: Series::new(vec![Some(1i32), None, None]).into(),
+        },
+        Test {
+            name: "coalesce-first-non-null",
+            columns: vec![
+                Series::new(vec![Some(1i32), None, None]).into(),
+                Series::new(vec![Some(2i32), Some(3i32), None]).into(),
+                Series::new(vec![Some(4i32), Some(5i32), Some(6i32)]).into(),
+            ],
+            expect: Series::new(vec![1i32, 3i32, 6i32]).into(),
+        },
+        Test {
+            name: "coalesce-unified-type",
+            columns: vec![
+                Series::new(vec![Some(1i8), None, None]).into(),
+                Series::new(vec![None, Some(300i16), None]).into(),
+                Series::new(vec![Some(7i64), Some(8i64), Some(70000i64)]).into(),
+            ],
+            expect: Series::new(vec![1i64, 300i64, 70000i64]).into(),
+        },
+        Test {
+            name: "coalesce-constant-first",
+            columns: vec![
+                DataColumn::Constant(DataValue::Int32(Some(5)), 3),
+                Series::new(vec![Some(1i32), None, Some(3i32)]).into(),
+            ],
+            expect: DataColumn::Constant(DataValue::Int32(Some(5)), 3),
+        },
+        Test {
+            name: "coalesce-short-circuit",
+            columns: vec![
+                Series::new(vec![1i64, 2i64, 3i64]).into(),
+                Series::new(vec![Some(4i64), None, Some(6i64)]).into(),
+            ],
+            expect: Series::new(vec![1i64, 2i64, 3i64]).into(),
+        },
+        Test {
+            name: "coalesce-constant-fallback",
+            columns: vec![
+                Series::new(vec![None, Some(2u8), None]).into(),
+                DataColumn::Constant(DataValue::UInt8(Some(0)), 3),
+            ],
+            expect: Series::new(vec![0u8, 2u8, 0u8]).into(),
+        },
+    ];
+
+    let func = CoalesceFunction::try_create("coalesce")?;
+    for t in tests {
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let args: Vec<DataType> = t.columns.iter().map(|c| c.data_type()).collect();
+        let v = func.eval(&columns, 3)?;
+        assert_eq!(func.return_type(&args)?, v.data_type(), "{}", t.name);
+        assert_eq!(
+            matches!(v, DataColumn::Constant(..)),
+            matches!(t.expect, DataColumn::Constant(..)),
+            "{}",
+            t.name
+        );
+        assert_eq!(&v, &t.expect, "{}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_if_null_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "if-null-constant-default",
+            columns: vec![
+                Series::new(vec![Some(1i32), None, Some(3i32)]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(0)), 3),
+            ],
+            expect: Series::new(vec![1i32, 0i32, 3i32]).into(),
+        },
+        Test {
+            name: "if-null-both-null",
+            columns: vec![
+                Series::new(vec![None, None, Some(3i32)]).into(),
+                Series::new(vec![Some(4i32), None, Some(6i32)]).into(),
+            ],
+            expect: Series::new(vec![Some(4i32), None, Some(3i32)]).into(),
+        },
+        Test {
+            name: "if-null-unified-type",
+            columns: vec![
+                Series::new(vec![Some(1i8), None, None]).into(),
+                Series::new(vec![7i64, 70000i64, -70000i64]).into(),
+            ],
+            expect: Series::new(vec![1i64, 70000i64, -70000i64]).into(),
+        },
+        Test {
+            name: "if-null-no-null",
+            columns: vec![
+                DataColumn::Constant(DataValue::Int64(Some(5)), 3),
+                Series::new(vec![Some(1i64), None, Some(3i64)]).into(),
+            ],
+            expect: DataColumn::Constant(DataValue::Int64(Some(5)), 3),
+        },
+        Test {
+            name: "if-null-null-value",
+            columns: vec![
+                DataColumn::Constant(DataValue::Null, 3),
+                Series::new(vec![Some(1u8), None, Some(3u8)]).into(),
+            ],
+            expect: Series::new(vec![Some(1u8), None, Some(3u8)]).into(),
+        },
+        Test {
+            name: "if-null-all-null",
+            columns: vec![
+                DataColumn::Constant(DataValue::Null, 3),
+                DataColumn::Constant(DataValue::Null, 3),
+            ],
+            expect: DataColumn::Constant(DataValue::Null, 3),
+        },
+    ];
+
+    let func = IfNullFunction::try_create("ifNull")?;
+    for t in tests {
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let args: Vec<DataType> = t.columns.iter().map(|c| c.data_type()).collect();
+        let v = func.eval(&columns, 3)?;
+        assert_eq!(func.return_type(&args)?, v.data_type(), "{}", t.name);
+        assert_eq!(
+            matches!(v, DataColumn::Constant(..)),
+            matches!(t.expect, DataColumn::Constant(..)),
+            "{}",
+            t.name
+        );
+        assert_eq!(&v, &t.expect, "{}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_null_if_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+    }
+
+    let tests = vec![
+        Test {
+            name: "null-if-equal",
+            columns: vec![
+                Series::new(vec![1i32, 2i32, 3i32]).into(),
+                Series::new(vec![1i32, 0i32, 3i32]).into(),
+            ],
+            expect: Series::new(vec![None, Some(2i32), None]).into(),
+        },
+        Test {
+            name: "null-if-not-equal",
+            columns: vec![
+                Series::new(vec![1i32, 2i32, 3i32]).into(),
+                DataColumn::Constant(DataValue::Int32(Some(0)), 3),
+            ],
+            expect: Series::new(vec![1i32, 2i32, 3i32]).into(),
+        },
+        Test {
+            name: "null-if-coercion",
+            columns: vec![
+                Series::new(vec![1i32, 2i32, 3i32]).into(),
+                DataColumn::Constant(DataValue::UInt64(Some(2)), 3),
+            ],
+            expect: Series::new(vec![Some(1i32), None, Some(3i32)]).into(),
+        },
+        Test {
+            name: "null-if-nulls",
+            columns: vec![
+                Series::new(vec![Some(1i32), None, Some(3i32)]).into(),
+                Series::new(vec![None, None, Some(3i32)]).into(),
+            ],
+            expect: Series::new(vec![Some(1i32), None, None]).into(),
+        },
+        Test {
+            name: "null-if-null-rhs",
+            columns: vec![
+                Series::new(vec![1i32, 2i32, 3i32]).into(),
+                DataColumn::Constant(DataValue::Null, 3),
+            ],
+            expect: Series::new(vec![1i32, 2i32, 3i32]).into(),
+        },
+    ];
+
+    let func = NullIfFunction::try_create("nullIf")?;
+    for t in tests {
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let args: Vec<DataType> = t.columns.iter().map(|c| c.data_type()).collect();
+        let v = func.eval(&columns, 3)?;
+        assert_eq!(func.return_type(&args)?, v.data_type(), "{}", t.name);
+        assert_eq!(&v, &t.expect, "{}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_null_if_function_constant() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        columns: Vec<DataColumn>,
+        expect: DataValue,
+    }
+
+    let tests = vec![
+        Test {
+            name: "null-if-constant-equal",
+            columns: vec![
+                DataColumn::Constant(DataValue::Int32(Some(1)), 3),
+                DataColumn::Constant(DataValue::UInt64(Some(1)), 3),
+            ],
+            expect: DataValue::Int32(None),
+        },
+        Test {
+            name: "null-if-constant-not-equal",
+            columns: vec![
+                DataColumn::Constant(DataValue::Int32(Some(1)), 3),
+                DataColumn::Constant(DataValue::Int32(Some(2)), 3),
+            ],
+            expect: DataValue::Int32(Some(1)),
+        },
+        Test {
+            name: "null-if-constant-null-rhs",
+            columns: vec![
+                DataColumn::Constant(DataValue::Int32(Some(1)), 3),
+                DataColumn::Constant(DataValue::Null, 3),
+            ],
+            expect: DataValue::Int32(Some(1)),
+        },
+    ];
+
+    // The constant arguments give a constant result, it is not expanded to the rows.
+    let func = NullIfFunction::try_create("nullIf")?;
+    for t in tests {
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .map(|c| DataColumnWithField::new(c.clone(), DataField::new("a", c.data_type(), true)))
+            .collect();
+
+        let v = func.eval(&columns, 3)?;
+        match v {
+            DataColumn::Constant(value, 3) => assert_eq!(value, t.expect, "{}", t.name),
+            v => panic!("{}: expect a constant, but got {:?}", t.name, v),
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_to_nullable_and_assume_not_null_functions() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+
+    let to_nullable = ToNullableFunction::try_create("toNullable")?;
+    let assume_not_null = AssumeNotNullFunction::try_create("assumeNotNull")?;
+    assert_eq!("toNullable", format!("{}", to_nullable));
+    assert_eq!("assumeNotNull", format!("{}", assume_not_null));
+
+    // Only the nullable flag is changed, the type and the values are kept.
+    assert!(to_nullable.nullable(&schema)?);
+    assert!(!assume_not_null.nullable(&schema)?);
+    assert_eq!(
+        DataType::Int32,
+        to_nullable.return_type(&[DataType::Int32])?
+    );
+    assert_eq!(
+        DataType::String,
+        assume_not_null.return_type(&[DataType::String])?
+    );
+
+    let columns: Vec<DataColumn> = vec![
+        Series::new(vec![1i32, 2, 3]).into(),
+        Series::new(vec![Some(1i32), None, Some(3)]).into(),
+        DataColumn::Constant(DataValue::Int32(Some(7)), 3),
+    ];
+    for column in columns.iter() {
+        let column = DataColumnWithField::new(
+            column.clone(),
+            DataField::new("a", column.data_type(), true),
+        );
+        let v = to_nullable.eval(&[column.clone()], 3)?;
+        assert_eq!(&v, column.column());
+    }
+
+    for column in [&columns[0], &columns[2]] {
+        let column = DataColumnWithField::new(
+            column.clone(),
+            DataField::new("a", column.data_type(), true),
+        );
+        let v = assume_not_null.eval(&[column.clone()], 3)?;
+        assert_eq!(&v, column.column());
+    }
+
+    // The values buffer is shared with the input, not copied.
+    let column = DataColumnWithField::new(
+        columns[0].clone(),
+        DataField::new("a", DataType::Int32, true),
+    );
+    let v = assume_not_null.eval(&[column.clone()], 3)?;
+    let input = column.column().to_array()?;
+    let output = v.to_array()?;
+    assert_eq!(
+        input.i32()?.inner().values().as_ptr(),
+        output.i32()?.inner().values().as_ptr()
+    );
+
+    // A null is actually present.
+    let nulls = vec![columns[1].clone(), DataColumn::Constant(DataValue::Null, 3)];
+    for column in nulls {
+        let column = DataColumnWithField::new(
+            column.clone(),
+            DataField::new("a", column.data_type(), true),
+        );
+        let err = assume_not_null.eval(&[column], 3).unwrap_err();
+        assert_eq!(
+            "Code: 10, displayText = assumeNotNull expects no null in column a, but got a null.",
+            err.to_string()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_is_null_and_is_not_null_functions() -> Result<()> {
+    let is_null = IsNullFunction::try_create_func("")?;
+    let is_not_null = IsNotNullFunction::try_create_func("")?;
+
+    // Array with interleaved nulls.
+    let column = DataColumnWithField::new(
+        Series::new(vec![None, Some(1i32), None, Some(3i32), None]).into(),
+        DataField::new("a", DataType::Int32, true),
+    );
+    let expect_null = vec![true, false, true, false, true];
+    let result = is_null.eval(&[column.clone()], 5)?;
+    assert_eq!(DataType::Boolean, result.data_type());
+    assert!(result
+        .to_array()?
+        .eq(&Series::new(expect_null.clone()))?
+        .all_true());
+
+    let expect_not_null: Vec<bool> = expect_null.iter().map(|v| !v).collect();
+    let result = is_not_null.eval(&[column], 5)?;
+    assert!(result
+        .to_array()?
+        .eq(&Series::new(expect_not_null))?
+        .all_true());
+
+    // An array without nulls gives a constant result.
+    let column = DataColumnWithField::new(
+        Series::new(vec![1i32, 2, 3]).into(),
+        DataField::new("a", DataType::Int32, false),
+    );
+    let result = is_null.eval(&[column.clone()], 3)?;
+    assert!(
+        matches!(
+            result,
+            DataColumn::Constant(DataValue::Boolean(Some(false)), 3)
+        ),
+        "case: isNull of non-nullable array, got {:?}",
+        result
+    );
+    let result = is_not_null.eval(&[column], 3)?;
+    assert!(
+        matches!(
+            result,
+            DataColumn::Constant(DataValue::Boolean(Some(true)), 3)
+        ),
+        "case: isNotNull of non-nullable array, got {:?}",
+        result
+    );
+
+    // Constants give a constant result.
+    let tests = vec![
+        (DataValue::Null, true),
+        (DataValue::Int32(None), true),
+        (DataValue::Int32(Some(1)), false),
+        (DataValue::String(Some(b"x".to_vec())), false),
+    ];
+    for (value, null) in tests {
+        let column = DataColumnWithField::new(
+            DataColumn::Constant(value.clone(), 3),
+            DataField::new("a", value.data_type(), true),
+        );
+
+        let result = is_null.eval(&[column.clone()], 3)?;
+        assert!(
+            matches!(&result, DataColumn::Constant(DataValue::Boolean(Some(v)), 3) if *v == null),
+            "case: isNull({:?}), got {:?}",
+            value,
+            result
+        );
+
+        let result = is_not_null.eval(&[column], 3)?;
+        assert!(
+            matches!(&result, DataColumn::Constant(DataValue::Boolean(Some(v)), 3) if *v != null),
+            "case: isNotNull({:?}), got {:?}",
+            value,
+            result
+        );
+    }
+
+    Ok(())
+}
@@ -19,6 +19,7 @@ use common_arrow::arrow::array::Int64Array;
 use common_datablocks::*;
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_functions::scalars::ArrayJoinFunction;
 use common_functions::scalars::InetAtonFunction;
 use common_functions::scalars::InetNtoaFunction;
 use common_functions::scalars::RunningDifferenceFunction;
@@ -688,3 +689,20 @@ fn test_inet_ntoa_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_array_join_function() -> Result<()> {
+    let func = ArrayJoinFunction::try_create("arrayJoin")?;
+    assert!(func.expands_rows());
+
+    let list = DataType::List(Box::new(DataField::new("item", DataType::String, true)));
+    assert_eq!(func.return_type(&[list])?, DataType::String);
+
+    let result = func.return_type(&[DataType::String]);
+    assert_eq!(
+        result.unwrap_err().message(),
+        "arrayJoin requires a list argument, but got String"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::AsciiFunction;
+use common_functions::scalars::CharFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_ascii_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::String, true)]);
+
+    let tests = vec![
+        Test {
+            name: "ascii-passed",
+            display: "ascii()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![Some("abc"), Some("1"), Some(""), None]).into()],
+            func: AsciiFunction::try_create("ascii")?,
+            expect: Series::new(vec![Some(97_u32), Some(49), Some(0), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "ascii-multibyte-passed",
+            display: "ascii()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["é", "数据", "😀x"]).into()],
+            func: AsciiFunction::try_create("ascii")?,
+            expect: Series::new(vec![0xe9_u32, 0x6570, 0x1f600]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
+
+#[test]
+fn test_char_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt32, true)]);
+
+    let tests = vec![Test {
+        name: "char-passed",
+        display: "char",
+        nullable: true,
+        arg_names: vec!["a"],
+        columns: vec![Series::new(vec![
+            Some(97_u32),
+            Some(0xe9),
+            Some(0x6570),
+            Some(0x1f600),
+            None,
+        ])
+        .into()],
+        func: CharFunction::try_create("char")?,
+        expect: Series::new(vec![Some("a"), Some("é"), Some("数"), Some("😀"), None]).into(),
+        error: "",
+    }];
+    run_tests(tests, schema)?;
+
+    // Surrogates, numbers beyond 0x10FFFF and negative numbers are not characters.
+    let func = CharFunction::try_create("char")?;
+    let tests = vec![
+        (
+            DataValue::UInt32(Some(0xd800)),
+            "Code: 6, displayText = Invalid code point 55296 of char.",
+        ),
+        (
+            DataValue::UInt32(Some(0x110000)),
+            "Code: 6, displayText = Invalid code point 1114112 of char.",
+        ),
+        (
+            DataValue::Int32(Some(-1)),
+            "Code: 6, displayText = Invalid code point -1 of char.",
+        ),
+    ];
+    for (value, expect) in tests {
+        let columns = vec![DataColumnWithField::new(
+            DataColumn::Constant(value.clone(), 1),
+            DataField::new("a", value.data_type(), false),
+        )];
+        let got = func.eval(&columns, 1);
+        assert_eq!(expect, got.unwrap_err().to_string(), "case: {:?}", value);
+    }
+    Ok(())
+}
@@ -0,0 +1,163 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::Base64DecodeFunction;
+use common_functions::scalars::Base64EncodeFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_base64_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::String, true)]);
+
+    let long = "x".repeat(100);
+    let long_encoded = format!("{}eA==", "eHh4".repeat(33));
+
+    let tests = vec![
+        Test {
+            name: "to_base64-passed",
+            display: "to_base64",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![
+                Series::new(vec![Some("a"), None, Some(""), Some("hello world!")]).into(),
+            ],
+            func: Base64EncodeFunction::try_create("to_base64")?,
+            expect: Series::new(vec![Some("YQ=="), None, Some(""), Some("aGVsbG8gd29ybGQh")])
+                .into(),
+            error: "",
+        },
+        Test {
+            name: "to_base64-longer-than-input-passed",
+            display: "to_base64",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["a", "a", "a", long.as_str()]).into()],
+            func: Base64EncodeFunction::try_create("to_base64")?,
+            expect: Series::new(vec!["YQ==", "YQ==", "YQ==", long_encoded.as_str()]).into(),
+            error: "",
+        },
+        Test {
+            name: "from_base64-passed",
+            display: "from_base64",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![
+                Some("YQ=="),
+                None,
+                Some("!!"),
+                Some(long_encoded.as_str()),
+            ])
+            .into()],
+            func: Base64DecodeFunction::try_create("from_base64", false)?,
+            expect: Series::new(vec![Some("a"), None, None, Some(long.as_str())]).into(),
+            error: "",
+        },
+        Test {
+            name: "base64Decode-passed",
+            display: "base64Decode",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![Some("YQ=="), None, Some("")]).into()],
+            func: Base64DecodeFunction::try_create("base64Decode", true)?,
+            expect: Series::new(vec![Some("a"), None, Some("")]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
+
+#[test]
+fn test_base64_round_trip() -> Result<()> {
+    let long = "x".repeat(1000);
+    let values = vec![
+        Some(""),
+        Some("a"),
+        Some("ab"),
+        Some("abc"),
+        None,
+        Some("Dobrý den"),
+        Some(long.as_str()),
+    ];
+    let rows = values.len();
+    let input: DataColumn = Series::new(values).into();
+
+    let encode = Base64EncodeFunction::try_create("base64Encode")?;
+    let decode = Base64DecodeFunction::try_create("base64Decode", true)?;
+
+    let field = DataField::new("a", DataType::String, true);
+    let encoded = encode.eval(
+        &[DataColumnWithField::new(input.clone(), field.clone())],
+        rows,
+    )?;
+    let decoded = decode.eval(&[DataColumnWithField::new(encoded, field)], rows)?;
+    assert_eq!(&decoded, &input);
+    Ok(())
+}
+
+#[test]
+fn test_base64_decode_invalid() -> Result<()> {
+    let field = DataField::new("a", DataType::String, true);
+    let input = DataColumnWithField::new(
+        Series::new(vec![Some("YQ=="), None, Some("Y"), Some("!!")]).into(),
+        field,
+    );
+
+    let decode = Base64DecodeFunction::try_create("tryBase64Decode", false)?;
+    let got = decode.eval(&[input.clone()], 4)?;
+    let expect: DataColumn = Series::new(vec![Some("a"), None, None, None]).into();
+    assert_eq!(&got, &expect);
+
+    let decode = Base64DecodeFunction::try_create("base64Decode", true)?;
+    let got = decode.eval(&[input], 4);
+    assert_eq!(
+        got.unwrap_err().to_string(),
+        "Code: 6, displayText = Invalid base64 string 'Y' at row 2."
+    );
+    Ok(())
+}
+
+#[test]
+fn test_base64_round_trip_binary() -> Result<()> {
+    let all_bytes: Vec<u8> = (0..=255u8).collect();
+    let values: Vec<Option<&[u8]>> = vec![
+        Some(&[0u8][..]),
+        Some(&[0xff, 0xfe, 0x00][..]),
+        Some(&[0x80, 0x00, 0x7f, 0xc3][..]),
+        None,
+        Some(&all_bytes[..]),
+    ];
+    let rows = values.len();
+    let input: DataColumn = Series::new(values).into();
+
+    let encode = Base64EncodeFunction::try_create("base64Encode")?;
+    let decode = Base64DecodeFunction::try_create("base64Decode", true)?;
+
+    let field = DataField::new("a", DataType::String, true);
+    let encoded = encode.eval(
+        &[DataColumnWithField::new(input.clone(), field.clone())],
+        rows,
+    )?;
+    assert_eq!(
+        encoded.to_array()?.string()?.inner().value(1),
+        b"//4A",
+        "encoded 0xfffe00"
+    );
+
+    let decoded = decode.eval(&[DataColumnWithField::new(encoded, field)], rows)?;
+    assert_eq!(&decoded, &input);
+    Ok(())
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ConcatWsFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_concat_ws_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("sep", DataType::String, true),
+        DataField::new("a", DataType::String, true),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::String, true),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "concat_ws-constant-separator-passed",
+            display: "CONCAT_WS",
+            nullable: true,
+            arg_names: vec!["sep", "a", "b", "c"],
+            columns: vec![
+                DataColumn::Constant(DataValue::String(Some(b", ".to_vec())), 2),
+                Series::new(vec!["a", "d"]).into(),
+                Series::new(vec!["b", ""]).into(),
+                Series::new(vec!["c", "f"]).into(),
+            ],
+            func: ConcatWsFunction::try_create("concat_ws")?,
+            expect: Series::new(vec!["a, b, c", "d, , f"]).into(),
+            error: "",
+        },
+        Test {
+            name: "concat_ws-null-skipped-passed",
+            display: "CONCAT_WS",
+            nullable: true,
+            arg_names: vec!["sep", "a", "b", "c"],
+            columns: vec![
+                DataColumn::Constant(DataValue::String(Some(b",".to_vec())), 4),
+                Series::new(vec![Some("a"), Some("a"), None, None]).into(),
+                Series::new(vec![None, Some("b"), Some("b"), None]).into(),
+                Series::new(vec![Some("c"), None, None, None]).into(),
+            ],
+            func: ConcatWsFunction::try_create("concat_ws")?,
+            expect: Series::new(vec!["a,c", "a,b", "b", ""]).into(),
+            error: "",
+        },
+        Test {
+            name: "concat_ws-array-separator-passed",
+            display: "CONCAT_WS",
+            nullable: true,
+            arg_names: vec!["sep", "a", "b", "c"],
+            columns: vec![
+                Series::new(vec![Some("-"), None, Some("")]).into(),
+                Series::new(vec!["a", "a", "a"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 3),
+                DataColumn::Constant(DataValue::String(None), 3),
+            ],
+            func: ConcatWsFunction::try_create("concat_ws")?,
+            expect: Series::new(vec![Some("a-b"), None, Some("ab")]).into(),
+            error: "",
+        },
+        Test {
+            name: "concat_ws-all-constant-passed",
+            display: "CONCAT_WS",
+            nullable: true,
+            arg_names: vec!["sep", "a", "b", "c"],
+            columns: vec![
+                DataColumn::Constant(DataValue::String(Some(b"|".to_vec())), 2),
+                DataColumn::Constant(DataValue::String(None), 2),
+                DataColumn::Constant(DataValue::String(None), 2),
+                DataColumn::Constant(DataValue::String(None), 2),
+            ],
+            func: ConcatWsFunction::try_create("concat_ws")?,
+            expect: DataColumn::Constant(DataValue::String(Some(vec![])), 2),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::HexFunction;
+use common_functions::scalars::UnhexFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_hex_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::String, true),
+        DataField::new("b", DataType::UInt64, true),
+        DataField::new("c", DataType::Int64, true),
+    ]);
+
+    let long = "a".repeat(100);
+    let long_hex = "61".repeat(100);
+
+    let tests = vec![
+        Test {
+            name: "hex-string-passed",
+            display: "HEX",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![
+                Series::new(vec![Some("abc"), None, Some(""), Some(long.as_str())]).into(),
+            ],
+            func: HexFunction::try_create("hex")?,
+            expect: Series::new(vec![
+                Some("616263"),
+                None,
+                Some(""),
+                Some(long_hex.as_str()),
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "hex-unsigned-passed",
+            display: "HEX",
+            nullable: true,
+            arg_names: vec!["b"],
+            columns: vec![Series::new(vec![0u64, 255, u64::MAX]).into()],
+            func: HexFunction::try_create("hex")?,
+            expect: Series::new(vec!["0", "ff", "ffffffffffffffff"]).into(),
+            error: "",
+        },
+        Test {
+            name: "hex-signed-passed",
+            display: "HEX",
+            nullable: true,
+            arg_names: vec!["c"],
+            columns: vec![Series::new(vec![-255i64, 16]).into()],
+            func: HexFunction::try_create("hex")?,
+            expect: Series::new(vec!["-ff", "10"]).into(),
+            error: "",
+        },
+        Test {
+            name: "hex-constant-passed",
+            display: "HEX",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![DataColumn::Constant(
+                DataValue::String(Some(b"abc".to_vec())),
+                3,
+            )],
+            func: HexFunction::try_create("hex")?,
+            expect: DataColumn::Constant(DataValue::String(Some(b"616263".to_vec())), 3),
+            error: "",
+        },
+        Test {
+            name: "unhex-passed",
+            display: "UNHEX",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![
+                Some("616263"),
+                Some("6A6b"),
+                None,
+                Some(""),
+                Some(long_hex.as_str()),
+            ])
+            .into()],
+            func: UnhexFunction::try_create("unhex")?,
+            expect: Series::new(vec![
+                Some("abc"),
+                Some("jk"),
+                None,
+                Some(""),
+                Some(long.as_str()),
+            ])
+            .into(),
+            error: "",
+        },
+        Test {
+            name: "unhex-invalid-passed",
+            display: "UNHEX",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["a", "616", "zz", "61 62"]).into()],
+            func: UnhexFunction::try_create("unhex")?,
+            expect: Series::new(vec![Option::<&str>::None, None, None, None]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
+
+#[test]
+fn test_hex_round_trip() -> Result<()> {
+    let values: Vec<&[u8]> = vec![b"", b"a", b"\x00\xff\x10", "Dobrý den".as_bytes()];
+    let rows = values.len();
+    let input: DataColumn = Series::new(values).into();
+
+    let hex = HexFunction::try_create("hex")?;
+    let unhex = UnhexFunction::try_create("unhex")?;
+
+    let field = DataField::new("a", DataType::String, true);
+    let encoded = hex.eval(
+        &[DataColumnWithField::new(input.clone(), field.clone())],
+        rows,
+    )?;
+    let decoded = unhex.eval(&[DataColumnWithField::new(encoded, field)], rows)?;
+    assert_eq!(&decoded, &input);
+    Ok(())
+}
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::CharLengthFunction;
+use common_functions::scalars::LengthFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_length_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::String, true)]);
+
+    let tests = vec![
+        Test {
+            name: "length-ascii-passed",
+            display: "length()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["", "word"]).into()],
+            func: LengthFunction::try_create("length")?,
+            expect: Series::new(vec![0_u64, 4_u64]).into(),
+            error: "",
+        },
+        Test {
+            name: "length-multibyte-passed",
+            display: "length()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["кириллица", "你好", "早ab"]).into()],
+            func: LengthFunction::try_create("length")?,
+            expect: Series::new(vec![18_u64, 6_u64, 5_u64]).into(),
+            error: "",
+        },
+        Test {
+            name: "length-null-passed",
+            display: "length()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![Some("abc"), None]).into()],
+            func: LengthFunction::try_create("length")?,
+            expect: Series::new(vec![Some(3_u64), None]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
+
+#[test]
+fn test_char_length_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::String, true)]);
+
+    let tests = vec![
+        Test {
+            name: "char_length-ascii-passed",
+            display: "char_length()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["", "hello world"]).into()],
+            func: CharLengthFunction::try_create("char_length")?,
+            expect: Series::new(vec![0_u64, 11_u64]).into(),
+            error: "",
+        },
+        Test {
+            name: "char_length-multibyte-passed",
+            display: "char_length()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["кириллица", "你好", "早ab"]).into()],
+            func: CharLengthFunction::try_create("char_length")?,
+            expect: Series::new(vec![9_u64, 2_u64, 3_u64]).into(),
+            error: "",
+        },
+        Test {
+            name: "char_length-null-passed",
+            display: "char_length()",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![Some("你好"), None]).into()],
+            func: CharLengthFunction::try_create("char_length")?,
+            expect: Series::new(vec![Some(2_u64), None]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
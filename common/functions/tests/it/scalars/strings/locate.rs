@@ -233,6 +233,175 @@ fn test_locate_function() -> Result<()> {
             expect: Series::new([2_u64, 3_u64]).into(),
             error: "",
         },
+        Test {
+            name: "not found",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("xbar".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("foobar".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(0)), 1),
+            error: "",
+        },
+        Test {
+            name: "found at start",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("foo".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("foobar".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(1)), 1),
+            error: "",
+        },
+        Test {
+            name: "overlapping",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("aa".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("aaaa".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(1)), 1),
+            error: "",
+        },
+        Test {
+            name: "overlapping with start",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("aa".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("aaaa".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt64(Some(2)), 1),
+                    DataField::new("p", DataType::UInt64, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(2)), 1),
+            error: "",
+        },
+        Test {
+            name: "start out of range",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("a".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("aaaa".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt64(Some(6)), 1),
+                    DataField::new("p", DataType::UInt64, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(0)), 1),
+            error: "",
+        },
+        Test {
+            name: "empty needle",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("abc".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt64(Some(4)), 1),
+                    DataField::new("p", DataType::UInt64, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(4)), 1),
+            error: "",
+        },
+        Test {
+            name: "multibyte haystack",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("好".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("你好好".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(2)), 1),
+            error: "",
+        },
+        Test {
+            name: "multibyte haystack with start",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("好".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("你好好".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::UInt64(Some(3)), 1),
+                    DataField::new("p", DataType::UInt64, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(3)), 1),
+            error: "",
+        },
+        Test {
+            name: "multibyte needle and haystack",
+            display: "locate",
+            args: vec![
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("bar".as_bytes().to_vec())), 1),
+                    DataField::new("ss", DataType::String, false),
+                ),
+                DataColumnWithField::new(
+                    DataColumn::Constant(DataValue::String(Some("早早bar".as_bytes().to_vec())), 1),
+                    DataField::new("s", DataType::String, false),
+                ),
+            ],
+            input_rows: 1,
+            expect: DataColumn::Constant(DataValue::UInt64(Some(3)), 1),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -248,3 +417,41 @@ fn test_locate_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_position_function() -> Result<()> {
+    let string = |v: &str| DataColumn::Constant(DataValue::String(Some(v.as_bytes().to_vec())), 4);
+    let column = |name: &str, column: DataColumn, data_type: DataType| {
+        DataColumnWithField::new(column, DataField::new(name, data_type, true))
+    };
+
+    let func = PositionFunction::try_create("position")?;
+    assert_eq!(func.variadic_arguments(), Some((2, 3)));
+    let instr = InstrFunction::try_create("instr")?;
+    assert_eq!(instr.variadic_arguments(), Some((2, 2)));
+
+    // A constant needle searched in a column of haystacks.
+    let haystacks = Series::new(vec![Some("abcabc"), Some("xyz"), None, Some("bcbc")]);
+    let args = vec![
+        column("ss", string("bc"), DataType::String),
+        column("s", haystacks.clone().into(), DataType::String),
+    ];
+    let got = func.eval(&args, 4)?;
+    let expect: DataColumn = Series::new(vec![Some(2u64), Some(0), None, Some(1)]).into();
+    assert_eq!(got.to_values()?, expect.to_values()?);
+
+    // The search starts at the third argument, the result is still counted from the start.
+    let args = vec![
+        column("ss", string("bc"), DataType::String),
+        column("s", haystacks.into(), DataType::String),
+        column(
+            "p",
+            Series::new(vec![Some(3u64), Some(1), Some(1), None]).into(),
+            DataType::UInt64,
+        ),
+    ];
+    let got = func.eval(&args, 4)?;
+    let expect: DataColumn = Series::new(vec![Some(5u64), Some(0), None, None]).into();
+    assert_eq!(got.to_values()?, expect.to_values()?);
+    Ok(())
+}
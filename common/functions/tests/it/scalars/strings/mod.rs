@@ -12,9 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod ascii;
+mod base64;
+mod concat_ws;
+mod hex;
+mod length;
 mod locate;
 mod lower;
+mod regexp_match;
+mod regexp_replace;
+mod repeat;
+mod replace;
+mod soundex;
+mod split;
+mod split_by_char;
+mod string_predicate;
 mod substring;
+mod to_fixed_string;
 mod trim;
 
 mod upper;
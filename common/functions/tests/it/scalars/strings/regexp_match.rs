@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::RegexpMatchFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_regexp_match_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("s", DataType::String, true),
+        DataField::new("p", DataType::String, true),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "regexp-match-unanchored-passed",
+            display: "regexpMatch",
+            nullable: true,
+            arg_names: vec!["s", "p"],
+            columns: vec![
+                Series::new(vec!["abc", "xabcx", "xyz"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"abc".to_vec())), 3),
+            ],
+            func: RegexpMatchFunction::try_create("regexpMatch")?,
+            expect: Series::new(vec![true, true, false]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-match-anchored-passed",
+            display: "regexpMatch",
+            nullable: true,
+            arg_names: vec!["s", "p"],
+            columns: vec![
+                Series::new(vec!["abc", "xabcx", "abcx"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"^abc$".to_vec())), 3),
+            ],
+            func: RegexpMatchFunction::try_create("regexpMatch")?,
+            expect: Series::new(vec![true, false, false]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-match-pattern-array-passed",
+            display: "regexpMatch",
+            nullable: true,
+            arg_names: vec!["s", "p"],
+            columns: vec![
+                Series::new(vec!["abc", "abc", "a1b2"]).into(),
+                Series::new(vec!["^b", "b", "^a\\d+b\\d$"]).into(),
+            ],
+            func: RegexpMatchFunction::try_create("regexpMatch")?,
+            expect: Series::new(vec![false, true, true]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-match-null-passed",
+            display: "regexpMatch",
+            nullable: true,
+            arg_names: vec!["s", "p"],
+            columns: vec![
+                Series::new(vec![Some("abc"), None, Some("abc")]).into(),
+                Series::new(vec![Some("a"), Some("a"), None]).into(),
+            ],
+            func: RegexpMatchFunction::try_create("regexpMatch")?,
+            expect: Series::new(vec![Some(true), None, None]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
+
+#[test]
+fn test_regexp_match_function_invalid_pattern() -> Result<()> {
+    let func = RegexpMatchFunction::try_create("regexpMatch")?;
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec!["abc"]).into(),
+            DataField::new("s", DataType::String, true),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::String(Some(b"(abc".to_vec())), 1),
+            DataField::new("p", DataType::String, true),
+        ),
+    ];
+
+    // The pattern is only compiled at eval time.
+    let got = func.eval(&columns, 1);
+    let expected_err = ErrorCode::BadArguments("");
+    assert_eq!(got.unwrap_err().code(), expected_err.code());
+    Ok(())
+}
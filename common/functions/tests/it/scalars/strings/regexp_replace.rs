@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RegexpReplaceFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_regexp_replace_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("s", DataType::String, true),
+        DataField::new("p", DataType::String, true),
+        DataField::new("r", DataType::String, true),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "regexp-replace-global-passed",
+            display: "regexpReplace",
+            nullable: true,
+            arg_names: vec!["s", "p", "r"],
+            columns: vec![
+                Series::new(vec!["a1b22c333", "abc"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"\\d+".to_vec())), 2),
+                DataColumn::Constant(DataValue::String(Some(b"#".to_vec())), 2),
+            ],
+            func: RegexpReplaceFunction::try_create("regexpReplace")?,
+            expect: Series::new(vec!["a#b#c#", "abc"]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-replace-capture-group-passed",
+            display: "regexpReplace",
+            nullable: true,
+            arg_names: vec!["s", "p", "r"],
+            columns: vec![
+                Series::new(vec!["2021-12-31", "1999-01-02"]).into(),
+                DataColumn::Constant(
+                    DataValue::String(Some(b"(\\d+)-(\\d+)-(?P<day>\\d+)".to_vec())),
+                    2,
+                ),
+                DataColumn::Constant(DataValue::String(Some(b"${day}/$2/$1".to_vec())), 2),
+            ],
+            func: RegexpReplaceFunction::try_create("regexpReplace")?,
+            expect: Series::new(vec!["31/12/2021", "02/01/1999"]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-replace-empty-match-passed",
+            display: "regexpReplace",
+            nullable: true,
+            arg_names: vec!["s", "p", "r"],
+            columns: vec![
+                Series::new(vec!["abc", ""]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"".to_vec())), 2),
+                DataColumn::Constant(DataValue::String(Some(b"-".to_vec())), 2),
+            ],
+            func: RegexpReplaceFunction::try_create("regexpReplace")?,
+            expect: Series::new(vec!["-a-b-c-", "-"]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-replace-literal-dollar-passed",
+            display: "regexpReplace",
+            nullable: true,
+            arg_names: vec!["s", "p", "r"],
+            columns: vec![
+                Series::new(vec!["price: 10", "price: 7"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"(\\d+)".to_vec())), 2),
+                Series::new(vec!["$$$1", "$ $1"]).into(),
+            ],
+            func: RegexpReplaceFunction::try_create("regexpReplace")?,
+            expect: Series::new(vec!["price: $10", "price: $ 7"]).into(),
+            error: "",
+        },
+        Test {
+            name: "regexp-replace-pattern-array-passed",
+            display: "regexpReplace",
+            nullable: true,
+            arg_names: vec!["s", "p", "r"],
+            columns: vec![
+                Series::new(vec![Some("aaa"), Some("bbb"), None]).into(),
+                Series::new(vec![Some("a"), None, Some("c")]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"x".to_vec())), 3),
+            ],
+            func: RegexpReplaceFunction::try_create("regexpReplace")?,
+            expect: Series::new(vec![Some("xxx"), None, None]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::RepeatFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_repeat_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("s", DataType::String, true),
+        DataField::new("n", DataType::Int64, true),
+        DataField::new("u", DataType::UInt8, true),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "repeat-signed-times-passed",
+            display: "REPEAT",
+            nullable: true,
+            arg_names: vec!["s", "n"],
+            columns: vec![
+                Series::new(vec![Some("abc"), Some("abc"), Some("abc"), None]).into(),
+                Series::new(vec![Some(3_i64), Some(0), Some(-2), Some(3)]).into(),
+            ],
+            func: RepeatFunction::try_create("repeat")?,
+            expect: Series::new(vec![Some("abcabcabc"), Some(""), Some(""), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "repeat-unsigned-times-passed",
+            display: "REPEAT",
+            nullable: true,
+            arg_names: vec!["s", "u"],
+            columns: vec![
+                DataColumn::Constant(DataValue::String(Some(b"ab".to_vec())), 3),
+                Series::new(vec![Some(2_u8), Some(0), None]).into(),
+            ],
+            func: RepeatFunction::try_create("repeat")?,
+            expect: Series::new(vec![Some("abab"), Some(""), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "repeat-constant-passed",
+            display: "REPEAT",
+            nullable: true,
+            arg_names: vec!["s", "n"],
+            columns: vec![
+                DataColumn::Constant(DataValue::String(Some(b"xy".to_vec())), 2),
+                DataColumn::Constant(DataValue::Int64(Some(3)), 2),
+            ],
+            func: RepeatFunction::try_create("repeat")?,
+            expect: DataColumn::Constant(DataValue::String(Some(b"xyxyxy".to_vec())), 2),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
+
+#[test]
+fn test_repeat_function_limits() -> Result<()> {
+    let func = RepeatFunction::try_create("repeat")?;
+    let long = "x".repeat(1000);
+
+    let tests = vec![
+        (
+            Series::new(vec!["abc"]).into(),
+            DataColumn::Constant(DataValue::Int64(Some(1000001)), 1),
+            "Code: 6, displayText = Too many times to repeat: (1000001), maximum is: 1000000.",
+        ),
+        // 1000 bytes repeated 1000000 times is beyond the 128 MiB limit.
+        (
+            Series::new(vec![long.as_str()]).into(),
+            DataColumn::Constant(DataValue::Int64(Some(1000000)), 1),
+            "Code: 6, displayText = The result of repeat is too large, maximum is: 134217728 bytes.",
+        ),
+        // Every row is under the limit, but the column in total is not.
+        (
+            Series::new(vec![long.as_str(); 3]).into(),
+            DataColumn::Constant(DataValue::Int64(Some(50000)), 3),
+            "Code: 6, displayText = The result of repeat is too large, maximum is: 134217728 bytes.",
+        ),
+    ];
+
+    for (s, n, expect) in tests {
+        let rows = s.len();
+        let columns = vec![
+            DataColumnWithField::new(s, DataField::new("s", DataType::String, true)),
+            DataColumnWithField::new(n, DataField::new("n", DataType::Int64, true)),
+        ];
+        let got = func.eval(&columns, rows);
+        assert_eq!(got.unwrap_err().to_string(), expect);
+    }
+    Ok(())
+}
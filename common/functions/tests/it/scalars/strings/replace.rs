@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ReplaceFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_replace_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("s", DataType::String, true),
+        DataField::new("from", DataType::String, false),
+        DataField::new("to", DataType::String, false),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "replace-no-match-passed",
+            display: "replace",
+            nullable: true,
+            arg_names: vec!["s", "from", "to"],
+            columns: vec![
+                Series::new(vec!["abc", "a"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"xyz".to_vec())), 2),
+                DataColumn::Constant(DataValue::String(Some(b"1".to_vec())), 2),
+            ],
+            func: ReplaceFunction::try_create("replace")?,
+            expect: Series::new(vec!["abc", "a"]).into(),
+            error: "",
+        },
+        Test {
+            name: "replace-multiple-matches-passed",
+            display: "replace",
+            nullable: true,
+            arg_names: vec!["s", "from", "to"],
+            columns: vec![
+                Series::new(vec!["aaaa123aaa", "aab", "baa"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"aa".to_vec())), 3),
+                DataColumn::Constant(DataValue::String(Some(b"c".to_vec())), 3),
+            ],
+            func: ReplaceFunction::try_create("replace")?,
+            expect: Series::new(vec!["cc123ca", "cb", "bc"]).into(),
+            error: "",
+        },
+        Test {
+            name: "replace-grow-and-shrink-passed",
+            display: "replace",
+            nullable: true,
+            arg_names: vec!["s", "from", "to"],
+            columns: vec![
+                Series::new(vec!["a-b-c", "a--b"]).into(),
+                Series::new(vec!["-", "--"]).into(),
+                Series::new(vec!["<->", ""]).into(),
+            ],
+            func: ReplaceFunction::try_create("replace")?,
+            expect: Series::new(vec!["a<->b<->c", "ab"]).into(),
+            error: "",
+        },
+        Test {
+            name: "replace-empty-from-passed",
+            display: "replace",
+            nullable: true,
+            arg_names: vec!["s", "from", "to"],
+            columns: vec![
+                Series::new(vec!["abc"]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"".to_vec())), 1),
+                DataColumn::Constant(DataValue::String(Some(b"x".to_vec())), 1),
+            ],
+            func: ReplaceFunction::try_create("replace")?,
+            expect: Series::new(vec!["abc"]).into(),
+            error: "",
+        },
+        Test {
+            name: "replace-null-passed",
+            display: "replace",
+            nullable: true,
+            arg_names: vec!["s", "from", "to"],
+            columns: vec![
+                Series::new(vec![Some("abc"), None]).into(),
+                DataColumn::Constant(DataValue::String(Some(b"b".to_vec())), 2),
+                DataColumn::Constant(DataValue::String(Some(b"x".to_vec())), 2),
+            ],
+            func: ReplaceFunction::try_create("replace")?,
+            expect: Series::new(vec![Some("axc"), None]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::SoundexFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_soundex_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::String, true)]);
+
+    let tests = vec![
+        Test {
+            name: "soundex-passed",
+            display: "soundex",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec![
+                Some("Robert"),
+                Some("Rupert"),
+                Some("Rubin"),
+                Some("Pfister"),
+                Some("Lee"),
+                None,
+            ])
+            .into()],
+            func: SoundexFunction::try_create("soundex")?,
+            expect: Series::new(vec![
+                Some("R163"),
+                Some("R163"),
+                Some("R150"),
+                Some("P236"),
+                Some("L000"),
+                None,
+            ])
+            .into(),
+            error: "",
+        },
+        // The letters of the same code separated by 'h' or 'w' are coded once, the ones separated
+        // by a vowel are not, and the code is truncated to four characters.
+        Test {
+            name: "soundex-separator-passed",
+            display: "soundex",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![
+                Series::new(vec!["Tymczak", "Honeyman", "Ashcraft", "Quadratically"]).into(),
+            ],
+            func: SoundexFunction::try_create("soundex")?,
+            expect: Series::new(vec!["T522", "H555", "A261", "Q363"]).into(),
+            error: "",
+        },
+        // The leading non-alphabetic chars are skipped, a value without letters is empty.
+        Test {
+            name: "soundex-non-alphabetic-passed",
+            display: "soundex",
+            nullable: true,
+            arg_names: vec!["a"],
+            columns: vec![Series::new(vec!["#3556 in bugdb", "123", "", "éa"]).into()],
+            func: SoundexFunction::try_create("soundex")?,
+            expect: Series::new(vec!["I512", "", "", "é000"]).into(),
+            error: "",
+        },
+    ];
+    run_tests(tests, schema)
+}
@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::SplitFunction;
+
+fn list(parts: Option<Vec<&str>>) -> DataValue {
+    let values = parts.map(|parts| {
+        parts
+            .iter()
+            .map(|p| DataValue::String(Some(p.as_bytes().to_vec())))
+            .collect::<Vec<_>>()
+    });
+    DataValue::List(values, DataType::String)
+}
+
+#[test]
+fn test_split_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        str: DataColumn,
+        delimiter: DataColumn,
+        expect: Result<Vec<DataValue>>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "multi-byte-delimiter",
+            str: Series::new(vec![
+                Some("a::b::::c"),
+                Some(""),
+                Some("::"),
+                Some("abc"),
+                None,
+            ])
+            .into(),
+            delimiter: DataColumn::Constant(DataValue::String(Some(b"::".to_vec())), 5),
+            expect: Ok(vec![
+                list(Some(vec!["a", "b", "", "c"])),
+                list(Some(vec![""])),
+                list(Some(vec!["", ""])),
+                list(Some(vec!["abc"])),
+                list(None),
+            ]),
+        },
+        Test {
+            name: "delimiter-column",
+            str: DataColumn::Constant(DataValue::String(Some(b"a,b;c".to_vec())), 3),
+            delimiter: Series::new(vec![Some(","), Some(";"), None]).into(),
+            expect: Ok(vec![
+                list(Some(vec!["a", "b;c"])),
+                list(Some(vec!["a,b", "c"])),
+                list(None),
+            ]),
+        },
+        Test {
+            name: "null-delimiter",
+            str: Series::new(vec!["a,b", "c"]).into(),
+            delimiter: DataColumn::Constant(DataValue::String(None), 2),
+            expect: Ok(vec![list(None); 2]),
+        },
+        Test {
+            name: "empty-delimiter",
+            str: Series::new(vec!["a,b"]).into(),
+            delimiter: DataColumn::Constant(DataValue::String(Some(vec![])), 1),
+            expect: Err(ErrorCode::BadArguments(
+                "The delimiter of split must not be empty",
+            )),
+        },
+    ];
+
+    let func = SplitFunction::try_create("split")?;
+    for t in tests {
+        let rows = t.str.len();
+        let columns = vec![
+            DataColumnWithField::new(t.str, DataField::new("s", DataType::String, true)),
+            DataColumnWithField::new(t.delimiter, DataField::new("d", DataType::String, true)),
+        ];
+        let got = func.eval(&columns, rows);
+        match t.expect {
+            Ok(expect) => {
+                let got = got?;
+                let expect_type = func.return_type(&[DataType::String, DataType::String])?;
+                assert_eq!(got.data_type(), expect_type, "case: {}", t.name);
+                assert_eq!(got.to_values()?, expect, "case: {}", t.name);
+            }
+            Err(expect) => {
+                assert_eq!(got.unwrap_err().to_string(), expect.to_string());
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::SplitByCharFunction;
+
+fn list(parts: Option<Vec<&str>>) -> DataValue {
+    let values = parts.map(|parts| {
+        parts
+            .iter()
+            .map(|p| DataValue::String(Some(p.as_bytes().to_vec())))
+            .collect::<Vec<_>>()
+    });
+    DataValue::List(values, DataType::String)
+}
+
+#[test]
+fn test_split_by_char_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        delimiter: DataColumn,
+        str: DataColumn,
+        expect: Vec<DataValue>,
+    }
+
+    let comma = || DataColumn::Constant(DataValue::String(Some(b",".to_vec())), 4);
+    let tests = vec![
+        Test {
+            name: "keep-empty-parts",
+            delimiter: comma(),
+            str: Series::new(vec![Some("a,b,,c"), Some(""), Some(",a,"), None]).into(),
+            expect: vec![
+                list(Some(vec!["a", "b", "", "c"])),
+                list(Some(vec![""])),
+                list(Some(vec!["", "a", ""])),
+                list(None),
+            ],
+        },
+        Test {
+            name: "no-delimiter-in-string",
+            delimiter: comma(),
+            str: DataColumn::Constant(DataValue::String(Some(b"abc".to_vec())), 4),
+            expect: vec![list(Some(vec!["abc"])); 4],
+        },
+        Test {
+            name: "null-delimiter",
+            delimiter: DataColumn::Constant(DataValue::String(None), 4),
+            str: Series::new(vec!["a,b", "c", "d", "e"]).into(),
+            expect: vec![list(None); 4],
+        },
+    ];
+
+    let func = SplitByCharFunction::try_create("splitByChar")?;
+    for t in tests {
+        let columns = vec![
+            DataColumnWithField::new(t.delimiter, DataField::new("d", DataType::String, true)),
+            DataColumnWithField::new(t.str, DataField::new("s", DataType::String, true)),
+        ];
+        let got = func.eval(&columns, 4)?;
+        let expect_type = func.return_type(&[DataType::String, DataType::String])?;
+        assert_eq!(got.data_type(), expect_type, "case: {}", t.name);
+        assert_eq!(got.to_values()?, t.expect, "case: {}", t.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_split_by_char_function_invalid_delimiter() -> Result<()> {
+    let func = SplitByCharFunction::try_create("splitByChar")?;
+    let str = DataColumnWithField::new(
+        Series::new(vec!["a,b"]).into(),
+        DataField::new("s", DataType::String, true),
+    );
+
+    let delimiters = vec![
+        (
+            DataColumn::Constant(DataValue::String(Some(b",,".to_vec())), 1),
+            "Code: 6, displayText = The delimiter of splitByChar must be exactly one byte, but got ',,'.",
+        ),
+        (
+            Series::new(vec![","]).into(),
+            "Code: 6, displayText = The delimiter of splitByChar must be a constant.",
+        ),
+    ];
+    for (delimiter, expect) in delimiters {
+        let columns = vec![
+            DataColumnWithField::new(delimiter, DataField::new("d", DataType::String, true)),
+            str.clone(),
+        ];
+        let got = func.eval(&columns, 1);
+        assert_eq!(got.unwrap_err().to_string(), expect);
+    }
+    Ok(())
+}
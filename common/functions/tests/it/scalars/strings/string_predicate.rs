@@ -0,0 +1,154 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::*;
+
+#[test]
+fn test_string_predicate_functions() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        func: Box<dyn Function>,
+        str: DataColumn,
+        pattern: DataColumn,
+        expect: DataColumn,
+    }
+
+    let strs = || -> DataColumn {
+        Series::new(vec![Some("abc"), Some("日本語"), Some(""), None]).into()
+    };
+    let constant =
+        |v: &str| DataColumn::Constant(DataValue::String(Some(v.as_bytes().to_vec())), 4);
+
+    let tests = vec![
+        Test {
+            name: "starts-with-empty-prefix",
+            func: StartsWithFunction::try_create("startsWith")?,
+            str: strs(),
+            pattern: constant(""),
+            expect: Series::new(vec![Some(true), Some(true), Some(true), None]).into(),
+        },
+        Test {
+            name: "starts-with-multibyte-prefix",
+            func: StartsWithFunction::try_create("startsWith")?,
+            str: strs(),
+            pattern: Series::new(vec![Some("ab"), Some("日本"), Some("a"), Some("a")]).into(),
+            expect: Series::new(vec![Some(true), Some(true), Some(false), None]).into(),
+        },
+        Test {
+            name: "starts-with-not-matching",
+            func: StartsWithFunction::try_create("startsWith")?,
+            str: strs(),
+            pattern: Series::new(vec![Some("bc"), Some("本"), Some(" "), None]).into(),
+            expect: Series::new(vec![Some(false), Some(false), Some(false), None]).into(),
+        },
+        Test {
+            name: "starts-with-prefix-longer-than-string",
+            func: StartsWithFunction::try_create("startsWith")?,
+            str: strs(),
+            pattern: constant("abcd"),
+            expect: Series::new(vec![Some(false), Some(false), Some(false), None]).into(),
+        },
+        Test {
+            name: "starts-with-constant-string",
+            func: StartsWithFunction::try_create("startsWith")?,
+            str: constant("abc"),
+            pattern: constant("a"),
+            expect: DataColumn::Constant(DataValue::Boolean(Some(true)), 4),
+        },
+        Test {
+            name: "ends-with-empty-suffix",
+            func: EndsWithFunction::try_create("endsWith")?,
+            str: strs(),
+            pattern: constant(""),
+            expect: Series::new(vec![Some(true), Some(true), Some(true), None]).into(),
+        },
+        Test {
+            name: "ends-with-multibyte-suffix",
+            func: EndsWithFunction::try_create("endsWith")?,
+            str: strs(),
+            pattern: constant("語"),
+            expect: Series::new(vec![Some(false), Some(true), Some(false), None]).into(),
+        },
+        Test {
+            name: "ends-with-not-matching",
+            func: EndsWithFunction::try_create("endsWith")?,
+            str: strs(),
+            pattern: Series::new(vec![Some("ab"), Some("日本"), Some("a"), Some("")]).into(),
+            expect: Series::new(vec![Some(false), Some(false), Some(false), None]).into(),
+        },
+        Test {
+            name: "ends-with-suffix-longer-than-string",
+            func: EndsWithFunction::try_create("endsWith")?,
+            str: constant("bc"),
+            pattern: Series::new(vec![Some("abc"), Some("c"), None, Some("bc")]).into(),
+            expect: Series::new(vec![Some(false), Some(true), None, Some(true)]).into(),
+        },
+        Test {
+            name: "contains-needle",
+            func: ContainsFunction::try_create("contains")?,
+            str: strs(),
+            pattern: Series::new(vec![Some("b"), Some("本"), Some(""), Some("")]).into(),
+            expect: Series::new(vec![Some(true), Some(true), Some(true), None]).into(),
+        },
+        Test {
+            name: "contains-partial-multibyte",
+            func: ContainsFunction::try_create("contains")?,
+            str: constant("日本語"),
+            pattern: Series::new(vec![Some("本語"), Some("本日"), None, Some("日本語!")]).into(),
+            expect: Series::new(vec![Some(true), Some(false), None, Some(false)]).into(),
+        },
+        Test {
+            name: "null-pattern",
+            func: ContainsFunction::try_create("contains")?,
+            str: strs(),
+            pattern: DataColumn::Constant(DataValue::String(None), 4),
+            expect: DataColumn::Constant(DataValue::Boolean(None), 4),
+        },
+    ];
+
+    for t in tests {
+        let columns = vec![
+            DataColumnWithField::new(t.str.clone(), DataField::new("s", DataType::String, true)),
+            DataColumnWithField::new(
+                t.pattern.clone(),
+                DataField::new("p", DataType::String, true),
+            ),
+        ];
+        let return_type = t.func.return_type(&[DataType::String, DataType::String])?;
+        let v = t.func.eval(&columns, 4)?;
+        assert_eq!(return_type, v.data_type(), "case: {}", t.name);
+        assert_eq!(&v, &t.expect, "case: {}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_string_predicate_functions_return_type() -> Result<()> {
+    let func = StartsWithFunction::try_create("startsWith")?;
+    assert_eq!(
+        func.return_type(&[DataType::Null, DataType::String])?,
+        DataType::Boolean
+    );
+
+    let expect = ErrorCode::IllegalDataType("Expected string or null, but got Int32");
+    let got = func
+        .return_type(&[DataType::String, DataType::Int32])
+        .unwrap_err();
+    assert_eq!(got.to_string(), expect.to_string());
+    Ok(())
+}
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_functions::scalars::ToFixedStringFunction;
+
+use super::run_tests;
+use super::Test;
+
+#[test]
+fn test_to_fixed_string_function() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("s", DataType::String, true),
+        DataField::new("n", DataType::UInt8, false),
+    ]);
+
+    let tests = vec![Test {
+        name: "to-fixed-string-pad-and-truncate-passed",
+        display: "toFixedString",
+        nullable: true,
+        arg_names: vec!["s", "n"],
+        columns: vec![
+            Series::new(vec![
+                Some("ab"),
+                Some("abcd"),
+                Some("abcdef"),
+                Some(""),
+                None,
+            ])
+            .into(),
+            DataColumn::Constant(DataValue::UInt8(Some(4)), 5),
+        ],
+        func: ToFixedStringFunction::try_create("toFixedString")?,
+        expect: Series::new(vec![
+            Some("ab\0\0"),
+            Some("abcd"),
+            Some("abcd"),
+            Some("\0\0\0\0"),
+            None,
+        ])
+        .into(),
+        error: "",
+    }];
+    run_tests(tests, schema.clone())?;
+
+    let func = ToFixedStringFunction::try_create("toFixedString")?;
+    let tests = vec![
+        (
+            Series::new(vec![2u8, 3]).into(),
+            "Code: 6, displayText = The length of toFixedString must be a constant.",
+        ),
+        (
+            DataColumn::Constant(DataValue::UInt8(Some(0)), 2),
+            "Code: 6, displayText = The length of toFixedString must be between 1 and 16777215, but got 0.",
+        ),
+        (
+            DataColumn::Constant(DataValue::UInt64(Some(16777216)), 2),
+            "Code: 6, displayText = The length of toFixedString must be between 1 and 16777215, but got 16777216.",
+        ),
+    ];
+    for (n, expect) in tests {
+        let columns = vec![
+            DataColumnWithField::new(
+                Series::new(vec!["a", "b"]).into(),
+                schema.field_with_name("s")?.clone(),
+            ),
+            DataColumnWithField::new(n, schema.field_with_name("n")?.clone()),
+        ];
+        let got = func.eval(&columns, 2);
+        assert_eq!(got.unwrap_err().to_string(), expect);
+    }
+
+    assert!(func
+        .return_type(&[DataType::String, DataType::UInt8])
+        .is_ok());
+    assert_eq!(
+        "Code: 7, displayText = Expected unsigned integer, but got Int64.",
+        func.return_type(&[DataType::String, DataType::Int64])
+            .unwrap_err()
+            .to_string()
+    );
+
+    // A character crossing the n-th byte is not split, its bytes are padded instead.
+    let tests: Vec<(&[u8], u8, &[u8])> = vec![
+        ("héllo".as_bytes(), 2, b"h\0"),
+        ("héllo".as_bytes(), 3, "hé".as_bytes()),
+        ("数据".as_bytes(), 4, "数\0".as_bytes()),
+        ("数据".as_bytes(), 6, "数据".as_bytes()),
+        // Not valid UTF-8, truncated at the n-th byte.
+        (&[0xff, 0xfe, 0xfd], 2, &[0xff, 0xfe]),
+    ];
+    for (s, n, expect) in tests {
+        let columns = vec![
+            DataColumnWithField::new(
+                DataColumn::Constant(DataValue::String(Some(s.to_vec())), 2),
+                schema.field_with_name("s")?.clone(),
+            ),
+            DataColumnWithField::new(
+                DataColumn::Constant(DataValue::UInt8(Some(n)), 2),
+                schema.field_with_name("n")?.clone(),
+            ),
+        ];
+        let v = func.eval(&columns, 2)?;
+        let expect = DataColumn::Constant(DataValue::String(Some(expect.to_vec())), 2);
+        assert_eq!(&v, &expect, "case: {:?} {}", s, n);
+        assert_eq!(
+            n as usize * 2,
+            v.to_array()?.string()?.inner().values().len()
+        );
+    }
+
+    // An integer is padded with '0' after its sign, a longer one is an error.
+    let columns = vec![
+        DataColumnWithField::new(
+            Series::new(vec![Some(7i64), Some(1234), Some(-5), Some(0), None]).into(),
+            DataField::new("i", DataType::Int64, true),
+        ),
+        DataColumnWithField::new(
+            DataColumn::Constant(DataValue::UInt8(Some(4)), 5),
+            schema.field_with_name("n")?.clone(),
+        ),
+    ];
+    let v = func.eval(&columns, 5)?;
+    let expect: DataColumn = Series::new(vec![
+        Some("0007"),
+        Some("1234"),
+        Some("-005"),
+        Some("0000"),
+        None,
+    ])
+    .into();
+    assert_eq!(&v, &expect);
+
+    let tests = vec![
+        (
+            DataValue::UInt32(Some(12345)),
+            4,
+            "Code: 6, displayText = The integer 12345 is longer than the length 4 of toFixedString.",
+        ),
+        (
+            DataValue::Int8(Some(-100)),
+            3,
+            "Code: 6, displayText = The integer -100 is longer than the length 3 of toFixedString.",
+        ),
+    ];
+    for (value, n, expect) in tests {
+        let columns = vec![
+            DataColumnWithField::new(
+                DataColumn::Constant(value.clone(), 1),
+                DataField::new("i", value.data_type(), false),
+            ),
+            DataColumnWithField::new(
+                DataColumn::Constant(DataValue::UInt8(Some(n)), 1),
+                schema.field_with_name("n")?.clone(),
+            ),
+        ];
+        let got = func.eval(&columns, 1);
+        assert_eq!(expect, got.unwrap_err().to_string(), "case: {:?}", value);
+    }
+    Ok(())
+}
@@ -87,3 +87,59 @@ fn test_to_type_name_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_to_type_name_types() -> Result<()> {
+    let list_field = DataField::new("item", DataType::String, true);
+    let struct_fields = vec![
+        DataField::new("x", DataType::UInt8, false),
+        DataField::new("y", DataType::Float64, true),
+    ];
+
+    let tests = vec![
+        (DataType::Boolean, false, "Boolean"),
+        (DataType::UInt8, false, "UInt8"),
+        (DataType::UInt16, false, "UInt16"),
+        (DataType::UInt32, false, "UInt32"),
+        (DataType::UInt64, false, "UInt64"),
+        (DataType::Int8, false, "Int8"),
+        (DataType::Int16, false, "Int16"),
+        (DataType::Int32, false, "Int32"),
+        (DataType::Int64, false, "Int64"),
+        (DataType::Float32, false, "Float32"),
+        (DataType::Float64, false, "Float64"),
+        (DataType::String, false, "String"),
+        (DataType::Date16, false, "Date16"),
+        (DataType::Date32, false, "Date32"),
+        (DataType::DateTime32(None), false, "DateTime32"),
+        (DataType::Null, true, "Null"),
+        (DataType::UInt32, true, "Nullable(UInt32)"),
+        (DataType::String, true, "Nullable(String)"),
+        (
+            DataType::List(Box::new(list_field)),
+            false,
+            "List(Nullable(String))",
+        ),
+        (
+            DataType::Struct(struct_fields),
+            true,
+            "Nullable(Struct(x UInt8, y Nullable(Float64)))",
+        ),
+    ];
+
+    let func = ToTypeNameFunction::try_create("toTypeName")?;
+    for (data_type, nullable, expect) in tests {
+        let field = DataField::new("a", data_type.clone(), nullable);
+        let value = DataValue::new_from_data_type(&data_type, true);
+        let columns = vec![DataColumnWithField::new(
+            DataColumn::Constant(value, 2),
+            field,
+        )];
+
+        let v = func.eval(&columns, 2)?;
+        let expect = DataColumn::Constant(DataValue::String(Some(expect.as_bytes().to_vec())), 2);
+        assert_eq!(&v, &expect, "{:?}", data_type);
+    }
+
+    Ok(())
+}
@@ -13,28 +13,48 @@
 //  limitations under the License.
 //
 
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::ClusterStatus;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReply;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReply;
+use common_meta_types::DropTablesReq;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchEvent;
+use common_meta_types::WatchReply;
+use common_meta_types::WatchReq;
+use futures::stream::BoxStream;
+use futures::StreamExt;
 
 #[async_trait::async_trait]
 pub trait MetaApi: Send + Sync {
@@ -48,16 +68,28 @@ pub trait MetaApi: Send + Sync {
 
     async fn list_databases(&self, req: ListDatabaseReq) -> Result<Vec<Arc<DatabaseInfo>>>;
 
+    /// List at most `req.limit` databases with the name prefix, after the ones listed by `req.token`.
+    async fn list_databases_page(&self, req: ListDatabasePageReq) -> Result<ListDatabasePageReply>;
+
     // table
 
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply>;
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    /// Create all of the tables or none of them, a failed request fails the whole batch.
+    async fn create_tables(&self, req: CreateTablesReq) -> Result<CreateTablesReply>;
+
+    /// Drop all of the tables or none of them, a failed request fails the whole batch.
+    async fn drop_tables(&self, req: DropTablesReq) -> Result<DropTablesReply>;
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>>;
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>>;
 
+    /// List at most `req.limit` tables with the name prefix, after the ones listed by `req.token`.
+    async fn list_tables_page(&self, req: ListTablePageReq) -> Result<ListTablePageReply>;
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)>;
 
     async fn upsert_table_option(
@@ -65,5 +97,48 @@ pub trait MetaApi: Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    /// Replace the meta of a table if its version matches `req.seq`.
+    /// A version mismatch is not an error: it returns the current version and meta, with `applied=false`.
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply>;
+
+    // watch
+
+    /// Returns the changes of databases and tables applied after `req.after_seq`, without waiting.
+    /// The changes are those applied to the serving node, a follower may lag behind the leader.
+    async fn watch(&self, req: WatchReq) -> Result<WatchReply>;
+
+    /// Follows the changes applied after `req.after_seq` by calling `watch` every `interval`.
+    /// The stream ends after yielding an error, e.g., when the changes to follow are already removed.
+    fn watch_stream(&self, req: WatchReq, interval: Duration) -> BoxStream<'_, Result<WatchEvent>> {
+        let state = Some((req, VecDeque::new()));
+
+        futures::stream::unfold(state, move |state| async move {
+            let (mut req, mut events) = state?;
+            loop {
+                if let Some(event) = events.pop_front() {
+                    return Some((Ok(event), Some((req, events))));
+                }
+
+                let reply = match self.watch(req.clone()).await {
+                    Ok(reply) => reply,
+                    Err(e) => return Some((Err(e), None)),
+                };
+
+                match reply.events.last() {
+                    Some(last) => req.after_seq = last.seq,
+                    None => tokio::time::sleep(interval).await,
+                }
+                events.extend(reply.events);
+            }
+        })
+        .boxed()
+    }
+
+    // cluster
+
+    /// Returns the leader, term, membership and replication state of the raft cluster.
+    /// A meta store without raft reports no leader and no node.
+    async fn get_cluster_status(&self, req: GetClusterStatusReq) -> Result<ClusterStatus>;
+
     fn name(&self) -> String;
 }
@@ -13,27 +13,41 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use common_base::tokio;
 use common_datavalues::chrono::Utc;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
+use common_meta_types::now_secs;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
+use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseMeta;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchEvent;
+use common_meta_types::WatchReq;
+use common_meta_types::WatchValue;
 use common_tracing::tracing;
+use futures::StreamExt;
 
 use crate::MetaApi;
 
@@ -55,6 +69,7 @@ impl MetaApiTestSuite {
                     engine: "github".to_string(),
                     ..Default::default()
                 },
+                expire_at: None,
             };
 
             let res = mt.create_database(req).await;
@@ -72,6 +87,7 @@ impl MetaApiTestSuite {
                     engine: "".to_string(),
                     ..Default::default()
                 },
+                expire_at: None,
             };
 
             let res = mt.create_database(req).await;
@@ -89,6 +105,7 @@ impl MetaApiTestSuite {
                     engine: "".to_string(),
                     ..DatabaseMeta::default()
                 },
+                expire_at: None,
             };
 
             let res = mt.create_database(req).await;
@@ -115,6 +132,7 @@ impl MetaApiTestSuite {
                     engine: "".to_string(),
                     ..DatabaseMeta::default()
                 },
+                expire_at: None,
             };
 
             let res = mt.create_database(req).await;
@@ -182,7 +200,7 @@ impl MetaApiTestSuite {
 
         tracing::info!("--- get_databases");
         {
-            let dbs = mt.list_databases(ListDatabaseReq {}).await?;
+            let dbs = mt.list_databases(ListDatabaseReq::default()).await?;
             let want: Vec<u64> = vec![1, 2];
             let got = dbs.iter().map(|x| x.database_id).collect::<Vec<_>>();
             assert_eq!(want, got)
@@ -204,6 +222,7 @@ impl MetaApiTestSuite {
                     engine: "".to_string(),
                     ..DatabaseMeta::default()
                 },
+                expire_at: None,
             };
 
             let res = mt.create_database(plan).await?;
@@ -236,6 +255,7 @@ impl MetaApiTestSuite {
                     created_on,
                     ..TableMeta::default()
                 },
+                expire_at: None,
             };
 
             {
@@ -432,6 +452,7 @@ impl MetaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                expire_at: None,
             };
 
             {
@@ -453,6 +474,450 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn table_batch_create_drop<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        self.create_database(mt, "db1").await?;
+
+        let create_req = |tbl_name: &str, if_not_exists: bool| CreateTableReq {
+            if_not_exists,
+            db: "db1".to_string(),
+            table: tbl_name.to_string(),
+            table_meta: TableMeta {
+                engine: "JSON".to_string(),
+                ..Default::default()
+            },
+            expire_at: None,
+        };
+        let drop_req = |tbl_name: &str, if_exists: bool| DropTableReq {
+            if_exists,
+            db: "db1".to_string(),
+            table: tbl_name.to_string(),
+        };
+
+        tracing::info!("--- create tb1 and tb2 in a batch");
+        {
+            let res = mt
+                .create_tables(CreateTablesReq {
+                    reqs: vec![create_req("tb1", false), create_req("tb2", false)],
+                })
+                .await?;
+            let got = res.replies.iter().map(|r| r.table_id).collect::<Vec<_>>();
+            assert_eq!(vec![1, 2], got);
+        }
+
+        tracing::info!("--- a batch with an existing table creates nothing");
+        {
+            let res = mt
+                .create_tables(CreateTablesReq {
+                    reqs: vec![create_req("tb3", false), create_req("tb1", false)],
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::TableAlreadyExists("").code(), err.code());
+
+            let res = mt.get_table(("db1", "tb3").into()).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                err.code(),
+                "tb3 is not created"
+            );
+        }
+
+        tracing::info!("--- a batch with a duplicated table creates nothing");
+        {
+            let res = mt
+                .create_tables(CreateTablesReq {
+                    reqs: vec![create_req("tb3", false), create_req("tb3", false)],
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::TableAlreadyExists("").code(), err.code());
+        }
+
+        tracing::info!("--- a batch with an unknown database creates nothing");
+        {
+            let mut req = create_req("tb3", false);
+            req.db = "nonexistent".to_string();
+
+            let res = mt
+                .create_tables(CreateTablesReq {
+                    reqs: vec![create_req("tb3", false), req],
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+
+            let res = mt.list_tables(ListTableReq::new("db1")).await?;
+            assert_eq!(2, res.len(), "only tb1 and tb2");
+        }
+
+        tracing::info!("--- create existing table with if_not_exists=true returns the existing id");
+        {
+            let res = mt
+                .create_tables(CreateTablesReq {
+                    reqs: vec![create_req("tb2", true), create_req("tb3", false)],
+                })
+                .await?;
+            let got = res.replies.iter().map(|r| r.table_id).collect::<Vec<_>>();
+            assert_eq!(vec![2, 3], got);
+        }
+
+        tracing::info!("--- a batch with an unknown table drops nothing");
+        {
+            let res = mt
+                .drop_tables(DropTablesReq {
+                    reqs: vec![drop_req("tb1", false), drop_req("nonexistent", false)],
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTable("").code(), err.code());
+
+            mt.get_table(("db1", "tb1").into()).await?;
+        }
+
+        tracing::info!("--- drop tb1, tb2 and nonexistent with if_exists=true");
+        {
+            let res = mt
+                .drop_tables(DropTablesReq {
+                    reqs: vec![
+                        drop_req("tb1", false),
+                        drop_req("tb2", false),
+                        drop_req("nonexistent", true),
+                    ],
+                })
+                .await?;
+            assert_eq!(3, res.replies.len());
+
+            let res = mt.list_tables(ListTableReq::new("db1")).await?;
+            let got = res.iter().map(|t| t.name.as_str()).collect::<Vec<_>>();
+            assert_eq!(vec!["tb3"], got);
+        }
+
+        Ok(())
+    }
+
+    pub async fn database_table_expire<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let expire_at = now_secs() + 1;
+
+        tracing::info!("--- create db1 expiring, db2 and db2.tb1 expiring, db2.tb2");
+        {
+            self.create_database_expire_at(mt, "db1", Some(expire_at))
+                .await?;
+            self.create_database(mt, "db2").await?;
+            self.create_table_expire_at(mt, "db2", "tb1", Some(expire_at))
+                .await?;
+            self.create_table(mt, "db2", "tb2").await?;
+        }
+
+        tracing::info!("--- all are visible before expiration");
+        {
+            mt.get_database(GetDatabaseReq::new("db1")).await?;
+            mt.get_table(("db2", "tb1").into()).await?;
+
+            let res = mt.list_databases(ListDatabaseReq::default()).await?;
+            assert_eq!(2, res.len());
+
+            let res = mt.list_tables(ListTableReq::new("db2")).await?;
+            assert_eq!(2, res.len());
+        }
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+
+        tracing::info!("--- expired db1 and db2.tb1 are not visible");
+        {
+            let res = mt.get_database(GetDatabaseReq::new("db1")).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+
+            let res = mt.get_table(("db2", "tb1").into()).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTable("").code(), err.code());
+
+            let res = mt.list_databases(ListDatabaseReq::default()).await?;
+            let got = res.iter().map(|x| x.db.as_str()).collect::<Vec<_>>();
+            assert_eq!(vec!["db2"], got);
+
+            let res = mt.list_tables(ListTableReq::new("db2")).await?;
+            let got = res.iter().map(|x| x.name.as_str()).collect::<Vec<_>>();
+            assert_eq!(vec!["tb2"], got);
+        }
+
+        Ok(())
+    }
+
+    pub async fn list_page<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        tracing::info!("--- create db1, db2, db3 and other");
+        for db_name in ["db2", "other", "db3", "db1"] {
+            self.create_database(mt, db_name).await?;
+        }
+
+        tracing::info!("--- list databases with prefix db by page");
+        {
+            let res = mt
+                .list_databases_page(ListDatabasePageReq::new("db", 2))
+                .await?;
+            let got = res
+                .databases
+                .iter()
+                .map(|x| x.db.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(vec!["db1", "db2"], got);
+            assert!(res.next_token.is_some());
+
+            let mut req = ListDatabasePageReq::new("db", 2);
+            req.token = res.next_token;
+            let res = mt.list_databases_page(req).await?;
+            let got = res
+                .databases
+                .iter()
+                .map(|x| x.db.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(vec!["db3"], got);
+            assert!(res.next_token.is_none(), "no more databases");
+        }
+
+        tracing::info!("--- create 25 tables with prefix tb_ and 1 other");
+        let mut want = vec![];
+        {
+            for i in 0..25 {
+                let tbl_name = format!("tb_{:02}", i);
+                self.create_table(mt, "db1", &tbl_name).await?;
+                want.push(tbl_name);
+            }
+            // A longer name is listed after the shorter ones.
+            self.create_table(mt, "db1", "tb_100").await?;
+            want.push("tb_100".to_string());
+            self.create_table(mt, "db1", "other").await?;
+            // Not listed: it has the prefix, but is in another database.
+            self.create_table(mt, "db2", "tb_99").await?;
+        }
+
+        tracing::info!("--- list tables with prefix tb_ by page");
+        {
+            let mut got = vec![];
+            let mut page_sizes = vec![];
+            let mut token = None;
+
+            loop {
+                let mut req = ListTablePageReq::new("db1", "tb_", 10);
+                req.token = token;
+                let res = mt.list_tables_page(req).await?;
+
+                page_sizes.push(res.tables.len());
+                got.extend(res.tables.iter().map(|t| t.name.clone()));
+
+                token = res.next_token;
+                if token.is_none() {
+                    break;
+                }
+            }
+
+            assert_eq!(vec![10, 10, 6], page_sizes);
+            assert_eq!(want, got);
+        }
+
+        tracing::info!("--- list tables with a prefix matching nothing");
+        {
+            let res = mt
+                .list_tables_page(ListTablePageReq::new("db1", "absent", 10))
+                .await?;
+            assert!(res.tables.is_empty());
+            assert!(res.next_token.is_none());
+        }
+
+        tracing::info!("--- list tables in unknown database");
+        {
+            let res = mt
+                .list_tables_page(ListTablePageReq::new("absent", "", 10))
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+        }
+
+        tracing::info!("--- list a page with limit 0 is an error");
+        {
+            let res = mt
+                .list_databases_page(ListDatabasePageReq::new("db", 0))
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::IllegalMetaOperationArgument("").code(),
+                err.code()
+            );
+
+            let res = mt
+                .list_tables_page(ListTablePageReq::new("db1", "", 0))
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::IllegalMetaOperationArgument("").code(),
+                err.code()
+            );
+        }
+
+        Ok(())
+    }
+
+    pub async fn table_update_meta<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        self.create_database(mt, "db1").await?;
+        self.create_table(mt, "db1", "tb1").await?;
+
+        let table = mt.get_table(("db1", "tb1").into()).await?;
+        let new_meta = TableMeta {
+            engine: "CSV".to_string(),
+            ..table.meta.clone()
+        };
+
+        tracing::info!("--- update table meta with the current version");
+        {
+            let res = mt
+                .update_table_meta(UpdateTableMetaReq::new(&table.ident, new_meta.clone()))
+                .await?;
+            assert!(res.applied);
+            assert_eq!(table.ident.version, res.prev_version);
+            assert_eq!(table.meta, res.prev_meta);
+            assert!(res.version > res.prev_version, "version increases");
+
+            let got = mt.get_table(("db1", "tb1").into()).await?;
+            assert_eq!("CSV", got.meta.engine);
+            assert_eq!(res.version, got.ident.version);
+        }
+
+        tracing::info!("--- update table meta with a stale version, returns the current meta");
+        {
+            let res = mt
+                .update_table_meta(UpdateTableMetaReq::new(&table.ident, TableMeta {
+                    engine: "Parquet".to_string(),
+                    ..table.meta.clone()
+                }))
+                .await?;
+            assert!(!res.applied);
+            assert_eq!(new_meta, res.prev_meta);
+            assert_eq!(res.prev_version, res.version);
+
+            let got = mt.get_table(("db1", "tb1").into()).await?;
+            assert_eq!("CSV", got.meta.engine, "table is not affected");
+        }
+
+        tracing::info!("--- update unknown table");
+        {
+            let res = mt
+                .update_table_meta(UpdateTableMetaReq::new(
+                    &TableIdent::new(100, 1),
+                    new_meta.clone(),
+                ))
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTableId("").code(), err.code());
+        }
+
+        Ok(())
+    }
+
+    pub async fn watch<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let db_name = "db1";
+
+        tracing::info!("--- create db1, tb1 and drop tb1");
+        {
+            self.create_database(mt, db_name).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                db: db_name.to_string(),
+                table: "tb1".to_string(),
+                table_meta: TableMeta {
+                    engine: "JSON".to_string(),
+                    ..Default::default()
+                },
+                expire_at: None,
+            };
+            mt.create_table(req.clone()).await?;
+
+            tracing::info!("--- create existing table with if_not_exists=true produces no event");
+            mt.create_table(CreateTableReq {
+                if_not_exists: true,
+                ..req
+            })
+            .await?;
+
+            mt.drop_table(DropTableReq {
+                if_exists: false,
+                db: db_name.to_string(),
+                table: "tb1".to_string(),
+            })
+            .await?;
+        }
+
+        tracing::info!("--- watch everything");
+        {
+            let res = mt
+                .watch(WatchReq {
+                    prefix: "".to_string(),
+                    after_seq: 0,
+                })
+                .await?;
+
+            let got = res
+                .events
+                .iter()
+                .map(|e| (e.seq, e.key.as_str()))
+                .collect::<Vec<_>>();
+            assert_eq!(vec![(1, "db1"), (2, "db1/tb1"), (3, "db1/tb1")], got);
+
+            assert!(matches!(res.events[0].value, Some(WatchValue::Database(_))));
+            match &res.events[1].value {
+                Some(WatchValue::Table(meta)) => assert_eq!("JSON", meta.engine),
+                v => panic!("expect table meta, but got {:?}", v),
+            }
+            assert_eq!(None, res.events[2].value, "drop table produces a tombstone");
+        }
+
+        tracing::info!("--- watch tables in db1 after seq 2");
+        {
+            let res = mt
+                .watch(WatchReq {
+                    prefix: "db1/".to_string(),
+                    after_seq: 2,
+                })
+                .await?;
+            assert_eq!(1, res.events.len());
+            assert_eq!(WatchEvent::table_key("db1", "tb1"), res.events[0].key);
+            assert_eq!(3, res.events[0].seq);
+        }
+
+        tracing::info!("--- watch after the last seq returns nothing");
+        {
+            let res = mt
+                .watch(WatchReq {
+                    prefix: "".to_string(),
+                    after_seq: 3,
+                })
+                .await?;
+            assert!(res.events.is_empty());
+        }
+
+        tracing::info!("--- watch_stream yields the applied changes and waits for new ones");
+        {
+            let req = WatchReq {
+                prefix: "".to_string(),
+                after_seq: 1,
+            };
+            let mut stream = mt.watch_stream(req, Duration::from_millis(100));
+
+            let got = stream.next().await.unwrap()?;
+            assert_eq!((2, "db1/tb1"), (got.seq, got.key.as_str()));
+            let got = stream.next().await.unwrap()?;
+            assert_eq!((3, "db1/tb1"), (got.seq, got.key.as_str()));
+
+            self.create_database(mt, "db2").await?;
+
+            let got = stream.next().await.unwrap()?;
+            assert_eq!((4, "db2"), (got.seq, got.key.as_str()));
+        }
+
+        Ok(())
+    }
 }
 
 impl MetaApiTestSuite {
@@ -461,7 +926,16 @@ impl MetaApiTestSuite {
         mt: &MT,
         db_name: &str,
     ) -> anyhow::Result<CreateDatabaseReply> {
-        tracing::info!("--- create database {}", db_name);
+        self.create_database_expire_at(mt, db_name, None).await
+    }
+
+    async fn create_database_expire_at<MT: MetaApi>(
+        &self,
+        mt: &MT,
+        db_name: &str,
+        expire_at: Option<u64>,
+    ) -> anyhow::Result<CreateDatabaseReply> {
+        tracing::info!("--- create database {} expire_at: {:?}", db_name, expire_at);
 
         let req = CreateDatabaseReq {
             if_not_exists: false,
@@ -470,12 +944,53 @@ impl MetaApiTestSuite {
                 engine: "".to_string(),
                 ..Default::default()
             },
+            expire_at,
         };
 
         let res = mt.create_database(req).await?;
         tracing::info!("create database res: {:?}", res);
         Ok(res)
     }
+
+    async fn create_table<MT: MetaApi>(
+        &self,
+        mt: &MT,
+        db_name: &str,
+        tbl_name: &str,
+    ) -> anyhow::Result<CreateTableReply> {
+        self.create_table_expire_at(mt, db_name, tbl_name, None)
+            .await
+    }
+
+    async fn create_table_expire_at<MT: MetaApi>(
+        &self,
+        mt: &MT,
+        db_name: &str,
+        tbl_name: &str,
+        expire_at: Option<u64>,
+    ) -> anyhow::Result<CreateTableReply> {
+        tracing::info!(
+            "--- create table {}.{} expire_at: {:?}",
+            db_name,
+            tbl_name,
+            expire_at
+        );
+
+        let req = CreateTableReq {
+            if_not_exists: false,
+            db: db_name.to_string(),
+            table: tbl_name.to_string(),
+            table_meta: TableMeta {
+                engine: "JSON".to_string(),
+                ..Default::default()
+            },
+            expire_at,
+        };
+
+        let res = mt.create_table(req).await?;
+        tracing::info!("create table res: {:?}", res);
+        Ok(res)
+    }
 }
 
 // Test write and read meta on different nodes
@@ -496,6 +1011,7 @@ impl MetaApiTestSuite {
                     engine: "github".to_string(),
                     ..Default::default()
                 },
+                expire_at: None,
             };
 
             let res = node_a.create_database(req).await;
@@ -545,6 +1061,7 @@ impl MetaApiTestSuite {
                         engine: "github".to_string(),
                         ..Default::default()
                     },
+                    expire_at: None,
                 };
                 let res = node_a.create_database(req).await;
                 tracing::info!("create database res: {:?}", res);
@@ -554,7 +1071,7 @@ impl MetaApiTestSuite {
 
         tracing::info!("--- list databases from node_b");
         {
-            let res = node_b.list_databases(ListDatabaseReq {}).await;
+            let res = node_b.list_databases(ListDatabaseReq::default()).await;
             tracing::debug!("get database list: {:?}", res);
             let res = res?;
             assert_eq!(2, res.len(), "database list len is 2");
@@ -583,6 +1100,7 @@ impl MetaApiTestSuite {
                     engine: "github".to_string(),
                     ..Default::default()
                 },
+                expire_at: None,
             };
             let res = node_a.create_database(req).await;
             tracing::info!("create database res: {:?}", res);
@@ -607,6 +1125,7 @@ impl MetaApiTestSuite {
                         options: options.clone(),
                         ..Default::default()
                     },
+                    expire_at: None,
                 };
                 let res = node_a.create_table(req).await;
                 tracing::info!("create table res: {:?}", res);
@@ -645,6 +1164,7 @@ impl MetaApiTestSuite {
                     engine: "github".to_string(),
                     ..Default::default()
                 },
+                expire_at: None,
             };
             let res = node_a.create_database(req).await;
             tracing::info!("create database res: {:?}", res);
@@ -668,6 +1188,7 @@ impl MetaApiTestSuite {
                     options: options.clone(),
                     ..Default::default()
                 },
+                expire_at: None,
             };
 
             let res = node_a.create_table(req).await;
@@ -696,4 +1217,370 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+
+    /// Create db on node_a, watch the change on node_b
+    pub async fn watch_diff_nodes<MT: MetaApi>(
+        &self,
+        node_a: &MT,
+        node_b: &MT,
+    ) -> anyhow::Result<()> {
+        tracing::info!("--- create db1 on node_a");
+        {
+            let res = self.create_database(node_a, "db1").await?;
+            assert_eq!(1, res.database_id, "first database id is 1");
+        }
+
+        tracing::info!("--- watch db1 on node_b");
+        {
+            // node_b serves watch with its local state, wait for the change to be replicated.
+            let mut events = vec![];
+            for _ in 0..50 {
+                let res = node_b
+                    .watch(WatchReq {
+                        prefix: "db1".to_string(),
+                        after_seq: 0,
+                    })
+                    .await?;
+                if !res.events.is_empty() {
+                    events = res.events;
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+
+            tracing::debug!("watch db1 on node_b: {:?}", events);
+            assert_eq!(1, events.len(), "db1 is created once");
+            assert_eq!(1, events[0].seq);
+            assert_eq!(WatchEvent::database_key("db1"), events[0].key);
+            assert!(matches!(events[0].value, Some(WatchValue::Database(_))));
+        }
+
+        Ok(())
+    }
+
+    /// Create table on node_a, update it on node_b, update it with a stale version on node_a
+    pub async fn table_update_meta_diff_nodes<MT: MetaApi>(
+        &self,
+        node_a: &MT,
+        node_b: &MT,
+    ) -> anyhow::Result<()> {
+        tracing::info!("--- create db1.tb1 on node_a");
+        let table = {
+            self.create_database(node_a, "db1").await?;
+            self.create_table(node_a, "db1", "tb1").await?;
+            node_a.get_table(("db1", "tb1").into()).await?
+        };
+
+        let new_meta = TableMeta {
+            engine: "CSV".to_string(),
+            ..table.meta.clone()
+        };
+
+        tracing::info!("--- update tb1 on node_b");
+        let updated_version = {
+            let got = node_b.get_table(("db1", "tb1").into()).await?;
+            assert_eq!(
+                table.ident, got.ident,
+                "node_b reads the version written on node_a"
+            );
+
+            let res = node_b
+                .update_table_meta(UpdateTableMetaReq::new(&got.ident, new_meta.clone()))
+                .await?;
+            assert!(res.applied);
+            res.version
+        };
+
+        tracing::info!("--- update tb1 with the stale version on node_a, conflict");
+        {
+            let res = node_a
+                .update_table_meta(UpdateTableMetaReq::new(&table.ident, TableMeta {
+                    engine: "Parquet".to_string(),
+                    ..table.meta.clone()
+                }))
+                .await?;
+            assert!(!res.applied);
+            assert_eq!(updated_version, res.prev_version);
+            assert_eq!(
+                new_meta, res.prev_meta,
+                "returns the meta written on node_b"
+            );
+        }
+
+        tracing::info!("--- get tb1 on node_a");
+        {
+            let got = node_a.get_table(("db1", "tb1").into()).await?;
+            assert_eq!(updated_version, got.ident.version);
+            assert_eq!("CSV", got.meta.engine);
+        }
+
+        Ok(())
+    }
+
+    /// Create tables in a batch on node_a, get all of them on node_b
+    pub async fn table_batch_diff_nodes<MT: MetaApi>(
+        &self,
+        node_a: &MT,
+        node_b: &MT,
+    ) -> anyhow::Result<()> {
+        let tbl_names = vec!["tb1", "tb2", "tb3"];
+
+        tracing::info!("--- create db1 and 3 tables in a batch on node_a");
+        {
+            self.create_database(node_a, "db1").await?;
+
+            let reqs = tbl_names
+                .iter()
+                .map(|name| CreateTableReq {
+                    if_not_exists: false,
+                    db: "db1".to_string(),
+                    table: name.to_string(),
+                    table_meta: TableMeta {
+                        engine: "JSON".to_string(),
+                        ..Default::default()
+                    },
+                    expire_at: None,
+                })
+                .collect();
+
+            let res = node_a.create_tables(CreateTablesReq { reqs }).await?;
+            assert_eq!(3, res.replies.len());
+        }
+
+        tracing::info!("--- get all tables on node_b");
+        {
+            for (i, name) in tbl_names.iter().enumerate() {
+                let got = node_b.get_table(("db1", *name).into()).await?;
+                assert_eq!(i as u64 + 1, got.ident.table_id);
+            }
+
+            let res = node_b.list_tables(ListTableReq::new("db1")).await?;
+            assert_eq!(3, res.len());
+        }
+
+        tracing::info!("--- drop all tables in a batch on node_b, list on node_a");
+        {
+            let reqs = tbl_names
+                .iter()
+                .map(|name| DropTableReq {
+                    if_exists: false,
+                    db: "db1".to_string(),
+                    table: name.to_string(),
+                })
+                .collect();
+            node_b.drop_tables(DropTablesReq { reqs }).await?;
+
+            let res = node_a.list_tables(ListTableReq::new("db1")).await?;
+            assert!(res.is_empty());
+        }
+
+        Ok(())
+    }
+
+    /// Create expiring database and table on node_a, they disappear on node_b after expiration.
+    pub async fn database_table_expire_diff_nodes<MT: MetaApi>(
+        &self,
+        node_a: &MT,
+        node_b: &MT,
+    ) -> anyhow::Result<()> {
+        let expire_at = now_secs() + 2;
+
+        tracing::info!("--- create db1 expiring, db2 and db2.tb1 expiring on node_a");
+        {
+            self.create_database_expire_at(node_a, "db1", Some(expire_at))
+                .await?;
+            self.create_database(node_a, "db2").await?;
+            self.create_table_expire_at(node_a, "db2", "tb1", Some(expire_at))
+                .await?;
+        }
+
+        tracing::info!("--- get db1 and db2.tb1 on node_b before expiration");
+        {
+            node_b.get_database(GetDatabaseReq::new("db1")).await?;
+            node_b.get_table(("db2", "tb1").into()).await?;
+        }
+
+        tracing::info!("--- db1 and db2.tb1 disappear from node_b after expiration");
+        {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+
+            let res = node_b.get_database(GetDatabaseReq::new("db1")).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+
+            let res = node_b.get_table(("db2", "tb1").into()).await;
+            let err = res.unwrap_err();
+            assert_eq!(ErrorCode::UnknownTable("").code(), err.code());
+
+            let res = node_b.list_databases(ListDatabaseReq::default()).await?;
+            let got = res.iter().map(|x| x.db.as_str()).collect::<Vec<_>>();
+            assert_eq!(vec!["db2"], got);
+
+            let res = node_b.list_tables(ListTableReq::new("db2")).await?;
+            assert!(res.is_empty());
+        }
+
+        Ok(())
+    }
+
+    /// Create many tables on node_a, list them by page alternately on node_a and node_b.
+    pub async fn list_page_diff_nodes<MT: MetaApi>(
+        &self,
+        node_a: &MT,
+        node_b: &MT,
+    ) -> anyhow::Result<()> {
+        tracing::info!("--- create db1 and 20 tables on node_a");
+        let mut want = vec![];
+        {
+            self.create_database(node_a, "db1").await?;
+
+            for i in 0..20 {
+                let tbl_name = format!("tb_{:02}", i);
+                self.create_table(node_a, "db1", &tbl_name).await?;
+                want.push(tbl_name);
+            }
+        }
+
+        tracing::info!("--- list tables by page, alternately on node_a and node_b");
+        {
+            let mut got = vec![];
+            let mut token = None;
+
+            for i in 0.. {
+                let node = if i % 2 == 0 { node_a } else { node_b };
+
+                let mut req = ListTablePageReq::new("db1", "tb_", 7);
+                req.token = token;
+                let res = node.list_tables_page(req).await?;
+
+                got.extend(res.tables.iter().map(|t| t.name.clone()));
+
+                token = res.next_token;
+                if token.is_none() {
+                    break;
+                }
+            }
+
+            assert_eq!(want, got);
+        }
+
+        Ok(())
+    }
+
+    pub async fn read_consistency_diff_nodes<MT: MetaApi>(
+        &self,
+        node_a: &MT,
+        node_b: &MT,
+    ) -> anyhow::Result<()> {
+        let linearizable = ReadConsistency::Linearizable;
+
+        tracing::info!("--- create db1 and tb1 on node_a");
+        {
+            self.create_database(node_a, "db1").await?;
+            self.create_table(node_a, "db1", "tb1").await?;
+        }
+
+        tracing::info!("--- linearizable reads on node_b observe the writes at once");
+        {
+            let res = node_b
+                .get_database(GetDatabaseReq::new("db1").with_consistency(linearizable))
+                .await?;
+            assert_eq!("db1", res.db);
+
+            let res = node_b
+                .list_databases(ListDatabaseReq::default().with_consistency(linearizable))
+                .await?;
+            assert_eq!(
+                vec!["db1"],
+                res.iter().map(|x| x.db.as_str()).collect::<Vec<_>>()
+            );
+
+            let res = node_b
+                .list_databases_page(
+                    ListDatabasePageReq::new("db", 10).with_consistency(linearizable),
+                )
+                .await?;
+            assert_eq!(1, res.databases.len());
+
+            let res = node_b
+                .get_table(GetTableReq::new("db1", "tb1").with_consistency(linearizable))
+                .await?;
+            assert_eq!("tb1", res.name);
+
+            let res = node_b
+                .list_tables(ListTableReq::new("db1").with_consistency(linearizable))
+                .await?;
+            assert_eq!(
+                vec!["tb1"],
+                res.iter().map(|x| x.name.as_str()).collect::<Vec<_>>()
+            );
+
+            let res = node_b
+                .list_tables_page(
+                    ListTablePageReq::new("db1", "tb", 10).with_consistency(linearizable),
+                )
+                .await?;
+            assert_eq!(1, res.tables.len());
+        }
+
+        tracing::info!("--- bounded stale reads on node_b observe the writes eventually");
+        {
+            self.create_table(node_a, "db1", "tb2").await?;
+
+            let mut got = None;
+            for _ in 0..50 {
+                let req =
+                    GetTableReq::new("db1", "tb2").with_consistency(ReadConsistency::BoundedStale);
+                match node_b.get_table(req).await {
+                    Ok(t) => {
+                        got = Some(t);
+                        break;
+                    }
+                    Err(e) => {
+                        assert_eq!(ErrorCode::UnknownTable("").code(), e.code());
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                    }
+                }
+            }
+            assert_eq!("tb2", got.unwrap().name);
+        }
+
+        Ok(())
+    }
+
+    /// Writes to the leader are served locally while writes to a follower are forwarded to the leader.
+    ///
+    /// `leader_forwarded` and `follower_forwarded` return the number of requests forwarded by each node.
+    pub async fn write_forward_diff_nodes<MT: MetaApi>(
+        &self,
+        leader: &MT,
+        follower: &MT,
+        leader_forwarded: impl Fn() -> u64,
+        follower_forwarded: impl Fn() -> u64,
+    ) -> anyhow::Result<()> {
+        let leader_before = leader_forwarded();
+        let follower_before = follower_forwarded();
+
+        tracing::info!("--- create db1 on leader, expect it is not forwarded");
+        {
+            self.create_database(leader, "db1").await?;
+            assert_eq!(leader_before, leader_forwarded());
+            assert_eq!(follower_before, follower_forwarded());
+        }
+
+        tracing::info!("--- create db2 on follower, expect it is forwarded");
+        {
+            self.create_database(follower, "db2").await?;
+            assert_eq!(leader_before, leader_forwarded());
+            assert_eq!(follower_before + 1, follower_forwarded());
+        }
+
+        tracing::info!("--- the forwarded write is visible on leader");
+        {
+            let res = leader.get_database(GetDatabaseReq::new("db2")).await?;
+            assert_eq!("db2", res.db);
+        }
+
+        Ok(())
+    }
 }
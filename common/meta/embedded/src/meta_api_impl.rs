@@ -17,25 +17,39 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use common_exception::Result;
 use common_meta_api::MetaApi;
+use common_meta_types::ClusterStatus;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReply;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReply;
+use common_meta_types::DropTablesReq;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchReply;
+use common_meta_types::WatchReq;
 
 use crate::MetaEmbedded;
 
@@ -61,6 +75,11 @@ impl MetaApi for MetaEmbedded {
         sm.list_databases(req).await
     }
 
+    async fn list_databases_page(&self, req: ListDatabasePageReq) -> Result<ListDatabasePageReply> {
+        let sm = self.inner.lock().await;
+        sm.list_databases_page(req).await
+    }
+
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
         let sm = self.inner.lock().await;
         sm.create_table(req).await
@@ -71,6 +90,16 @@ impl MetaApi for MetaEmbedded {
         sm.drop_table(req).await
     }
 
+    async fn create_tables(&self, req: CreateTablesReq) -> Result<CreateTablesReply> {
+        let sm = self.inner.lock().await;
+        sm.create_tables(req).await
+    }
+
+    async fn drop_tables(&self, req: DropTablesReq) -> Result<DropTablesReply> {
+        let sm = self.inner.lock().await;
+        sm.drop_tables(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         let sm = self.inner.lock().await;
         sm.get_table(req).await
@@ -81,6 +110,11 @@ impl MetaApi for MetaEmbedded {
         sm.list_tables(req).await
     }
 
+    async fn list_tables_page(&self, req: ListTablePageReq) -> Result<ListTablePageReply> {
+        let sm = self.inner.lock().await;
+        sm.list_tables_page(req).await
+    }
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
         let sm = self.inner.lock().await;
         sm.get_table_by_id(table_id).await
@@ -94,6 +128,21 @@ impl MetaApi for MetaEmbedded {
         sm.upsert_table_option(req).await
     }
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        let sm = self.inner.lock().await;
+        sm.update_table_meta(req).await
+    }
+
+    async fn watch(&self, req: WatchReq) -> Result<WatchReply> {
+        let sm = self.inner.lock().await;
+        sm.watch(req).await
+    }
+
+    async fn get_cluster_status(&self, req: GetClusterStatusReq) -> Result<ClusterStatus> {
+        let sm = self.inner.lock().await;
+        sm.get_cluster_status(req).await
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
@@ -19,28 +19,42 @@ use std::sync::Arc;
 
 use common_arrow::arrow_format::flight::data::Action;
 use common_exception::ErrorCode;
+use common_meta_types::ClusterStatus;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReply;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReply;
+use common_meta_types::DropTablesReq;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaId;
 use common_meta_types::PrefixListReply;
 use common_meta_types::TableInfo;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchReply;
+use common_meta_types::WatchReq;
 use prost::Message;
 use tonic::Request;
 
@@ -57,13 +71,22 @@ pub enum MetaFlightAction {
     DropDatabase(DropDatabaseReq),
     GetDatabase(GetDatabaseReq),
     ListDatabases(ListDatabaseReq),
+    ListDatabasesPage(ListDatabasePageReq),
 
     CreateTable(CreateTableReq),
     DropTable(DropTableReq),
+    CreateTables(CreateTablesReq),
+    DropTables(DropTablesReq),
     GetTable(GetTableReq),
     GetTableExt(GetTableExtReq),
     ListTables(ListTableReq),
+    ListTablesPage(ListTablePageReq),
     CommitTable(UpsertTableOptionReq),
+    UpdateTableMeta(UpdateTableMetaReq),
+
+    Watch(WatchReq),
+
+    GetClusterStatus(GetClusterStatusReq),
 
     UpsertKV(UpsertKVAction),
     GetKV(GetKVAction),
@@ -166,6 +189,14 @@ impl RequestFor for DropTableReq {
     type Reply = DropTableReply;
 }
 
+impl RequestFor for CreateTablesReq {
+    type Reply = CreateTablesReply;
+}
+
+impl RequestFor for DropTablesReq {
+    type Reply = DropTablesReply;
+}
+
 impl RequestFor for GetTableReq {
     type Reply = Arc<TableInfo>;
 }
@@ -189,3 +220,23 @@ impl RequestFor for ListTableReq {
 impl RequestFor for ListDatabaseReq {
     type Reply = Vec<Arc<DatabaseInfo>>;
 }
+
+impl RequestFor for ListTablePageReq {
+    type Reply = ListTablePageReply;
+}
+
+impl RequestFor for ListDatabasePageReq {
+    type Reply = ListDatabasePageReply;
+}
+
+impl RequestFor for UpdateTableMetaReq {
+    type Reply = UpdateTableMetaReply;
+}
+
+impl RequestFor for WatchReq {
+    type Reply = WatchReply;
+}
+
+impl RequestFor for GetClusterStatusReq {
+    type Reply = ClusterStatus;
+}
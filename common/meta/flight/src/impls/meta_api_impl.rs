@@ -17,25 +17,39 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_meta_api::MetaApi;
+use common_meta_types::ClusterStatus;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReply;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReply;
+use common_meta_types::DropTablesReq;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchReply;
+use common_meta_types::WatchReq;
 
 use crate::GetTableExtReq;
 use crate::MetaFlightClient;
@@ -64,6 +78,13 @@ impl MetaApi for MetaFlightClient {
         self.do_action(req).await
     }
 
+    async fn list_databases_page(
+        &self,
+        req: ListDatabasePageReq,
+    ) -> common_exception::Result<ListDatabasePageReply> {
+        self.do_action(req).await
+    }
+
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply, ErrorCode> {
         self.do_action(req).await
     }
@@ -72,6 +93,14 @@ impl MetaApi for MetaFlightClient {
         self.do_action(req).await
     }
 
+    async fn create_tables(&self, req: CreateTablesReq) -> Result<CreateTablesReply, ErrorCode> {
+        self.do_action(req).await
+    }
+
+    async fn drop_tables(&self, req: DropTablesReq) -> Result<DropTablesReply, ErrorCode> {
+        self.do_action(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> common_exception::Result<Arc<TableInfo>> {
         self.do_action(req).await
     }
@@ -83,6 +112,13 @@ impl MetaApi for MetaFlightClient {
         self.do_action(req).await
     }
 
+    async fn list_tables_page(
+        &self,
+        req: ListTablePageReq,
+    ) -> common_exception::Result<ListTablePageReply> {
+        self.do_action(req).await
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -98,6 +134,24 @@ impl MetaApi for MetaFlightClient {
         self.do_action(req).await
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply, ErrorCode> {
+        self.do_action(req).await
+    }
+
+    async fn watch(&self, req: WatchReq) -> Result<WatchReply, ErrorCode> {
+        self.do_action(req).await
+    }
+
+    async fn get_cluster_status(
+        &self,
+        req: GetClusterStatusReq,
+    ) -> Result<ClusterStatus, ErrorCode> {
+        self.do_action(req).await
+    }
+
     fn name(&self) -> String {
         "MetaFlightClient".to_string()
     }
@@ -17,13 +17,19 @@ use std::sync::Arc;
 use async_raft::raft::AppendEntriesRequest;
 use async_raft::raft::InstallSnapshotRequest;
 use async_raft::raft::VoteRequest;
+use common_meta_types::ClusterStatus;
 use common_meta_types::DatabaseInfo;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::GetKVReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
 use common_meta_types::ListKVReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
 use common_meta_types::MGetKVActionReply;
@@ -62,13 +68,17 @@ pub enum ForwardRequestBody {
     Write(LogEntry),
 
     ListDatabase(ListDatabaseReq),
+    ListDatabasePage(ListDatabasePageReq),
     GetDatabase(GetDatabaseReq),
     ListTable(ListTableReq),
+    ListTablePage(ListTablePageReq),
     GetTable(GetTableReq),
 
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
+
+    GetClusterStatus(GetClusterStatusReq),
 }
 
 /// A request that is forwarded from one raft node to another
@@ -92,13 +102,17 @@ pub enum ForwardResponse {
     Join(()),
     AppliedState(AppliedState),
     ListDatabase(Vec<Arc<DatabaseInfo>>),
+    ListDatabasePage(ListDatabasePageReply),
     DatabaseInfo(Arc<DatabaseInfo>),
     ListTable(Vec<Arc<TableInfo>>),
+    ListTablePage(ListTablePageReply),
     TableInfo(Arc<TableInfo>),
 
     GetKV(GetKVActionReply),
     MGetKV(MGetKVActionReply),
     ListKV(PrefixListReply),
+
+    ClusterStatus(ClusterStatus),
 }
 
 impl tonic::IntoRequest<RaftRequest> for ForwardRequest {
@@ -22,6 +22,7 @@ use common_meta_types::NodeId;
 use common_meta_types::SeqNum;
 use common_meta_types::SeqV;
 use common_meta_types::TableMeta;
+use common_meta_types::WatchEvent;
 
 use crate::state::RaftStateKey;
 use crate::state::RaftStateValue;
@@ -30,6 +31,7 @@ use crate::state_machine::ClientLastRespValue;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaValue;
 use crate::state_machine::TableLookupKey;
+use crate::state_machine::TableNameValue;
 
 /// Types for raft log in SledTree
 pub struct Logs {}
@@ -133,3 +135,23 @@ impl SledKeySpace for TableLookup {
     type K = TableLookupKey;
     type V = SeqV<TableLookupValue>;
 }
+
+/// Changes of databases and tables in the order they are applied, keyed by the change seq.
+pub struct MetaChanges {}
+
+impl SledKeySpace for MetaChanges {
+    const PREFIX: u8 = 13;
+    const NAME: &'static str = "meta-changes";
+    type K = u64;
+    type V = WatchEvent;
+}
+
+/// The names of a table by its id, the reverse of `TableLookup`.
+pub struct TableNames {}
+
+impl SledKeySpace for TableNames {
+    const PREFIX: u8 = 14;
+    const NAME: &'static str = "table-names";
+    type K = u64;
+    type V = TableNameValue;
+}
@@ -16,6 +16,7 @@ use std::fmt::Debug;
 
 use async_raft::AppDataResponse;
 use common_exception::ErrorCode;
+use common_exception::SerializedError;
 use common_meta_types::AddResult;
 use common_meta_types::Change;
 use common_meta_types::DatabaseMeta;
@@ -46,6 +47,9 @@ pub enum AppliedState {
 
     TableMeta(Change<TableMeta>),
 
+    /// The changes of a batch of tables, or the error that rejects the whole batch.
+    TableMetaBatch(Result<Vec<Change<TableMeta>>, SerializedError>),
+
     KV(Change<Vec<u8>>),
 
     #[try_into(ignore)]
@@ -109,6 +113,8 @@ impl AppliedState {
             AppliedState::DatabaseId(ref ch) => ch.changed(),
             AppliedState::DatabaseMeta(ref ch) => ch.changed(),
             AppliedState::TableMeta(ref ch) => ch.changed(),
+            AppliedState::TableMetaBatch(Ok(ref chs)) => chs.iter().any(|ch| ch.changed()),
+            AppliedState::TableMetaBatch(Err(_)) => false,
             AppliedState::KV(ref ch) => ch.changed(),
             AppliedState::None => false,
         }
@@ -137,6 +143,8 @@ impl AppliedState {
             AppliedState::DatabaseId(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::DatabaseMeta(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::TableMeta(Change { ref prev, .. }) => prev.is_none(),
+            AppliedState::TableMetaBatch(Ok(ref chs)) => chs.iter().all(|ch| ch.prev.is_none()),
+            AppliedState::TableMetaBatch(Err(_)) => true,
             AppliedState::KV(Change { ref prev, .. }) => prev.is_none(),
             AppliedState::None => true,
         }
@@ -149,6 +157,8 @@ impl AppliedState {
             AppliedState::DatabaseId(Change { ref result, .. }) => result.is_none(),
             AppliedState::DatabaseMeta(Change { ref result, .. }) => result.is_none(),
             AppliedState::TableMeta(Change { ref result, .. }) => result.is_none(),
+            AppliedState::TableMetaBatch(Ok(ref chs)) => chs.iter().all(|ch| ch.result.is_none()),
+            AppliedState::TableMetaBatch(Err(_)) => true,
             AppliedState::KV(Change { ref result, .. }) => result.is_none(),
             AppliedState::None => true,
         }
@@ -17,11 +17,13 @@ pub use client_last_resp::ClientLastRespValue;
 pub use sm::SerializableSnapshot;
 pub use sm::SnapshotKeyValue;
 pub use sm::StateMachine;
+pub use sm::MAX_META_CHANGES;
 pub use snapshot::Snapshot;
 pub use state_machine_meta::StateMachineMetaKey;
 pub use state_machine_meta::StateMachineMetaValue;
 pub use table_lookup::TableLookupKey;
 pub use table_lookup::TableLookupValue;
+pub use table_lookup::TableNameValue;
 
 pub mod applied_state;
 pub mod client_last_resp;
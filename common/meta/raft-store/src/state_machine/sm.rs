@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::convert::Infallible;
 use std::convert::TryInto;
 use std::fmt::Debug;
@@ -32,9 +34,12 @@ use common_meta_sled_store::SledKeySpace;
 use common_meta_sled_store::SledTree;
 use common_meta_sled_store::Store;
 use common_meta_sled_store::TransactionSledTree;
+use common_meta_types::now_secs;
 use common_meta_types::Change;
 use common_meta_types::Cmd;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseMeta;
+use common_meta_types::DropTablesReq;
 use common_meta_types::KVMeta;
 use common_meta_types::LogEntry;
 use common_meta_types::LogId;
@@ -45,6 +50,8 @@ use common_meta_types::NodeId;
 use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TableMeta;
+use common_meta_types::WatchEvent;
+use common_meta_types::WatchValue;
 use common_tracing::tracing;
 use serde::Deserialize;
 use serde::Serialize;
@@ -56,10 +63,12 @@ use crate::sled_key_spaces::ClientLastResps;
 use crate::sled_key_spaces::DatabaseLookup;
 use crate::sled_key_spaces::Databases;
 use crate::sled_key_spaces::GenericKV;
+use crate::sled_key_spaces::MetaChanges;
 use crate::sled_key_spaces::Nodes;
 use crate::sled_key_spaces::Sequences;
 use crate::sled_key_spaces::StateMachineMeta;
 use crate::sled_key_spaces::TableLookup;
+use crate::sled_key_spaces::TableNames;
 use crate::sled_key_spaces::Tables;
 use crate::state_machine::AppliedState;
 use crate::state_machine::ClientLastRespValue;
@@ -70,6 +79,7 @@ use crate::state_machine::StateMachineMetaKey::LastMembership;
 use crate::state_machine::StateMachineMetaValue;
 use crate::state_machine::TableLookupKey;
 use crate::state_machine::TableLookupValue;
+use crate::state_machine::TableNameValue;
 
 /// seq number key to generate database id
 const SEQ_DATABASE_ID: &str = "database_id";
@@ -78,6 +88,9 @@ const SEQ_TABLE_ID: &str = "table_id";
 /// seq number key to database meta version
 const SEQ_DATABASE_META_ID: &str = "database_meta_id";
 
+/// The number of the latest meta changes kept for `watch`, an older change is removed when a new one is applied.
+pub const MAX_META_CHANGES: u64 = 1024;
+
 /// sled db tree name for nodes
 // const TREE_NODES: &str = "nodes";
 // const TREE_META: &str = "meta";
@@ -348,7 +361,16 @@ impl StateMachine {
                 }
             }
 
-            Cmd::CreateDatabase { ref name, ref meta } => {
+            Cmd::CreateDatabase {
+                ref name,
+                ref meta,
+                ref expire_at,
+                ref now,
+            } => {
+                if let Some(now) = now {
+                    self.txn_purge_expired_database_by_name(name, *now, txn_tree)?;
+                }
+
                 let db_id = self.txn_incr_seq(SEQ_DATABASE_ID, txn_tree).map_err(|e| {
                     let e: ConflictableTransactionError<Infallible> = e.into();
                     ErrorCode::from(e)
@@ -357,12 +379,12 @@ impl StateMachine {
                 let db_lookup_tree = txn_tree.key_space::<DatabaseLookup>();
 
                 let (prev, result) = self
-                    .sub_txn_tree_upsert(
+                    .sub_txn_tree_upsert_meta(
                         &db_lookup_tree,
                         name,
                         &MatchSeq::Exact(0),
                         Operation::Update(db_id),
-                        None,
+                        Self::expire_meta(expire_at),
                     )
                     .map_err(|e| {
                         let e: ConflictableTransactionError<Infallible> = e.into();
@@ -396,7 +418,7 @@ impl StateMachine {
 
                 let dbs = txn_tree.key_space::<Databases>();
                 let (prev_meta, result_meta) = self
-                    .sub_txn_tree_upsert(
+                    .sub_txn_tree_upsert_meta(
                         &dbs,
                         &db_id,
                         &MatchSeq::Exact(0),
@@ -414,6 +436,16 @@ impl StateMachine {
                             let e: ConflictableTransactionError<Infallible> = e.into();
                             ErrorCode::from(e)
                         })?;
+
+                    self.txn_append_meta_change(
+                        WatchEvent::database_key(name),
+                        Some(WatchValue::Database(meta.clone())),
+                        txn_tree,
+                    )
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
                 }
 
                 tracing::debug!(
@@ -434,7 +466,7 @@ impl StateMachine {
                 let dbs = txn_tree.key_space::<DatabaseLookup>();
 
                 let (prev, result) = self
-                    .sub_txn_tree_upsert(&dbs, name, &MatchSeq::Any, Operation::Delete, None)
+                    .sub_txn_tree_upsert_meta(&dbs, name, &MatchSeq::Any, Operation::Delete, None)
                     .map_err(|e| {
                         let e: ConflictableTransactionError<Infallible> = e.into();
                         ErrorCode::from(e)
@@ -458,7 +490,19 @@ impl StateMachine {
 
                     let dbs = txn_tree.key_space::<Databases>();
                     let (prev_meta, result_meta) = self
-                        .sub_txn_tree_upsert(&dbs, &db_id, &MatchSeq::Any, Operation::Delete, None)
+                        .sub_txn_tree_upsert_meta(
+                            &dbs,
+                            &db_id,
+                            &MatchSeq::Any,
+                            Operation::Delete,
+                            None,
+                        )
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+
+                    self.txn_append_meta_change(WatchEvent::database_key(name), None, txn_tree)
                         .map_err(|e| {
                             let e: ConflictableTransactionError<Infallible> = e.into();
                             ErrorCode::from(e)
@@ -483,7 +527,17 @@ impl StateMachine {
                 ref db_name,
                 ref table_name,
                 ref table_meta,
+                ref expire_at,
+                ref now,
             } => {
+                if let Some(now) = now {
+                    self.txn_purge_expired_table(db_name, table_name, *now, txn_tree)
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+                }
+
                 let db_id = self.txn_get_database_id(db_name, txn_tree).map_err(|e| {
                     let e: ConflictableTransactionError<Infallible> = e.into();
                     ErrorCode::from(e)
@@ -521,12 +575,12 @@ impl StateMachine {
                     ErrorCode::from(e)
                 })?;
 
-                self.sub_txn_tree_upsert(
+                self.sub_txn_tree_upsert_meta(
                     &table_lookup_tree,
                     &lookup_key,
                     &MatchSeq::Exact(0),
                     Operation::Update(TableLookupValue(table_id)),
-                    None,
+                    Self::expire_meta(expire_at),
                 )
                 .map_err(|e| {
                     let e: ConflictableTransactionError<Infallible> = e.into();
@@ -535,7 +589,7 @@ impl StateMachine {
 
                 let table_tree = txn_tree.key_space::<Tables>();
                 let (prev, result) = self
-                    .sub_txn_tree_upsert(
+                    .sub_txn_tree_upsert_meta(
                         &table_tree,
                         &table_id,
                         &MatchSeq::Exact(0),
@@ -550,11 +604,33 @@ impl StateMachine {
                 tracing::debug!("applied create Table: {}={:?}", table_name, result);
 
                 if prev.is_none() && result.is_some() {
+                    let table_names = txn_tree.key_space::<TableNames>();
+                    table_names
+                        .insert(&table_id, &TableNameValue {
+                            database_id: lookup_key.database_id,
+                            db_name: db_name.to_string(),
+                            table_name: table_name.to_string(),
+                        })
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+
                     self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
                         .map_err(|e| {
                             let e: ConflictableTransactionError<Infallible> = e.into();
                             ErrorCode::from(e)
                         })?;
+
+                    self.txn_append_meta_change(
+                        WatchEvent::table_key(db_name, table_name),
+                        result.as_ref().map(|sv| WatchValue::Table(sv.data.clone())),
+                        txn_tree,
+                    )
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
                 }
 
                 Ok(AppliedState::TableMeta(Change::new_with_id(
@@ -588,7 +664,7 @@ impl StateMachine {
 
                 let table_id = seq_table_id.unwrap().data.0;
 
-                self.sub_txn_tree_upsert(
+                self.sub_txn_tree_upsert_meta(
                     &table_lookup_tree,
                     &lookup_key,
                     &MatchSeq::Any,
@@ -602,7 +678,7 @@ impl StateMachine {
 
                 let tables = txn_tree.key_space::<Tables>();
                 let (prev, result) = self
-                    .sub_txn_tree_upsert(
+                    .sub_txn_tree_upsert_meta(
                         &tables,
                         &table_id,
                         &MatchSeq::Any,
@@ -614,16 +690,106 @@ impl StateMachine {
                         ErrorCode::from(e)
                     })?;
                 if prev.is_some() && result.is_none() {
+                    let table_names = txn_tree.key_space::<TableNames>();
+                    table_names.remove(&table_id).map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
+
                     self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)
                         .map_err(|e| {
                             let e: ConflictableTransactionError<Infallible> = e.into();
                             ErrorCode::from(e)
                         })?;
+
+                    self.txn_append_meta_change(
+                        WatchEvent::table_key(db_name, table_name),
+                        None,
+                        txn_tree,
+                    )
+                    .map_err(|e| {
+                        let e: ConflictableTransactionError<Infallible> = e.into();
+                        ErrorCode::from(e)
+                    })?;
                 }
                 tracing::debug!("applied drop Table: {} {:?}", table_name, result);
                 Ok(Change::new_with_id(table_id, prev, result).into())
             }
 
+            Cmd::CreateTables(ref req) => {
+                // Check the whole batch before applying any of it, to make it all-or-nothing.
+                if let Err(e) = self.txn_check_create_tables(req, txn_tree) {
+                    return Ok(AppliedState::TableMetaBatch(Err(e.into())));
+                }
+
+                let mut changes = Vec::with_capacity(req.reqs.len());
+                for r in &req.reqs {
+                    let cmd = Cmd::CreateTable {
+                        db_name: r.db.clone(),
+                        table_name: r.table.clone(),
+                        table_meta: r.table_meta.clone(),
+                        expire_at: r.expire_at,
+                        now: None,
+                    };
+                    let ch: Change<TableMeta> = self.apply_cmd(&cmd, txn_tree)?.try_into().unwrap();
+                    changes.push(ch);
+                }
+
+                tracing::debug!("applied create Tables: {}", changes.len());
+                Ok(AppliedState::TableMetaBatch(Ok(changes)))
+            }
+
+            Cmd::DropTables(ref req) => {
+                // Check the whole batch before applying any of it, to make it all-or-nothing.
+                if let Err(e) = self.txn_check_drop_tables(req, txn_tree) {
+                    return Ok(AppliedState::TableMetaBatch(Err(e.into())));
+                }
+
+                let mut changes = Vec::with_capacity(req.reqs.len());
+                for r in &req.reqs {
+                    let cmd = Cmd::DropTable {
+                        db_name: r.db.clone(),
+                        table_name: r.table.clone(),
+                    };
+                    let ch: Change<TableMeta> = self.apply_cmd(&cmd, txn_tree)?.try_into().unwrap();
+                    changes.push(ch);
+                }
+
+                tracing::debug!("applied drop Tables: {}", changes.len());
+                Ok(AppliedState::TableMetaBatch(Ok(changes)))
+            }
+
+            Cmd::PurgeExpiredMeta {
+                now,
+                ref databases,
+                ref tables,
+            } => {
+                let mut purged = 0;
+
+                // Tables are looked up by database name, thus they are purged first.
+                for (db_name, table_name) in tables {
+                    let removed = self
+                        .txn_purge_expired_table(db_name, table_name, *now, txn_tree)
+                        .map_err(|e| {
+                            let e: ConflictableTransactionError<Infallible> = e.into();
+                            ErrorCode::from(e)
+                        })?;
+                    purged += removed as u64;
+                }
+
+                for name in databases {
+                    let removed = self.txn_purge_expired_database_by_name(name, *now, txn_tree)?;
+                    purged += removed as u64;
+                }
+
+                tracing::debug!(
+                    "applied purge expired meta: now: {} purged: {}",
+                    now,
+                    purged
+                );
+                Ok(AppliedState::None)
+            }
+
             Cmd::UpsertKV {
                 key,
                 seq,
@@ -643,59 +809,167 @@ impl StateMachine {
             }
 
             Cmd::UpsertTableOptions(ref req) => {
-                let table_tree = txn_tree.key_space::<Tables>();
-                let prev = table_tree.get(&req.table_id).map_err(|e| {
-                    let e: ConflictableTransactionError<Infallible> = e.into();
-                    ErrorCode::from(e)
-                })?;
+                self.apply_update_table_meta(req.table_id, &req.seq, txn_tree, |prev| {
+                    let mut table_meta = prev.clone();
+                    let opts = &mut table_meta.options;
+
+                    for (k, opt_v) in &req.options {
+                        match opt_v {
+                            None => {
+                                opts.remove(k);
+                            }
+                            Some(v) => {
+                                opts.insert(k.to_string(), v.to_string());
+                            }
+                        }
+                    }
+                    table_meta
+                })
+            }
 
-                // Unlike other Cmd, prev to be None is not allowed for upsert-options.
-                let prev = prev.ok_or_else(|| {
-                    ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id))
-                })?;
+            Cmd::UpdateTableMeta(ref req) => {
+                self.apply_update_table_meta(req.table_id, &req.seq, txn_tree, |_prev| {
+                    req.new_table_meta.clone()
+                })
+            }
+        }
+    }
 
-                if req.seq.match_seq(&prev).is_err() {
-                    let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
-                    return Ok(res);
-                }
+    /// Replace the meta of the table of `table_id` with `update(prev_meta)` if its version matches `seq`.
+    ///
+    /// Unlike other Cmd, the table must exist. An unknown `table_id` is returned as a change
+    /// without `prev`, instead of an error, for the caller to report:
+    /// an error would abort applying the log entry on every replica.
+    fn apply_update_table_meta(
+        &self,
+        table_id: u64,
+        seq: &MatchSeq,
+        txn_tree: &TransactionSledTree,
+        update: impl FnOnce(&TableMeta) -> TableMeta,
+    ) -> common_exception::Result<AppliedState> {
+        let table_tree = txn_tree.key_space::<Tables>();
+        let prev = table_tree.get(&table_id).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
 
-                let meta = prev.meta.clone();
-                let mut table_meta = prev.data.clone();
-                let opts = &mut table_meta.options;
+        let prev = match prev {
+            Some(prev) => prev,
+            None => {
+                return Ok(AppliedState::TableMeta(Change::new_with_id(
+                    table_id, None, None,
+                )));
+            }
+        };
 
-                for (k, opt_v) in &req.options {
-                    match opt_v {
-                        None => {
-                            opts.remove(k);
-                        }
-                        Some(v) => {
-                            opts.insert(k.to_string(), v.to_string());
-                        }
-                    }
-                }
+        if seq.match_seq(&prev).is_err() {
+            return Ok(AppliedState::TableMeta(Change::nochange_with_id(
+                table_id,
+                Some(prev),
+            )));
+        }
+
+        let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
+        let sv = SeqV {
+            seq: new_seq,
+            meta: prev.meta.clone(),
+            data: update(&prev.data),
+        };
+
+        table_tree.insert(&table_id, &sv).map_err(|e| {
+            let e: ConflictableTransactionError<Infallible> = e.into();
+            ErrorCode::from(e)
+        })?;
 
-                let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree).map_err(|e| {
+        if let Some(key) = self.get_table_watch_key(table_id, txn_tree)? {
+            self.txn_append_meta_change(key, Some(WatchValue::Table(sv.data.clone())), txn_tree)
+                .map_err(|e| {
                     let e: ConflictableTransactionError<Infallible> = e.into();
                     ErrorCode::from(e)
                 })?;
-                let sv = SeqV {
-                    seq: new_seq,
-                    meta,
-                    data: table_meta,
-                };
+        }
+
+        tracing::debug!("applied update table meta: {}={:?}", table_id, sv);
+        Ok(AppliedState::TableMeta(Change::new_with_id(
+            table_id,
+            Some(prev),
+            Some(sv),
+        )))
+    }
 
-                table_tree.insert(&req.table_id, &sv).map_err(|e| {
+    /// The watch key of the table of `table_id`, i.e., `<db>/<table>`.
+    ///
+    /// It is found by `TableNames`, which has no entry for a table created before it was added.
+    /// Such a table is searched in the lookups instead. The lookups are not changed by the Cmd
+    /// updating a table meta, thus they are read out of the transaction, which does not support
+    /// iterating.
+    fn get_table_watch_key(
+        &self,
+        table_id: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<Option<String>> {
+        let table_names = txn_tree
+            .key_space::<TableNames>()
+            .get(&table_id)
+            .map_err(|e| {
+                let e: ConflictableTransactionError<Infallible> = e.into();
+                ErrorCode::from(e)
+            })?;
+
+        if let Some(names) = table_names {
+            let db_id = self
+                .txn_get_database_id(&names.db_name, txn_tree)
+                .map_err(|e| {
                     let e: ConflictableTransactionError<Infallible> = e.into();
                     ErrorCode::from(e)
                 })?;
 
-                Ok(AppliedState::TableMeta(Change::new_with_id(
-                    req.table_id,
-                    Some(prev),
-                    Some(sv),
-                )))
+            // The database of the table is dropped, even if another one of the same name exists.
+            if db_id != Some(names.database_id) {
+                return Ok(None);
+            }
+            return Ok(Some(WatchEvent::table_key(
+                &names.db_name,
+                &names.table_name,
+            )));
+        }
+
+        let mut table_key = None;
+        for r in self.table_lookup().range(..)? {
+            let (k, seq_table_id) = r?;
+            if seq_table_id.data.0 == table_id {
+                table_key = Some(k);
+                break;
+            }
+        }
+
+        let table_key = match table_key {
+            Some(table_key) => table_key,
+            None => return Ok(None),
+        };
+
+        for r in self.database_lookup().range(..)? {
+            let (db_name, seq_db_id) = r?;
+            if seq_db_id.data == table_key.database_id {
+                return Ok(Some(WatchEvent::table_key(&db_name, &table_key.table_name)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// List the names of the tables in the database of `db_id`.
+    fn list_table_names(&self, db_id: u64) -> common_exception::Result<Vec<String>> {
+        let mut table_names = vec![];
+        for r in self.table_lookup().range(..)? {
+            let (k, _) = r?;
+            if k.database_id == db_id {
+                table_names.push(k.table_name);
             }
         }
+        Ok(table_names)
     }
 
     async fn sub_tree_upsert<'s, V, KS>(
@@ -775,6 +1049,248 @@ impl StateMachine {
         Ok(curr.0)
     }
 
+    /// Check that every table in the batch can be created, taking the tables created by the
+    /// preceding requests in the same batch into account.
+    fn txn_check_create_tables(
+        &self,
+        req: &CreateTablesReq,
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<()> {
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        let mut created = HashSet::new();
+
+        for r in &req.reqs {
+            let db_id = self
+                .txn_get_database_id(&r.db, txn_tree)
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?
+                .ok_or_else(|| ErrorCode::UnknownDatabase(r.db.clone()))?;
+
+            let lookup_key = TableLookupKey {
+                database_id: db_id,
+                table_name: r.table.clone(),
+            };
+            let present = table_lookup_tree.get(&lookup_key).map_err(|e| {
+                let e: ConflictableTransactionError<Infallible> = e.into();
+                ErrorCode::from(e)
+            })?;
+
+            let name = (db_id, r.table.clone());
+            if (present.is_some() || created.contains(&name)) && !r.if_not_exists {
+                return Err(ErrorCode::TableAlreadyExists(format!(
+                    "table exists: {}",
+                    r.table
+                )));
+            }
+            created.insert(name);
+        }
+
+        Ok(())
+    }
+
+    /// Check that every table in the batch can be dropped, taking the tables dropped by the
+    /// preceding requests in the same batch into account.
+    fn txn_check_drop_tables(
+        &self,
+        req: &DropTablesReq,
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<()> {
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        let mut dropped = HashSet::new();
+
+        for r in &req.reqs {
+            let db_id = self
+                .txn_get_database_id(&r.db, txn_tree)
+                .map_err(|e| {
+                    let e: ConflictableTransactionError<Infallible> = e.into();
+                    ErrorCode::from(e)
+                })?
+                .ok_or_else(|| ErrorCode::UnknownDatabase(r.db.clone()))?;
+
+            let lookup_key = TableLookupKey {
+                database_id: db_id,
+                table_name: r.table.clone(),
+            };
+            let present = table_lookup_tree.get(&lookup_key).map_err(|e| {
+                let e: ConflictableTransactionError<Infallible> = e.into();
+                ErrorCode::from(e)
+            })?;
+
+            let name = (db_id, r.table.clone());
+            if (present.is_none() || dropped.contains(&name)) && !r.if_exists {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "Unknown table: '{:}'",
+                    r.table
+                )));
+            }
+            dropped.insert(name);
+        }
+
+        Ok(())
+    }
+
+    /// Remove a table and its meta if it is expired at `now`. Returns whether it is removed.
+    fn txn_purge_expired_table(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        now: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> TxnResult<bool> {
+        let db_id = match self.txn_get_database_id(db_name, txn_tree)? {
+            Some(db_id) => db_id,
+            None => return Ok(false),
+        };
+
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        match table_lookup_tree.get(&lookup_key)? {
+            Some(seq_table_id) if seq_table_id.get_expire_at() < now => {}
+            _ => return Ok(false),
+        };
+
+        self.txn_remove_table(db_name, lookup_key, txn_tree)
+    }
+
+    /// Remove a table and its meta, along with a change for `watch`. Returns whether it is removed.
+    fn txn_remove_table(
+        &self,
+        db_name: &str,
+        lookup_key: TableLookupKey,
+        txn_tree: &TransactionSledTree,
+    ) -> TxnResult<bool> {
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        let (prev, _) = self.sub_txn_tree_upsert_meta(
+            &table_lookup_tree,
+            &lookup_key,
+            &MatchSeq::Any,
+            Operation::Delete,
+            None,
+        )?;
+
+        let seq_table_id = match prev {
+            Some(seq_table_id) => seq_table_id,
+            None => return Ok(false),
+        };
+
+        let tables = txn_tree.key_space::<Tables>();
+        self.sub_txn_tree_upsert_meta(
+            &tables,
+            &seq_table_id.data.0,
+            &MatchSeq::Any,
+            Operation::Delete,
+            None,
+        )?;
+        txn_tree
+            .key_space::<TableNames>()
+            .remove(&seq_table_id.data.0)?;
+
+        let table_name = lookup_key.table_name;
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+        self.txn_append_meta_change(WatchEvent::table_key(db_name, &table_name), None, txn_tree)?;
+
+        tracing::debug!("removed table: {}.{}", db_name, table_name);
+        Ok(true)
+    }
+
+    /// Remove a database and its meta if it is expired at `now`, along with all of its tables.
+    /// Returns whether it is removed.
+    fn txn_purge_expired_database_by_name(
+        &self,
+        name: &str,
+        now: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<bool> {
+        // A sled transaction can not scan, the tables are listed with the tree before this log,
+        // which is the same as in this transaction except for the tables removed by it, and
+        // removing a table twice is a no-op.
+        let table_names = match self.database_lookup().get(&name.to_string())? {
+            Some(seq_db_id) => self.list_table_names(seq_db_id.data)?,
+            None => vec![],
+        };
+        self.txn_purge_expired_database(name, &table_names, now, txn_tree)
+            .map_err(|e| {
+                let e: ConflictableTransactionError<Infallible> = e.into();
+                ErrorCode::from(e)
+            })
+    }
+
+    /// Remove a database and its meta if it is expired at `now`, along with all of its tables,
+    /// which are listed in `table_names`. Returns whether it is removed.
+    fn txn_purge_expired_database(
+        &self,
+        name: &str,
+        table_names: &[String],
+        now: u64,
+        txn_tree: &TransactionSledTree,
+    ) -> TxnResult<bool> {
+        let name = name.to_string();
+
+        let db_lookup_tree = txn_tree.key_space::<DatabaseLookup>();
+        let seq_db_id = match db_lookup_tree.get(&name)? {
+            Some(seq_db_id) if seq_db_id.get_expire_at() < now => seq_db_id,
+            _ => return Ok(false),
+        };
+
+        // A table can not outlive its database.
+        for table_name in table_names {
+            let lookup_key = TableLookupKey {
+                database_id: seq_db_id.data,
+                table_name: table_name.to_string(),
+            };
+            self.txn_remove_table(&name, lookup_key, txn_tree)?;
+        }
+
+        self.sub_txn_tree_upsert_meta(
+            &db_lookup_tree,
+            &name,
+            &MatchSeq::Any,
+            Operation::Delete,
+            None,
+        )?;
+
+        let dbs = txn_tree.key_space::<Databases>();
+        self.sub_txn_tree_upsert_meta(
+            &dbs,
+            &seq_db_id.data,
+            &MatchSeq::Any,
+            Operation::Delete,
+            None,
+        )?;
+
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+        self.txn_append_meta_change(WatchEvent::database_key(&name), None, txn_tree)?;
+
+        tracing::debug!("purged expired database: {}", name);
+        Ok(true)
+    }
+
+    /// Record a change of a database or a table for `watch`, with a newly allocated change seq.
+    /// Only the latest `MAX_META_CHANGES` changes are kept.
+    fn txn_append_meta_change(
+        &self,
+        key: String,
+        value: Option<WatchValue>,
+        txn_tree: &TransactionSledTree,
+    ) -> TxnResult<()> {
+        let seq = self.txn_incr_seq(MetaChanges::NAME, txn_tree)?;
+
+        let changes = txn_tree.key_space::<MetaChanges>();
+        changes.insert(&seq, &WatchEvent { seq, key, value })?;
+        if seq > MAX_META_CHANGES {
+            changes.remove(&(seq - MAX_META_CHANGES))?;
+        }
+
+        tracing::debug!("applied meta change: seq: {}", seq);
+        Ok(())
+    }
+
     #[allow(clippy::type_complexity)]
     fn sub_txn_tree_upsert<'s, V, KS>(
         &'s self,
@@ -793,6 +1309,45 @@ impl StateMachine {
         // If prev is timed out, treat it as a None.
         let prev = Self::unexpired_opt(prev);
 
+        self.sub_txn_tree_upsert_prev(sub_tree, key, prev, seq, value_op, value_meta)
+    }
+
+    /// Upsert a record of a database or a table, an expired one is still treated as present.
+    ///
+    /// Whether a record is expired depends on the clock of a node. An expired database or table
+    /// is thus removed only by a `Cmd::PurgeExpiredMeta` or replaced by a create, with the `now`
+    /// assigned by the proposer, so that every node applies a log to the same state.
+    #[allow(clippy::type_complexity)]
+    fn sub_txn_tree_upsert_meta<'s, V, KS>(
+        &'s self,
+        sub_tree: &AsTxnKeySpace<'s, KS>,
+        key: &KS::K,
+        seq: &MatchSeq,
+        value_op: Operation<V>,
+        value_meta: Option<KVMeta>,
+    ) -> TxnResult<(Option<SeqV<V>>, Option<SeqV<V>>)>
+    where
+        V: Clone + Debug,
+        KS: SledKeySpace<V = SeqV<V>>,
+    {
+        let prev = sub_tree.get(key)?;
+        self.sub_txn_tree_upsert_prev(sub_tree, key, prev, seq, value_op, value_meta)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn sub_txn_tree_upsert_prev<'s, V, KS>(
+        &'s self,
+        sub_tree: &AsTxnKeySpace<'s, KS>,
+        key: &KS::K,
+        prev: Option<SeqV<V>>,
+        seq: &MatchSeq,
+        value_op: Operation<V>,
+        value_meta: Option<KVMeta>,
+    ) -> TxnResult<(Option<SeqV<V>>, Option<SeqV<V>>)>
+    where
+        V: Clone + Debug,
+        KS: SledKeySpace<V = SeqV<V>>,
+    {
         if seq.match_seq(&prev).is_err() {
             return Ok((prev.clone(), prev));
         }
@@ -844,6 +1399,7 @@ impl StateMachine {
         let seq_dbi = self
             .database_lookup()
             .get(db_name)?
+            .and_then(Self::unexpired)
             .ok_or_else(|| ErrorCode::UnknownDatabase(db_name.to_string()))?;
 
         Ok(seq_dbi.data)
@@ -1001,6 +1557,44 @@ impl StateMachine {
         Ok(result)
     }
 
+    /// List the databases and tables that are expired at `now`, as `(db_names, (db_name, table_name)s)`.
+    ///
+    /// They are not removed until a `Cmd::PurgeExpiredMeta` listing them is applied.
+    #[allow(clippy::type_complexity)]
+    pub fn list_expired_meta(
+        &self,
+        now: u64,
+    ) -> common_exception::Result<(Vec<String>, Vec<(String, String)>)> {
+        let mut db_names = HashMap::new();
+        let mut databases = vec![];
+
+        for r in self.database_lookup().range(..)? {
+            let (db_name, seq_db_id) = r?;
+            if seq_db_id.get_expire_at() < now {
+                databases.push(db_name.clone());
+            }
+            db_names.insert(seq_db_id.data, db_name);
+        }
+
+        let mut tables = vec![];
+
+        for r in self.table_lookup().range(..)? {
+            let (k, seq_table_id) = r?;
+            if seq_table_id.get_expire_at() >= now {
+                continue;
+            }
+            if let Some(db_name) = db_names.get(&k.database_id) {
+                tables.push((db_name.clone(), k.table_name));
+            }
+        }
+
+        Ok((databases, tables))
+    }
+
+    fn expire_meta(expire_at: &Option<u64>) -> Option<KVMeta> {
+        expire_at.map(|t| KVMeta { expire_at: Some(t) })
+    }
+
     pub fn unexpired_opt<V: Debug>(seq_value: Option<SeqV<V>>) -> Option<SeqV<V>> {
         seq_value.and_then(Self::unexpired)
     }
@@ -1023,10 +1617,7 @@ impl StateMachine {
 
         // TODO(xp): maybe it needs a expiration queue for efficient cleaning up.
 
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let now = now_secs();
 
         tracing::debug!("seq_value: {:?} now: {}", seq_value, now);
 
@@ -1094,4 +1685,13 @@ impl StateMachine {
     pub fn table_lookup(&self) -> AsKeySpace<TableLookup> {
         self.sm_tree.key_space()
     }
+
+    pub fn table_names(&self) -> AsKeySpace<TableNames> {
+        self.sm_tree.key_space()
+    }
+
+    /// Changes of databases and tables, to serve `watch`.
+    pub fn meta_changes(&self) -> AsKeySpace<MetaChanges> {
+        self.sm_tree.key_space()
+    }
 }
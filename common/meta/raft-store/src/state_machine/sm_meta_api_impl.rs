@@ -13,32 +13,49 @@
 // limitations under the License.
 
 use std::convert::TryInto;
+use std::ops::Bound;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
+use common_exception::SerializedError;
 use common_meta_api::MetaApi;
+use common_meta_types::now_secs;
 use common_meta_types::Change;
+use common_meta_types::ClusterStatus;
 use common_meta_types::Cmd;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReply;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DatabaseMeta;
 use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReply;
+use common_meta_types::DropTablesReq;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchReply;
+use common_meta_types::WatchReq;
 use common_tracing::tracing;
 
 use crate::state_machine::StateMachine;
@@ -53,6 +70,9 @@ impl MetaApi for StateMachine {
         let cmd = Cmd::CreateDatabase {
             name: req.db.clone(),
             meta: req.meta.clone(),
+            expire_at: req.expire_at,
+            // Replace an expired database of the same name.
+            now: Some(now_secs()),
         };
 
         let res = self.sm_tree.txn(true, |t| {
@@ -119,6 +139,10 @@ impl MetaApi for StateMachine {
         let it = self.database_lookup().range(..)?;
         for r in it {
             let (db_name, seq_id) = r?;
+            let seq_id = match Self::unexpired(seq_id) {
+                Some(seq_id) => seq_id,
+                None => continue,
+            };
             let seq_meta = self.get_database_meta_by_id(&seq_id.data)?;
 
             let db_info = DatabaseInfo {
@@ -132,6 +156,46 @@ impl MetaApi for StateMachine {
         Ok(res)
     }
 
+    async fn list_databases_page(
+        &self,
+        req: ListDatabasePageReq,
+    ) -> Result<ListDatabasePageReply, ErrorCode> {
+        check_page_limit(req.limit)?;
+
+        let mut entries = vec![];
+
+        let start = page_start(&req.prefix, &req.token);
+        for r in self.database_lookup().range((start, Bound::Unbounded))? {
+            let (db_name, seq_id) = r?;
+            if !db_name.starts_with(&req.prefix) {
+                break;
+            }
+            if let Some(seq_id) = Self::unexpired(seq_id) {
+                entries.push((db_name, seq_id.data));
+                if entries.len() > req.limit {
+                    break;
+                }
+            }
+        }
+
+        let (entries, next_token) = take_page(entries, req.limit);
+
+        let mut databases = vec![];
+        for (db_name, db_id) in entries {
+            let seq_meta = self.get_database_meta_by_id(&db_id)?;
+            databases.push(Arc::new(DatabaseInfo {
+                database_id: db_id,
+                db: db_name,
+                meta: seq_meta.data,
+            }));
+        }
+
+        Ok(ListDatabasePageReply {
+            databases,
+            next_token,
+        })
+    }
+
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply, ErrorCode> {
         let db_name = &req.db;
         let table_name = &req.table;
@@ -145,6 +209,9 @@ impl MetaApi for StateMachine {
             db_name: db_name.clone(),
             table_name: table_name.clone(),
             table_meta,
+            expire_at: req.expire_at,
+            // Replace an expired table of the same name.
+            now: Some(now_secs()),
         };
 
         let res = self.sm_tree.txn(true, |t| {
@@ -195,6 +262,39 @@ impl MetaApi for StateMachine {
         Ok(DropTableReply {})
     }
 
+    async fn create_tables(&self, req: CreateTablesReq) -> Result<CreateTablesReply, ErrorCode> {
+        let cr = Cmd::CreateTables(req);
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cr, &t).unwrap();
+            Ok(r)
+        })?;
+
+        let res: Result<Vec<Change<TableMeta>>, SerializedError> = res.try_into().unwrap();
+        let replies = res?
+            .into_iter()
+            .map(|mut ch| CreateTableReply {
+                table_id: ch.ident.take().unwrap(),
+            })
+            .collect();
+
+        Ok(CreateTablesReply { replies })
+    }
+
+    async fn drop_tables(&self, req: DropTablesReq) -> Result<DropTablesReply, ErrorCode> {
+        let cr = Cmd::DropTables(req);
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cr, &t).unwrap();
+            Ok(r)
+        })?;
+
+        let res: Result<Vec<Change<TableMeta>>, SerializedError> = res.try_into().unwrap();
+        let replies = res?.iter().map(|_| DropTableReply {}).collect();
+
+        Ok(DropTablesReply { replies })
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, ErrorCode> {
         let db = &req.db_name;
         let table_name = &req.table_name;
@@ -207,6 +307,7 @@ impl MetaApi for StateMachine {
                 database_id: db_id,
                 table_name: table_name.to_string(),
             })?
+            .and_then(Self::unexpired)
             .ok_or_else(|| ErrorCode::UnknownTable(format!("Unknown table: '{:}'", table_name)))?;
         let table_id = table_id.data.0;
 
@@ -236,6 +337,10 @@ impl MetaApi for StateMachine {
         let tables_iter = self.table_lookup().range(..)?;
         for r in tables_iter {
             let (k, seq_table_id) = r?;
+            let seq_table_id = match Self::unexpired(seq_table_id) {
+                Some(seq_table_id) => seq_table_id,
+                None => continue,
+            };
 
             let got_db_id = k.database_id;
             let table_name = k.table_name;
@@ -264,6 +369,67 @@ impl MetaApi for StateMachine {
         Ok(tbls)
     }
 
+    async fn list_tables_page(
+        &self,
+        req: ListTablePageReq,
+    ) -> Result<ListTablePageReply, ErrorCode> {
+        check_page_limit(req.limit)?;
+
+        let db_name = &req.db_name;
+        let db_id = self.get_database_id(db_name)?;
+
+        let mut entries = vec![];
+
+        let key = |table_name| TableLookupKey {
+            database_id: db_id,
+            table_name,
+        };
+        // The keys of a database are contiguous but not in the order of name, see
+        // `TableLookupKey::ser`. Thus the scan starts from the first key of the database and skips
+        // the names without the prefix, a page is in the order of the keys.
+        let start = match &req.token {
+            Some(token) => Bound::Excluded(key(token.clone())),
+            None => Bound::Included(key("".to_string())),
+        };
+        for r in self.table_lookup().range((start, Bound::Unbounded))? {
+            let (k, seq_table_id) = r?;
+            if k.database_id != db_id {
+                break;
+            }
+            if !k.table_name.starts_with(&req.prefix) {
+                continue;
+            }
+            if let Some(seq_table_id) = Self::unexpired(seq_table_id) {
+                entries.push((k.table_name, seq_table_id.data.0));
+                if entries.len() > req.limit {
+                    break;
+                }
+            }
+        }
+
+        let (entries, next_token) = take_page(entries, req.limit);
+
+        let tables = self.tables();
+        let mut tbls = vec![];
+        for (table_name, table_id) in entries {
+            let seq_table_meta = tables.get(&table_id)?.ok_or_else(|| {
+                ErrorCode::IllegalMetaState(format!(" table of id {}, not found", table_id))
+            })?;
+
+            tbls.push(Arc::new(TableInfo::new(
+                db_name,
+                &table_name,
+                TableIdent::new(table_id, seq_table_meta.seq),
+                seq_table_meta.data,
+            )));
+        }
+
+        Ok(ListTablePageReply {
+            tables: tbls,
+            next_token,
+        })
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -292,7 +458,9 @@ impl MetaApi for StateMachine {
         })?;
         if !res.changed() {
             let ch: Change<TableMeta> = res.try_into().unwrap();
-            let (prev, _result) = ch.unwrap();
+            let (prev, _result) = ch.unpack();
+            let prev = prev
+                .ok_or_else(|| ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id)))?;
 
             return Err(ErrorCode::TableVersionMissMatch(format!(
                 "targeting version {:?}, current version {}",
@@ -303,7 +471,112 @@ impl MetaApi for StateMachine {
         Ok(UpsertTableOptionReply {})
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply, ErrorCode> {
+        let table_id = req.table_id;
+        let cmd = Cmd::UpdateTableMeta(req);
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+
+        let applied = res.changed();
+        let ch: Change<TableMeta> = res.try_into().unwrap();
+        let (prev, result) = match ch.unpack() {
+            (Some(prev), Some(result)) => (prev, result),
+            _ => {
+                return Err(ErrorCode::UnknownTableId(format!("table_id:{}", table_id)));
+            }
+        };
+
+        Ok(UpdateTableMetaReply {
+            applied,
+            prev_version: prev.seq,
+            prev_meta: prev.data,
+            version: result.seq,
+        })
+    }
+
+    async fn watch(&self, req: WatchReq) -> Result<WatchReply, ErrorCode> {
+        // The changes right after `after_seq` must be kept, or the client would miss some of them.
+        if let Some(r) = self.meta_changes().range(..)?.next() {
+            let (oldest, _event) = r?;
+            if oldest - 1 > req.after_seq {
+                return Err(ErrorCode::MetaChangesCompacted(format!(
+                    "the changes after seq {} are removed, the oldest kept change is {}",
+                    req.after_seq, oldest
+                )));
+            }
+        }
+
+        let mut events = vec![];
+
+        let range = (Bound::Excluded(req.after_seq), Bound::Unbounded);
+        for r in self.meta_changes().range(range)? {
+            let (_seq, event) = r?;
+            if event.key.starts_with(&req.prefix) {
+                events.push(event);
+            }
+        }
+
+        Ok(WatchReply { events })
+    }
+
+    async fn get_cluster_status(
+        &self,
+        _req: GetClusterStatusReq,
+    ) -> Result<ClusterStatus, ErrorCode> {
+        // A state machine knows the membership it applied, but nothing about the raft roles.
+        let last_applied = self.get_last_applied()?;
+        let membership = self
+            .get_membership()?
+            .map(|m| m.members.into_iter().collect())
+            .unwrap_or_default();
+
+        Ok(ClusterStatus {
+            leader: None,
+            term: last_applied.term,
+            nodes: vec![],
+            membership,
+        })
+    }
+
     fn name(&self) -> String {
         "StateMachine".to_string()
     }
 }
+
+fn check_page_limit(limit: usize) -> Result<(), ErrorCode> {
+    if limit == 0 {
+        return Err(ErrorCode::IllegalMetaOperationArgument(
+            "the limit of a page must be greater than 0",
+        ));
+    }
+    Ok(())
+}
+
+/// The first name to list: the one after the `token` of the previous page, but not before `prefix`.
+fn page_start(prefix: &str, token: &Option<String>) -> Bound<String> {
+    match token {
+        Some(token) if token.as_str() >= prefix => Bound::Excluded(token.clone()),
+        _ => Bound::Included(prefix.to_string()),
+    }
+}
+
+/// Take the first `limit` of the entries scanned in the order of the keys,
+/// and returns the token to list the rest, if there are any.
+///
+/// The token is the name of the last entry taken,
+/// thus it does not depend on the state of the node that built it.
+fn take_page<T>(mut entries: Vec<(String, T)>, limit: usize) -> (Vec<(String, T)>, Option<String>) {
+    if entries.len() <= limit {
+        return (entries, None);
+    }
+
+    entries.truncate(limit);
+    let next_token = entries.last().map(|(name, _)| name.clone());
+    (entries, next_token)
+}
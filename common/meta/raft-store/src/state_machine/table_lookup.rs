@@ -32,6 +32,11 @@ pub struct TableLookupKey {
 }
 
 impl SledOrderedSerde for TableLookupKey {
+    /// The uvarint database id followed by the uvarint length and the bytes of the table name.
+    ///
+    /// This is the on-disk format of the existing trees and snapshots, it must not be changed.
+    /// The keys of a database are contiguous since a uvarint is never a prefix of another one,
+    /// but inside a database they are not in the order of name.
     fn ser(&self) -> Result<IVec, ErrorCode> {
         let mut buf = BytesMut::new();
         if buf.write_uvarint(self.database_id).is_ok() && buf.write_string(&self.table_name).is_ok()
@@ -72,3 +77,11 @@ impl fmt::Display for TableLookupValue {
         write!(f, "{}", self.0)
     }
 }
+
+/// The database and the name of a table, looked up by the table id.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TableNameValue {
+    pub database_id: DbKey,
+    pub db_name: String,
+    pub table_name: String,
+}
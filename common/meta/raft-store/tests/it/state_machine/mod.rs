@@ -25,12 +25,15 @@ use async_raft::LogId;
 use common_base::tokio;
 use common_exception::ErrorCode;
 use common_meta_api::KVApi;
+use common_meta_api::MetaApi;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot;
 use common_meta_raft_store::state_machine::testing::pretty_snapshot_iter;
 use common_meta_raft_store::state_machine::testing::snapshot_logs;
 use common_meta_raft_store::state_machine::AppliedState;
 use common_meta_raft_store::state_machine::SerializableSnapshot;
 use common_meta_raft_store::state_machine::StateMachine;
+use common_meta_raft_store::state_machine::TableLookupKey;
+use common_meta_raft_store::state_machine::MAX_META_CHANGES;
 use common_meta_types::Change;
 use common_meta_types::Cmd;
 use common_meta_types::DatabaseMeta;
@@ -41,6 +44,8 @@ use common_meta_types::Operation;
 use common_meta_types::SeqV;
 use common_meta_types::TableMeta;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchReq;
+use common_meta_types::WatchValue;
 use common_tracing::tracing;
 use maplit::btreeset;
 use maplit::hashmap;
@@ -165,6 +170,8 @@ async fn test_state_machine_apply_add_database() -> anyhow::Result<()> {
                         engine: c.engine.to_string(),
                         ..Default::default()
                     },
+                    expire_at: None,
+                    now: None,
                 },
                 &t,
             )
@@ -207,6 +214,8 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
                     engine: "defeault".to_string(),
                     ..Default::default()
                 },
+                expire_at: None,
+                now: None,
             },
             &t,
         )
@@ -219,6 +228,8 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
                 db_name: "db1".to_string(),
                 table_name: "tb1".to_string(),
                 table_meta: Default::default(),
+                expire_at: None,
+                now: None,
             },
             &t,
         )
@@ -283,27 +294,41 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
         version = got.seq;
     }
 
-    tracing::info!("--- update with invalid table_id");
+    tracing::info!("--- check the update is recorded for watch");
     {
-        m.sm_tree.txn(true, |t| {
-            let r = m.apply_cmd(
+        let (_seq, event) = m.meta_changes().range(..)?.last().unwrap()?;
+        assert_eq!("db1/tb1", event.key);
+        assert_eq!(
+            Some(WatchValue::Table(
+                m.get_table_meta_by_id(&table_id)?.unwrap().data
+            )),
+            event.value
+        );
+    }
+
+    tracing::info!("--- check the names of the table are looked up by id");
+    {
+        let got = m.table_names().get(&table_id)?.unwrap();
+        assert_eq!("db1", got.db_name);
+        assert_eq!("tb1", got.table_name);
+    }
+
+    tracing::info!("--- update with invalid table_id is applied as no prev");
+    {
+        let resp = m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
                 &Cmd::UpsertTableOptions(UpsertTableOptionReq {
                     table_id: 0,
                     seq: MatchSeq::Exact(version - 1),
                     options: hashmap! {},
                 }),
                 &t,
-            );
-
-            let err = r.unwrap_err();
-
-            assert_eq!(
-                ErrorCode::UnknownTableId("Unknown table id").code(),
-                err.code()
-            );
-
-            Ok(AppliedState::None)
+            )
+            .unwrap())
         })?;
+
+        let ch: Change<TableMeta> = resp.try_into().unwrap();
+        assert_eq!((None, None), ch.unpack());
     }
 
     tracing::info!("--- update with mismatched seq wont update anything");
@@ -377,6 +402,289 @@ async fn test_state_machine_apply_upsert_table_option() -> anyhow::Result<()> {
         }
     }
 
+    tracing::info!("--- the update of a table of a dropped database is not recorded for watch");
+    {
+        m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::DropDatabase {
+                    name: "db1".to_string(),
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+        m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateDatabase {
+                    name: "db1".to_string(),
+                    meta: Default::default(),
+                    expire_at: None,
+                    now: None,
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+
+        let last_change = m.meta_changes().range_keys(..)?.last().cloned();
+        m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::UpsertTableOptions(UpsertTableOptionReq {
+                    table_id,
+                    seq: MatchSeq::Any,
+                    options: hashmap! {},
+                }),
+                &t,
+            )
+            .unwrap())
+        })?;
+        assert_eq!(
+            last_change,
+            m.meta_changes().range_keys(..)?.last().cloned()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_trim_meta_changes() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let m = StateMachine::open(&tc.raft_config, 1).await?;
+
+    tracing::info!("--- apply 2 more changes than the kept ones");
+    for i in 0..MAX_META_CHANGES + 2 {
+        m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateDatabase {
+                    name: format!("db{}", i),
+                    meta: DatabaseMeta::default(),
+                    expire_at: None,
+                    now: None,
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+    }
+
+    tracing::info!("--- only the latest changes are kept");
+    {
+        let seqs = m.meta_changes().range_keys(..)?;
+        assert_eq!(MAX_META_CHANGES as usize, seqs.len());
+        assert_eq!(Some(&3), seqs.first());
+        assert_eq!(Some(&(MAX_META_CHANGES + 2)), seqs.last());
+    }
+
+    tracing::info!("--- watch after a removed change is an error");
+    {
+        let res = m
+            .watch(WatchReq {
+                prefix: "".to_string(),
+                after_seq: 1,
+            })
+            .await;
+        let err = res.unwrap_err();
+        assert_eq!(ErrorCode::MetaChangesCompacted("").code(), err.code());
+
+        let res = m
+            .watch(WatchReq {
+                prefix: "".to_string(),
+                after_seq: 2,
+            })
+            .await?;
+        assert_eq!(MAX_META_CHANGES as usize, res.events.len());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_purge_expired_meta() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let m = StateMachine::open(&tc.raft_config, 1).await?;
+
+    tracing::info!("--- prepare databases and tables expiring at 100, 300 or never");
+
+    let dbs = [("db1", Some(100)), ("db2", Some(300)), ("db3", None)];
+    for (name, expire_at) in dbs {
+        m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateDatabase {
+                    name: name.to_string(),
+                    meta: Default::default(),
+                    expire_at,
+                    now: None,
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+    }
+
+    let tbls = [
+        ("db3", "tb1", Some(100)),
+        ("db3", "tb2", Some(300)),
+        ("db1", "tb3", None),
+    ];
+    for (db_name, name, expire_at) in tbls {
+        m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateTable {
+                    db_name: db_name.to_string(),
+                    table_name: name.to_string(),
+                    table_meta: Default::default(),
+                    expire_at,
+                    now: None,
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+    }
+
+    let db1_id = m.database_lookup().get(&"db1".to_string())?.unwrap().data;
+    let db3_id = m.get_database_id(&"db3".to_string())?;
+    let table_lookup_key = |name: &str| TableLookupKey {
+        database_id: db3_id,
+        table_name: name.to_string(),
+    };
+
+    tracing::info!("--- an expired database is not replaced until it is purged");
+    {
+        let res = m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateDatabase {
+                    name: "db1".to_string(),
+                    meta: Default::default(),
+                    expire_at: None,
+                    now: None,
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+        let ch: Change<DatabaseMeta> = res.try_into().unwrap();
+        assert_eq!(Some(db1_id), ch.ident);
+        assert!(!ch.changed());
+    }
+
+    tracing::info!("--- list expired at 200");
+    {
+        let (databases, tables) = m.list_expired_meta(200)?;
+        assert_eq!(vec!["db1".to_string()], databases);
+        assert_eq!(vec![("db3".to_string(), "tb1".to_string())], tables);
+    }
+
+    tracing::info!("--- purge at 200, entries not expired or absent are kept");
+    {
+        m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::PurgeExpiredMeta {
+                    now: 200,
+                    databases: vec!["db1".to_string(), "db2".to_string(), "db3".to_string()],
+                    tables: vec![
+                        ("db3".to_string(), "tb1".to_string()),
+                        ("db3".to_string(), "tb2".to_string()),
+                        ("db3".to_string(), "absent".to_string()),
+                        ("absent".to_string(), "tb1".to_string()),
+                    ],
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+
+        let lookup = m.database_lookup();
+        assert!(lookup.get(&"db1".to_string())?.is_none());
+        assert!(lookup.get(&"db2".to_string())?.is_some());
+        assert!(lookup.get(&"db3".to_string())?.is_some());
+
+        let lookup = m.table_lookup();
+        assert!(lookup.get(&table_lookup_key("tb1"))?.is_none());
+        assert!(lookup.get(&table_lookup_key("tb2"))?.is_some());
+
+        // The tables of a purged database are purged along with it.
+        let tb3_key = TableLookupKey {
+            database_id: db1_id,
+            table_name: "tb3".to_string(),
+        };
+        assert!(lookup.get(&tb3_key)?.is_none());
+
+        let (databases, tables) = m.list_expired_meta(200)?;
+        assert!(databases.is_empty());
+        assert!(tables.is_empty());
+    }
+
+    tracing::info!("--- a create with now replaces an expired entry in the same log");
+    {
+        let db2_id = m.get_database_id(&"db2".to_string())?;
+        let tb2_id = m
+            .table_lookup()
+            .get(&table_lookup_key("tb2"))?
+            .unwrap()
+            .data
+            .0;
+
+        // Not expired at 200 yet.
+        let res = m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateDatabase {
+                    name: "db2".to_string(),
+                    meta: Default::default(),
+                    expire_at: None,
+                    now: Some(200),
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+        let ch: Change<DatabaseMeta> = res.try_into().unwrap();
+        assert_eq!(Some(db2_id), ch.ident);
+        assert!(!ch.changed());
+
+        let res = m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateDatabase {
+                    name: "db2".to_string(),
+                    meta: Default::default(),
+                    expire_at: None,
+                    now: Some(400),
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+        let ch: Change<DatabaseMeta> = res.try_into().unwrap();
+        assert_ne!(Some(db2_id), ch.ident);
+        assert!(ch.changed());
+
+        let res = m.sm_tree.txn(true, |t| {
+            Ok(m.apply_cmd(
+                &Cmd::CreateTable {
+                    db_name: "db3".to_string(),
+                    table_name: "tb2".to_string(),
+                    table_meta: Default::default(),
+                    expire_at: None,
+                    now: Some(400),
+                },
+                &t,
+            )
+            .unwrap())
+        })?;
+        let ch: Change<TableMeta> = res.try_into().unwrap();
+        assert_ne!(Some(tb2_id), ch.ident);
+        assert!(ch.changed());
+        let seq_table_id = m.table_lookup().get(&table_lookup_key("tb2"))?.unwrap();
+        assert_eq!(u64::MAX, seq_table_id.get_expire_at());
+    }
+
     Ok(())
 }
 
@@ -14,6 +14,7 @@
 
 use std::ops::Bound;
 
+use common_meta_raft_store::state_machine::TableLookupKey;
 use common_meta_sled_store::sled;
 use common_meta_sled_store::SledOrderedSerde;
 use common_meta_sled_store::SledRangeSerde;
@@ -62,3 +63,17 @@ fn test_node_id_range_serde() -> anyhow::Result<()> {
     assert_eq!(want, got);
     Ok(())
 }
+
+#[test]
+fn test_table_lookup_key_serde() -> anyhow::Result<()> {
+    // The format of the existing trees and snapshots: uvarint(300), uvarint(2), "tb".
+    let stored = sled::IVec::from(vec![0xac, 0x02, 0x02, b't', b'b']);
+    let key = TableLookupKey {
+        database_id: 300,
+        table_name: "tb".to_string(),
+    };
+
+    assert_eq!(key, TableLookupKey::de(&stored)?);
+    assert_eq!(stored, key.ser()?);
+    Ok(())
+}
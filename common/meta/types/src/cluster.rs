@@ -43,6 +43,27 @@ impl fmt::Display for Node {
     }
 }
 
+/// Get the raft state of the meta cluster from the leader.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct GetClusterStatusReq {}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NodeStatus {
+    pub node_id: NodeId,
+    /// The last applied log index of the leader itself,
+    /// or the last log index the leader has replicated to a follower.
+    pub last_applied: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClusterStatus {
+    pub leader: Option<NodeId>,
+    pub term: u64,
+    /// The replication state of every node in `membership`, in node id order.
+    pub nodes: Vec<NodeStatus>,
+    pub membership: Vec<NodeId>,
+}
+
 /// Query node
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Default)]
 #[serde(default)]
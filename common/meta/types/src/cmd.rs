@@ -18,12 +18,15 @@ use async_raft::NodeId;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::CreateTablesReq;
 use crate::DatabaseMeta;
+use crate::DropTablesReq;
 use crate::KVMeta;
 use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::TableMeta;
+use crate::UpdateTableMetaReq;
 use crate::UpsertTableOptionReq;
 
 /// A Cmd describes what a user want to do to raft state machine
@@ -38,7 +41,18 @@ pub enum Cmd {
     AddNode { node_id: NodeId, node: Node },
 
     /// Add a database if absent
-    CreateDatabase { name: String, meta: DatabaseMeta },
+    CreateDatabase {
+        name: String,
+        meta: DatabaseMeta,
+
+        /// Expiration time in second since 1970. `None` means it never expires.
+        expire_at: Option<u64>,
+
+        /// Time in second since 1970, assigned when proposing this log. A database of the same
+        /// name expired at `now` is replaced in the same transaction. `None` never replaces one.
+        #[serde(default)]
+        now: Option<u64>,
+    },
 
     /// Drop a database if absent
     DropDatabase { name: String },
@@ -48,11 +62,44 @@ pub enum Cmd {
         db_name: String,
         table_name: String,
         table_meta: TableMeta,
+
+        /// Expiration time in second since 1970. `None` means it never expires.
+        expire_at: Option<u64>,
+
+        /// Time in second since 1970, assigned when proposing this log. A table of the same
+        /// name expired at `now` is replaced in the same transaction. `None` never replaces one.
+        #[serde(default)]
+        now: Option<u64>,
     },
 
     /// Drop a table if absent
     DropTable { db_name: String, table_name: String },
 
+    /// Create a batch of tables in one transaction.
+    ///
+    /// The batch is checked before applying: if any table can not be created,
+    /// none is created and the error is returned.
+    CreateTables(CreateTablesReq),
+
+    /// Drop a batch of tables in one transaction.
+    ///
+    /// The batch is checked before applying: if any table can not be dropped,
+    /// none is dropped and the error is returned.
+    DropTables(DropTablesReq),
+
+    /// Remove the listed databases and tables that are expired at `now`.
+    ///
+    /// `now` is assigned when proposing this log, instead of when applying it,
+    /// so that every node purges exactly the same entries.
+    /// A listed entry that is absent or not yet expired at `now` is left as is.
+    PurgeExpiredMeta {
+        /// Time in second since 1970.
+        now: u64,
+        databases: Vec<String>,
+        /// The tables to purge, as `(db_name, table_name)`.
+        tables: Vec<(String, String)>,
+    },
+
     /// Update, remove or insert table options.
     ///
     /// This Cmd requires a present table to operate on.
@@ -62,6 +109,15 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Replace the meta of a table if the seq matches.
+    ///
+    /// This Cmd requires a present table to operate on.
+    /// Otherwise an `UnknownTableId` is returned.
+    ///
+    /// With mismatched seq, it returns a unchanged state: (prev:TableMeta, prev:TableMeta)
+    /// Otherwise it returns the TableMeta before and after update.
+    UpdateTableMeta(UpdateTableMetaReq),
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -89,8 +145,13 @@ impl fmt::Display for Cmd {
             Cmd::AddNode { node_id, node } => {
                 write!(f, "add_node:{}={}", node_id, node)
             }
-            Cmd::CreateDatabase { name, meta } => {
-                write!(f, "create_db:{}={}", name, meta)
+            Cmd::CreateDatabase {
+                name,
+                meta,
+                expire_at,
+                ..
+            } => {
+                write!(f, "create_db:{}={} ({:?})", name, meta, expire_at)
             }
             Cmd::DropDatabase { name } => {
                 write!(f, "drop_db:{}", name)
@@ -99,8 +160,14 @@ impl fmt::Display for Cmd {
                 db_name,
                 table_name,
                 table_meta,
+                expire_at,
+                ..
             } => {
-                write!(f, "create_table:{}-{}={}", db_name, table_name, table_meta)
+                write!(
+                    f,
+                    "create_table:{}-{}={} ({:?})",
+                    db_name, table_name, table_meta, expire_at
+                )
             }
             Cmd::DropTable {
                 db_name,
@@ -108,6 +175,33 @@ impl fmt::Display for Cmd {
             } => {
                 write!(f, "delete_table:{}-{}", db_name, table_name)
             }
+            Cmd::CreateTables(req) => {
+                let names: Vec<_> = req
+                    .reqs
+                    .iter()
+                    .map(|r| format!("{}-{}", r.db, r.table))
+                    .collect();
+                write!(f, "create_tables:{}", names.join(","))
+            }
+            Cmd::DropTables(req) => {
+                let names: Vec<_> = req
+                    .reqs
+                    .iter()
+                    .map(|r| format!("{}-{}", r.db, r.table))
+                    .collect();
+                write!(f, "delete_tables:{}", names.join(","))
+            }
+            Cmd::PurgeExpiredMeta {
+                now,
+                databases,
+                tables,
+            } => {
+                write!(
+                    f,
+                    "purge_expired_meta: now:{} databases:{:?} tables:{:?}",
+                    now, databases, tables
+                )
+            }
             Cmd::UpsertKV {
                 key,
                 seq,
@@ -127,6 +221,13 @@ impl fmt::Display for Cmd {
                     req.table_id, req.seq, req.options
                 )
             }
+            Cmd::UpdateTableMeta(req) => {
+                write!(
+                    f,
+                    "update-table-meta: table-id:{}({:?}) = {:?}",
+                    req.table_id, req.seq, req.new_table_meta
+                )
+            }
         }
     }
 }
@@ -17,6 +17,9 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::ReadConsistency;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
 pub struct DatabaseNameIdent {
@@ -59,6 +62,12 @@ pub struct CreateDatabaseReq {
     pub if_not_exists: bool,
     pub db: String,
     pub meta: DatabaseMeta,
+
+    /// Expiration time in second since 1970, after which the database is no longer visible.
+    /// To create a database with a TTL, set it to `now + ttl`.
+    /// `None` means it never expires.
+    #[serde(default)]
+    pub expire_at: Option<u64>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -78,6 +87,9 @@ pub struct DropDatabaseReply {}
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetDatabaseReq {
     pub inner: DatabaseNameIdent,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
 }
 
 impl Deref for GetDatabaseReq {
@@ -94,9 +106,67 @@ impl GetDatabaseReq {
             inner: DatabaseNameIdent {
                 db_name: db_name.into(),
             },
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> GetDatabaseReq {
+        self.consistency = consistency;
+        self
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ListDatabaseReq {
+    #[serde(default)]
+    pub consistency: ReadConsistency,
+}
+
+impl ListDatabaseReq {
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> ListDatabaseReq {
+        self.consistency = consistency;
+        self
+    }
+}
+
+/// List a page of the databases whose names start with `prefix`, in the order of name.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ListDatabasePageReq {
+    pub prefix: String,
+
+    /// The max number of databases to return, it must be greater than 0.
+    pub limit: usize,
+
+    /// The `next_token` of the previous page, or `None` to list from the first page.
+    pub token: Option<String>,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
+}
+
+impl ListDatabasePageReq {
+    pub fn new(prefix: impl Into<String>, limit: usize) -> ListDatabasePageReq {
+        ListDatabasePageReq {
+            prefix: prefix.into(),
+            limit,
+            token: None,
+            consistency: ReadConsistency::default(),
         }
     }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> ListDatabasePageReq {
+        self.consistency = consistency;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
-pub struct ListDatabaseReq {}
+pub struct ListDatabasePageReply {
+    pub databases: Vec<Arc<DatabaseInfo>>,
+
+    /// The token to list the next page. `None` if there are no more databases.
+    ///
+    /// It consists only of the names of the listed entries,
+    /// thus a listing started on one node can continue on another.
+    pub next_token: Option<String>,
+}
@@ -25,6 +25,7 @@ mod match_seq;
 mod operation;
 mod raft_txid;
 mod raft_types;
+mod read_consistency;
 mod seq_num;
 mod seq_value;
 mod table;
@@ -35,12 +36,16 @@ mod user_info;
 mod user_privilege;
 mod user_quota;
 mod user_stage;
+mod watch;
 
 pub use change::AddResult;
 pub use change::Change;
 pub use change::OkOrExist;
+pub use cluster::ClusterStatus;
+pub use cluster::GetClusterStatusReq;
 pub use cluster::Node;
 pub use cluster::NodeInfo;
+pub use cluster::NodeStatus;
 pub use cluster::Slot;
 pub use cmd::Cmd;
 pub use database::CreateDatabaseReply;
@@ -50,6 +55,8 @@ pub use database::DatabaseMeta;
 pub use database::DropDatabaseReply;
 pub use database::DropDatabaseReq;
 pub use database::GetDatabaseReq;
+pub use database::ListDatabasePageReply;
+pub use database::ListDatabasePageReq;
 pub use database::ListDatabaseReq;
 pub use errors::ConflictSeq;
 pub use kv_message::GetKVActionReply;
@@ -71,20 +78,30 @@ pub use raft_types::LogId;
 pub use raft_types::LogIndex;
 pub use raft_types::NodeId;
 pub use raft_types::Term;
+pub use read_consistency::ReadConsistency;
 pub use seq_num::SeqNum;
+pub use seq_value::now_secs;
 pub use seq_value::IntoSeqV;
 pub use seq_value::KVMeta;
 pub use seq_value::SeqV;
 pub use table::CreateTableReply;
 pub use table::CreateTableReq;
+pub use table::CreateTablesReply;
+pub use table::CreateTablesReq;
 pub use table::DropTableReply;
 pub use table::DropTableReq;
+pub use table::DropTablesReply;
+pub use table::DropTablesReq;
 pub use table::GetTableReq;
+pub use table::ListTablePageReply;
+pub use table::ListTablePageReq;
 pub use table::ListTableReq;
 pub use table::TableIdent;
 pub use table::TableInfo;
 pub use table::TableMeta;
 pub use table::TableNameIndent;
+pub use table::UpdateTableMetaReply;
+pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
 pub use user_auth::PasswordType;
@@ -97,3 +114,7 @@ pub use user_privilege::UserPrivilegeSet;
 pub use user_privilege::UserPrivilegeType;
 pub use user_quota::UserQuota;
 pub use user_stage::*;
+pub use watch::WatchEvent;
+pub use watch::WatchReply;
+pub use watch::WatchReq;
+pub use watch::WatchValue;
@@ -0,0 +1,30 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+/// How up to date a read of databases and tables has to be.
+/// A meta store without followers, e.g. the embedded one, serves both alike.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReadConsistency {
+    /// Served by the leader: it observes every write completed before the read.
+    Linearizable,
+    /// Served by the local state machine of the node receiving the read.
+    /// It is faster but a follower may lag behind the leader.
+    BoundedStale,
+}
+
+impl Default for ReadConsistency {
+    fn default() -> Self {
+        ReadConsistency::Linearizable
+    }
+}
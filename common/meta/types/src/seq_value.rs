@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::convert::TryInto;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -24,6 +26,14 @@ pub struct KVMeta {
     pub expire_at: Option<u64>,
 }
 
+/// The current time in second since 1970, in which `KVMeta::expire_at` is.
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 /// Some value bound with a seq number
 #[derive(Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq)]
 pub struct SeqV<T = Vec<u8>> {
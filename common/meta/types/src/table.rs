@@ -27,6 +27,7 @@ use maplit::hashmap;
 use crate::database::DatabaseNameIdent;
 use crate::MatchSeq;
 use crate::MetaVersion;
+use crate::ReadConsistency;
 
 /// Globally unique identifier of a version of TableMeta.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
@@ -187,6 +188,12 @@ pub struct CreateTableReq {
     pub db: String,
     pub table: String,
     pub table_meta: TableMeta,
+
+    /// Expiration time in second since 1970, after which the table is no longer visible.
+    /// To create a table with a TTL, set it to `now + ttl`.
+    /// `None` means it never expires.
+    #[serde(default)]
+    pub expire_at: Option<u64>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -204,6 +211,30 @@ pub struct DropTableReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct DropTableReply {}
 
+/// Create a batch of tables atomically: either all of them are created or none is.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CreateTablesReq {
+    pub reqs: Vec<CreateTableReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CreateTablesReply {
+    /// One reply for every request, in the request order.
+    pub replies: Vec<CreateTableReply>,
+}
+
+/// Drop a batch of tables atomically: either all of them are dropped or none is.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DropTablesReq {
+    pub reqs: Vec<DropTableReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DropTablesReply {
+    /// One reply for every request, in the request order.
+    pub replies: Vec<DropTableReply>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReq {
     pub table_id: u64,
@@ -233,9 +264,43 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+/// Replace the meta of a table only if its current version matches `seq`, i.e., a compare-and-swap.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReq {
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub new_table_meta: TableMeta,
+}
+
+impl UpdateTableMetaReq {
+    pub fn new(table_ident: &TableIdent, new_table_meta: TableMeta) -> UpdateTableMetaReq {
+        UpdateTableMetaReq {
+            table_id: table_ident.table_id,
+            seq: MatchSeq::Exact(table_ident.version),
+            new_table_meta,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReply {
+    /// Whether the new meta is written. It is false if the version does not match.
+    pub applied: bool,
+
+    /// The version and the meta of the table before this request.
+    pub prev_version: MetaVersion,
+    pub prev_meta: TableMeta,
+
+    /// The version of the table after this request, the same as `prev_version` if not applied.
+    pub version: MetaVersion,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
 }
 
 impl Deref for GetTableReq {
@@ -256,13 +321,22 @@ impl GetTableReq {
     pub fn new(db_name: impl Into<String>, table_name: impl Into<String>) -> GetTableReq {
         GetTableReq {
             inner: TableNameIndent::new(db_name, table_name),
+            consistency: ReadConsistency::default(),
         }
     }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> GetTableReq {
+        self.consistency = consistency;
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct ListTableReq {
     pub inner: DatabaseNameIdent,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
 }
 
 impl Deref for ListTableReq {
@@ -279,6 +353,74 @@ impl ListTableReq {
             inner: DatabaseNameIdent {
                 db_name: db_name.into(),
             },
+            consistency: ReadConsistency::default(),
         }
     }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> ListTableReq {
+        self.consistency = consistency;
+        self
+    }
+}
+
+/// List a page of the tables in a database whose names start with `prefix`.
+///
+/// The tables are in the order of their lookup keys, which sorts a shorter name first,
+/// the order is the same on every node.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ListTablePageReq {
+    pub inner: DatabaseNameIdent,
+
+    pub prefix: String,
+
+    /// The max number of tables to return, it must be greater than 0.
+    pub limit: usize,
+
+    /// The `next_token` of the previous page, or `None` to list from the first page.
+    pub token: Option<String>,
+
+    #[serde(default)]
+    pub consistency: ReadConsistency,
+}
+
+impl Deref for ListTablePageReq {
+    type Target = DatabaseNameIdent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl ListTablePageReq {
+    pub fn new(
+        db_name: impl Into<String>,
+        prefix: impl Into<String>,
+        limit: usize,
+    ) -> ListTablePageReq {
+        ListTablePageReq {
+            inner: DatabaseNameIdent {
+                db_name: db_name.into(),
+            },
+            prefix: prefix.into(),
+            limit,
+            token: None,
+            consistency: ReadConsistency::default(),
+        }
+    }
+
+    pub fn with_consistency(mut self, consistency: ReadConsistency) -> ListTablePageReq {
+        self.consistency = consistency;
+        self
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ListTablePageReply {
+    pub tables: Vec<Arc<TableInfo>>,
+
+    /// The token to list the next page. `None` if there are no more tables.
+    ///
+    /// It consists only of the names of the listed entries,
+    /// thus a listing started on one node can continue on another.
+    pub next_token: Option<String>,
 }
@@ -0,0 +1,59 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use crate::DatabaseMeta;
+use crate::TableMeta;
+
+/// Fetch the changes of databases and tables applied after `after_seq`, whose key starts with `prefix`.
+///
+/// The key of a database is `<db>`, the key of a table is `<db>/<table>`.
+/// E.g., `db1/` watches all tables in `db1`, `""` watches everything.
+/// A client follows the changes by calling `watch` again with the seq of the last event it received.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WatchReq {
+    pub prefix: String,
+    pub after_seq: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum WatchValue {
+    Database(DatabaseMeta),
+    Table(TableMeta),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WatchEvent {
+    /// Increases with every change, in the order the changes are applied.
+    pub seq: u64,
+    pub key: String,
+    /// The value after the change, `None` is the tombstone of a dropped database or table.
+    pub value: Option<WatchValue>,
+}
+
+impl WatchEvent {
+    pub fn database_key(db: &str) -> String {
+        db.to_string()
+    }
+
+    pub fn table_key(db: &str, table: &str) -> String {
+        format!("{}/{}", db, table)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WatchReply {
+    /// Events in seq order.
+    pub events: Vec<WatchEvent>,
+}
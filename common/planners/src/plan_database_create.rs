@@ -35,6 +35,7 @@ impl From<CreateDatabasePlan> for CreateDatabaseReq {
             if_not_exists: p.if_not_exists,
             db: p.db.clone(),
             meta: p.meta,
+            expire_at: None,
         }
     }
 }
@@ -45,6 +46,7 @@ impl From<&CreateDatabasePlan> for CreateDatabaseReq {
             if_not_exists: p.if_not_exists,
             db: p.db.clone(),
             meta: p.meta.clone(),
+            expire_at: None,
         }
     }
 }
@@ -24,6 +24,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::scalars::CastFunction;
 use common_functions::scalars::FunctionFactory;
 use lazy_static::lazy_static;
 
@@ -268,7 +269,11 @@ impl Expression {
             Expression::Wildcard => Result::Err(ErrorCode::IllegalDataType(
                 "Wildcard expressions are not valid to get return nullable",
             )),
-            Expression::Cast { expr, .. } => expr.nullable(input_schema),
+            Expression::Cast { expr, data_type } => {
+                let from_type = expr.to_data_type(input_schema)?;
+                Ok(expr.nullable(input_schema)?
+                    || CastFunction::is_narrowing_numeric_cast(&from_type, data_type))
+            }
             Expression::Sort { expr, .. } => expr.nullable(input_schema),
         }
     }
@@ -41,6 +41,7 @@ impl From<CreateTablePlan> for CreateTableReq {
             db: p.db,
             table: p.table,
             table_meta: p.table_meta,
+            expire_at: None,
         }
     }
 }
@@ -121,3 +121,44 @@ fn test_expression_validate() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_expression_nullable_functions() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, true),
+        DataField::new("b", DataType::Int32, false),
+    ]);
+
+    // assumeNotNull strips the nullability, toNullable adds it, the type is kept.
+    let field = Expression::create_scalar_function("assumeNotNull", vec![col("a")])
+        .to_data_field(&schema)?;
+    assert_eq!(&DataType::Int32, field.data_type());
+    assert!(!field.is_nullable());
+
+    let field =
+        Expression::create_scalar_function("toNullable", vec![col("b")]).to_data_field(&schema)?;
+    assert_eq!(&DataType::Int32, field.data_type());
+    assert!(field.is_nullable());
+
+    Ok(())
+}
+
+#[test]
+fn test_expression_cast_nullable() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int64, false)]);
+
+    // Widening never overflows, narrowing is null for a value out of range.
+    let expr = Expression::Cast {
+        expr: Box::new(col("a")),
+        data_type: DataType::Float64,
+    };
+    assert!(!expr.nullable(&schema)?);
+
+    let expr = Expression::Cast {
+        expr: Box::new(col("a")),
+        data_type: DataType::Int8,
+    };
+    assert!(expr.nullable(&schema)?);
+
+    Ok(())
+}
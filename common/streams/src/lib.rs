@@ -16,8 +16,11 @@ mod sources;
 mod stream;
 mod stream_abort;
 mod stream_cast;
+mod stream_collect;
 mod stream_correct_with_schema;
 mod stream_datablock;
+mod stream_filter;
+mod stream_limit;
 mod stream_limit_by;
 mod stream_progress;
 mod stream_skip;
@@ -30,8 +33,13 @@ pub use sources::*;
 pub use stream::*;
 pub use stream_abort::AbortStream;
 pub use stream_cast::CastStream;
+pub use stream_collect::collect_blocks;
+pub use stream_collect::collect_single;
 pub use stream_correct_with_schema::CorrectWithSchemaStream;
 pub use stream_datablock::DataBlockStream;
+pub use stream_filter::FilterPredicate;
+pub use stream_filter::FilterStream;
+pub use stream_limit::LimitStream;
 pub use stream_limit_by::LimitByStream;
 pub use stream_progress::ProgressStream;
 pub use stream_skip::SkipStream;
@@ -30,6 +30,7 @@ pub struct CsvSource<R> {
     schema: DataSchemaRef,
     block_size: usize,
     rows: usize,
+    strict_fields: bool,
 }
 
 impl<R> CsvSource<R>
@@ -41,8 +42,28 @@ where R: AsyncRead + Unpin + Send
         header: bool,
         block_size: usize,
     ) -> Result<Self> {
+        Self::try_create_with_format(reader, schema, header, b',', b'"', false, block_size)
+    }
+
+    /// With `strict_fields`, a record of a different number of fields than the schema is an
+    /// error. Otherwise the records must have the same number of fields, the columns without a
+    /// field are null and the fields without a column are ignored.
+    pub fn try_create_with_format(
+        reader: R,
+        schema: DataSchemaRef,
+        header: bool,
+        delimiter: u8,
+        quote: u8,
+        strict_fields: bool,
+        block_size: usize,
+    ) -> Result<Self> {
+        // A strict number of fields is checked against the schema while reading,
+        // so that the error can tell the expected number.
         let reader = AsyncReaderBuilder::new()
             .has_headers(header)
+            .delimiter(delimiter)
+            .quote(quote)
+            .flexible(strict_fields)
             .create_reader(reader);
 
         Ok(Self {
@@ -50,6 +71,7 @@ where R: AsyncRead + Unpin + Send
             block_size,
             schema,
             rows: 0,
+            strict_fields,
         })
     }
 }
@@ -77,6 +99,18 @@ where R: AsyncRead + Unpin + Send
             if record.is_empty() {
                 break;
             }
+            if self.strict_fields && record.len() != desers.len() {
+                let line = match record.position() {
+                    Some(position) => position.line() as usize,
+                    None => self.rows + 1,
+                };
+                return Err(ErrorCode::BadBytes(format!(
+                    "Expected {} fields in csv line {}, but got {}",
+                    desers.len(),
+                    line,
+                    record.len()
+                )));
+            }
             for (col, deser) in desers.iter_mut().enumerate() {
                 match record.get(col) {
                     Some(bytes) => deser.de_text(bytes)?,
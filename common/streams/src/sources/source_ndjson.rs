@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use futures::io::BufReader;
+use futures::AsyncBufReadExt;
+use futures::AsyncRead;
+use serde_json::Value;
+
+use crate::Source;
+
+/// Reads newline-delimited JSON, every line is an object whose keys are the column names.
+/// Missing keys and JSON nulls are read as nulls, unknown keys are ignored.
+/// Nested objects and arrays are not flattened, they are read as their JSON text.
+pub struct NDJsonSource<R> {
+    reader: BufReader<R>,
+    schema: DataSchemaRef,
+    block_size: usize,
+    lines: usize,
+    buf: Vec<u8>,
+}
+
+impl<R> NDJsonSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    pub fn try_create(reader: R, schema: DataSchemaRef, block_size: usize) -> Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(reader),
+            schema,
+            block_size,
+            lines: 0,
+            buf: vec![],
+        })
+    }
+}
+
+#[async_trait]
+impl<R> Source for NDJsonSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        let mut desers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.block_size))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rows = 0;
+        while rows < self.block_size {
+            self.buf.clear();
+            let size = self
+                .reader
+                .read_until(b'\n', &mut self.buf)
+                .await
+                .map_err_to_code(ErrorCode::BadBytes, || {
+                    format!("Read ndjson error at line {}", self.lines + 1)
+                })?;
+            if size == 0 {
+                break;
+            }
+            self.lines += 1;
+
+            // Blank lines are skipped.
+            if self.buf.iter().all(|c| c.is_ascii_whitespace()) {
+                continue;
+            }
+
+            let line = self.lines;
+            let value: Value = serde_json::from_slice(&self.buf)
+                .map_err_to_code(ErrorCode::BadBytes, || {
+                    format!("Parse ndjson error at line {}", line)
+                })?;
+            let object = value.as_object().ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "Expected a json object in ndjson line {}, but got {}",
+                    line, value
+                ))
+            })?;
+
+            for (field, deser) in self.schema.fields().iter().zip(desers.iter_mut()) {
+                let result = match object.get(field.name()) {
+                    None | Some(Value::Null) => {
+                        deser.de_null();
+                        Ok(())
+                    }
+                    Some(Value::String(s)) => deser.de_text(s.as_bytes()),
+                    Some(v) => deser.de_text(v.to_string().as_bytes()),
+                };
+                result.map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "Parse ndjson error at line {}, column {}, cause: {}",
+                        line,
+                        field.name(),
+                        e.message()
+                    ))
+                })?;
+            }
+            rows += 1;
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        let series = desers
+            .iter_mut()
+            .map(|deser| deser.finish_to_series())
+            .collect::<Vec<_>>();
+
+        Ok(Some(DataBlock::create_by_array(
+            self.schema.clone(),
+            series,
+        )))
+    }
+}
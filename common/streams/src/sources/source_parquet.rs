@@ -20,6 +20,9 @@ use common_arrow::arrow::io::parquet::read::decompress;
 use common_arrow::arrow::io::parquet::read::page_stream_to_array;
 use common_arrow::arrow::io::parquet::read::read_metadata_async;
 use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
+use common_arrow::is_parquet_codec_enabled;
+use common_arrow::parquet::compression::Compression;
+use common_arrow::parquet::metadata::ColumnChunkMetaData;
 use common_arrow::parquet::read::get_page_stream;
 use common_dal::DataAccessor;
 use common_datablocks::DataBlock;
@@ -64,32 +67,28 @@ impl ParquetSource {
             metadata: None,
         }
     }
-}
 
-#[async_trait]
-impl Source for ParquetSource {
-    async fn read(&mut self) -> Result<Option<DataBlock>> {
-        let metadata = match self.metadata.clone() {
-            Some(m) => m,
-            None => {
-                let mut reader = self
-                    .data_accessor
-                    .get_input_stream(self.path.as_str(), None)?;
-                let m = read_metadata_async(&mut reader)
-                    .await
-                    .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
-                self.metadata = Some(m.clone());
-                self.row_groups = m.row_groups.len();
-                self.row_group = 0;
-                m
-            }
-        };
+    /// Read the footer metadata of the parquet file.
+    pub async fn read_metadata(&self) -> Result<FileMetaData> {
+        let mut reader = self
+            .data_accessor
+            .get_input_stream(self.path.as_str(), None)?;
+        read_metadata_async(&mut reader)
+            .await
+            .map_err(|e| ErrorCode::ParquetError(e.to_string()))
+    }
 
-        if self.row_group >= self.row_groups {
-            return Ok(None);
+    /// Read the projected columns of one row group into a block.
+    pub async fn read_row_group(
+        &self,
+        metadata: &FileMetaData,
+        row_group: usize,
+    ) -> Result<DataBlock> {
+        for idx in &self.projection {
+            check_compression(&self.path, metadata.row_groups[row_group].column(*idx))?;
         }
+
         let col_num = self.projection.len();
-        let row_group = self.row_group;
         let cols = self
             .projection
             .clone()
@@ -123,8 +122,55 @@ impl Source for ParquetSource {
         let n = std::cmp::min(buffer_size, col_num);
         let data_cols = stream.buffered(n).try_collect().await?;
 
+        Ok(DataBlock::create(self.block_schema.clone(), data_cols))
+    }
+}
+
+/// Fail with an error naming the codec if the column is compressed by a codec this build lacks,
+/// instead of the opaque error raised while decompressing the pages.
+fn check_compression(path: &str, col_meta: &ColumnChunkMetaData) -> Result<()> {
+    let compression = col_meta.compression();
+    if is_parquet_codec_enabled(compression) {
+        return Ok(());
+    }
+
+    let codec = format!("{:?}", compression).to_lowercase();
+    match compression {
+        Compression::Lzo => Err(ErrorCode::ParquetError(format!(
+            "Column {} of parquet file {} uses lzo compression, which is not supported",
+            col_meta.descriptor().name(),
+            path
+        ))),
+        _ => Err(ErrorCode::ParquetError(format!(
+            "Column {} of parquet file {} uses {} compression, which requires the parquet-{} feature of common-arrow",
+            col_meta.descriptor().name(),
+            path,
+            codec,
+            codec
+        ))),
+    }
+}
+
+#[async_trait]
+impl Source for ParquetSource {
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        let metadata = match self.metadata.clone() {
+            Some(m) => m,
+            None => {
+                let m = self.read_metadata().await?;
+                self.metadata = Some(m.clone());
+                self.row_groups = m.row_groups.len();
+                self.row_group = 0;
+                m
+            }
+        };
+
+        if self.row_group >= self.row_groups {
+            return Ok(None);
+        }
+
+        let block = self.read_row_group(&metadata, self.row_group).await?;
         self.row_group += 1;
-        let block = DataBlock::create(self.block_schema.clone(), data_cols);
         Ok(Some(block))
     }
 }
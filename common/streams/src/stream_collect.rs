@@ -0,0 +1,35 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::TryStreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Drain the stream into its blocks, stopping at the first error.
+pub async fn collect_blocks(stream: SendableDataBlockStream) -> Result<Vec<DataBlock>> {
+    stream.try_collect::<Vec<_>>().await
+}
+
+/// Drain the stream and concat its blocks into one, stopping at the first error.
+/// An empty stream gives an empty block without columns, as it has no schema.
+pub async fn collect_single(stream: SendableDataBlockStream) -> Result<DataBlock> {
+    let blocks = collect_blocks(stream).await?;
+    match blocks.len() {
+        0 => Ok(DataBlock::empty()),
+        1 => Ok(blocks.into_iter().next().unwrap()),
+        _ => DataBlock::concat_blocks(&blocks),
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_datavalues::columns::DataColumn;
+use common_exception::Result;
+use futures::ready;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Evaluates the predicate of a block, e.g. with an expression executor, to a boolean column.
+pub type FilterPredicate = Box<dyn Fn(&DataBlock) -> Result<DataColumn> + Send + Sync>;
+
+/// Keeps the rows of each block for which the predicate is true, null is treated as false.
+/// The blocks without any row left are dropped.
+pub struct FilterStream {
+    input: SendableDataBlockStream,
+    predicate: FilterPredicate,
+}
+
+impl FilterStream {
+    pub fn new(input: SendableDataBlockStream, predicate: FilterPredicate) -> Self {
+        FilterStream { input, predicate }
+    }
+}
+
+impl Stream for FilterStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let block = match ready!(self.input.poll_next_unpin(ctx)) {
+                Some(Ok(block)) if block.is_empty() => continue,
+                Some(Ok(block)) => block,
+                other => return Poll::Ready(other),
+            };
+
+            let filtered = (self.predicate)(&block)
+                .and_then(|predicate| DataBlock::filter_block(&block, &predicate));
+            match filtered {
+                Ok(filtered) if filtered.is_empty() => continue,
+                other => return Poll::Ready(Some(other)),
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::ready;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::SendableDataBlockStream;
+
+/// Skips the first `offset` rows and then returns at most `limit` rows, slicing the blocks at the boundaries.
+/// Once the limit is reached the input is not polled anymore, so that it can be dropped with the stream.
+pub struct LimitStream {
+    input: SendableDataBlockStream,
+    limit: Option<usize>,
+    offset: usize,
+}
+
+impl LimitStream {
+    pub fn new(input: SendableDataBlockStream, limit: Option<usize>, offset: usize) -> Self {
+        LimitStream {
+            input,
+            limit,
+            offset,
+        }
+    }
+}
+
+impl Stream for LimitStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.limit == Some(0) {
+                return Poll::Ready(None);
+            }
+
+            let block = match ready!(self.input.poll_next_unpin(ctx)) {
+                Some(Ok(block)) => block,
+                other => return Poll::Ready(other),
+            };
+
+            let rows = block.num_rows();
+            if self.offset > 0 && self.offset >= rows {
+                self.offset -= rows;
+                continue;
+            }
+
+            let start = self.offset;
+            let mut len = rows - start;
+            self.offset = 0;
+            if let Some(limit) = self.limit {
+                len = len.min(limit);
+                self.limit = Some(limit - len);
+            }
+
+            if start == 0 && len == rows {
+                return Poll::Ready(Some(Ok(block)));
+            }
+            return Poll::Ready(Some(Ok(block.slice(start, len))));
+        }
+    }
+}
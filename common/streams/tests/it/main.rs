@@ -14,7 +14,10 @@
 
 mod source;
 mod stream_cast;
+mod stream_collect;
 mod stream_datablock;
+mod stream_filter;
+mod stream_limit;
 mod stream_limit_by;
 mod stream_progress;
 mod stream_skip;
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::*;
+
+fn create_stream(items: Vec<Result<DataBlock>>) -> SendableDataBlockStream {
+    Box::pin(futures::stream::iter(items))
+}
+
+#[tokio::test]
+async fn test_collect_blocks() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let blocks = vec![
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i32, 2])]),
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![3i32])]),
+    ];
+
+    let stream = create_stream(blocks.iter().cloned().map(Ok).collect());
+    let collected = collect_blocks(stream).await?;
+    assert_eq!(
+        vec![2, 1],
+        collected.iter().map(|b| b.num_rows()).collect::<Vec<_>>()
+    );
+
+    let stream = create_stream(blocks.into_iter().map(Ok).collect());
+    let block = collect_single(stream).await?;
+    assert_eq!(3, block.num_rows());
+    assert_eq!(&schema, block.schema());
+    let expected = vec![
+        "+---+", //
+        "| a |", //
+        "+---+", //
+        "| 1 |", //
+        "| 2 |", //
+        "| 3 |", //
+        "+---+", //
+    ];
+    assert_blocks_eq(expected, &[block]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_empty_stream() -> Result<()> {
+    assert!(collect_blocks(create_stream(vec![])).await?.is_empty());
+
+    let block = collect_single(create_stream(vec![])).await?;
+    assert_eq!(0, block.num_rows());
+    assert_eq!(0, block.num_columns());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collect_erroring_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let block = DataBlock::create_by_array(schema, vec![Series::new(vec![1i32])]);
+    let items = || {
+        vec![
+            Ok(block.clone()),
+            Err(ErrorCode::BadBytes("first")),
+            Ok(block.clone()),
+            Err(ErrorCode::BadArguments("second")),
+        ]
+    };
+
+    // The first error is returned.
+    let err = collect_blocks(create_stream(items())).await.unwrap_err();
+    assert_eq!("Code: 46, displayText = first.", err.to_string());
+
+    let err = collect_single(create_stream(items())).await.unwrap_err();
+    assert_eq!("Code: 46, displayText = first.", err.to_string());
+    Ok(())
+}
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_streams::*;
+use futures::TryStreamExt;
+
+#[tokio::test]
+async fn test_filter_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+
+    // Three blocks with the ids from 0 to 30, ten rows each.
+    let blocks = (0..3)
+        .map(|i| {
+            let ids = (i * 10..(i + 1) * 10).collect::<Vec<i32>>();
+            DataBlock::create_by_array(schema.clone(), vec![Series::new(ids)])
+        })
+        .collect::<Vec<_>>();
+
+    // The predicate id >= 15 and id != 25, the first block is entirely filtered out.
+    let predicate: FilterPredicate = Box::new(|block| {
+        let mask = block
+            .column(0)
+            .to_values()?
+            .iter()
+            .map(|v| matches!(v, DataValue::Int32(Some(id)) if *id >= 15 && *id != 25))
+            .collect::<Vec<_>>();
+        Ok(Series::new(mask).into())
+    });
+
+    let input = DataBlockStream::create(schema, None, blocks);
+    let stream = FilterStream::new(Box::pin(input), predicate);
+    let results = stream.try_collect::<Vec<_>>().await?;
+
+    let rows = results.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rows, vec![5, 9]);
+
+    let expected = vec![
+        "+----+", "| id |", "+----+", "| 15 |", "| 16 |", "| 17 |", "| 18 |", "| 19 |", "| 20 |",
+        "| 21 |", "| 22 |", "| 23 |", "| 24 |", "| 26 |", "| 27 |", "| 28 |", "| 29 |", "+----+",
+    ];
+    assert_blocks_eq(expected, &results);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_stream_null_and_constant_predicates() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let blocks = vec![
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1i32, 2, 3])]),
+        DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![4i32, 5, 6])]),
+    ];
+
+    // Null is treated as false, a constant false drops the whole block.
+    let predicate: FilterPredicate = Box::new(|block| {
+        let first = block.column(0).to_values()?[0].clone();
+        if first == DataValue::Int32(Some(1)) {
+            Ok(Series::new(vec![Some(true), None, Some(true)]).into())
+        } else {
+            Ok(DataColumn::Constant(DataValue::Boolean(Some(false)), 3))
+        }
+    });
+
+    let input = DataBlockStream::create(schema, None, blocks);
+    let stream = FilterStream::new(Box::pin(input), predicate);
+    let results = stream.try_collect::<Vec<_>>().await?;
+
+    let rows = results.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(rows, vec![2]);
+    assert_blocks_eq(
+        vec!["+----+", "| id |", "+----+", "| 1  |", "| 3  |", "+----+"],
+        &results,
+    );
+    Ok(())
+}
@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::*;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::*;
+use futures::stream::StreamExt;
+use futures::TryStreamExt;
+
+fn create_blocks(schema: &DataSchemaRef) -> Vec<DataBlock> {
+    // Three blocks with the ids from 0 to 30, ten rows each.
+    (0..3)
+        .map(|i| {
+            let ids = (i * 10..(i + 1) * 10).collect::<Vec<i32>>();
+            DataBlock::create_by_array(schema.clone(), vec![Series::new(ids)])
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_limit_stream() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+
+    struct Test {
+        name: &'static str,
+        limit: Option<usize>,
+        offset: usize,
+        expect_rows: Vec<usize>,
+        expect_first: i32,
+    }
+
+    let tests = vec![
+        Test {
+            name: "inside blocks",
+            limit: Some(12),
+            offset: 5,
+            expect_rows: vec![5, 7],
+            expect_first: 5,
+        },
+        Test {
+            name: "on block boundaries",
+            limit: Some(10),
+            offset: 10,
+            expect_rows: vec![10],
+            expect_first: 10,
+        },
+        Test {
+            name: "limit only",
+            limit: Some(15),
+            offset: 0,
+            expect_rows: vec![10, 5],
+            expect_first: 0,
+        },
+        Test {
+            name: "offset only",
+            limit: None,
+            offset: 25,
+            expect_rows: vec![5],
+            expect_first: 25,
+        },
+        Test {
+            name: "limit beyond the end",
+            limit: Some(100),
+            offset: 20,
+            expect_rows: vec![10],
+            expect_first: 20,
+        },
+        Test {
+            name: "offset beyond the end",
+            limit: Some(10),
+            offset: 30,
+            expect_rows: vec![],
+            expect_first: 0,
+        },
+        Test {
+            name: "zero limit",
+            limit: Some(0),
+            offset: 0,
+            expect_rows: vec![],
+            expect_first: 0,
+        },
+    ];
+
+    for t in tests {
+        let input = DataBlockStream::create(schema.clone(), None, create_blocks(&schema));
+        let stream = LimitStream::new(Box::pin(input), t.limit, t.offset);
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let rows = blocks.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        assert_eq!(rows, t.expect_rows, "case: {}", t.name);
+
+        let mut ids = vec![];
+        for block in blocks {
+            ids.extend(block.column(0).to_values()?);
+        }
+        let expect_ids = (0..t.expect_rows.iter().sum::<usize>())
+            .map(|i| DataValue::Int32(Some(t.expect_first + i as i32)))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, expect_ids, "case: {}", t.name);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_limit_stream_stops_polling_input() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+
+    // The input fails after the blocks, which must not be seen once the limit is reached.
+    let blocks = DataBlockStream::create(schema.clone(), None, create_blocks(&schema));
+    let failure = futures::stream::iter(vec![Err(ErrorCode::UnImplement("polled"))]);
+    let input = blocks.chain(failure);
+
+    let mut stream = LimitStream::new(Box::pin(input), Some(30), 0);
+    for _ in 0..3 {
+        assert!(stream.next().await.transpose()?.is_some());
+    }
+    assert!(stream.next().await.is_none());
+    Ok(())
+}
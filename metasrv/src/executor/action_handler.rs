@@ -68,14 +68,25 @@ impl ActionHandler {
             MetaFlightAction::GetDatabase(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::DropDatabase(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::ListDatabases(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::ListDatabasesPage(a) => s.serialize(self.handle(a).await?),
 
             // table
             MetaFlightAction::CreateTable(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::DropTable(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::CreateTables(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::DropTables(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::GetTable(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::ListTables(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::ListTablesPage(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::GetTableExt(a) => s.serialize(self.handle(a).await?),
             MetaFlightAction::CommitTable(a) => s.serialize(self.handle(a).await?),
+            MetaFlightAction::UpdateTableMeta(a) => s.serialize(self.handle(a).await?),
+
+            // watch
+            MetaFlightAction::Watch(a) => s.serialize(self.handle(a).await?),
+
+            // cluster
+            MetaFlightAction::GetClusterStatus(a) => s.serialize(self.handle(a).await?),
         }
     }
 }
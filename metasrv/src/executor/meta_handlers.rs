@@ -17,35 +17,56 @@ use std::convert::TryInto;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
+use common_exception::SerializedError;
+use common_meta_api::MetaApi;
 use common_meta_flight::GetTableExtReq;
+use common_meta_types::now_secs;
 use common_meta_types::AddResult;
 use common_meta_types::Change;
+use common_meta_types::ClusterStatus;
 use common_meta_types::Cmd::CreateDatabase;
 use common_meta_types::Cmd::CreateTable;
+use common_meta_types::Cmd::CreateTables;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
+use common_meta_types::Cmd::DropTables;
+use common_meta_types::Cmd::UpdateTableMeta;
 use common_meta_types::Cmd::UpsertTableOptions;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReply;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DatabaseMeta;
 use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReply;
+use common_meta_types::DropTablesReq;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
 use common_meta_types::OkOrExist;
+use common_meta_types::ReadConsistency;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchReply;
+use common_meta_types::WatchReq;
 use common_tracing::tracing;
 
 use crate::executor::action_handler::RequestHandler;
@@ -66,6 +87,9 @@ impl RequestHandler<CreateDatabaseReq> for ActionHandler {
             cmd: CreateDatabase {
                 name: db_name.clone(),
                 meta: db_meta.clone(),
+                expire_at: req.expire_at,
+                // Replace an expired database of the same name.
+                now: Some(now_secs()),
             },
         };
 
@@ -96,8 +120,16 @@ impl RequestHandler<CreateDatabaseReq> for ActionHandler {
 #[async_trait::async_trait]
 impl RequestHandler<GetDatabaseReq> for ActionHandler {
     async fn handle(&self, req: GetDatabaseReq) -> common_exception::Result<Arc<DatabaseInfo>> {
-        let res = self.meta_node.consistent_read(req).await?;
-        Ok(res)
+        match req.consistency {
+            ReadConsistency::Linearizable => {
+                let res = self.meta_node.consistent_read(req).await?;
+                Ok(res)
+            }
+            ReadConsistency::BoundedStale => {
+                let sm = self.meta_node.get_state_machine().await;
+                sm.get_database(req).await
+            }
+        }
     }
 }
 
@@ -150,6 +182,9 @@ impl RequestHandler<CreateTableReq> for ActionHandler {
                 db_name: db_name.clone(),
                 table_name: table_name.clone(),
                 table_meta,
+                expire_at: req.expire_at,
+                // Replace an expired table of the same name.
+                now: Some(now_secs()),
             },
         };
 
@@ -211,11 +246,71 @@ impl RequestHandler<DropTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<CreateTablesReq> for ActionHandler {
+    async fn handle(&self, req: CreateTablesReq) -> common_exception::Result<CreateTablesReply> {
+        tracing::info!("create tables: {}", req.reqs.len());
+
+        let cr = LogEntry {
+            txid: None,
+            cmd: CreateTables(req),
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        let res: Result<Vec<Change<TableMeta>>, SerializedError> = res.try_into().unwrap();
+        let replies = res?
+            .into_iter()
+            .map(|mut ch| match ch.ident.take() {
+                Some(table_id) => Ok(CreateTableReply { table_id }),
+                None => Err(ErrorCode::BadArguments(
+                    "create tables: a created table has no table id",
+                )),
+            })
+            .collect::<common_exception::Result<Vec<_>>>()?;
+
+        Ok(CreateTablesReply { replies })
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<DropTablesReq> for ActionHandler {
+    async fn handle(&self, req: DropTablesReq) -> common_exception::Result<DropTablesReply> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: DropTables(req),
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        let res: Result<Vec<Change<TableMeta>>, SerializedError> = res.try_into().unwrap();
+        let replies = res?.iter().map(|_| DropTableReply {}).collect();
+
+        Ok(DropTablesReply { replies })
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<GetTableReq> for ActionHandler {
     async fn handle(&self, req: GetTableReq) -> common_exception::Result<Arc<TableInfo>> {
-        let res = self.meta_node.consistent_read(req).await?;
-        Ok(res)
+        match req.consistency {
+            ReadConsistency::Linearizable => {
+                let res = self.meta_node.consistent_read(req).await?;
+                Ok(res)
+            }
+            ReadConsistency::BoundedStale => {
+                let sm = self.meta_node.get_state_machine().await;
+                sm.get_table(req).await
+            }
+        }
     }
 }
 
@@ -240,22 +335,73 @@ impl RequestHandler<GetTableExtReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<ListDatabasePageReq> for ActionHandler {
+    async fn handle(
+        &self,
+        req: ListDatabasePageReq,
+    ) -> common_exception::Result<ListDatabasePageReply> {
+        match req.consistency {
+            ReadConsistency::Linearizable => {
+                let res = self.meta_node.consistent_read(req).await?;
+                Ok(res)
+            }
+            ReadConsistency::BoundedStale => {
+                let sm = self.meta_node.get_state_machine().await;
+                sm.list_databases_page(req).await
+            }
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<ListDatabaseReq> for ActionHandler {
     async fn handle(
         &self,
         req: ListDatabaseReq,
     ) -> common_exception::Result<Vec<Arc<DatabaseInfo>>> {
-        let res = self.meta_node.consistent_read(req).await?;
-        Ok(res)
+        match req.consistency {
+            ReadConsistency::Linearizable => {
+                let res = self.meta_node.consistent_read(req).await?;
+                Ok(res)
+            }
+            ReadConsistency::BoundedStale => {
+                let sm = self.meta_node.get_state_machine().await;
+                sm.list_databases(req).await
+            }
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl RequestHandler<ListTableReq> for ActionHandler {
     async fn handle(&self, req: ListTableReq) -> common_exception::Result<Vec<Arc<TableInfo>>> {
-        let res = self.meta_node.consistent_read(req).await?;
-        Ok(res)
+        match req.consistency {
+            ReadConsistency::Linearizable => {
+                let res = self.meta_node.consistent_read(req).await?;
+                Ok(res)
+            }
+            ReadConsistency::BoundedStale => {
+                let sm = self.meta_node.get_state_machine().await;
+                sm.list_tables(req).await
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<ListTablePageReq> for ActionHandler {
+    async fn handle(&self, req: ListTablePageReq) -> common_exception::Result<ListTablePageReply> {
+        match req.consistency {
+            ReadConsistency::Linearizable => {
+                let res = self.meta_node.consistent_read(req).await?;
+                Ok(res)
+            }
+            ReadConsistency::BoundedStale => {
+                let sm = self.meta_node.get_state_machine().await;
+                sm.list_tables_page(req).await
+            }
+        }
     }
 }
 
@@ -278,7 +424,9 @@ impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
 
         if !res.changed() {
             let ch: Change<TableMeta> = res.try_into().unwrap();
-            let (prev, _result) = ch.unwrap();
+            let (prev, _result) = ch.unpack();
+            let prev = prev
+                .ok_or_else(|| ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id)))?;
 
             return Err(ErrorCode::TableVersionMissMatch(format!(
                 "targeting version {:?}, current version {}",
@@ -289,3 +437,58 @@ impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
         Ok(UpsertTableOptionReply {})
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<UpdateTableMetaReq> for ActionHandler {
+    async fn handle(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> common_exception::Result<UpdateTableMetaReply> {
+        let table_id = req.table_id;
+        let cr = LogEntry {
+            txid: None,
+            cmd: UpdateTableMeta(req),
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        let applied = res.changed();
+        let ch: Change<TableMeta> = res.try_into().unwrap();
+        let (prev, result) = match ch.unpack() {
+            (Some(prev), Some(result)) => (prev, result),
+            _ => {
+                return Err(ErrorCode::UnknownTableId(format!("table_id:{}", table_id)));
+            }
+        };
+
+        Ok(UpdateTableMetaReply {
+            applied,
+            prev_version: prev.seq,
+            prev_meta: prev.data,
+            version: result.seq,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<WatchReq> for ActionHandler {
+    async fn handle(&self, req: WatchReq) -> common_exception::Result<WatchReply> {
+        // Served by the local state machine: a watcher connected to a follower
+        // sees a change once it is replicated to that follower.
+        let sm = self.meta_node.get_state_machine().await;
+        sm.watch(req).await
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<GetClusterStatusReq> for ActionHandler {
+    async fn handle(&self, req: GetClusterStatusReq) -> common_exception::Result<ClusterStatus> {
+        // Forwarded to the leader, the only node that knows the replication state of the others.
+        let res = self.meta_node.consistent_read(req).await?;
+        Ok(res)
+    }
+}
@@ -69,6 +69,11 @@ impl<'a> MetaLeader<'a> {
                 let res = sm.list_databases(req).await?;
                 Ok(ForwardResponse::ListDatabase(res))
             }
+            ForwardRequestBody::ListDatabasePage(req) => {
+                let sm = self.meta_node.get_state_machine().await;
+                let res = sm.list_databases_page(req).await?;
+                Ok(ForwardResponse::ListDatabasePage(res))
+            }
 
             ForwardRequestBody::GetDatabase(req) => {
                 let sm = self.meta_node.get_state_machine().await;
@@ -80,6 +85,11 @@ impl<'a> MetaLeader<'a> {
                 let res = sm.list_tables(req).await?;
                 Ok(ForwardResponse::ListTable(res))
             }
+            ForwardRequestBody::ListTablePage(req) => {
+                let sm = self.meta_node.get_state_machine().await;
+                let res = sm.list_tables_page(req).await?;
+                Ok(ForwardResponse::ListTablePage(res))
+            }
             ForwardRequestBody::GetTable(req) => {
                 let sm = self.meta_node.get_state_machine().await;
                 let res = sm.get_table(req).await?;
@@ -100,6 +110,11 @@ impl<'a> MetaLeader<'a> {
                 let res = sm.prefix_list_kv(&req.prefix).await?;
                 Ok(ForwardResponse::ListKV(res))
             }
+
+            ForwardRequestBody::GetClusterStatus(_req) => {
+                let res = self.meta_node.get_cluster_status();
+                Ok(ForwardResponse::ClusterStatus(res))
+            }
         }
     }
 
@@ -14,7 +14,10 @@
 
 use std::collections::BTreeSet;
 use std::fmt::Debug;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_raft::config::Config;
 use async_raft::Raft;
@@ -37,11 +40,14 @@ use common_meta_raft_store::state_machine::AppliedState;
 use common_meta_raft_store::state_machine::StateMachine;
 use common_meta_raft_store::state_machine::TableLookupKey;
 use common_meta_raft_store::state_machine::TableLookupValue;
+use common_meta_types::now_secs;
+use common_meta_types::ClusterStatus;
 use common_meta_types::Cmd;
 use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
 use common_meta_types::Node;
 use common_meta_types::NodeId;
+use common_meta_types::NodeStatus;
 use common_meta_types::SeqV;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
@@ -55,10 +61,15 @@ use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::ForwardRequestBody;
 use crate::meta_service::JoinRequest;
 use crate::meta_service::MetaServiceImpl;
+use crate::metrics::META_METRIC_FORWARDED_REQUESTS;
+use crate::metrics::META_METRIC_LOCAL_REQUESTS;
 use crate::network::Network;
 use crate::store::MetaRaftStore;
 use crate::Opened;
 
+/// The interval for the leader to check for and purge expired databases and tables.
+const PURGE_EXPIRED_META_INTERVAL: Duration = Duration::from_secs(1);
+
 // MetaRaft is a impl of the generic Raft handling meta data R/W.
 pub type MetaRaft = Raft<LogEntry, AppliedState, Network, MetaRaftStore>;
 
@@ -71,6 +82,10 @@ pub struct MetaNode {
     pub running_tx: watch::Sender<()>,
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<common_exception::Result<()>>>>,
+    // the number of forwardable requests served by this node, including the ones forwarded to it.
+    pub local_requests: AtomicU64,
+    // the number of forwardable requests this node forwarded to the leader.
+    pub forwarded_requests: AtomicU64,
 }
 
 impl Opened for MetaNode {
@@ -117,6 +132,8 @@ impl MetaNodeBuilder {
             running_tx: tx,
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
+            local_requests: AtomicU64::new(0),
+            forwarded_requests: AtomicU64::new(0),
         });
 
         if self.monitor_metrics {
@@ -124,6 +141,8 @@ impl MetaNodeBuilder {
             MetaNode::subscribe_metrics(mn.clone(), metrics_rx).await;
         }
 
+        MetaNode::spawn_purge_expired_meta(mn.clone()).await;
+
         let addr = if let Some(a) = self.addr.take() {
             a
         } else {
@@ -361,6 +380,81 @@ impl MetaNode {
         jh.push(h);
     }
 
+    // spawn a task to purge expired databases and tables periodically, when this node is the leader.
+    pub async fn spawn_purge_expired_meta(mn: Arc<Self>) {
+        let mut running_rx = mn.running_rx.clone();
+        let mut jh = mn.join_handles.lock().await;
+
+        let mn = mn.clone();
+
+        let span = tracing::span!(tracing::Level::INFO, "purge-expired-meta");
+
+        let h = tokio::task::spawn(
+            async move {
+                let mut interval = tokio::time::interval(PURGE_EXPIRED_META_INTERVAL);
+                loop {
+                    tokio::select! {
+                        _ = running_rx.changed() => {
+                           return Ok::<(), ErrorCode>(());
+                        }
+                        _ = interval.tick() => {}
+                    }
+
+                    if mn.metrics_rx.borrow().current_leader != Some(mn.sto.id) {
+                        continue;
+                    }
+
+                    let res = mn.purge_expired_meta().await;
+                    if let Err(e) = res {
+                        tracing::info!(
+                            "fail to purge expired meta: my id={}, err: {:?}",
+                            mn.sto.id,
+                            e
+                        );
+                    }
+                }
+            }
+            .instrument(span),
+        );
+        jh.push(h);
+    }
+
+    /// Submit a `PurgeExpiredMeta` log if there are databases or tables expired by now.
+    ///
+    /// The timestamp is assigned here, i.e., by the leader, thus every node purges the same entries.
+    async fn purge_expired_meta(&self) -> Result<(), MetaError> {
+        let now = now_secs();
+
+        let (databases, tables) = {
+            let sm = self.sto.state_machine.read().await;
+            sm.list_expired_meta(now)
+                .map_err(|e| MetaError::UnknownError(e.to_string()))?
+        };
+
+        if databases.is_empty() && tables.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!(
+            "purge expired meta: now: {} databases: {:?} tables: {:?}",
+            now,
+            databases,
+            tables
+        );
+
+        self.write(LogEntry {
+            txid: None,
+            cmd: Cmd::PurgeExpiredMeta {
+                now,
+                databases,
+                tables,
+            },
+        })
+        .await?;
+
+        Ok(())
+    }
+
     /// Start MetaNode in either `boot`, `single`, `join` or `open` mode,
     /// according to config.
     #[tracing::instrument(level = "info", skip(config))]
@@ -505,6 +599,40 @@ impl MetaNode {
         self.sto.get_non_voters().await
     }
 
+    /// Build the cluster status from the latest metrics of the local raft node.
+    /// The replication state of the other nodes is only reported by a leader.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn get_cluster_status(&self) -> ClusterStatus {
+        let metrics = self.metrics_rx.borrow().clone();
+        let replication = metrics.leader_metrics.as_ref().map(|l| &l.replication);
+
+        let membership: Vec<NodeId> = metrics.membership_config.members.iter().cloned().collect();
+        let nodes = membership
+            .iter()
+            .map(|node_id| {
+                let last_applied = if *node_id == metrics.id {
+                    metrics.last_applied
+                } else {
+                    replication
+                        .and_then(|r| r.get(node_id))
+                        .map(|r| r.matched.index)
+                        .unwrap_or_default()
+                };
+                NodeStatus {
+                    node_id: *node_id,
+                    last_applied,
+                }
+            })
+            .collect();
+
+        ClusterStatus {
+            leader: metrics.current_leader,
+            term: metrics.current_term,
+            nodes,
+            membership,
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn consistent_read<Request, Reply>(&self, req: Request) -> Result<Reply, MetaError>
     where
@@ -540,7 +668,11 @@ impl MetaNode {
         };
 
         let e = match res {
-            Ok(x) => return Ok(x),
+            Ok(x) => {
+                self.local_requests.fetch_add(1, Ordering::Relaxed);
+                metrics::increment_counter!(META_METRIC_LOCAL_REQUESTS);
+                return Ok(x);
+            }
             Err(e) => e,
         };
 
@@ -561,9 +693,22 @@ impl MetaNode {
 
         let res: ForwardResponse = self.forward(&leader_id, r2).await?;
 
+        self.forwarded_requests.fetch_add(1, Ordering::Relaxed);
+        metrics::increment_counter!(META_METRIC_FORWARDED_REQUESTS);
+
         Ok(res)
     }
 
+    /// Returns the number of forwardable requests this node served itself.
+    pub fn local_request_count(&self) -> u64 {
+        self.local_requests.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of forwardable requests this node forwarded to the leader.
+    pub fn forwarded_request_count(&self) -> u64 {
+        self.forwarded_requests.load(Ordering::Relaxed)
+    }
+
     /// Return a MetaLeader if `self` believes it is the leader.
     ///
     /// Otherwise it returns the leader in a ForwardToLeader error.
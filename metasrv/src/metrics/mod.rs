@@ -15,3 +15,8 @@
 mod metric_service;
 
 pub use metric_service::MetricService;
+
+/// Forwardable requests (writes and consistent reads) served by the receiving node.
+pub static META_METRIC_LOCAL_REQUESTS: &str = "metasrv.local_requests";
+/// Forwardable requests the receiving node forwarded to the leader.
+pub static META_METRIC_FORWARDED_REQUESTS: &str = "metasrv.forwarded_requests";
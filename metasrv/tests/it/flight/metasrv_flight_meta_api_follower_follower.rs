@@ -15,7 +15,10 @@
 //! Test metasrv MetaApi by writing to one follower and then reading from another follower.
 
 use common_base::tokio;
+use common_meta_api::MetaApi;
 use common_meta_api::MetaApiTestSuite;
+use common_meta_types::GetClusterStatusReq;
+use common_tracing::tracing;
 
 use crate::init_meta_ut;
 use crate::tests::service::start_metasrv_cluster;
@@ -79,3 +82,153 @@ async fn test_meta_api_list_table() -> anyhow::Result<()> {
         .list_table_diff_nodes(&follower1, &follower2)
         .await
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_table_update_meta() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let follower1 = tcs[1].flight_client().await?;
+    let follower2 = tcs[2].flight_client().await?;
+
+    MetaApiTestSuite {}
+        .table_update_meta_diff_nodes(&follower1, &follower2)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_table_batch() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let follower1 = tcs[1].flight_client().await?;
+    let follower2 = tcs[2].flight_client().await?;
+
+    MetaApiTestSuite {}
+        .table_batch_diff_nodes(&follower1, &follower2)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_database_table_expire() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let follower1 = tcs[1].flight_client().await?;
+    let follower2 = tcs[2].flight_client().await?;
+
+    MetaApiTestSuite {}
+        .database_table_expire_diff_nodes(&follower1, &follower2)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_list_page() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let follower1 = tcs[1].flight_client().await?;
+    let follower2 = tcs[2].flight_client().await?;
+
+    MetaApiTestSuite {}
+        .list_page_diff_nodes(&follower1, &follower2)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_read_consistency() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let follower1 = tcs[1].flight_client().await?;
+    let follower2 = tcs[2].flight_client().await?;
+
+    MetaApiTestSuite {}
+        .read_consistency_diff_nodes(&follower1, &follower2)
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_cluster_status() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let mut statuses = vec![];
+    for tc in tcs.iter() {
+        let client = tc.flight_client().await?;
+        let status = client
+            .get_cluster_status(GetClusterStatusReq::default())
+            .await?;
+        tracing::info!(
+            "cluster status from node {}: {:?}",
+            tc.config.raft_config.id,
+            status
+        );
+        statuses.push(status);
+    }
+
+    let want = &statuses[0];
+    assert_eq!(Some(0), want.leader, "the first node is the leader");
+
+    for status in statuses.iter() {
+        assert_eq!(want.leader, status.leader, "exactly one leader");
+        assert_eq!(want.term, status.term, "consistent term");
+        assert_eq!(vec![0, 1, 2], status.membership);
+
+        let node_ids = status.nodes.iter().map(|n| n.node_id).collect::<Vec<_>>();
+        assert_eq!(vec![0, 1, 2], node_ids);
+        assert!(status.nodes[0].last_applied > 0, "the leader applied logs");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_write_forward() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let leader = tcs[0].flight_client().await?;
+    let follower = tcs[1].flight_client().await?;
+
+    let leader_node = tcs[0].flight_srv.as_ref().unwrap().get_meta_node();
+    let follower_node = tcs[1].flight_srv.as_ref().unwrap().get_meta_node();
+
+    MetaApiTestSuite {}
+        .write_forward_diff_nodes(
+            &leader,
+            &follower,
+            || leader_node.forwarded_request_count(),
+            || follower_node.forwarded_request_count(),
+        )
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+async fn test_meta_api_watch() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_meta_ut!();
+    let _ent = ut_span.enter();
+
+    let tcs = start_metasrv_cluster(&[0, 1, 2]).await?;
+
+    let follower1 = tcs[1].flight_client().await?;
+    let follower2 = tcs[2].flight_client().await?;
+
+    MetaApiTestSuite {}
+        .watch_diff_nodes(&follower1, &follower2)
+        .await
+}
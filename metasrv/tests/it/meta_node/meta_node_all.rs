@@ -248,6 +248,8 @@ async fn test_meta_node_add_database() -> anyhow::Result<()> {
                             engine_options: Default::default(),
                             options: Default::default(),
                         },
+                        expire_at: None,
+                        now: None,
                     },
                 })
                 .await;
@@ -18,25 +18,39 @@ use std::time::Duration;
 
 use common_exception::Result;
 use common_meta_api::MetaApi;
+use common_meta_types::ClusterStatus;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
 use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
+use common_meta_types::CreateTablesReply;
+use common_meta_types::CreateTablesReq;
 use common_meta_types::DatabaseInfo;
 use common_meta_types::DropDatabaseReply;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
+use common_meta_types::DropTablesReply;
+use common_meta_types::DropTablesReq;
+use common_meta_types::GetClusterStatusReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
+use common_meta_types::ListDatabasePageReply;
+use common_meta_types::ListDatabasePageReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTablePageReply;
+use common_meta_types::ListTablePageReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_meta_types::WatchReply;
+use common_meta_types::WatchReq;
 
 use crate::common::MetaClientProvider;
 
@@ -97,6 +111,11 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn list_databases_page(&self, req: ListDatabasePageReq) -> Result<ListDatabasePageReply> {
+        self.query_backend(move |cli| async move { cli.list_databases_page(req).await })
+            .await
+    }
+
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
         // TODO validate plan by table engine first
         self.query_backend(move |cli| async move { cli.create_table(req).await })
@@ -108,6 +127,16 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn create_tables(&self, req: CreateTablesReq) -> Result<CreateTablesReply> {
+        self.query_backend(move |cli| async move { cli.create_tables(req).await })
+            .await
+    }
+
+    async fn drop_tables(&self, req: DropTablesReq) -> Result<DropTablesReply> {
+        self.query_backend(move |cli| async move { cli.drop_tables(req).await })
+            .await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         self.query_backend(move |cli| async move { cli.get_table(req).await })
             .await
@@ -118,6 +147,11 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn list_tables_page(&self, req: ListTablePageReq) -> Result<ListTablePageReply> {
+        self.query_backend(move |cli| async move { cli.list_tables_page(req).await })
+            .await
+    }
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
         self.query_backend(move |cli| async move { cli.get_table_by_id(table_id).await })
             .await
@@ -131,6 +165,21 @@ impl MetaApi for MetaRemote {
             .await
     }
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        self.query_backend(move |cli| async move { cli.update_table_meta(req).await })
+            .await
+    }
+
+    async fn watch(&self, req: WatchReq) -> Result<WatchReply> {
+        self.query_backend(move |cli| async move { cli.watch(req).await })
+            .await
+    }
+
+    async fn get_cluster_status(&self, req: GetClusterStatusReq) -> Result<ClusterStatus> {
+        self.query_backend(move |cli| async move { cli.get_cluster_status(req).await })
+            .await
+    }
+
     fn name(&self) -> String {
         "meta-remote".to_owned()
     }
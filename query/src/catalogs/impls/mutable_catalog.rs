@@ -100,6 +100,7 @@ impl MutableCatalog {
                 engine: "".to_string(),
                 ..Default::default()
             },
+            expire_at: None,
         };
         meta.create_database(req).await?;
 
@@ -139,7 +140,11 @@ impl Catalog for MutableCatalog {
     }
 
     async fn list_databases(&self) -> Result<Vec<Arc<dyn Database>>> {
-        let dbs = self.ctx.meta.list_databases(ListDatabaseReq {}).await?;
+        let dbs = self
+            .ctx
+            .meta
+            .list_databases(ListDatabaseReq::default())
+            .await?;
 
         dbs.iter().try_fold(vec![], |mut acc, item| {
             let db = self.build_db_instance(item)?;
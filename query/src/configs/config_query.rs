@@ -55,6 +55,7 @@ const QUERY_RPC_TLS_SERVICE_DOMAIN_NAME: &str = "QUERY_RPC_TLS_SERVICE_DOMAIN_NA
 
 const QUERY_TABLE_ENGINE_CSV_ENABLED: &str = "QUERY_TABLE_ENGINE_CSV_ENABLED";
 const QUERY_TABLE_ENGINE_PARQUET_ENABLED: &str = "QUERY_TABLE_ENGINE_PARQUET_ENABLED";
+const QUERY_TABLE_ENGINE_JSON_ENABLED: &str = "QUERY_TABLE_ENGINE_JSON_ENABLED";
 const QUERY_TABLE_ENGINE_MEMORY_ENABLED: &str = "QUERY_TABLE_ENGINE_MEMORY_ENABLED";
 const QUERY_DATABASE_ENGINE_GITHUB_ENABLED: &str = "QUERY_DATABASE_ENGINE_GITHUB_ENABLED";
 
@@ -148,6 +149,10 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_TABLE_ENGINE_PARQUET_ENABLED)]
     pub table_engine_parquet_enabled: bool,
 
+    /// Table engine JSONEachRow enabled
+    #[clap(long, env = QUERY_TABLE_ENGINE_JSON_ENABLED)]
+    pub table_engine_json_enabled: bool,
+
     /// Table engine memory enabled
     #[clap(
         long,
@@ -217,6 +222,7 @@ impl Default for QueryConfig {
             rpc_tls_query_service_domain_name: "localhost".to_string(),
             table_engine_csv_enabled: false,
             table_engine_parquet_enabled: false,
+            table_engine_json_enabled: false,
             table_engine_memory_enabled: true,
             database_engine_github_enabled: true,
             wait_timeout_mills: 5000,
@@ -393,6 +399,13 @@ impl QueryConfig {
             bool,
             QUERY_TABLE_ENGINE_PARQUET_ENABLED
         );
+        env_helper!(
+            mut_config,
+            query,
+            table_engine_json_enabled,
+            bool,
+            QUERY_TABLE_ENGINE_JSON_ENABLED
+        );
         env_helper!(
             mut_config,
             query,
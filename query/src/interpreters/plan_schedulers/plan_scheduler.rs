@@ -48,6 +48,7 @@ use crate::api::BroadcastAction;
 use crate::api::FlightAction;
 use crate::api::ShuffleAction;
 use crate::sessions::QueryContext;
+use crate::storages::Table;
 
 #[derive(PartialEq)]
 enum RunningMode {
@@ -785,7 +786,7 @@ impl PlanScheduler {
 
         match table.is_local() {
             true => self.visit_local_data_source(plan),
-            false => self.visit_cluster_data_source(plan),
+            false => self.visit_cluster_data_source(table.as_ref(), plan),
         }
     }
 
@@ -795,10 +796,17 @@ impl PlanScheduler {
         Ok(())
     }
 
-    fn visit_cluster_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+    fn visit_cluster_data_source(
+        &mut self,
+        table: &dyn Table,
+        plan: &ReadDataSourcePlan,
+    ) -> Result<()> {
         self.running_mode = RunningMode::Cluster;
 
-        let nodes_parts = self.repartition(plan);
+        let nodes_parts = match table.cluster_partitions(&plan.parts, self.cluster_nodes.len()) {
+            Some(nodes_parts) => nodes_parts,
+            None => self.repartition(plan),
+        };
         for index in 0..self.nodes_plan.len() {
             let mut read_plan = plan.clone();
             read_plan.parts = nodes_parts[index].clone();
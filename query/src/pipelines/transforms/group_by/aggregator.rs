@@ -158,20 +158,18 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
         params: &AggregatorParams,
     ) -> Result<Vec<Vec<Series>>> {
         let aggregate_functions_arguments = &params.aggregate_functions_arguments_name;
-        let mut aggregate_arguments_columns =
-            Vec::with_capacity(aggregate_functions_arguments.len());
-        for function_arguments in aggregate_functions_arguments {
-            let mut function_arguments_column = Vec::with_capacity(function_arguments.len());
-
-            for argument_name in function_arguments {
-                let argument_column = block.try_column_by_name(argument_name)?;
-                function_arguments_column.push(argument_column.to_array()?);
-            }
-
-            aggregate_arguments_columns.push(function_arguments_column);
-        }
+        let columns = aggregate_functions_arguments
+            .iter()
+            .flatten()
+            .map(|argument_name| Ok(block.try_column_by_name(argument_name)?.clone()))
+            .collect::<Result<Vec<_>>>()?;
 
-        Ok(aggregate_arguments_columns)
+        // The functions of the same constant argument, e.g. count(1) and sum(1), share its array.
+        let mut arrays = DataColumn::to_arrays(&columns)?.into_iter();
+        Ok(aggregate_functions_arguments
+            .iter()
+            .map(|function_arguments| arrays.by_ref().take(function_arguments.len()).collect())
+            .collect())
     }
 
     #[inline(never)]
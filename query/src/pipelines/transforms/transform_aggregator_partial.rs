@@ -115,11 +115,19 @@ impl Processor for AggregatorPartialTransform {
             let block = block?;
             let rows = block.num_rows();
 
+            let columns = arg_names
+                .iter()
+                .flatten()
+                .map(|name| Ok(block.try_column_by_name(name)?.clone()))
+                .collect::<Result<Vec<_>>>()?;
+
+            // The functions of the same constant argument share its array.
+            let mut arrays = DataColumn::to_arrays(&columns)?.into_iter();
             for (idx, func) in funcs.iter().enumerate() {
-                let mut arg_columns = vec![];
-                for name in arg_names[idx].iter() {
-                    arg_columns.push(block.try_column_by_name(name)?.to_array()?);
-                }
+                let arg_columns = arrays
+                    .by_ref()
+                    .take(arg_names[idx].len())
+                    .collect::<Vec<_>>();
                 let place = places[idx].into();
                 func.accumulate(place, &arg_columns, rows)?;
             }
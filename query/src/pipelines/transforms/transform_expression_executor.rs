@@ -20,6 +20,7 @@ use common_datavalues::columns::DataColumn;
 use common_datavalues::prelude::DataColumnWithField;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -88,7 +89,7 @@ impl ExpressionExecutor {
             column_map.insert(f.name(), column);
         }
 
-        let rows = block.num_rows();
+        let mut rows = block.num_rows();
         for action in self.chain.actions.iter() {
             if let ExpressionAction::Alias(alias) = action {
                 if let Some(v) = alias_action_map.get_mut(alias.arg_name.as_str()) {
@@ -103,6 +104,9 @@ impl ExpressionExecutor {
             }
 
             match action {
+                ExpressionAction::Function(f) if f.func.expands_rows() => {
+                    rows = Self::expand_rows(&mut column_map, &f.arg_names[0], &f.name)?;
+                }
                 ExpressionAction::Input(input) => {
                     let column = block.try_column_by_name(&input.name)?.clone();
                     let column = DataColumnWithField::new(
@@ -215,4 +219,50 @@ impl ExpressionExecutor {
             project_columns,
         ))
     }
+
+    /// Expand each row of all the columns evaluated so far into a row per element of the list
+    /// column `arg_name`, the elements are the column `name`. Returns the number of the rows.
+    fn expand_rows<'a>(
+        column_map: &mut HashMap<&'a str, DataColumnWithField>,
+        arg_name: &str,
+        name: &'a str,
+    ) -> Result<usize> {
+        let arg_column = column_map.get(arg_name).cloned().ok_or_else(|| {
+            ErrorCode::LogicalError("Arguments must be prepared before function transform")
+        })?;
+
+        // The list column itself is kept, it is replicated like the other columns.
+        column_map.insert(
+            name,
+            DataColumnWithField::new(
+                arg_column.column().clone(),
+                DataField::new(name, arg_column.data_type().clone(), true),
+            ),
+        );
+
+        let names = column_map.keys().copied().collect::<Vec<_>>();
+        let fields = names
+            .iter()
+            .map(|n| DataField::new(n, column_map[n].data_type().clone(), true))
+            .collect::<Vec<_>>();
+        let columns = names
+            .iter()
+            .map(|n| column_map[n].column().clone())
+            .collect::<Vec<_>>();
+        let block = DataBlock::create(DataSchemaRefExt::create(fields), columns);
+
+        let expanded = DataBlock::array_join(&block, name)?;
+        for (i, n) in names.into_iter().enumerate() {
+            let field = if n == name {
+                expanded.schema().field(i).clone()
+            } else {
+                column_map[n].field().clone()
+            };
+            column_map.insert(
+                n,
+                DataColumnWithField::new(expanded.column(i).clone(), field),
+            );
+        }
+        Ok(expanded.num_rows())
+    }
 }
@@ -16,14 +16,15 @@ use std::any::Any;
 use std::sync::Arc;
 use std::time::Instant;
 
-use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
 use common_streams::CorrectWithSchemaStream;
+use common_streams::FilterPredicate;
+use common_streams::FilterStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
-use tokio_stream::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
@@ -36,7 +37,7 @@ pub type WhereTransform = FilterTransform<false>;
 pub struct FilterTransform<const HAVING: bool> {
     schema: DataSchemaRef,
     input: Arc<dyn Processor>,
-    executor: Arc<ExpressionExecutor>,
+    executor: ExpressionExecutorRef,
 }
 
 impl<const HAVING: bool> FilterTransform<HAVING> {
@@ -63,19 +64,6 @@ impl<const HAVING: bool> FilterTransform<HAVING> {
             false,
         )
     }
-
-    fn filter(executor: Arc<ExpressionExecutor>, data: DataBlock) -> Result<DataBlock> {
-        let filter_block = executor.execute(&data)?;
-        DataBlock::filter_block(&data, filter_block.column(0))
-    }
-
-    fn filter_map(executor: ExpressionExecutorRef, data: DataBlock) -> Option<Result<DataBlock>> {
-        match Self::filter(executor, data) {
-            Err(error) => Some(Err(error)),
-            Ok(data_block) if data_block.is_empty() => None,
-            Ok(data_block) => Some(Ok(data_block)),
-        }
-    }
 }
 
 #[async_trait::async_trait]
@@ -104,17 +92,19 @@ impl<const HAVING: bool> Processor for FilterTransform<HAVING> {
         let input_stream = self.input.execute().await?;
         let executor = self.executor.clone();
 
-        let stream = input_stream.filter_map(move |data_block| match data_block {
-            Ok(data_block) if data_block.is_empty() => None,
-            Err(fail) => Some(Err(fail)),
-            Ok(data_block) => {
-                tracing::debug!("execute...");
-                let start = Instant::now();
-                let res = Self::filter_map(executor.clone(), data_block);
-                tracing::debug!("Filter cost: {:?}", start.elapsed());
-                res
+        let predicate: FilterPredicate = Box::new(move |data_block| {
+            tracing::debug!("execute...");
+            let start = Instant::now();
+            let filter_block = executor.execute(data_block)?;
+            tracing::debug!("Filter cost: {:?}", start.elapsed());
+            if filter_block.num_rows() != data_block.num_rows() {
+                return Err(ErrorCode::BadArguments(
+                    "The filter must not change the number of rows, e.g. by arrayJoin",
+                ));
             }
+            Ok(filter_block.column(0).clone())
         });
+        let stream = FilterStream::new(input_stream, predicate);
 
         Ok(Box::pin(CorrectWithSchemaStream::new(
             Box::pin(stream),
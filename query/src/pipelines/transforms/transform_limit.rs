@@ -16,9 +16,8 @@ use std::any::Any;
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_streams::LimitStream;
 use common_streams::SendableDataBlockStream;
-use common_streams::SkipStream;
-use common_streams::TakeStream;
 use common_tracing::tracing;
 
 use crate::pipelines::processors::EmptyProcessor;
@@ -62,14 +61,9 @@ impl Processor for LimitTransform {
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         tracing::debug!("execute...");
         let input_stream = self.input.execute().await?;
-        Ok(Box::pin(match (self.limit, self.offset) {
+        Ok(match (self.limit, self.offset) {
             (None, 0) => input_stream,
-            (None, offset) => Box::pin(SkipStream::new(Box::pin(input_stream), offset)),
-            (Some(limit), 0) => Box::pin(TakeStream::new(input_stream, limit)),
-            (Some(limit), offset) => Box::pin(TakeStream::new(
-                Box::pin(SkipStream::new(input_stream, offset)),
-                limit,
-            )),
-        }))
+            (limit, offset) => Box::pin(LimitStream::new(input_stream, limit, offset)),
+        })
     }
 }
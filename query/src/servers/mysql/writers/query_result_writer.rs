@@ -76,7 +76,9 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::DateTime32(_) | DataType::DateTime64(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_DATETIME)
+                }
                 DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
                 DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
                 DataType::Struct(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
@@ -164,6 +166,22 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                     let tz: Tz = tz.parse().unwrap();
                                     row_writer.write_col(v.to_date_time(&tz).naive_local())?
                                 }
+                                (
+                                    DataType::DateTime64(precision, tz),
+                                    DataValue::Int64(Some(v)),
+                                ) => {
+                                    let tz = tz.clone();
+                                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
+                                    let tz: Tz = tz.parse().unwrap();
+                                    let dt =
+                                        v.to_date_time64(*precision, &tz).ok_or_else(|| {
+                                            ErrorCode::BadDataValueType(format!(
+                                            "The value {} is out of the range of DateTime64({})",
+                                            v, precision
+                                        ))
+                                        })?;
+                                    row_writer.write_col(dt.naive_local())?
+                                }
                                 (DataType::String, DataValue::String(Some(v))) => {
                                     row_writer.write_col(v)?
                                 }
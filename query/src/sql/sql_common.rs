@@ -58,8 +58,20 @@ impl SQLCommon {
                     "DATE32" => Ok(DataType::Date32),
                     "DATETIME" => Ok(DataType::DateTime32(None)),
                     "DATETIME32" => Ok(DataType::DateTime32(None)),
+                    "DATETIME64" => Ok(DataType::DateTime64(3, None)),
                     "SIGNED" => Ok(DataType::Int64),
                     "UNSIGNED" => Ok(DataType::UInt64),
+                    name if name.starts_with("DATETIME64(") && name.ends_with(')') => {
+                        let precision = &name["DATETIME64(".len()..name.len() - 1];
+                        match precision.trim().parse::<u32>() {
+                            Ok(precision) => DataType::create_date_time64(precision, None)
+                                .map_err(|e| ErrorCode::IllegalDataType(e.message())),
+                            Err(_) => Result::Err(ErrorCode::IllegalDataType(format!(
+                                "The precision of DateTime64 must be an integer, but got {}",
+                                precision
+                            ))),
+                        }
+                    }
 
                     _ => Result::Err(ErrorCode::IllegalDataType(format!(
                         "The SQL data type {:?} is not implemented",
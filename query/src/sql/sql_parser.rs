@@ -35,8 +35,10 @@ use serde::Deserialize;
 use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
+use sqlparser::ast::DataType as SQLDataType;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::Statement;
 use sqlparser::ast::TableConstraint;
 use sqlparser::ast::Value;
@@ -489,9 +491,28 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    /// Parses a data type as sqlparser does, except that the arguments of a custom type such as
+    /// `DateTime64(6)` are kept in its name instead of being left to the caller.
+    fn parse_data_type(&mut self) -> Result<SQLDataType, ParserError> {
+        match self.parser.parse_data_type()? {
+            SQLDataType::Custom(ObjectName(mut idents))
+                if idents.len() == 1 && self.parser.consume_token(&Token::LParen) =>
+            {
+                let mut args = vec![self.parse_value_or_ident()?];
+                while self.parser.consume_token(&Token::Comma) {
+                    args.push(self.parse_value_or_ident()?);
+                }
+                self.parser.expect_token(&Token::RParen)?;
+                idents[0].value = format!("{}({})", idents[0].value, args.join(", "));
+                Ok(SQLDataType::Custom(ObjectName(idents)))
+            }
+            data_type => Ok(data_type),
+        }
+    }
+
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
         let name = self.parser.parse_identifier()?;
-        let data_type = self.parser.parse_data_type()?;
+        let data_type = self.parse_data_type()?;
         let collation = if self.parser.parse_keyword(Keyword::COLLATE) {
             Some(self.parser.parse_object_name()?)
         } else {
@@ -415,6 +415,12 @@ impl ExprRPNBuilder {
                 high,
             } => self.visit_between(expr, negated, low, high),
             Expr::Tuple(exprs) => self.visit_tuple(exprs),
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => self.visit_case(operand, conditions, results, else_result),
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -542,6 +548,45 @@ impl ExprRPNBuilder {
         Ok(())
     }
 
+    /// Lower CASE WHEN to multiIf(cond1, result1, cond2, result2, ..., else_result).
+    /// `CASE operand WHEN value` compares the operand with each value, a missing ELSE is NULL.
+    fn visit_case(
+        &mut self,
+        operand: &Option<Box<Expr>>,
+        conditions: &[Expr],
+        results: &[Expr],
+        else_result: &Option<Box<Expr>>,
+    ) -> Result<()> {
+        if conditions.is_empty() || conditions.len() != results.len() {
+            return Err(ErrorCode::SyntaxException(
+                "Case must have the same number of WHEN and THEN clauses, at least one.",
+            ));
+        }
+
+        for (condition, result) in conditions.iter().zip(results) {
+            match operand {
+                None => self.visit(condition)?,
+                Some(operand) => {
+                    self.visit(operand)?;
+                    self.visit(condition)?;
+                    self.rpn
+                        .push(ExprRPNItem::binary_operator(BinaryOperator::Eq.to_string()));
+                }
+            }
+            self.visit(result)?;
+        }
+
+        match else_result {
+            None => self.rpn.push(ExprRPNItem::Value(Value::Null)),
+            Some(else_result) => self.visit(else_result)?,
+        };
+
+        let name = String::from("multiIf");
+        self.rpn
+            .push(ExprRPNItem::function(name, conditions.len() * 2 + 1));
+        Ok(())
+    }
+
     fn visit_position(&mut self, substr_expr: &Expr, str_expr: &Expr) -> Result<()> {
         self.visit(substr_expr)?;
         self.visit(str_expr)?;
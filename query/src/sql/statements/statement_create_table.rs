@@ -36,6 +36,7 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::storages::parquet::ParquetTable;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
@@ -127,6 +128,20 @@ impl DfCreateTable {
                 let origin_table = ctx.get_table(&origin_db_name, &origin_table_name).await?;
                 Ok(origin_table.schema())
             }
+            // For a parquet table without column definitions, for example
+            // 'CREATE TABLE t ENGINE = Parquet location = "a.parquet"',
+            // we use the schema stored in the parquet footer.
+            None if self.columns.is_empty()
+                && self.query.is_none()
+                && self.engine.eq_ignore_ascii_case("Parquet") =>
+            {
+                match self.options.get("location") {
+                    Some(location) => ParquetTable::infer_schema(&ctx, location).await,
+                    None => Err(ErrorCode::BadOption(
+                        "Parquet Engine must contains file location options",
+                    )),
+                }
+            }
             None => {
                 let expr_analyzer = ExpressionAnalyzer::create(ctx);
                 let mut fields = Vec::with_capacity(self.columns.len());
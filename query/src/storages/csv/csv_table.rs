@@ -18,8 +18,11 @@ use std::fs::File;
 use std::sync::Arc;
 
 use async_stream::stream;
+use common_base::tokio::sync::mpsc;
+use common_base::TrySpawn;
 use common_dal::DataAccessor;
 use common_dal::Local;
+use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
@@ -31,6 +34,9 @@ use common_planners::Statistics;
 use common_streams::CsvSource;
 use common_streams::SendableDataBlockStream;
 use common_streams::Source;
+use common_tracing::tracing;
+use futures::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
 
 use crate::sessions::QueryContext;
 use crate::storages::csv::count_lines;
@@ -42,12 +48,16 @@ pub struct CsvTable {
     // TODO: support s3 protocol && support gob matcher files
     file: String,
     has_header: bool,
+    delimiter: u8,
+    quote: u8,
+    strict_fields: bool,
 }
 
 impl CsvTable {
     pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
         let options = table_info.options();
         let has_header = options.get("has_header").is_some();
+        let strict_fields = options.get("strict_fields").is_some();
         let file = match options.get("location") {
             None => {
                 return Result::Err(ErrorCode::BadOption(
@@ -56,15 +66,33 @@ impl CsvTable {
             }
             Some(v) => v.clone(),
         };
+        let delimiter = parse_char_option(options.get("delimiter"), "delimiter", b',')?;
+        let quote = parse_char_option(options.get("quote"), "quote", b'"')?;
 
         Ok(Box::new(Self {
             table_info,
             file,
             has_header,
+            delimiter,
+            quote,
+            strict_fields,
         }))
     }
 }
 
+/// The delimiter and quote options must be a single ascii character, `\t` stands for the tab.
+fn parse_char_option(value: Option<&String>, name: &str, default: u8) -> Result<u8> {
+    match value.map(|v| v.as_str()) {
+        None => Ok(default),
+        Some("\\t") => Ok(b'\t'),
+        Some(v) if v.len() == 1 && v.is_ascii() => Ok(v.as_bytes()[0]),
+        Some(v) => Err(ErrorCode::BadOption(format!(
+            "CSV Engine {} option must be a single ascii character, but got {}",
+            name, v
+        ))),
+    }
+}
+
 #[async_trait::async_trait]
 impl Table for CsvTable {
     fn as_any(&self) -> &dyn Any {
@@ -100,36 +128,62 @@ impl Table for CsvTable {
         let schema = plan.schema();
         let block_size = ctx.get_settings().get_max_block_size()? as usize;
         let has_header = self.has_header;
+        let delimiter = self.delimiter;
+        let quote = self.quote;
+        let strict_fields = self.strict_fields;
 
         let conf = ctx.get_config().storage;
         let dal = Arc::new(Local::new(conf.disk.temp_data_path.as_str()));
 
+        // The stream ends at the first error, the rest of the file is not parsed.
         let s = stream! {
-            loop {
-                let partitions = ctx_clone.try_get_partitions(1);
-                match partitions {
-                    Ok(partitions) => {
-                        if partitions.is_empty() {
-                            break;
-                        }
+            'partitions: loop {
+                let partitions = match ctx_clone.try_get_partitions(1) {
+                    Ok(partitions) if partitions.is_empty() => break,
+                    Ok(partitions) => partitions,
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
+                };
 
-                        let part = partitions.get(0).unwrap();
-                        let reader = dal.get_input_stream(&part.name, None)?;
-                        let mut source = CsvSource::try_create(reader, schema.clone(), has_header, block_size)?;
-
-                        loop {
-                            let block = source.read().await;
-                            match block {
-                                Ok(None) => break,
-                                Ok(Some(b)) =>  yield(Ok(b)),
-                                Err(e) => yield(Err(e)),
-                            }
+                let part = partitions.get(0).unwrap();
+                let source = dal.get_input_stream(&part.name, None).and_then(|reader| {
+                    CsvSource::try_create_with_format(reader, schema.clone(), has_header, delimiter, quote, strict_fields, block_size)
+                });
+                let mut source = match source {
+                    Ok(source) => source,
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
+                };
+
+                loop {
+                    match source.read().await {
+                        Ok(None) => break,
+                        Ok(Some(b)) => yield(Ok(b)),
+                        Err(e) => {
+                            yield(Err(e));
+                            break 'partitions;
                         }
                     }
-                    Err(e) =>  yield(Err(e))
                 }
             }
         };
-        Ok(Box::pin(s))
+
+        // Parse in a separate task, which is at most two blocks ahead of the consumer.
+        let (sender, receiver) = mpsc::channel::<Result<DataBlock>>(2);
+        ctx.try_spawn(async move {
+            let mut s = Box::pin(s);
+            while let Some(block) = s.next().await {
+                if let Err(error) = sender.send(block).await {
+                    // Stop parsing, the consumer is gone.
+                    tracing::debug!("CSV table cannot push data: {}", error);
+                    return;
+                }
+            }
+        })?;
+        Ok(Box::pin(ReceiverStream::new(receiver)))
     }
 }
@@ -52,6 +52,7 @@ impl RepoCommentsTable {
                 engine_options: options.into(),
                 ..Default::default()
             },
+            expire_at: None,
         };
         ctx.meta.create_table(req).await?;
         Ok(())
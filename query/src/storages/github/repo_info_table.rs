@@ -56,6 +56,7 @@ impl RepoInfoTable {
                 engine_options: options.into(),
                 ..Default::default()
             },
+            expire_at: None,
         };
         ctx.meta.create_table(req).await?;
         Ok(())
@@ -60,6 +60,7 @@ impl RepoIssuesTable {
                 engine_options: options.into(),
                 ..Default::default()
             },
+            expire_at: None,
         };
         ctx.meta.create_table(req).await?;
         Ok(())
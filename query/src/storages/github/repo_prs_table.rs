@@ -59,6 +59,7 @@ impl RepoPRsTable {
                 engine_options: options.into(),
                 ..Default::default()
             },
+            expire_at: None,
         };
         ctx.meta.create_table(req).await?;
         Ok(())
@@ -0,0 +1,162 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::any::Any;
+use std::sync::Arc;
+
+use async_stream::stream;
+use common_base::tokio::sync::mpsc;
+use common_base::TrySpawn;
+use common_dal::DataAccessor;
+use common_dal::Local;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_planners::Extras;
+use common_planners::Part;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_streams::NDJsonSource;
+use common_streams::SendableDataBlockStream;
+use common_streams::Source;
+use common_tracing::tracing;
+use futures::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::csv::count_lines;
+use crate::storages::StorageContext;
+use crate::storages::Table;
+
+/// Reads a newline-delimited JSON file, the columns of the table are read from the keys of the
+/// objects with the same names. Nested objects and arrays are read as their JSON text.
+pub struct JsonTable {
+    table_info: TableInfo,
+    file: String,
+}
+
+impl JsonTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = table_info.options();
+        let file = match options.get("location") {
+            None => {
+                return Result::Err(ErrorCode::BadOption(
+                    "JSONEachRow Engine must contains file location options",
+                ));
+            }
+            Some(v) => v.clone(),
+        };
+
+        Ok(Box::new(Self { table_info, file }))
+    }
+
+    fn get_dal(ctx: &QueryContext) -> Arc<Local> {
+        let conf = ctx.get_config().storage;
+        Arc::new(Local::new(conf.disk.temp_data_path.as_str()))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for JsonTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<QueryContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        let file = &self.file;
+        let content = Self::get_dal(&ctx).read(file).await?;
+        // Blank lines are counted too, so the number of rows is an estimation.
+        let lines_count = count_lines(content.as_slice())?;
+        let bytes = content.len();
+
+        let parts = vec![Part {
+            name: file.clone(),
+            version: 0,
+        }];
+        Ok((Statistics::new_estimated(lines_count, bytes), parts))
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let ctx_clone = ctx.clone();
+        let schema = plan.schema();
+        let block_size = ctx.get_settings().get_max_block_size()? as usize;
+
+        let dal = Self::get_dal(&ctx);
+
+        // The stream ends at the first error, the rest of the file is not parsed.
+        let s = stream! {
+            'partitions: loop {
+                let partitions = match ctx_clone.try_get_partitions(1) {
+                    Ok(partitions) if partitions.is_empty() => break,
+                    Ok(partitions) => partitions,
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
+                };
+
+                let part = partitions.get(0).unwrap();
+                let source = dal.get_input_stream(&part.name, None).and_then(|reader| {
+                    NDJsonSource::try_create(reader, schema.clone(), block_size)
+                });
+                let mut source = match source {
+                    Ok(source) => source,
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
+                };
+
+                loop {
+                    match source.read().await {
+                        Ok(None) => break,
+                        Ok(Some(b)) => yield(Ok(b)),
+                        Err(e) => {
+                            yield(Err(e));
+                            break 'partitions;
+                        }
+                    }
+                }
+            }
+        };
+
+        // Parse in a separate task, which is at most two blocks ahead of the consumer.
+        let (sender, receiver) = mpsc::channel::<Result<DataBlock>>(2);
+        ctx.try_spawn(async move {
+            let mut s = Box::pin(s);
+            while let Some(block) = s.next().await {
+                if let Err(error) = sender.send(block).await {
+                    // Stop parsing, the consumer is gone.
+                    tracing::debug!("JSONEachRow table cannot push data: {}", error);
+                    return;
+                }
+            }
+        })?;
+        Ok(Box::pin(ReceiverStream::new(receiver)))
+    }
+}
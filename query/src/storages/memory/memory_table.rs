@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::columns::DataColumn;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
 use common_meta_types::TableInfo;
@@ -58,6 +59,40 @@ impl MemoryTable {
         let table = Self { table_info, blocks };
         Ok(Box::new(table))
     }
+
+    /// Append a block to the in memory data, it is visible to the reads that start after this.
+    pub fn append_block(&self, block: DataBlock) -> Result<()> {
+        self.check_block(&block)?;
+        self.blocks.write().push(block);
+        Ok(())
+    }
+
+    /// The columns of the block must have the types of the table columns, in the same order.
+    fn check_block(&self, block: &DataBlock) -> Result<()> {
+        let schema = self.table_info.schema();
+        if block.num_columns() != schema.fields().len() {
+            return Err(ErrorCode::DataStructMissMatch(format!(
+                "Memory table {} has {} columns, but the block has {}",
+                self.table_info.desc,
+                schema.fields().len(),
+                block.num_columns()
+            )));
+        }
+
+        for (field, block_field) in schema.fields().iter().zip(block.schema().fields()) {
+            if field.data_type() != block_field.data_type() {
+                return Err(ErrorCode::DataStructMissMatch(format!(
+                    "Column {} of memory table {} is {}, but the block column {} is {}",
+                    field.name(),
+                    self.table_info.desc,
+                    field.data_type(),
+                    block_field.name(),
+                    block_field.data_type()
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -91,20 +126,19 @@ impl Table for MemoryTable {
                     None => Box::new(|_: usize| true),
                 };
 
-                blocks
+                let rows = blocks.iter().map(|block| block.num_rows()).sum();
+                let bytes = blocks
                     .iter()
-                    .fold(Statistics::default(), |mut stats, block| {
-                        stats.read_rows += block.num_rows() as usize;
-                        stats.read_bytes += (0..block.num_columns())
-                            .into_iter()
-                            .collect::<Vec<usize>>()
-                            .iter()
-                            .filter(|cid| projection_filter(**cid))
-                            .map(|cid| block.columns()[*cid].get_array_memory_size() as u64)
-                            .sum::<u64>() as usize;
-
-                        stats
+                    .map(|block| {
+                        (0..block.num_columns())
+                            .filter(|cid| projection_filter(*cid))
+                            .map(|cid| block.columns()[cid].get_array_memory_size())
+                            .sum::<usize>()
                     })
+                    .sum();
+
+                // The blocks are all in memory, thus the statistics are exact.
+                Statistics::new_exact(rows, bytes)
             }
             None => {
                 let rows = blocks.iter().map(|block| block.num_rows()).sum();
@@ -168,14 +202,16 @@ impl Table for MemoryTable {
         operations: Vec<DataBlock>,
         overwrite: bool,
     ) -> Result<()> {
-        if overwrite {
-            let mut blocks = self.blocks.write();
-            blocks.clear();
+        // Check all the blocks first, so nothing is written if one of them is invalid.
+        for block in operations.iter() {
+            self.check_block(block)?;
         }
+
         let mut blocks = self.blocks.write();
-        for block in operations {
-            blocks.push(block);
+        if overwrite {
+            blocks.clear();
         }
+        blocks.extend(operations);
         Ok(())
     }
 
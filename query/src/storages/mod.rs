@@ -16,6 +16,7 @@ pub mod csv;
 pub mod fuse;
 pub mod github;
 pub mod index;
+pub mod json;
 pub mod memory;
 pub mod null;
 pub mod parquet;
@@ -12,6 +12,14 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod parquet_location;
+mod parquet_partitions;
 mod parquet_table;
+mod row_group_pruner;
 
+pub use parquet_location::LocalParquetSource;
+pub use parquet_location::ParquetLocation;
+pub use parquet_location::ParquetSource;
+pub use parquet_location::S3ParquetSource;
+pub use parquet_partitions::node_partitions;
 pub use parquet_table::ParquetTable;
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_dal::DataAccessor;
+use common_dal::Local;
+use common_dal::S3;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+
+const S3_SCHEME: &str = "s3://";
+
+/// Where the parquet files of a table are stored, selected by the scheme of the `location` option.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParquetLocation {
+    /// A file or a directory of files, relative to the `temp_data_path` of the disk storage.
+    Local(String),
+    /// An object `s3://bucket/key`, read with the region, endpoint and credentials of the s3 storage.
+    S3 { bucket: String, key: String },
+}
+
+impl ParquetLocation {
+    pub fn parse(location: &str) -> Result<Self> {
+        let scheme = location.get(..S3_SCHEME.len());
+        if !matches!(scheme, Some(s) if s.eq_ignore_ascii_case(S3_SCHEME)) {
+            return Ok(ParquetLocation::Local(location.to_string()));
+        }
+
+        match location[S3_SCHEME.len()..].split_once('/') {
+            Some((bucket, key)) if !bucket.is_empty() && !key.is_empty() => {
+                Ok(ParquetLocation::S3 {
+                    bucket: bucket.to_string(),
+                    key: key.to_string(),
+                })
+            }
+            _ => Err(ErrorCode::BadOption(format!(
+                "Parquet Engine location must be s3://bucket/key for s3, but got {}",
+                location
+            ))),
+        }
+    }
+
+    /// The source reading the files at this location.
+    pub fn source(&self) -> Arc<dyn ParquetSource> {
+        match self {
+            ParquetLocation::Local(location) => Arc::new(LocalParquetSource {
+                location: location.clone(),
+            }),
+            ParquetLocation::S3 { bucket, key } => Arc::new(S3ParquetSource {
+                bucket: bucket.clone(),
+                key: key.clone(),
+            }),
+        }
+    }
+}
+
+/// Opens and lists the parquet files of a table, an implementation for each kind of
+/// `ParquetLocation`. Another object storage is plugged in by implementing it over the
+/// `DataAccessor` of that storage.
+#[async_trait::async_trait]
+pub trait ParquetSource: Send + Sync {
+    /// Opens the accessor reading the files listed by `list_files`.
+    fn open(&self, ctx: &QueryContext) -> Result<Arc<dyn DataAccessor>>;
+
+    /// List the files of the table.
+    async fn list_files(&self, ctx: &QueryContext) -> Result<Vec<String>>;
+
+    /// Whether the files may differ among the nodes of a cluster.
+    fn is_local(&self) -> bool;
+}
+
+/// A file or a directory of files, relative to the `temp_data_path` of the disk storage.
+pub struct LocalParquetSource {
+    location: String,
+}
+
+#[async_trait::async_trait]
+impl ParquetSource for LocalParquetSource {
+    fn open(&self, ctx: &QueryContext) -> Result<Arc<dyn DataAccessor>> {
+        Ok(Arc::new(Local::new(&local_root(ctx))))
+    }
+
+    /// A directory stands for all the `.parquet` files in it, sorted by name, any other location
+    /// for the file itself.
+    async fn list_files(&self, ctx: &QueryContext) -> Result<Vec<String>> {
+        let location = &self.location;
+
+        // Errors on an invalid path are left to the read of the file.
+        let path = match Local::new(&local_root(ctx)).prefix_with_root(location) {
+            Ok(path) => path,
+            Err(_) => return Ok(vec![location.to_string()]),
+        };
+        match tokio::fs::metadata(&path).await {
+            Ok(metadata) if metadata.is_dir() => {}
+            _ => return Ok(vec![location.to_string()]),
+        }
+
+        let mut files = vec![];
+        let mut entries = tokio::fs::read_dir(&path).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let is_file = tokio::fs::metadata(entry.path()).await?.is_file();
+            if is_file && name.ends_with(".parquet") {
+                files.push(format!("{}/{}", location.trim_end_matches('/'), name));
+            }
+        }
+        if files.is_empty() {
+            return Err(ErrorCode::ParquetError(format!(
+                "No parquet file found in directory {}",
+                location
+            )));
+        }
+
+        files.sort();
+        Ok(files)
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+/// An object `s3://bucket/key`, read with the region, endpoint and credentials of the s3 storage.
+pub struct S3ParquetSource {
+    bucket: String,
+    key: String,
+}
+
+#[async_trait::async_trait]
+impl ParquetSource for S3ParquetSource {
+    fn open(&self, ctx: &QueryContext) -> Result<Arc<dyn DataAccessor>> {
+        let conf = ctx.get_config().storage.s3;
+        Ok(Arc::new(S3::try_create(
+            &conf.region,
+            &conf.endpoint_url,
+            &self.bucket,
+            &conf.access_key_id,
+            &conf.secret_access_key,
+            conf.enable_pod_iam_policy,
+        )?))
+    }
+
+    /// The object is always a single file, listing a prefix is not supported yet.
+    async fn list_files(&self, _ctx: &QueryContext) -> Result<Vec<String>> {
+        Ok(vec![self.key.clone()])
+    }
+
+    // An S3 object is read by every node of the cluster.
+    fn is_local(&self) -> bool {
+        false
+    }
+}
+
+fn local_root(ctx: &QueryContext) -> String {
+    ctx.get_config().storage.disk.temp_data_path
+}
@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use common_planners::Part;
+use common_planners::Partitions;
+
+/// Select the partitions read by the `node_index`-th of `nodes` cluster nodes.
+///
+/// A partition is assigned by the hash of its name, so the assignment only depends on the
+/// partition itself, not on the order or the number of the other partitions.
+/// Every partition is assigned to exactly one node.
+pub fn node_partitions(parts: &[Part], nodes: usize, node_index: usize) -> Partitions {
+    parts
+        .iter()
+        .filter(|part| partition_node(part, nodes) == node_index)
+        .cloned()
+        .collect()
+}
+
+fn partition_node(part: &Part, nodes: usize) -> usize {
+    // DefaultHasher::new() always uses the same keys, unlike the hasher of a HashMap.
+    let mut hasher = DefaultHasher::new();
+    part.name.hash(&mut hasher);
+    (hasher.finish() % nodes.max(1) as u64) as usize
+}
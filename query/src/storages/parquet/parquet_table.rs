@@ -16,7 +16,15 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_stream::stream;
-use common_dal::Local;
+use common_arrow::arrow::io::parquet::read::get_schema;
+use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
+use common_base::tokio;
+use common_base::tokio::sync::mpsc;
+use common_base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
@@ -27,34 +35,208 @@ use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_streams::ParquetSource;
 use common_streams::SendableDataBlockStream;
-use common_streams::Source;
+use common_tracing::tracing;
+use futures::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
 
+use super::parquet_location;
+use super::parquet_location::ParquetLocation;
+use super::parquet_partitions::node_partitions;
+use super::row_group_pruner::RowGroupPruner;
 use crate::sessions::QueryContext;
 use crate::storages::StorageContext;
 use crate::storages::Table;
 
 pub struct ParquetTable {
     table_info: TableInfo,
-    file: String,
+    source: Arc<dyn parquet_location::ParquetSource>,
+    // The max number of row groups being read at the same time.
+    read_threads: usize,
+    // The max number of blocks read ahead of the consumer.
+    read_buffer_size: usize,
 }
 
+const DEFAULT_READ_BUFFER_SIZE: usize = 2;
+const MAX_READ_BUFFER_SIZE: usize = 64;
+
 impl ParquetTable {
     pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
         let options = table_info.options();
-        let file = options.get("location").cloned();
-        return match file {
-            Some(file) => {
-                let table = ParquetTable {
-                    table_info,
-                    file: file.trim_matches(|s| s == '\'' || s == '"').to_string(),
-                };
-                Ok(Box::new(table))
+        let source = match options.get("location") {
+            Some(location) => ParquetLocation::parse(trim_option(location))?.source(),
+            None => {
+                return Err(ErrorCode::BadOption(
+                    "Parquet Engine must contains file location options".to_string(),
+                ));
             }
-            _ => Result::Err(ErrorCode::BadOption(
-                "Parquet Engine must contains file location options".to_string(),
-            )),
         };
+
+        let read_threads = match options.get("read_threads") {
+            None => 1,
+            Some(v) => match trim_option(v).parse::<usize>() {
+                Ok(n) if n > 0 => n,
+                _ => {
+                    return Err(ErrorCode::BadOption(format!(
+                        "Parquet Engine read_threads option must be a positive integer, but got {}",
+                        v
+                    )));
+                }
+            },
+        };
+
+        let read_buffer_size = match options.get("read_buffer_size") {
+            None => DEFAULT_READ_BUFFER_SIZE,
+            Some(v) => match trim_option(v).parse::<usize>() {
+                Ok(n) if n > 0 && n <= MAX_READ_BUFFER_SIZE => n,
+                _ => {
+                    return Err(ErrorCode::BadOption(format!(
+                        "Parquet Engine read_buffer_size option must be an integer between 1 and {}, but got {}",
+                        MAX_READ_BUFFER_SIZE, v
+                    )));
+                }
+            },
+        };
+
+        Ok(Box::new(ParquetTable {
+            table_info,
+            source,
+            read_threads,
+            read_buffer_size,
+        }))
+    }
+
+    /// Infer the table schema from the footer of the parquet file at `location`.
+    /// For a directory, the schema is inferred from its first parquet file,
+    /// and every other file must have the same columns.
+    pub async fn infer_schema(ctx: &QueryContext, location: &str) -> Result<DataSchemaRef> {
+        let files_source = ParquetLocation::parse(trim_option(location))?.source();
+        let dal = files_source.open(ctx)?;
+        let files = files_source.list_files(ctx).await?;
+
+        let mut inferred: Option<(String, DataSchemaRef)> = None;
+        for file in files {
+            let source = ParquetSource::new(
+                dal.clone(),
+                file.clone(),
+                DataSchemaRefExt::create(vec![]),
+                vec![],
+            );
+            let metadata = source.read_metadata().await?;
+            match &inferred {
+                None => inferred = Some((file, Arc::new(file_schema(&metadata)?))),
+                Some((first, schema)) => {
+                    check_schema(&file, schema, &metadata)?;
+                    let columns = file_schema(&metadata)?.fields().len();
+                    if columns != schema.fields().len() {
+                        return Err(ErrorCode::ParquetError(format!(
+                            "Parquet file {} has {} columns, but {} has {}",
+                            file,
+                            columns,
+                            first,
+                            schema.fields().len()
+                        )));
+                    }
+                }
+            }
+        }
+
+        // `list_files` returns at least one file.
+        Ok(inferred.unwrap().1)
+    }
+}
+
+/// Check the columns declared by the table against the schema stored in the parquet footer.
+/// The columns are matched by position, as the projection is applied to the file columns.
+fn check_schema(file: &str, table_schema: &DataSchemaRef, metadata: &FileMetaData) -> Result<()> {
+    let file_schema = file_schema(metadata)?;
+    let file_fields = file_schema.fields();
+
+    let mut differences = vec![];
+    for (idx, field) in table_schema.fields().iter().enumerate() {
+        match file_fields.get(idx) {
+            None => differences.push(format!(
+                "column {} `{}` {} is missing in the file",
+                idx,
+                field.name(),
+                field.data_type()
+            )),
+            Some(file_field) => {
+                let same_type = field.data_type() == file_field.data_type()
+                    || field.data_type().to_physical_type()
+                        == file_field.data_type().to_physical_type();
+                if field.name() != file_field.name() || !same_type {
+                    differences.push(format!(
+                        "column {} declared as `{}` {}, but is `{}` {} in the file",
+                        idx,
+                        field.name(),
+                        field.data_type(),
+                        file_field.name(),
+                        file_field.data_type()
+                    ));
+                }
+            }
+        }
     }
+
+    if differences.is_empty() {
+        return Ok(());
+    }
+
+    Err(ErrorCode::ParquetError(format!(
+        "Schema of parquet file {} does not match the table schema: {}",
+        file,
+        differences.join("; ")
+    )))
+}
+
+/// Keep the row groups holding the first `limit` rows of the file, the rows of a row group
+/// are known from the footer, so the row groups after the limit are not read at all.
+fn limit_row_groups(
+    metadata: &FileMetaData,
+    row_groups: Vec<usize>,
+    limit: Option<usize>,
+) -> Vec<usize> {
+    let limit = match limit {
+        None => return row_groups,
+        Some(limit) => limit,
+    };
+
+    let mut rows = 0;
+    row_groups
+        .into_iter()
+        .take_while(|idx| {
+            let needed = rows < limit;
+            rows += metadata.row_groups[*idx].num_rows();
+            needed
+        })
+        .collect()
+}
+
+fn file_schema(metadata: &FileMetaData) -> Result<DataSchema> {
+    let arrow_schema = get_schema(metadata).map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+    Ok(DataSchema::from(arrow_schema))
+}
+
+/// The partition of the `row_group` of `file`, e.g. `data.parquet#3`, or of all the row groups
+/// left by the filters, e.g. `data.parquet#`, if the footer of the file could not be read.
+fn part_name(file: &str, row_group: Option<usize>) -> String {
+    match row_group {
+        Some(idx) => format!("{}#{}", file, idx),
+        None => format!("{}#", file),
+    }
+}
+
+fn parse_part_name(name: &str) -> Result<(String, Option<usize>)> {
+    let invalid = || ErrorCode::ParquetError(format!("Invalid parquet partition {}", name));
+    let (file, row_group) = name.rsplit_once('#').ok_or_else(invalid)?;
+    match row_group {
+        "" => Ok((file.to_string(), None)),
+        idx => Ok((file.to_string(), Some(idx.parse().map_err(|_| invalid())?))),
+    }
+}
+
+fn trim_option(value: &str) -> &str {
+    value.trim_matches(|s| s == '\'' || s == '"')
 }
 
 #[async_trait::async_trait]
@@ -67,20 +249,83 @@ impl Table for ParquetTable {
         &self.table_info
     }
 
+    fn is_local(&self) -> bool {
+        self.source.is_local()
+    }
+
     fn benefit_column_prune(&self) -> bool {
         true
     }
 
+    fn cluster_partitions(&self, parts: &[Part], nodes: usize) -> Option<Vec<Partitions>> {
+        Some(
+            (0..nodes)
+                .map(|node_index| node_partitions(parts, nodes, node_index))
+                .collect(),
+        )
+    }
+
     async fn read_partitions(
         &self,
-        _ctx: Arc<QueryContext>,
-        _push_downs: Option<Extras>,
+        ctx: Arc<QueryContext>,
+        push_downs: Option<Extras>,
     ) -> Result<(Statistics, Partitions)> {
-        let parts = vec![Part {
-            name: self.file.clone(),
-            version: 0,
-        }];
-        Ok((Statistics::default(), parts))
+        // The row count in the footer is exact, so that `SELECT count(*)` is answered
+        // by the StatisticsExact optimizer without decoding any column.
+        // If a footer is unreadable, the error is left to `read`.
+        let dal = self.source.open(&ctx)?;
+        let pruner = RowGroupPruner::create(self.get_table_info().schema(), &push_downs);
+
+        let mut parts = vec![];
+        let mut statistics = Some((0, 0, true));
+        for file in self.source.list_files(&ctx).await? {
+            let source = ParquetSource::new(
+                dal.clone(),
+                file.clone(),
+                self.get_table_info().schema(),
+                vec![],
+            );
+            match source.read_metadata().await {
+                Ok(metadata) => {
+                    let row_groups = pruner.apply(&metadata);
+                    if row_groups.is_empty() {
+                        // No row group can match the filter, the file is not read at all.
+                        continue;
+                    }
+
+                    if let Some((rows, bytes, is_exact)) = statistics.as_mut() {
+                        for idx in &row_groups {
+                            *rows += metadata.row_groups[*idx].num_rows();
+                            *bytes += metadata.row_groups[*idx].total_byte_size();
+                        }
+                        *is_exact &= row_groups.len() == metadata.row_groups.len();
+                    }
+
+                    // A partition for each row group, which spreads even a single file over
+                    // the nodes of a cluster.
+                    for idx in row_groups {
+                        parts.push(Part {
+                            name: part_name(&file, Some(idx)),
+                            version: 0,
+                        });
+                    }
+                }
+                Err(_) => {
+                    statistics = None;
+                    parts.push(Part {
+                        name: part_name(&file, None),
+                        version: 0,
+                    });
+                }
+            }
+        }
+
+        let statistics = match statistics {
+            Some((rows, bytes, true)) => Statistics::new_exact(rows, bytes),
+            Some((rows, bytes, false)) => Statistics::new_estimated(rows, bytes),
+            None => Statistics::default(),
+        };
+        Ok((statistics, parts))
     }
 
     async fn read(
@@ -91,34 +336,136 @@ impl Table for ParquetTable {
         let ctx_clone = ctx.clone();
         let table_schema = self.get_table_info().schema();
         let projection = plan.projections();
-        let conf = ctx.get_config().storage;
-        let dal = Arc::new(Local::new(conf.disk.temp_data_path.as_str()));
+        let dal = self.source.open(&ctx)?;
+        let read_threads = self.read_threads;
+        let read_buffer_size = self.read_buffer_size;
+        let pruner = RowGroupPruner::create(table_schema.clone(), &plan.push_downs);
+
+        // Without filters and order by, the rows after the pushed down limit are never used,
+        // e.g. `SELECT * FROM t LIMIT 10`, so the reading stops once the limit is reached.
+        let mut remaining = plan.push_downs.as_ref().and_then(|extras| {
+            match extras.filters.is_empty() && extras.order_by.is_empty() {
+                true => extras.limit,
+                false => None,
+            }
+        });
+
+        let mut last_metadata: Option<(String, FileMetaData)> = None;
 
+        // The stream ends at the first error, so the query fails instead of seeing truncated results.
         let s = stream! {
-            loop {
-                let partitions = ctx_clone.try_get_partitions(1);
-                match partitions {
-                    Ok(partitions) => {
-                        if partitions.is_empty() {
-                            break;
-                        }
-                        let part = partitions.get(0).unwrap();
+            'partitions: loop {
+                if remaining == Some(0) {
+                    break;
+                }
+                let partitions = match ctx_clone.try_get_partitions(1) {
+                    Ok(partitions) if partitions.is_empty() => break,
+                    Ok(partitions) => partitions,
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
+                };
+                let part = partitions.get(0).unwrap();
+                let (file, row_group) = match parse_part_name(&part.name) {
+                    Ok(file_row_group) => file_row_group,
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
+                };
+
+                // The partitions of the row groups of a file are usually adjacent,
+                // so the footer of the last file is kept instead of reading it for every part.
+                let source = ParquetSource::new(dal.clone(), file.clone(), table_schema.clone(), projection.clone());
+                let metadata = match last_metadata.take() {
+                    Some((last_file, metadata)) if last_file == file => Ok(metadata),
+                    _ => source.read_metadata().await.and_then(|metadata| {
+                        check_schema(&file, &table_schema, &metadata)?;
+                        Ok(metadata)
+                    }),
+                };
+                let metadata = match metadata {
+                    Ok(metadata) => metadata,
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
+                };
+
+                let row_groups = match row_group {
+                    None => pruner.apply(&metadata),
+                    Some(idx) if idx < metadata.row_groups.len() => vec![idx],
+                    Some(idx) => {
+                        yield(Err(ErrorCode::ParquetError(format!(
+                            "Parquet file {} has {} row groups, but the row group {} is read",
+                            file,
+                            metadata.row_groups.len(),
+                            idx
+                        ))));
+                        break;
+                    }
+                };
 
-                        let mut source = ParquetSource::new(dal.clone(), part.name.clone(), table_schema.clone(), projection.clone());
+                // Up to `read_threads` row groups are read concurrently, `buffered` still
+                // yields the blocks in the row group order of the file.
+                let row_groups = limit_row_groups(&metadata, row_groups, remaining);
+                let mut blocks = futures::stream::iter(row_groups)
+                    .map(|row_group| source.read_row_group(&metadata, row_group))
+                    .buffered(read_threads);
 
-                        loop {
-                            let block = source.read().await;
-                            match block {
-                                Ok(None) => break,
-                                Ok(Some(b)) =>  yield(Ok(b)),
-                                Err(e) => yield(Err(e)),
-                            }
+                let mut failed = false;
+                while let Some(block) = blocks.next().await {
+                    let block = match (block, remaining.as_mut()) {
+                        (Ok(block), Some(remaining)) if block.num_rows() > *remaining => {
+                            let block = block.slice(0, *remaining);
+                            *remaining = 0;
+                            Ok(block)
+                        }
+                        (Ok(block), Some(remaining)) => {
+                            *remaining -= block.num_rows();
+                            Ok(block)
                         }
+                        (block, _) => block,
+                    };
+
+                    failed = block.is_err();
+                    yield(block);
+                    if failed || remaining == Some(0) {
+                        break;
                     }
-                    Err(e) =>  yield(Err(e))
                 }
+                if failed || remaining == Some(0) {
+                    break 'partitions;
+                }
+                drop(blocks);
+                last_metadata = Some((file, metadata));
             }
         };
-        Ok(Box::pin(s))
+
+        // The blocks are read in a separate task, which may run up to `read_buffer_size`
+        // blocks ahead of the consumer before it is blocked by the channel.
+        // Dropping the returned stream closes the channel, the task then stops at once,
+        // even in the middle of reading a row group, instead of at its next send.
+        let (sender, receiver) = mpsc::channel::<Result<DataBlock>>(read_buffer_size);
+        ctx.try_spawn(async move {
+            let mut s = Box::pin(s);
+            loop {
+                let block = tokio::select! {
+                    block = s.next() => block,
+                    _ = sender.closed() => None,
+                };
+                let block = match block {
+                    Some(block) => block,
+                    None => return,
+                };
+                if let Err(error) = sender.send(block).await {
+                    // Stop reading, the consumer is gone.
+                    tracing::debug!("Parquet table cannot push data: {}", error);
+                    return;
+                }
+            }
+        })?;
+        Ok(Box::pin(ReceiverStream::new(receiver)))
     }
 }
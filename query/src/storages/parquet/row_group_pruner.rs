@@ -0,0 +1,124 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use common_arrow::arrow::io::parquet::read::schema::FileMetaData;
+use common_arrow::parquet::metadata::RowGroupMetaData;
+use common_arrow::parquet::statistics::BinaryStatistics;
+use common_arrow::parquet::statistics::PrimitiveStatistics;
+use common_arrow::parquet::statistics::Statistics as ParquetStatistics;
+use common_datavalues::prelude::*;
+use common_planners::Extras;
+
+use crate::storages::index::BlockStatistics;
+use crate::storages::index::ColumnStatistics;
+use crate::storages::index::RangeFilter;
+
+/// Skips the row groups whose min/max statistics in the parquet footer can not match the filter.
+pub struct RowGroupPruner {
+    schema: DataSchemaRef,
+    filter: Option<RangeFilter>,
+}
+
+impl RowGroupPruner {
+    pub fn create(schema: DataSchemaRef, push_downs: &Option<Extras>) -> Self {
+        let filter = match push_downs {
+            // for the time being, we only handle the first expr, as the fuse engine does.
+            // If the filter can not be analyzed, every row group is read.
+            Some(exprs) if !exprs.filters.is_empty() => {
+                RangeFilter::try_create(&exprs.filters[0], schema.clone()).ok()
+            }
+            _ => None,
+        };
+        RowGroupPruner { schema, filter }
+    }
+
+    /// Returns the indexes of the row groups that may contain matching rows, in file order.
+    pub fn apply(&self, metadata: &FileMetaData) -> Vec<usize> {
+        let row_groups = 0..metadata.row_groups.len();
+        match &self.filter {
+            None => row_groups.collect(),
+            Some(filter) => row_groups
+                .filter(|idx| {
+                    let stats = row_group_statistics(&self.schema, &metadata.row_groups[*idx]);
+                    // A row group without usable statistics of the filtered columns is kept.
+                    filter.eval(&stats).unwrap_or(true)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Collects the statistics of the columns by their index in the table schema,
+/// as the columns are matched with the file columns by position.
+fn row_group_statistics(schema: &DataSchemaRef, row_group: &RowGroupMetaData) -> BlockStatistics {
+    let mut stats = BlockStatistics::new();
+    for (idx, field) in schema.fields().iter().enumerate() {
+        if idx >= row_group.columns().len() {
+            break;
+        }
+        let col_meta = row_group.column(idx);
+        let col_stats = match col_meta.statistics() {
+            Some(Ok(col_stats)) => col_stats,
+            _ => continue,
+        };
+        if let Some((min, max)) = min_max(field.data_type(), col_stats.as_ref()) {
+            if let Some(null_count) = col_stats.null_count() {
+                stats.insert(idx as u32, ColumnStatistics {
+                    min,
+                    max,
+                    null_count: null_count as u64,
+                    in_memory_size: col_meta.uncompressed_size() as u64,
+                });
+            }
+        }
+    }
+    stats
+}
+
+macro_rules! primitive_min_max {
+    ($STATS: expr, $PHYSICAL: ty, $VARIANT: ident, $NATIVE: ty) => {{
+        let s = $STATS
+            .as_any()
+            .downcast_ref::<PrimitiveStatistics<$PHYSICAL>>()?;
+        (
+            DataValue::$VARIANT(Some(s.min_value? as $NATIVE)),
+            DataValue::$VARIANT(Some(s.max_value? as $NATIVE)),
+        )
+    }};
+}
+
+/// The unsigned integers are not handled: they are stored as signed physical values,
+/// whose order may differ from the order of the unsigned values.
+fn min_max(data_type: &DataType, stats: &dyn ParquetStatistics) -> Option<(DataValue, DataValue)> {
+    let min_max = match data_type {
+        DataType::Int8 => primitive_min_max!(stats, i32, Int8, i8),
+        DataType::Int16 => primitive_min_max!(stats, i32, Int16, i16),
+        DataType::Int32 => primitive_min_max!(stats, i32, Int32, i32),
+        DataType::Int64 => primitive_min_max!(stats, i64, Int64, i64),
+        DataType::Float32 => primitive_min_max!(stats, f32, Float32, f32),
+        DataType::Float64 => primitive_min_max!(stats, f64, Float64, f64),
+        DataType::String => {
+            let s = stats.as_any().downcast_ref::<BinaryStatistics>()?;
+            (
+                DataValue::String(s.min_value.clone()),
+                DataValue::String(s.max_value.clone()),
+            )
+        }
+        _ => return None,
+    };
+    if min_max.0.is_null() || min_max.1.is_null() {
+        return None;
+    }
+    Some(min_max)
+}
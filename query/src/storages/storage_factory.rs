@@ -24,6 +24,7 @@ use crate::configs::Config;
 use crate::storages::csv::CsvTable;
 use crate::storages::fuse::FuseTable;
 use crate::storages::github::GithubTable;
+use crate::storages::json::JsonTable;
 use crate::storages::memory::MemoryTable;
 use crate::storages::null::NullTable;
 use crate::storages::parquet::ParquetTable;
@@ -58,6 +59,11 @@ impl StorageFactory {
             creators.insert("CSV".to_string(), Arc::new(CsvTable::try_create));
         }
 
+        // Register JSONEachRow table engine.
+        if conf.query.table_engine_json_enabled {
+            creators.insert("JSONEACHROW".to_string(), Arc::new(JsonTable::try_create));
+        }
+
         // Register memory table engine.
         if conf.query.table_engine_memory_enabled {
             creators.insert("MEMORY".to_string(), Arc::new(MemoryTable::try_create));
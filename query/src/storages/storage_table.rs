@@ -80,6 +80,12 @@ pub trait Table: Sync + Send {
         }]))
     }
 
+    /// Split the partitions of a cluster read among the `nodes` cluster nodes, the i-th
+    /// partitions are read by the i-th node. None leaves the split to the plan scheduler.
+    fn cluster_partitions(&self, _parts: &[Part], _nodes: usize) -> Option<Vec<Partitions>> {
+        None
+    }
+
     fn table_args(&self) -> Option<Vec<Expression>> {
         None
     }
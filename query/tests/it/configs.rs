@@ -61,6 +61,7 @@ rpc_tls_query_server_root_ca_cert = \"\"
 rpc_tls_query_service_domain_name = \"localhost\"
 table_engine_csv_enabled = false
 table_engine_parquet_enabled = false
+table_engine_json_enabled = false
 table_engine_memory_enabled = true
 database_engine_github_enabled = true
 wait_timeout_mills = 5000
@@ -137,6 +138,7 @@ fn test_env_config() -> Result<()> {
     std::env::set_var("S3_STORAGE_BUCKET", "us.bucket");
     std::env::set_var("QUERY_TABLE_ENGINE_CSV_ENABLED", "true");
     std::env::set_var("QUERY_TABLE_ENGINE_PARQUET_ENABLED", "true");
+    std::env::set_var("QUERY_TABLE_ENGINE_JSON_ENABLED", "true");
     std::env::set_var("QUERY_TABLE_ENGINE_MEMORY_ENABLED", "true");
     std::env::set_var("QUERY_DATABASE_ENGINE_GITHUB_ENABLED", "false");
     std::env::remove_var("CONFIG_FILE");
@@ -170,6 +172,7 @@ fn test_env_config() -> Result<()> {
 
     assert!(configured.query.table_engine_csv_enabled);
     assert!(configured.query.table_engine_parquet_enabled);
+    assert!(configured.query.table_engine_json_enabled);
     assert!(configured.query.table_engine_memory_enabled);
     assert!(!configured.query.database_engine_github_enabled);
 
@@ -204,6 +207,7 @@ fn test_env_config() -> Result<()> {
     std::env::remove_var("S3_STORAGE_ENABLE_POD_IAM_POLICY");
     std::env::remove_var("QUERY_TABLE_ENGINE_CSV_ENABLED");
     std::env::remove_var("QUERY_TABLE_ENGINE_PARQUET_ENABLED");
+    std::env::remove_var("QUERY_TABLE_ENGINE_JSON_ENABLED");
     std::env::remove_var("QUERY_TABLE_ENGINE_MEMORY_ENABLED");
     std::env::remove_var("QUERY_DATABASE_ENGINE_GITHUB_ENABLED");
     Ok(())
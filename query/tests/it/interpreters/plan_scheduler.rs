@@ -15,12 +15,15 @@
 use std::sync::Arc;
 
 use common_base::tokio;
-use common_datavalues::DataValue;
+use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
 use common_planners::*;
 use databend_query::api::FlightAction;
 use databend_query::interpreters::PlanScheduler;
 use databend_query::sessions::QueryContext;
+use databend_query::storages::parquet::node_partitions;
 
 use crate::tests::create_query_context_with_cluster;
 use crate::tests::ClusterDescriptor;
@@ -324,6 +327,67 @@ async fn test_scheduler_plan_with_convergent_and_normal_stage() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_scheduler_plan_with_cluster_parquet_source() -> Result<()> {
+    // The row groups of a single S3 parquet file are spread over the nodes.
+    let parts: Partitions = (0..20)
+        .map(|i| Part {
+            name: format!("s3://bucket/file.parquet#{}", i),
+            version: 0,
+        })
+        .collect();
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let table_info = TableInfo {
+        desc: "'default'.'test_parquet'".to_string(),
+        ident: Default::default(),
+        name: "test_parquet".to_string(),
+        meta: TableMeta {
+            schema: schema.clone(),
+            engine: "PARQUET".to_string(),
+            options: maplit::hashmap! {
+                "location".to_string() => "s3://bucket/file.parquet".to_string(),
+            },
+            ..Default::default()
+        },
+    };
+    let read_source = ReadDataSourcePlan {
+        table_info,
+        scan_fields: None,
+        parts: parts.clone(),
+        statistics: Statistics::default(),
+        description: "".to_string(),
+        tbl_args: None,
+        push_downs: None,
+    };
+
+    let context = create_env().await?;
+    let scheduler = PlanScheduler::try_create(context)?;
+    let scheduled_tasks = scheduler.reschedule(&PlanNode::Stage(StagePlan {
+        kind: StageKind::Convergent,
+        scatters_expr: Expression::create_literal(DataValue::UInt64(Some(0))),
+        input: Arc::new(PlanNode::ReadSource(read_source)),
+    }))?;
+
+    let expected: Vec<Partitions> = (0..2).map(|i| node_partitions(&parts, 2, i)).collect();
+    let mut read_parts = 0;
+    for (_node, remote_action) in scheduled_tasks.get_tasks()? {
+        match remote_action {
+            FlightAction::PrepareShuffleAction(action) => match &action.plan {
+                PlanNode::ReadSource(plan) => {
+                    assert!(!plan.parts.is_empty());
+                    assert!(expected.contains(&plan.parts));
+                    read_parts += plan.parts.len();
+                }
+                _ => panic!("the nodes must read the parquet partitions"),
+            },
+            _ => panic!(),
+        }
+    }
+    assert_eq!(parts.len(), read_parts);
+
+    Ok(())
+}
+
 async fn create_env() -> Result<Arc<QueryContext>> {
     create_query_context_with_cluster(
         ClusterDescriptor::new()
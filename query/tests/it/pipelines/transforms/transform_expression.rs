@@ -141,3 +141,63 @@ async fn test_transform_expression_issue2857() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_expression_array_join() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(8)?;
+    pipeline.add_source(Arc::new(source))?;
+
+    let parts = Expression::create_scalar_function("splitByChar", vec![
+        lit(",".as_bytes()),
+        lit("a,b".as_bytes()),
+    ]);
+    if let PlanNode::Projection(plan) = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .project(&[
+            col("number"),
+            Expression::create_scalar_function("arrayJoin", vec![parts]).alias("e"),
+        ])?
+        .build()?
+    {
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(ProjectionTransform::try_create(
+                plan.input.schema(),
+                plan.schema.clone(),
+                plan.expr.clone(),
+            )?))
+        })?;
+    }
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(16, result.iter().map(|b| b.num_rows()).sum::<usize>());
+
+    let expected = vec![
+        "+--------+---+",
+        "| number | e |",
+        "+--------+---+",
+        "| 0      | a |",
+        "| 0      | b |",
+        "| 1      | a |",
+        "| 1      | b |",
+        "| 2      | a |",
+        "| 2      | b |",
+        "| 3      | a |",
+        "| 3      | b |",
+        "| 4      | a |",
+        "| 4      | b |",
+        "| 5      | a |",
+        "| 5      | b |",
+        "| 6      | a |",
+        "| 6      | b |",
+        "| 7      | a |",
+        "| 7      | b |",
+        "+--------+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -220,6 +220,28 @@ fn create_table() -> Result<()> {
     });
     expect_parse_ok(sql, expected)?;
 
+    // the arguments of a custom type are kept in its name
+    let sql = "CREATE TABLE t(c1 DateTime64(6), c2 DateTime64)";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def(
+                "c1",
+                DataType::Custom(ObjectName(vec![Ident::new("DateTime64(6)")])),
+            ),
+            make_column_def(
+                "c2",
+                DataType::Custom(ObjectName(vec![Ident::new("DateTime64")])),
+            ),
+        ],
+        engine: "FUSE".to_string(),
+        options: maplit::hashmap! {},
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
     // create table as select statement
     let sql = "CREATE TABLE db1.test1(c1 int, c2 varchar(255)) ENGINE = Parquet location = 'batcave' AS SELECT * FROM t2";
     let expected = DfStatement::CreateTable(DfCreateTable {
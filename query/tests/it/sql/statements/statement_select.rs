@@ -116,6 +116,16 @@ async fn test_statement_select_analyze() -> Result<()> {
             query: "SELECT avg(number), max(number + 1) + 1 FROM numbers_mt(10000) GROUP BY 1;",
             expect: "QueryAnalyzeState { before_group_by: [1, number, (number + 1)], group_by: [1], aggregate: [avg(number), max((number + 1))], before_projection: [avg(number), (max((number + 1)) + 1)], projection: [avg(number), (max((number + 1)) + 1)] }",
         },
+        TestCase {
+            name: "Case when query",
+            query: "SELECT CASE WHEN number > 5 THEN 1 WHEN number > 2 THEN 2 ELSE 3 END FROM numbers(10)",
+            expect: "QueryAnalyzeState { before_projection: [multiIf((number > 5), 1, (number > 2), 2, 3)], projection: [multiIf((number > 5), 1, (number > 2), 2, 3)] }",
+        },
+        TestCase {
+            name: "Case operand query without else",
+            query: "SELECT CASE number WHEN 1 THEN 10 WHEN 2 THEN 20 END FROM numbers(10)",
+            expect: "QueryAnalyzeState { before_projection: [multiIf((number = 1), 10, (number = 2), 20, NULL)], projection: [multiIf((number = 1), 10, (number = 2), 20, NULL)] }",
+        },
     ];
 
     for test_case in &tests {
@@ -14,17 +14,23 @@
 //
 
 use std::env;
+use std::fs::File;
+use std::io::Write;
 
 use common_base::tokio;
 use common_datablocks::assert_blocks_sorted_eq;
+use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
 use common_planners::*;
 use databend_query::storages::csv::CsvTable;
+use databend_query::storages::Table;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
+use tempfile::TempDir;
 
 #[tokio::test]
 async fn test_csv_table() -> Result<()> {
@@ -141,3 +147,105 @@ async fn test_csv_table_parse_error() -> Result<()> {
     );
     Ok(())
 }
+
+fn create_csv_table(schema: DataSchemaRef, options: &[(&str, &str)]) -> Result<Box<dyn Table>> {
+    let options: TableOptions = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    CsvTable::try_create(crate::tests::create_storage_context()?, TableInfo {
+        desc: "'default'.'test_csv'".into(),
+        name: "test_csv".into(),
+        ident: Default::default(),
+        meta: TableMeta {
+            schema,
+            engine: "Csv".to_string(),
+            options,
+            ..Default::default()
+        },
+    })
+}
+
+async fn read_csv_table(table: Box<dyn Table>) -> Result<Vec<DataBlock>> {
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table
+        .read_plan(ctx.clone(), Some(Extras::default()))
+        .await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test]
+async fn test_csv_table_wrong_number_of_fields() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let path = tmp_dir.path().join("wrong_fields.csv");
+    let mut file = File::create(&path)?;
+    write!(file, "1,a\n2,b\n3,c,extra\n4,d\n")?;
+    let location = path.display().to_string();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt64, false),
+        DataField::new("name", DataType::String, false),
+    ]);
+    let table = create_csv_table(schema, &[("location", &location), ("strict_fields", "1")])?;
+    match read_csv_table(table).await {
+        Ok(_) => panic!("the third line should be rejected"),
+        Err(e) => {
+            assert_eq!(e.code(), ErrorCode::BadBytes("").code());
+            assert_eq!(e.message(), "Expected 2 fields in csv line 3, but got 3");
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_csv_table_format_options() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let path = tmp_dir.path().join("options.csv");
+    let mut file = File::create(&path)?;
+    write!(file, "id|name\n1|'a|b'\n2|'c'\n")?;
+    let location = path.display().to_string();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt64, false),
+        DataField::new("name", DataType::String, false),
+    ]);
+    let table = create_csv_table(schema, &[
+        ("location", &location),
+        ("has_header", "1"),
+        ("delimiter", "|"),
+        ("quote", "'"),
+    ])?;
+
+    let result = read_csv_table(table).await?;
+    assert_blocks_sorted_eq(
+        vec![
+            "+----+------+",
+            "| id | name |",
+            "+----+------+",
+            "| 1  | a|b  |",
+            "| 2  | c    |",
+            "+----+------+",
+        ],
+        result.as_slice(),
+    );
+    Ok(())
+}
+
+#[test]
+fn test_csv_table_invalid_delimiter() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::UInt64, false)]);
+    let table = create_csv_table(schema, &[("location", "test.csv"), ("delimiter", "||")]);
+
+    match table {
+        Ok(_) => panic!("delimiter || should be rejected"),
+        Err(e) => assert_eq!(
+            e.message(),
+            "CSV Engine delimiter option must be a single ascii character, but got ||"
+        ),
+    }
+    Ok(())
+}
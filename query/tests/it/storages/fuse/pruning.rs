@@ -58,6 +58,7 @@ async fn test_block_pruner() -> Result<()> {
             options: [(TBL_OPT_KEY_CHUNK_BLOCK_NUM.to_owned(), "1".to_owned())].into(),
             ..Default::default()
         },
+        expire_at: None,
     };
 
     let catalog = ctx.get_catalog();
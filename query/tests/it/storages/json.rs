@@ -0,0 +1,156 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::fs;
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::*;
+use databend_query::storages::json::JsonTable;
+use databend_query::storages::Table;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+use tempfile::TempDir;
+
+fn create_json_table(schema: DataSchemaRef, options: &[(&str, &str)]) -> Result<Box<dyn Table>> {
+    let options: TableOptions = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    JsonTable::try_create(crate::tests::create_storage_context()?, TableInfo {
+        desc: "'default'.'test_json'".into(),
+        name: "test_json".into(),
+        ident: Default::default(),
+        meta: TableMeta {
+            schema,
+            engine: "JSONEachRow".to_string(),
+            options,
+            ..Default::default()
+        },
+    })
+}
+
+async fn read_json_table(table: &dyn Table) -> Result<Vec<DataBlock>> {
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test]
+async fn test_json_table() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("sample.ndjson");
+    let file = file.to_str().unwrap();
+    let content = concat!(
+        "{\"id\": 1, \"name\": \"a\", \"score\": 1.5, \"ok\": true, \"tags\": [1, 2]}\n",
+        "\n",
+        "{\"id\": 2, \"name\": \"b\", \"ok\": null, \"tags\": {\"x\": 1}}\n",
+        "{\"id\": 3, \"name\": \"c\", \"score\": 3.25, \"ok\": false, \"extra\": 0}\n",
+    );
+    fs::write(file, content)?;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::UInt64, false),
+        DataField::new("name", DataType::String, false),
+        DataField::new("score", DataType::Float64, true),
+        DataField::new("ok", DataType::Boolean, true),
+        DataField::new("tags", DataType::String, true),
+    ]);
+    let table = create_json_table(schema, &[("location", file)])?;
+
+    // The statistics are estimated from the lines and the size of the file.
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx, None).await?;
+    assert!(!source_plan.statistics.is_exact);
+    assert_eq!(source_plan.statistics.read_rows, 4);
+    assert_eq!(source_plan.statistics.read_bytes, content.len());
+
+    // Missing keys are nulls, unknown keys are ignored, nested values are kept as json text.
+    let result = read_json_table(table.as_ref()).await?;
+    let expected = vec![
+        "+----+------+-------+-------+---------+",
+        "| id | name | score | ok    | tags    |",
+        "+----+------+-------+-------+---------+",
+        "| 1  | a    | 1.5   | true  | [1,2]   |",
+        "| 2  | b    | NULL  | NULL  | {\"x\":1} |",
+        "| 3  | c    | 3.25  | false | NULL    |",
+        "+----+------+-------+-------+---------+",
+    ];
+    assert_blocks_eq(expected, result.as_slice());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_table_parse_error() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::UInt64, true)]);
+
+    struct Test {
+        name: &'static str,
+        content: &'static str,
+        error: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "not an object",
+            content: "{\"id\": 1}\n[1]\n",
+            error: "Expected a json object in ndjson line 2, but got [1]",
+        },
+        Test {
+            name: "invalid json",
+            content: "{\"id\": 1}\n\n{\"id\": \n",
+            error: "Parse ndjson error at line 3, cause: ",
+        },
+        Test {
+            name: "invalid value",
+            content: "{\"id\": \"abc\"}\n",
+            error: "Parse ndjson error at line 1, column id, cause: ",
+        },
+    ];
+
+    for (i, t) in tests.iter().enumerate() {
+        let file = tmp_dir.path().join(format!("error_{}.ndjson", i));
+        let file = file.to_str().unwrap();
+        fs::write(file, t.content)?;
+
+        let table = create_json_table(schema.clone(), &[("location", file)])?;
+        let err = read_json_table(table.as_ref()).await.unwrap_err();
+        assert!(
+            err.message().starts_with(t.error),
+            "case: {}, error: {}",
+            t.name,
+            err.message()
+        );
+    }
+
+    // The location is required.
+    match create_json_table(schema, &[]) {
+        Ok(_) => panic!("the location option is required"),
+        Err(e) => assert_eq!(
+            e.message(),
+            "JSONEachRow Engine must contains file location options"
+        ),
+    }
+    Ok(())
+}
@@ -114,8 +114,8 @@ async fn test_memorytable() -> Result<()> {
             ],
         ];
         let expected_statistics_vec = vec![
-            Statistics::new_estimated(4usize, 16usize),
-            Statistics::new_estimated(4usize, 32usize),
+            Statistics::new_exact(4usize, 16usize),
+            Statistics::new_exact(4usize, 32usize),
             Statistics::new_exact(4usize, 48usize),
         ];
 
@@ -200,3 +200,129 @@ async fn test_memorytable() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_memorytable_append_block() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt32, false),
+        DataField::new("b", DataType::UInt64, false),
+    ]);
+    let table = MemoryTable::try_create(crate::tests::create_storage_context()?, TableInfo {
+        desc: "'default'.'a'".into(),
+        name: "a".into(),
+        ident: Default::default(),
+        meta: TableMeta {
+            schema: schema.clone(),
+            engine: "Memory".to_string(),
+            options: TableOptions::default(),
+            ..Default::default()
+        },
+    })?;
+    let memory_table = table.as_any().downcast_ref::<MemoryTable>().unwrap();
+
+    memory_table.append_block(DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![1u32, 2]),
+        Series::new(vec![11u64, 22]),
+    ]))?;
+    memory_table.append_block(DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![3u32]),
+        Series::new(vec![33u64]),
+    ]))?;
+
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+    assert_eq!(
+        Statistics::new_exact(3usize, 36usize),
+        source_plan.statistics
+    );
+
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_blocks_sorted_eq(
+        vec![
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 1 | 11 |",
+            "| 2 | 22 |",
+            "| 3 | 33 |",
+            "+---+----+",
+        ],
+        &result,
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_memorytable_append_block_schema_mismatch() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt32, false),
+        DataField::new("b", DataType::UInt64, false),
+    ]);
+    let table = MemoryTable::try_create(crate::tests::create_storage_context()?, TableInfo {
+        desc: "'default'.'a'".into(),
+        name: "a".into(),
+        ident: Default::default(),
+        meta: TableMeta {
+            schema: schema.clone(),
+            engine: "Memory".to_string(),
+            options: TableOptions::default(),
+            ..Default::default()
+        },
+    })?;
+    let memory_table = table.as_any().downcast_ref::<MemoryTable>().unwrap();
+
+    // An empty table reads no block.
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+    assert_eq!(
+        Statistics::new_exact(0usize, 0usize),
+        source_plan.statistics
+    );
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_blocks_sorted_eq(vec!["++", "++"], &result);
+
+    let narrow_schema =
+        DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt32, false)]);
+    let err = memory_table
+        .append_block(DataBlock::create_by_array(narrow_schema, vec![
+            Series::new(vec![1u32]),
+        ]))
+        .unwrap_err();
+    assert_eq!(
+        "Code: 17, displayText = Memory table 'default'.'a' has 2 columns, but the block has 1.",
+        err.to_string()
+    );
+
+    let wrong_schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt32, false),
+        DataField::new("c", DataType::String, false),
+    ]);
+    let block = DataBlock::create_by_array(wrong_schema, vec![
+        Series::new(vec![1u32]),
+        Series::new(vec!["x"]),
+    ]);
+    let err = memory_table.append_block(block.clone()).unwrap_err();
+    assert_eq!(
+        "Code: 17, displayText = Column b of memory table 'default'.'a' is UInt64, but the block column c is String.",
+        err.to_string()
+    );
+
+    // Nothing is committed if one of the blocks is invalid.
+    let valid = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1u32]),
+        Series::new(vec![11u64]),
+    ]);
+    assert!(table
+        .commit(ctx.clone(), vec![valid, block], false)
+        .await
+        .is_err());
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    assert_eq!(0, source_plan.statistics.read_rows);
+
+    Ok(())
+}
@@ -15,6 +15,7 @@
 mod csv;
 mod fuse;
 mod index;
+mod json;
 mod memory;
 mod null;
 mod parquet;
@@ -14,51 +14,804 @@
 //
 
 use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
+use common_arrow::arrow::io::parquet::write::Compression;
 use common_base::tokio;
+use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
 use common_planners::*;
+use common_streams::collect_blocks;
+use databend_query::optimizers::Optimizer;
+use databend_query::optimizers::StatisticsExactOptimizer;
+use databend_query::storages::parquet::node_partitions;
+use databend_query::storages::parquet::ParquetLocation;
 use databend_query::storages::parquet::ParquetTable;
+use databend_query::storages::Table;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
+use tempfile::TempDir;
 
-#[tokio::test]
-async fn test_parquet_table() -> Result<()> {
-    let options: TableOptions = [(
-        "location".to_string(),
-        env::current_dir()?
-            .join("../tests/data/alltypes_plain.parquet")
-            .display()
-            .to_string(),
-    )]
-    .iter()
-    .cloned()
-    .collect();
+use crate::tests::ParquetTestData;
+
+fn create_parquet_table(schema: DataSchemaRef, options: &[(&str, &str)]) -> Result<Box<dyn Table>> {
+    let options: TableOptions = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
 
-    let ctx = crate::tests::create_query_context()?;
     let table_info = TableInfo {
         desc: "'default'.'test_parquet_table'".to_string(),
         ident: Default::default(),
         name: "test_parquet".to_string(),
         meta: TableMeta {
-            schema: DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]),
+            schema,
             engine: "test_parquet".into(),
             options,
             ..Default::default()
         },
     };
-    let table = ParquetTable::try_create(crate::tests::create_storage_context()?, table_info)?;
+    ParquetTable::try_create(crate::tests::create_storage_context()?, table_info)
+}
+
+fn alltypes_plain_location() -> Result<String> {
+    Ok(env::current_dir()?
+        .join("../tests/data/alltypes_plain.parquet")
+        .display()
+        .to_string())
+}
+
+#[tokio::test]
+async fn test_parquet_table() -> Result<()> {
+    let location = alltypes_plain_location()?;
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let table = create_parquet_table(schema, &[("location", &location)])?;
 
+    let ctx = crate::tests::create_query_context()?;
     let source_plan = table.read_plan(ctx.clone(), None).await?;
     ctx.try_set_partitions(source_plan.parts.clone())?;
 
     let stream = table.read(ctx, &source_plan).await?;
-    let blocks = stream.try_collect::<Vec<_>>().await?;
+    let blocks = collect_blocks(stream).await?;
     let rows: usize = blocks.iter().map(|block| block.num_rows()).sum();
 
     assert_eq!(rows, 8);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_parquet_table_count_from_metadata() -> Result<()> {
+    let location = alltypes_plain_location()?;
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let table = create_parquet_table(schema, &[("location", &location)])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    assert!(source_plan.statistics.is_exact);
+    assert_eq!(source_plan.statistics.read_rows, 8);
+
+    let aggr_expr = Expression::AggregateFunction {
+        op: "count".to_string(),
+        distinct: false,
+        params: vec![],
+        args: vec![Expression::create_literal(DataValue::UInt64(Some(0)))],
+    };
+    let source_plan = PlanNode::ReadSource(source_plan);
+    let plan = PlanBuilder::from(&source_plan)
+        .expression(
+            &[Expression::create_literal(DataValue::UInt64(Some(0)))],
+            "Before GroupBy",
+        )?
+        .aggregate_partial(&[aggr_expr.clone()], &[])?
+        .aggregate_final(source_plan.schema(), &[aggr_expr], &[])?
+        .project(&[Expression::Column("count(0)".to_string())])?
+        .build()?;
+
+    // The count comes from the footer, the parquet file is not scanned.
+    let mut statistics_exact = StatisticsExactOptimizer::create(ctx);
+    let optimized = statistics_exact.optimize(&plan)?;
+    let expect = "\
+        Projection: count(0):UInt64\
+        \n  Projection: 8 as count(0):UInt64\
+        \n    Expression: 8:UInt64 (Exact Statistics)\
+        \n      ReadDataSource: scan partitions: [1], scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1]";
+    assert_eq!(format!("{:?}", optimized), expect);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_concurrent_read() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("concurrent.parquet");
+    let file = file.to_str().unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    // Each block is written as a row group.
+    let blocks = (0..10)
+        .map(|i| {
+            let ids = (i * 100..(i + 1) * 100).collect::<Vec<i32>>();
+            DataBlock::create_by_array(schema.clone(), vec![Series::new(ids)])
+        })
+        .collect::<Vec<_>>();
+    ParquetTestData::create().write_to_parquet(file, &blocks);
+
+    let table = create_parquet_table(schema, &[("location", file), ("read_threads", "4")])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let blocks = collect_blocks(stream).await?;
+    assert_eq!(blocks.len(), 10);
+
+    // Row groups are read concurrently but still come out in file order.
+    let mut ids = vec![];
+    for block in blocks {
+        ids.extend(block.try_column_by_name("id")?.to_values()?);
+    }
+    let expected = (0..1000)
+        .map(|id| DataValue::Int32(Some(id)))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, expected);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_prune_row_groups() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("prune.parquet");
+    let file = file.to_str().unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    // Two row groups: [0, 100) and [100, 200).
+    let blocks = (0..2)
+        .map(|i| {
+            let ids = (i * 100..(i + 1) * 100).collect::<Vec<i32>>();
+            DataBlock::create_by_array(schema.clone(), vec![Series::new(ids)])
+        })
+        .collect::<Vec<_>>();
+    ParquetTestData::create().write_to_parquet(file, &blocks);
+
+    struct Test {
+        name: &'static str,
+        filter: Expression,
+        expect_parts: usize,
+        expect_rows: usize,
+        expect_exact: bool,
+        expect_ids: Vec<i32>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "the first row group is excluded",
+            filter: col("id").gt_eq(lit(150i32)),
+            expect_parts: 1,
+            expect_rows: 100,
+            expect_exact: false,
+            expect_ids: (100..200).collect(),
+        },
+        Test {
+            name: "every row group is excluded",
+            filter: col("id").gt(lit(1000i32)),
+            expect_parts: 0,
+            expect_rows: 0,
+            expect_exact: true,
+            expect_ids: vec![],
+        },
+        Test {
+            name: "a filter not analyzable reads everything",
+            filter: modular(col("id"), lit(7i32)).eq(lit(0i32)),
+            expect_parts: 2,
+            expect_rows: 200,
+            expect_exact: true,
+            expect_ids: (0..200).collect(),
+        },
+    ];
+
+    for t in tests {
+        let table = create_parquet_table(schema.clone(), &[("location", file)])?;
+
+        let ctx = crate::tests::create_query_context()?;
+        let push_downs = Extras {
+            projection: None,
+            filters: vec![t.filter],
+            limit: None,
+            order_by: vec![],
+        };
+        let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+        assert_eq!(source_plan.parts.len(), t.expect_parts, "case: {}", t.name);
+        assert_eq!(
+            source_plan.statistics.read_rows, t.expect_rows,
+            "case: {}",
+            t.name
+        );
+        assert_eq!(
+            source_plan.statistics.is_exact, t.expect_exact,
+            "case: {}",
+            t.name
+        );
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+
+        // The blocks hold the rows of the row groups read, the filter itself is not applied.
+        let stream = table.read(ctx, &source_plan).await?;
+        let blocks = collect_blocks(stream).await?;
+        let mut ids = vec![];
+        for block in blocks {
+            ids.extend(block.try_column_by_name("id")?.to_values()?);
+        }
+        let expect_ids = t
+            .expect_ids
+            .into_iter()
+            .map(|id| DataValue::Int32(Some(id)))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, expect_ids, "case: {}", t.name);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_limit_push_down() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("limit.parquet");
+    let file = file.to_str().unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    // Ten row groups of 100 rows.
+    let blocks = (0..10)
+        .map(|i| {
+            let ids = (i * 100..(i + 1) * 100).collect::<Vec<i32>>();
+            DataBlock::create_by_array(schema.clone(), vec![Series::new(ids)])
+        })
+        .collect::<Vec<_>>();
+    ParquetTestData::create().write_to_parquet(file, &blocks);
+
+    struct Test {
+        name: &'static str,
+        push_downs: Extras,
+        expect_rows: Vec<usize>,
+    }
+
+    let tests = vec![
+        Test {
+            name: "the limit stops in the middle of a row group",
+            push_downs: Extras {
+                limit: Some(250),
+                ..Extras::default()
+            },
+            expect_rows: vec![100, 100, 50],
+        },
+        Test {
+            name: "the limit stops at the end of a row group",
+            push_downs: Extras {
+                limit: Some(200),
+                ..Extras::default()
+            },
+            expect_rows: vec![100, 100],
+        },
+        Test {
+            name: "the limit is beyond the rows of the file",
+            push_downs: Extras {
+                limit: Some(5000),
+                ..Extras::default()
+            },
+            expect_rows: vec![100; 10],
+        },
+        Test {
+            name: "the limit is not applied before the filters",
+            push_downs: Extras {
+                filters: vec![col("id").gt_eq(lit(0i32))],
+                limit: Some(250),
+                ..Extras::default()
+            },
+            expect_rows: vec![100; 10],
+        },
+        Test {
+            name: "the limit is not applied before the order by",
+            push_downs: Extras {
+                limit: Some(250),
+                order_by: vec![col("id")],
+                ..Extras::default()
+            },
+            expect_rows: vec![100; 10],
+        },
+    ];
+
+    for t in tests {
+        let table = create_parquet_table(schema.clone(), &[("location", file)])?;
+
+        let ctx = crate::tests::create_query_context()?;
+        let source_plan = table.read_plan(ctx.clone(), Some(t.push_downs)).await?;
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+
+        let stream = table.read(ctx, &source_plan).await?;
+        let blocks = collect_blocks(stream).await?;
+        let rows = blocks.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        assert_eq!(rows, t.expect_rows, "case: {}", t.name);
+
+        let mut ids = vec![];
+        for block in blocks {
+            ids.extend(block.try_column_by_name("id")?.to_values()?);
+        }
+        let expect_ids = (0..rows.iter().sum::<usize>() as i32)
+            .map(|id| DataValue::Int32(Some(id)))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, expect_ids, "case: {}", t.name);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parquet_table_invalid_read_threads() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let table = create_parquet_table(schema, &[
+        ("location", "test.parquet"),
+        ("read_threads", "0"),
+    ]);
+
+    match table {
+        Ok(_) => panic!("read_threads = 0 should be rejected"),
+        Err(e) => assert_eq!(
+            e.message(),
+            "Parquet Engine read_threads option must be a positive integer, but got 0"
+        ),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_read_buffer_size() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("read_buffer.parquet");
+    let file = file.to_str().unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    // Many tiny row groups, the reader runs ahead of the consumer by up to 64 blocks.
+    let blocks = (0..200)
+        .map(|i| DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![i as i32])]))
+        .collect::<Vec<_>>();
+    ParquetTestData::create().write_to_parquet(file, &blocks);
+
+    let table = create_parquet_table(schema, &[
+        ("location", file),
+        ("read_threads", "4"),
+        ("read_buffer_size", "64"),
+    ])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let blocks = collect_blocks(stream).await?;
+
+    let mut ids = vec![];
+    for block in blocks {
+        ids.extend(block.try_column_by_name("id")?.to_values()?);
+    }
+    let expected = (0..200)
+        .map(|id| DataValue::Int32(Some(id)))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, expected);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_read_ahead() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("read_ahead.parquet");
+    let file = file.to_str().unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let blocks = (0..200)
+        .map(|i| DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![i as i32])]))
+        .collect::<Vec<_>>();
+    ParquetTestData::create().write_to_parquet(file, &blocks);
+
+    // A partition is a row group of one block, the reader takes it from the context to read it.
+    // While the consumer waits, the reader has read the consumed block, the buffered ones and
+    // the one it is blocked sending.
+    for read_buffer_size in [1, 64] {
+        let table = create_parquet_table(schema.clone(), &[
+            ("location", file),
+            ("read_buffer_size", &read_buffer_size.to_string()),
+        ])?;
+
+        let ctx = crate::tests::create_query_context()?;
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        assert_eq!(200, source_plan.parts.len());
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+
+        let mut stream = table.read(ctx.clone(), &source_plan).await?;
+        assert!(stream.try_next().await?.is_some());
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        let read = 200 - ctx.try_get_partitions(200)?.len();
+        assert!(
+            read > read_buffer_size && read <= read_buffer_size + 2,
+            "read_buffer_size {}, read {} partitions",
+            read_buffer_size,
+            read
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_drop_stream_stops_reader() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("drop_stream.parquet");
+    let file = file.to_str().unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let blocks = (0..200)
+        .map(|i| DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![i as i32])]))
+        .collect::<Vec<_>>();
+    ParquetTestData::create().write_to_parquet(file, &blocks);
+
+    let table = create_parquet_table(schema, &[("location", file), ("read_buffer_size", "1")])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    // The reader task holds a reference of the context until it terminates.
+    let references = Arc::strong_count(&ctx);
+    let mut stream = table.read(ctx.clone(), &source_plan).await?;
+    assert!(stream.try_next().await?.is_some());
+    drop(stream);
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while Arc::strong_count(&ctx) != references {
+        assert!(
+            Instant::now() < deadline,
+            "the reader task is still running"
+        );
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parquet_table_invalid_read_buffer_size() -> Result<()> {
+    for size in ["0", "65", "abc"] {
+        let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+        let table = create_parquet_table(schema, &[
+            ("location", "test.parquet"),
+            ("read_buffer_size", size),
+        ]);
+
+        match table {
+            Ok(_) => panic!("read_buffer_size = {} should be rejected", size),
+            Err(e) => assert_eq!(
+                e.message(),
+                format!(
+                    "Parquet Engine read_buffer_size option must be an integer between 1 and 64, but got {}",
+                    size
+                )
+            ),
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_snappy() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let file = tmp_dir.path().join("snappy.parquet");
+    let file = file.to_str().unwrap();
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("name", DataType::String, false),
+        DataField::new("age", DataType::Int32, false),
+    ]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec!["jack", "ace", "bohu"]),
+        Series::new(vec![11, 6, 24]),
+    ]);
+    ParquetTestData::create().write_to_parquet_with_compression(
+        file,
+        &[block.clone()],
+        Compression::Snappy,
+    );
+
+    let table = create_parquet_table(schema, &[("location", file)])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let blocks = collect_blocks(stream).await?;
+    assert_eq!(blocks.len(), 1);
+    for name in ["name", "age"] {
+        assert_eq!(
+            blocks[0].try_column_by_name(name)?.to_values()?,
+            block.try_column_by_name(name)?.to_values()?
+        );
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_schema_mismatch() -> Result<()> {
+    let location = alltypes_plain_location()?;
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::String, false),
+        DataField::new("bool_col", DataType::Boolean, false),
+    ]);
+    let table = create_parquet_table(schema, &[("location", &location)])?;
+
+    let ctx = crate::tests::create_query_context()?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    match collect_blocks(stream).await {
+        Ok(_) => panic!("reading with a mismatched schema should fail"),
+        Err(e) => {
+            assert_eq!(e.code(), ErrorCode::ParquetError("").code());
+            assert_eq!(
+                e.message(),
+                format!(
+                    "Schema of parquet file {} does not match the table schema: column 0 declared as `id` String, but is `id` Int32 in the file",
+                    location
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_read_errors() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let missing = tmp_dir.path().join("missing.parquet");
+    let corrupt = tmp_dir.path().join("corrupt.parquet");
+    std::fs::write(&corrupt, b"this is not a parquet file")?;
+
+    let cases = vec![
+        (missing, ErrorCode::DalPathNotFound("").code()),
+        (corrupt, ErrorCode::ParquetError("").code()),
+    ];
+
+    for (file, code) in cases {
+        let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+        let table = create_parquet_table(schema, &[("location", file.to_str().unwrap())])?;
+
+        let ctx = crate::tests::create_query_context()?;
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+
+        // The error must be surfaced by the stream, not swallowed into an empty result.
+        let stream = table.read(ctx, &source_plan).await?;
+        match collect_blocks(stream).await {
+            Ok(blocks) => panic!("expect error, but got {} blocks", blocks.len()),
+            Err(e) => assert_eq!(e.code(), code, "file: {:?}", file),
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_infer_schema() -> Result<()> {
+    let location = alltypes_plain_location()?;
+    let ctx = crate::tests::create_query_context()?;
+    let schema = ParquetTable::infer_schema(&ctx, &location).await?;
+
+    let expect = vec![
+        ("id", DataType::Int32),
+        ("bool_col", DataType::Boolean),
+        ("tinyint_col", DataType::Int32),
+        ("smallint_col", DataType::Int32),
+        ("int_col", DataType::Int32),
+        ("bigint_col", DataType::Int64),
+        ("float_col", DataType::Float32),
+        ("double_col", DataType::Float64),
+        ("date_string_col", DataType::String),
+        ("string_col", DataType::String),
+        ("timestamp_col", DataType::DateTime32(None)),
+    ];
+    let got = schema
+        .fields()
+        .iter()
+        .map(|f| (f.name().as_str(), f.data_type().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(got, expect);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_table_infer_schema_directory() -> Result<()> {
+    let tmp_dir = TempDir::new()?;
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("name", DataType::String, false),
+        DataField::new("age", DataType::Int32, false),
+    ]);
+    // The files are read in name order, other files in the directory are ignored.
+    for (file, age) in [("b.parquet", 2), ("a.parquet", 1)] {
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![file]),
+            Series::new(vec![age]),
+        ]);
+        let path = tmp_dir.path().join(file);
+        ParquetTestData::create().write_to_parquet(path.to_str().unwrap(), &[block]);
+    }
+    std::fs::write(tmp_dir.path().join("README"), b"not a parquet file")?;
+
+    let location = tmp_dir.path().to_str().unwrap();
+    let ctx = crate::tests::create_query_context()?;
+    let inferred = ParquetTable::infer_schema(&ctx, location).await?;
+    assert_eq!(inferred.fields().len(), 2);
+    assert_eq!(inferred.field(0).name(), "name");
+    assert_eq!(inferred.field(1).data_type(), &DataType::Int32);
+
+    let table = create_parquet_table(inferred, &[("location", location)])?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    assert_eq!(source_plan.parts.len(), 2);
+    assert!(source_plan.statistics.is_exact);
+    assert_eq!(source_plan.statistics.read_rows, 2);
+    ctx.try_set_partitions(source_plan.parts.clone())?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let blocks = collect_blocks(stream).await?;
+    let mut ages = vec![];
+    for block in blocks {
+        ages.extend(block.try_column_by_name("age")?.to_values()?);
+    }
+    assert_eq!(ages, vec![
+        DataValue::Int32(Some(1)),
+        DataValue::Int32(Some(2))
+    ]);
+
+    // A file with different columns is rejected.
+    let other = DataSchemaRefExt::create(vec![DataField::new("name", DataType::String, false)]);
+    let block = DataBlock::create_by_array(other, vec![Series::new(vec!["c"])]);
+    let path = tmp_dir.path().join("c.parquet");
+    ParquetTestData::create().write_to_parquet(path.to_str().unwrap(), &[block]);
+
+    let ctx = crate::tests::create_query_context()?;
+    match ParquetTable::infer_schema(&ctx, location).await {
+        Ok(_) => panic!("inferring the schema of mismatched files should fail"),
+        Err(e) => assert_eq!(
+            e.message(),
+            format!(
+                "Schema of parquet file {}/c.parquet does not match the table schema: column 1 `age` Int32 is missing in the file",
+                location
+            )
+        ),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_parquet_location_source() -> Result<()> {
+    let ctx = crate::tests::create_query_context()?;
+
+    let source = ParquetLocation::parse("s3://bucket/dir/file.parquet")?.source();
+    assert!(!source.is_local());
+    assert_eq!(
+        vec!["dir/file.parquet".to_string()],
+        source.list_files(&ctx).await?
+    );
+
+    let source = ParquetLocation::parse("tests/data/alltypes_plain.parquet")?.source();
+    assert!(source.is_local());
+    assert_eq!(
+        vec!["tests/data/alltypes_plain.parquet".to_string()],
+        source.list_files(&ctx).await?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parquet_location_parse() -> Result<()> {
+    assert_eq!(
+        ParquetLocation::parse("tests/data/alltypes_plain.parquet")?,
+        ParquetLocation::Local("tests/data/alltypes_plain.parquet".to_string())
+    );
+    assert_eq!(
+        ParquetLocation::parse("s3://bucket/dir/file.parquet")?,
+        ParquetLocation::S3 {
+            bucket: "bucket".to_string(),
+            key: "dir/file.parquet".to_string(),
+        }
+    );
+    assert_eq!(
+        ParquetLocation::parse("S3://bucket/file.parquet")?,
+        ParquetLocation::S3 {
+            bucket: "bucket".to_string(),
+            key: "file.parquet".to_string(),
+        }
+    );
+
+    for location in ["s3://bucket", "s3://bucket/", "s3:///file.parquet"] {
+        let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+        match create_parquet_table(schema, &[("location", location)]) {
+            Ok(_) => panic!("location {} must be rejected", location),
+            Err(e) => assert_eq!(
+                e.message(),
+                format!(
+                    "Parquet Engine location must be s3://bucket/key for s3, but got {}",
+                    location
+                )
+            ),
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parquet_node_partitions() -> Result<()> {
+    let parts: Partitions = (0..1000)
+        .map(|i| Part {
+            name: format!("data/part_{}.parquet", i),
+            version: 0,
+        })
+        .collect();
+
+    let nodes = 4;
+    let mut seen = vec![0; parts.len()];
+    for node_index in 0..nodes {
+        let node_parts = node_partitions(&parts, nodes, node_index);
+        // Around 250 partitions for each node.
+        assert!(
+            (200..=300).contains(&node_parts.len()),
+            "node {} reads {} partitions",
+            node_index,
+            node_parts.len()
+        );
+        // The assignment is deterministic.
+        assert_eq!(node_parts, node_partitions(&parts, nodes, node_index));
+
+        for part in node_parts {
+            let i = parts.iter().position(|p| p == &part).unwrap();
+            seen[i] += 1;
+        }
+    }
+    // Every partition is read by exactly one node.
+    assert!(seen.iter().all(|n| *n == 1));
+
+    // A partition keeps its node when the other partitions change.
+    let node_parts = node_partitions(&parts, nodes, 1);
+    let sub_node_parts = node_partitions(&parts[..500], nodes, 1);
+    assert!(sub_node_parts.iter().all(|p| node_parts.contains(p)));
+
+    // A single node reads everything.
+    assert_eq!(parts, node_partitions(&parts, 1, 0));
+    Ok(())
+}
+
+#[test]
+fn test_parquet_table_cluster_partitions() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("id", DataType::Int32, false)]);
+    let parts: Partitions = (0..10)
+        .map(|i| Part {
+            name: format!("s3://bucket/file.parquet#{}", i),
+            version: 0,
+        })
+        .collect();
+
+    // Only the S3 tables are read by the whole cluster.
+    let local = create_parquet_table(schema.clone(), &[("location", "tests/data")])?;
+    assert!(local.is_local());
+    let s3 = create_parquet_table(schema, &[("location", "s3://bucket/file.parquet")])?;
+    assert!(!s3.is_local());
+
+    let nodes_parts = s3.cluster_partitions(&parts, 3).unwrap();
+    assert_eq!(3, nodes_parts.len());
+    for (node_index, node_parts) in nodes_parts.iter().enumerate() {
+        assert_eq!(node_parts, &node_partitions(&parts, 3, node_index));
+    }
+    assert_eq!(
+        parts.len(),
+        nodes_parts.iter().map(|parts| parts.len()).sum::<usize>()
+    );
+    Ok(())
+}
@@ -38,7 +38,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 42);
+    assert_eq!(block.num_rows(), 43);
 
     let expected = vec![
         "+--------------------------------------+------------------+-------+-------------+",
@@ -80,6 +80,7 @@ async fn test_configs_table() -> Result<()> {
         "| database_engine_github_enabled       | true             | query |             |",
         "| table_engine_memory_enabled          | true             | query |             |",
         "| table_engine_parquet_enabled         | false            | query |             |",
+        "| table_engine_json_enabled            | false            | query |             |",
         "| tenant_id                            |                  | query |             |",
         "| wait_timeout_mills                   | 5000             | query |             |",
         "| table_cache_enabled                  | false            | query |             |",
@@ -54,11 +54,20 @@ impl ParquetTestData {
     }
 
     pub fn write_to_parquet(&self, path: &str, blocks: &[DataBlock]) {
+        self.write_to_parquet_with_compression(path, blocks, Compression::Uncompressed)
+    }
+
+    pub fn write_to_parquet_with_compression(
+        &self,
+        path: &str,
+        blocks: &[DataBlock],
+        compression: Compression,
+    ) {
         let schema = blocks[0].schema().to_arrow();
 
         let options = WriteOptions {
             write_statistics: true,
-            compression: Compression::Uncompressed,
+            compression,
             version: Version::V2,
         };
 
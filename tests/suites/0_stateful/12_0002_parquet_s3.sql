@@ -0,0 +1,8 @@
+DROP TABLE IF EXISTS default.test_parquet_s3;
+
+create table test_parquet_s3 Engine = Parquet location = 's3://testbucket/tests/data/alltypes_plain.parquet';
+
+select date_string_col, avg(id), max(bigint_col) from default.test_parquet_s3 group by date_string_col order by date_string_col desc;
+select count(*) from default.test_parquet_s3 where id > 3;
+
+DROP TABLE default.test_parquet_s3;
@@ -10,4 +10,7 @@ SELECT 20 DIV 6;
 SELECT 20 DIV 7;
 SELECT 20 DIV 8.1;
 SELECT 20.1 DIV 9;
+SELECT intDiv(-7, 2), -7 % 2, 7 % -2;
+SELECT intDiv(number, 0) FROM numbers(2); -- {ErrorCode 6}
+SELECT modulo(number, 0) FROM numbers(2); -- {ErrorCode 6}
 SELECT toTypeName(-toUInt32(1)), toTypeName(-toUInt64(1)), toTypeName(-toInt32(1)), toTypeName(-toFloat32(1)), toTypeName(+toUInt8(1));
@@ -28,3 +28,15 @@ SELECT  toDate('2021-03-05') + 1 = toDate('2021-03-06');
 SELECT  toString(toDate('2021-03-05') + 1) = '2021-03-06';
 SELECT toDateTime(toDate('2021-03-05')) = toDateTime('2021-03-05 00:00:00');
 SELECT toDate(toDateTime('2021-03-05 01:00:00')) = toDate('2021-03-05');
+SELECT toDateTime('2021-03-05 01:01:01.999') = toDateTime('2021-03-05 01:01:01');
+SELECT toDateTime('2021-03-05 09:01:01+08:00') = toDateTime('2021-03-05 01:01:01');
+SELECT toDateTime('2021-03-05 25:01:01');
+
+SELECT '===STRICT===';
+SELECT toUInt8Strict(255);
+SELECT toUInt8Strict(300); -- {ErrorCode 10}
+SELECT toInt32Strict('32');
+SELECT toInt32Strict('abc'); -- {ErrorCode 10}
+SELECT toDateTimeStrict('2021-03-05 01:01:01') = toDateTime('2021-03-05 01:01:01');
+SELECT toDateTimeStrict('2021-03-05 25:01:01'); -- {ErrorCode 10}
+SELECT toInt32Strict(NULL);
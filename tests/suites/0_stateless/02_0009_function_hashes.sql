@@ -32,3 +32,5 @@ SELECT XXHASH32('1.2');
 SELECT XXHASH64('1234567890');
 SELECT XXHASH64('1');
 SELECT XXHASH64('1.2');
+SELECT XXHASH64(toInt32(1));
+SELECT XXHASH64(toUInt64(1));
@@ -5,3 +5,5 @@ select if(number>0, 'Z+', 'zero') from numbers(3) order by number;
 select if(number<1, true, null) from numbers(3) order by number;
 select toTypeName(if(number % 3 = 0, toUInt32(1), toInt64(3))) from numbers(10) limit 1;
 select toTypeName(if(number % 3 = 0, toUInt32(1), toFloat32(3))) from numbers(10) limit 1;
+select if(null, 1, 2);
+select if(true, number, 0) from numbers(2) order by number;
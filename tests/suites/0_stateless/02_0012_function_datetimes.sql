@@ -29,6 +29,7 @@ select toStartOfFifteenMinutes(toDateTime(1630320462));
 select toStartOfMinute(toDateTime(1630320462));
 select toStartOfFiveMinutes(toDateTime(1630320462));
 select toStartOfTenMinutes(toDateTime(1630320462));
+select toStartOfDay(toDateTime(1630320462));
 select timeSlot(now()) <= now();
 select '===round-end===';
 
@@ -166,4 +167,5 @@ select toSecond(toDateTime(1634551542))  =  42;
 select '===toSecond===';
 select '===toMonday===';
 select toMonday(toDateTime(1634614318))  =  toDate('2021-10-18');
+select toMonday(toDate(18919))  =  toDate('2021-10-18');
 select '===toMonday===';
@@ -6,5 +6,10 @@ INSERT INTO nullable_test VALUES(1, Null), (Null, 2), (3, 3);
 SELECT a, isNull(a), b, isNotNull(b) FROM nullable_test ORDER BY a, b ASC;
 SELECT a FROM nullable_test WHERE a is Not Null ORDER BY a;
 SELECT b FROM nullable_test WHERE a is Null ORDER BY b;
+SELECT a, b, coalesce(a, b), coalesce(b, a, 0) FROM nullable_test ORDER BY a, b ASC;
+SELECT a, b, nullIf(a, b) FROM nullable_test ORDER BY a, b ASC;
+SELECT coalesce(null, null);
+SELECT a, b, ifNull(a, b), ifNull(b, 0) FROM nullable_test ORDER BY a, b ASC;
+SELECT ifNull(null, null);
 
 DROP TABLE IF EXISTS nullable_test;
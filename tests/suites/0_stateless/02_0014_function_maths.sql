@@ -11,6 +11,7 @@ SELECT abs('-233.0');
 SELECT abs('blah') = 0;
 SELECT abs(TRUE); -- {ErrorCode 7}
 SELECT abs(NULL);
+SELECT abs(toInt8(-128));
 SELECT abs(value) FROM math_sample_numbers;
 SELECT abs(value) + abs(-1) FROM math_sample_numbers;
 
@@ -23,6 +23,8 @@ drop table strings_repeat_sample_u64;
 select '=======> const_const';
 select repeat('abc', 3);
 select repeat('abc', 0);
+select repeat('abc', -1);
+select repeat('abc', 1000001); -- {ErrorCode 6}
 
 select '=======> const_array';
 CREATE TABLE strings_repeat_sample_2_u8(n Uint8) engine=Memory;
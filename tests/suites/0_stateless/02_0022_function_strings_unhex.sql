@@ -2,3 +2,6 @@ select unhex('616263');
 select unhex('hello');
 select unhex(hex('hello'));
 select unhex(null);
+select unhex('616');
+select unhex('6G');
+select unhex('6A6B');
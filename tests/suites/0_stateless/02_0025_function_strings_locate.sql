@@ -2,6 +2,8 @@ SELECT '===LOCATE===';
 SELECT LOCATE('bar', 'foobarbar');
 SELECT LOCATE('xbar', 'foobar');
 SELECT LOCATE('bar', 'foobarbar', 5);
+SELECT LOCATE('好', '你好好');
+SELECT LOCATE('好', '你好好', 3);
 SELECT '===POSITION===';
 SELECT POSITION('bar' IN 'foobarbar');
 SELECT POSITION('xbar' IN 'foobar');
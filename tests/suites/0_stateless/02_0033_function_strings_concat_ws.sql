@@ -5,3 +5,6 @@ SELECT CONCAT_WS(NULL, 'data', 'fuse', 'labs');
 SELECT CONCAT_WS(',', number, 'data', number+1) from numbers(3) order by number;
 SELECT CONCAT_WS(NULL, number, 'data') from numbers(3);
 SELECT CONCAT_WS(',', NULL); -- is emtpy, not NULL
+SELECT CONCAT_WS(',', 'data', 'bend', NULL);
+SELECT CONCAT_WS(',', NULL, 'data', '', 'bend');
+SELECT CONCAT_WS(toString(number), 'data', 'bend') from numbers(2) order by number;
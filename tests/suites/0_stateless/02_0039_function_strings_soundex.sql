@@ -22,3 +22,5 @@ SELECT SOUNDEX(1.23);
 SELECT SOUNDEX('1.23');
 SELECT SOUNDEX('pneumonoultramicroscopicsilicovolcanoconiosis');
 SELECT SOUNDEX('pneumonoultramicroscopicsilicovolcanoconiosis hello world');
+SELECT SOUNDEX('Robert'), SOUNDEX('Tymczak'), SUBSTRING(SOUNDEX('Ashcraft'), 1, 4);
+SELECT SOUNDEX('éa');
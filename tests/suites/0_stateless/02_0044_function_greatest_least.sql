@@ -0,0 +1,5 @@
+SELECT greatest(1, 5, 3), least(1, 5, 3);
+SELECT greatest(1, 2.5), least(-1, 2.5);
+SELECT greatest(NULL, 2, NULL), least(NULL, NULL);
+SELECT least('b', 'abc', 'c');
+SELECT greatest(number, 3), least(number, 3) FROM numbers(5) ORDER BY number;
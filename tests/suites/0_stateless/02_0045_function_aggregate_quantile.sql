@@ -0,0 +1,4 @@
+SELECT quantile(number) FROM numbers(1000);
+SELECT quantileExact(0.25)(number) FROM numbers_mt(101);
+SELECT quantileExact(1)(number), quantileExact(0)(number) FROM numbers_mt(10000);
+SELECT quantile(0.5)(number) BETWEEN 48000 AND 52000 FROM numbers_mt(100000);
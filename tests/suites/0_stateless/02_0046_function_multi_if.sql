@@ -0,0 +1,4 @@
+select multiIf(number > 3, 'big', number > 1, 'medium', 'small') from numbers(5) order by number;
+select multiIf(number % 2 = 0, number, null, 100, 0) from numbers(3) order by number;
+select case when number > 1 then 'a' when number > 0 then 'b' else 'c' end from numbers(3) order by number;
+select case number when 0 then 'zero' when 1 then 'one' end from numbers(3) order by number;
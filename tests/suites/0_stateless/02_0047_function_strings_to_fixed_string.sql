@@ -0,0 +1,9 @@
+SELECT hex(toFixedString('ab', 4));
+SELECT toFixedString('abcdef', 3);
+SELECT length(toFixedString('', 5));
+SELECT hex(toFixedString('数据', 4));
+SELECT toFixedString(NULL, 2);
+SELECT length(toFixedString(toString(number), 2)) FROM numbers(3) ORDER BY number;
+SELECT toFixedString(42, 5), toFixedString(-42, 5), toFixedString(12345, 5);
+SELECT toFixedString(number * 5, 2) FROM numbers(3) ORDER BY toFixedString(number * 5, 2) DESC;
+SELECT toFixedString(123456, 5); -- {ErrorCode 6}
@@ -0,0 +1,6 @@
+SELECT toString(number) FROM numbers(3) ORDER BY number;
+SELECT toString(-3), toString(1.5);
+SELECT toString(number % 2 = 0) FROM numbers(2) ORDER BY number;
+SELECT toString(toDate(18893 + number)) FROM numbers(2) ORDER BY number;
+SELECT toString(toDateTime(1632397739));
+SELECT toString(NULL);
@@ -0,0 +1,6 @@
+SELECT char(97);
+SELECT char(25968);
+SELECT ascii(char(128512));
+SELECT ascii('');
+SELECT char(NULL);
+SELECT char(number + 65) FROM numbers(3) ORDER BY number;
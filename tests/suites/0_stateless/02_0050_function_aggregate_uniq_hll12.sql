@@ -0,0 +1,5 @@
+SELECT uniqHLL12(number) FROM numbers_mt(0);
+SELECT uniqHLL12(number % 5000) BETWEEN 4750 AND 5250 FROM numbers_mt(100000);
+SELECT uniqHLL12(toString(number % 3000), number % 2) BETWEEN 2850 AND 3150 FROM numbers_mt(100000);
+SELECT uniqHLL12(NULL), uniqHLL12(number) FROM numbers(3) WHERE number > 10;
+SELECT number % 2 AS k, uniqHLL12(number) BETWEEN 19000 AND 21000 FROM numbers_mt(40000) GROUP BY k ORDER BY k;
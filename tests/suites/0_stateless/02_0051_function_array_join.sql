@@ -0,0 +1,5 @@
+SELECT arrayJoin(splitByChar(',', 'a,b,c'));
+SELECT number, arrayJoin(splitByChar(',', 'x,y')) AS e FROM numbers(2) ORDER BY number, e;
+SELECT count(arrayJoin(splitByChar(',', 'a,b,c'))) FROM numbers(4);
+SELECT arrayJoin(1); -- {ErrorCode 7}
+SELECT number FROM numbers(2) WHERE arrayJoin(splitByChar(',', 'a,b')) = 'a'; -- {ErrorCode 6}
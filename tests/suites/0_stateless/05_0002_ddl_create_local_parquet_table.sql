@@ -17,3 +17,7 @@ create table test_parquet (
 select date_string_col, avg(id), max(bigint_col) from default.test_parquet group by date_string_col order by date_string_col desc;
 
 DROP TABLE IF EXISTS default.test_parquet;
+
+create table test_parquet_inferred Engine = Parquet location = 'tests/data/alltypes_plain.parquet';
+select count(*), max(bigint_col) from default.test_parquet_inferred;
+DROP TABLE IF EXISTS default.test_parquet_inferred;